@@ -0,0 +1,41 @@
+-- Migration: Replace free-form event date/time with structured schedules
+-- Created: 2026-10-18
+--
+-- starts_at/ends_at are RFC 3339 UTC timestamps (e.g. 2025-02-15T21:00:00Z) so they
+-- sort and compare as text. timezone is the IANA zone the event is held in, and
+-- recurrence is an RRULE value such as FREQ=WEEKLY;BYDAY=FR (NULL for one-off events).
+
+ALTER TABLE events ADD COLUMN starts_at TEXT;
+ALTER TABLE events ADD COLUMN ends_at TEXT;
+ALTER TABLE events ADD COLUMN timezone TEXT NOT NULL DEFAULT 'America/Los_Angeles';
+ALTER TABLE events ADD COLUMN recurrence TEXT;
+
+-- Seed events from 0002_seed_data.sql (times are Pacific)
+UPDATE events SET starts_at = '2025-02-15T21:00:00Z', ends_at = '2025-02-16T01:00:00Z'
+WHERE uuid = '550e8400-e29b-41d4-a716-446655440001';
+
+UPDATE events SET starts_at = '2025-01-24T23:00:00Z', ends_at = '2025-01-25T01:00:00Z',
+                  recurrence = 'FREQ=WEEKLY;BYDAY=FR'
+WHERE uuid = '550e8400-e29b-41d4-a716-446655440002';
+
+UPDATE events SET starts_at = '2025-03-09T02:00:00Z', ends_at = '2025-03-09T04:00:00Z'
+WHERE uuid = '550e8400-e29b-41d4-a716-446655440003';
+
+-- Any other event's free-form date cannot be parsed reliably: anchor it at its
+-- creation time with a one hour duration so an admin can correct it
+UPDATE events SET starts_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at),
+                  ends_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at, '+1 hour')
+WHERE starts_at IS NULL;
+
+UPDATE events SET recurrence = 'FREQ=WEEKLY'
+WHERE recurring AND recurrence IS NULL;
+
+DROP INDEX IF EXISTS idx_events_date;
+DROP INDEX IF EXISTS idx_events_recurring;
+
+ALTER TABLE events DROP COLUMN date;
+ALTER TABLE events DROP COLUMN time;
+ALTER TABLE events DROP COLUMN recurring;
+
+CREATE INDEX idx_events_starts_at ON events(starts_at);
+CREATE INDEX idx_events_ends_at ON events(ends_at);
@@ -0,0 +1,14 @@
+-- Migration: Track blog post likes per visitor
+-- Created: 2026-10-18
+--
+-- visitor_hash is a salted SHA-256 of the visitor's IP address, user agent and
+-- language (see api/src/visitor.rs); no raw client data is stored.
+-- blog_posts.likes stays the denormalised total shown on the site.
+
+CREATE TABLE blog_post_likes (
+    post_id TEXT NOT NULL,
+    visitor_hash TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    PRIMARY KEY (post_id, visitor_hash),
+    FOREIGN KEY (post_id) REFERENCES blog_posts (id) ON DELETE CASCADE
+);
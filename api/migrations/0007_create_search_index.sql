@@ -0,0 +1,125 @@
+-- Migration: Full-text search index over blog posts, issues, projects and events
+-- Created: 2026-10-18
+--
+-- search_index is an FTS5 table with one row per searchable resource. kind holds the
+-- SearchResultKind variant, ref_id the resource's public id and url where it lives.
+-- Triggers keep it in sync with the source tables. blog_posts.body_text is the
+-- post's markdown as plain text; the API fills it in when a post is saved.
+
+ALTER TABLE blog_posts ADD COLUMN body_text TEXT;
+
+CREATE VIRTUAL TABLE search_index USING fts5(
+    kind UNINDEXED,
+    ref_id UNINDEXED,
+    url UNINDEXED,
+    title,
+    body,
+    tags,
+    tokenize = 'porter unicode61'
+);
+
+-- Blog posts
+
+CREATE TRIGGER blog_posts_search_insert AFTER INSERT ON blog_posts BEGIN
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    VALUES ('BlogPost', NEW.id, '/blog/' || NEW.slug, NEW.title,
+            NEW.excerpt || char(10) || coalesce(NEW.body_text, ''),
+            (SELECT group_concat(value, ' ') FROM json_each(NEW.tags)));
+END;
+
+-- Only searchable columns, so view and like counters do not rewrite the index
+CREATE TRIGGER blog_posts_search_update AFTER UPDATE OF title, slug, excerpt, tags, body_text ON blog_posts BEGIN
+    DELETE FROM search_index WHERE kind = 'BlogPost' AND ref_id = OLD.id;
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    VALUES ('BlogPost', NEW.id, '/blog/' || NEW.slug, NEW.title,
+            NEW.excerpt || char(10) || coalesce(NEW.body_text, ''),
+            (SELECT group_concat(value, ' ') FROM json_each(NEW.tags)));
+END;
+
+CREATE TRIGGER blog_posts_search_delete AFTER DELETE ON blog_posts BEGIN
+    DELETE FROM search_index WHERE kind = 'BlogPost' AND ref_id = OLD.id;
+END;
+
+-- Issues
+
+CREATE TRIGGER issues_search_insert AFTER INSERT ON issues BEGIN
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    VALUES ('Issue', NEW.uuid, NEW.github_url, NEW.title, NEW.description,
+            NEW.repo || ' ' || coalesce((SELECT group_concat(value, ' ') FROM json_each(NEW.tags)), ''));
+END;
+
+CREATE TRIGGER issues_search_update AFTER UPDATE ON issues BEGIN
+    DELETE FROM search_index WHERE kind = 'Issue' AND ref_id = OLD.uuid;
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    VALUES ('Issue', NEW.uuid, NEW.github_url, NEW.title, NEW.description,
+            NEW.repo || ' ' || coalesce((SELECT group_concat(value, ' ') FROM json_each(NEW.tags)), ''));
+END;
+
+CREATE TRIGGER issues_search_delete AFTER DELETE ON issues BEGIN
+    DELETE FROM search_index WHERE kind = 'Issue' AND ref_id = OLD.uuid;
+END;
+
+-- Projects (tags are the tech stack and skills needed)
+
+CREATE TRIGGER projects_search_insert AFTER INSERT ON projects BEGIN
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    VALUES ('Project', NEW.uuid, '/projects', NEW.name, NEW.description,
+            (SELECT group_concat(value, ' ') FROM (
+                SELECT value FROM json_each(NEW.tech_stack)
+                UNION ALL SELECT value FROM json_each(NEW.skills_needed))));
+END;
+
+CREATE TRIGGER projects_search_update AFTER UPDATE ON projects BEGIN
+    DELETE FROM search_index WHERE kind = 'Project' AND ref_id = OLD.uuid;
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    VALUES ('Project', NEW.uuid, '/projects', NEW.name, NEW.description,
+            (SELECT group_concat(value, ' ') FROM (
+                SELECT value FROM json_each(NEW.tech_stack)
+                UNION ALL SELECT value FROM json_each(NEW.skills_needed))));
+END;
+
+CREATE TRIGGER projects_search_delete AFTER DELETE ON projects BEGIN
+    DELETE FROM search_index WHERE kind = 'Project' AND ref_id = OLD.uuid;
+END;
+
+-- Events
+
+CREATE TRIGGER events_search_insert AFTER INSERT ON events BEGIN
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    VALUES ('Event', NEW.uuid, '/events', NEW.title,
+            NEW.description || char(10) || NEW.location, NEW.event_type);
+END;
+
+CREATE TRIGGER events_search_update AFTER UPDATE ON events BEGIN
+    DELETE FROM search_index WHERE kind = 'Event' AND ref_id = OLD.uuid;
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    VALUES ('Event', NEW.uuid, '/events', NEW.title,
+            NEW.description || char(10) || NEW.location, NEW.event_type);
+END;
+
+CREATE TRIGGER events_search_delete AFTER DELETE ON events BEGIN
+    DELETE FROM search_index WHERE kind = 'Event' AND ref_id = OLD.uuid;
+END;
+
+-- Index existing rows. Blog post bodies are added by POST /v1/search/reindex.
+
+INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+SELECT 'BlogPost', id, '/blog/' || slug, title, excerpt,
+       (SELECT group_concat(value, ' ') FROM json_each(blog_posts.tags))
+FROM blog_posts;
+
+INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+SELECT 'Issue', uuid, github_url, title, description,
+       repo || ' ' || coalesce((SELECT group_concat(value, ' ') FROM json_each(issues.tags)), '')
+FROM issues;
+
+INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+SELECT 'Project', uuid, '/projects', name, description,
+       (SELECT group_concat(value, ' ') FROM (
+           SELECT value FROM json_each(projects.tech_stack)
+           UNION ALL SELECT value FROM json_each(projects.skills_needed)))
+FROM projects;
+
+INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+SELECT 'Event', uuid, '/events', title, description || char(10) || location, event_type
+FROM events;
@@ -0,0 +1,20 @@
+-- Migration: Record merged pull requests by club members
+-- Created: 2026-10-18
+--
+-- One row per merged PR; /v1/stats counts these per semester. merged_at is an
+-- RFC 3339 UTC timestamp so it compares as text.
+
+CREATE TABLE contributions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    uuid TEXT UNIQUE NOT NULL, -- For public API
+    member_id INTEGER NOT NULL,
+    repo TEXT NOT NULL,
+    pr_url TEXT NOT NULL UNIQUE,
+    title TEXT NOT NULL,
+    merged_at TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (member_id) REFERENCES members (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_contributions_merged_at ON contributions(merged_at);
+CREATE INDEX idx_contributions_member ON contributions(member_id);
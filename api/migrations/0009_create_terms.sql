@@ -0,0 +1,26 @@
+-- Migration: Academic terms
+-- Created: 2026-10-18
+--
+-- Terms are the club's unit of time: stats, event archives and petition
+-- milestones are grouped by the term whose dates contain them. starts_on and
+-- ends_on are YYYY-MM-DD dates in club local time; ends_on is inclusive.
+
+CREATE TABLE terms (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    slug TEXT UNIQUE NOT NULL,
+    name TEXT NOT NULL,
+    starts_on TEXT NOT NULL,
+    ends_on TEXT NOT NULL,
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    CHECK (ends_on >= starts_on)
+);
+
+CREATE INDEX idx_terms_starts_on ON terms(starts_on);
+
+INSERT INTO terms (slug, name, starts_on, ends_on) VALUES
+    ('fall-2024', 'Fall 2024', '2024-08-26', '2024-12-20'),
+    ('spring-2025', 'Spring 2025', '2025-01-27', '2025-05-23'),
+    ('fall-2025', 'Fall 2025', '2025-08-25', '2025-12-19'),
+    ('spring-2026', 'Spring 2026', '2026-01-26', '2026-05-22'),
+    ('fall-2026', 'Fall 2026', '2026-08-24', '2026-12-18'),
+    ('spring-2027', 'Spring 2027', '2027-01-25', '2027-05-21');
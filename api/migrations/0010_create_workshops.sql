@@ -0,0 +1,84 @@
+-- Migration: Workshops and workshop registrations
+-- Created: 2026-10-18
+--
+-- Registrations beyond a workshop's capacity are stored as 'waitlisted' and
+-- promoted in signup order when a seat frees up. Only a SHA-256 hash of each
+-- confirmation token is kept. starts_at/ends_at are RFC 3339 UTC timestamps
+-- so they compare as text.
+
+CREATE TABLE workshops (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    uuid TEXT UNIQUE NOT NULL, -- For public API
+    title TEXT NOT NULL,
+    description TEXT NOT NULL,
+    level TEXT NOT NULL CHECK (level IN ('Easy', 'Medium', 'Hard')),
+    starts_at TEXT NOT NULL,
+    ends_at TEXT NOT NULL,
+    location TEXT NOT NULL,
+    instructor TEXT NOT NULL,
+    topics TEXT NOT NULL DEFAULT '[]', -- JSON array
+    prerequisites TEXT NOT NULL DEFAULT '[]', -- JSON array
+    materials_url TEXT,
+    capacity INTEGER NOT NULL CHECK (capacity > 0),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_workshops_starts_at ON workshops(starts_at);
+
+CREATE TABLE workshop_registrations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    workshop_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    email TEXT NOT NULL, -- Lowercased; never returned by public endpoints
+    token_hash TEXT UNIQUE NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('registered', 'waitlisted')),
+    created_at TEXT NOT NULL,
+    UNIQUE (workshop_id, email),
+    FOREIGN KEY (workshop_id) REFERENCES workshops (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_workshop_registrations_status ON workshop_registrations(workshop_id, status, id);
+
+-- Workshops previously hardcoded on the website
+INSERT INTO workshops (uuid, title, description, level, starts_at, ends_at, location, instructor, topics, prerequisites, capacity, created_at, updated_at) VALUES
+(
+    '7d0c7f0e-5a51-4d44-9a7e-2f1f5f0c0b01',
+    'Summer 2024 Workshop #2: CLI Tools & Open Source',
+    'Built command-line tools and made first open source contributions. Several students got their first PRs merged!',
+    'Easy', '2024-07-20T20:00:00Z', '2024-07-21T00:00:00Z', 'Online (Summer Session)', 'The Rust Club',
+    '["Command-line argument parsing with clap","File I/O and text processing","Finding good first issues","Git workflow for contributions","Code review best practices"]',
+    '[]', 40, '2024-06-01T00:00:00Z', '2024-06-01T00:00:00Z'
+),
+(
+    '7d0c7f0e-5a51-4d44-9a7e-2f1f5f0c0b02',
+    'Fall 2024 Workshop #1: Rust Fundamentals & HTTP Servers',
+    'Introduction to Rust for beginners, ending with building a simple HTTP server. Great first stepping stone into systems programming.',
+    'Easy', '2024-09-21T20:00:00Z', '2024-09-22T00:00:00Z', 'Engineering Building, Room 301', 'The Rust Club',
+    '["Rust installation and cargo basics","Ownership, borrowing, and lifetimes","Pattern matching and error handling","Building a simple HTTP server","Introduction to async programming"]',
+    '[]', 30, '2024-08-26T00:00:00Z', '2024-08-26T00:00:00Z'
+),
+(
+    '7d0c7f0e-5a51-4d44-9a7e-2f1f5f0c0b03',
+    'Fall 2024 Workshop #2: Game Development with Bevy',
+    'Built a complete 2D game using the Bevy engine. Students created portfolio projects they could show to potential employers.',
+    'Medium', '2024-11-16T21:00:00Z', '2024-11-17T01:00:00Z', 'Engineering Building, Room 301', 'The Rust Club',
+    '["Bevy ECS (Entity Component System)","Asset loading and sprite rendering","Game logic and state management","Input handling and physics","Packaging and distribution"]',
+    '[]', 30, '2024-08-26T00:00:00Z', '2024-08-26T00:00:00Z'
+),
+(
+    '7d0c7f0e-5a51-4d44-9a7e-2f1f5f0c0b04',
+    'Spring 2025 Workshop #1: Rust Fundamentals & CLI Tools',
+    'Our first workshop this semester! Perfect for newcomers to Rust. Learn the fundamentals and build your first CLI tool to add to your portfolio.',
+    'Easy', '2025-02-15T21:00:00Z', '2025-02-16T01:00:00Z', 'Engineering Building, Room 301', 'The Rust Club',
+    '["Setting up Rust development environment","Understanding ownership and borrowing","Working with Cargo and crates.io","Building a command-line todo application","Error handling with Result<T, E>","Testing your Rust code"]',
+    '[]', 30, '2025-01-27T00:00:00Z', '2025-01-27T00:00:00Z'
+),
+(
+    '7d0c7f0e-5a51-4d44-9a7e-2f1f5f0c0b05',
+    'Spring 2025 Workshop #2: Web Development & Deployment',
+    'Our second and final workshop this semester. Build a complete web application with Rust and deploy it to the cloud. Great for your resume!',
+    'Medium', '2025-04-12T20:00:00Z', '2025-04-13T00:00:00Z', 'Engineering Building, Room 301', 'The Rust Club',
+    '["Web frameworks: Actix-Web vs Axum vs Rocket","RESTful API design and implementation","Database integration with SQLx","Frontend integration (connecting with React/Vue)","Deployment to cloud platforms (AWS/Railway/Shuttle)","Adding your project to your portfolio"]',
+    '[]', 30, '2025-01-27T00:00:00Z', '2025-01-27T00:00:00Z'
+);
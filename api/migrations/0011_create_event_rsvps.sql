@@ -0,0 +1,39 @@
+-- Migration: Event RSVPs, capacity and attendance check-ins
+-- Created: 2026-10-18
+--
+-- A member has at most one RSVP per event; for recurring events it covers the
+-- whole series. Only 'going' RSVPs count against capacity (NULL = no limit).
+-- Only a SHA-256 hash of each RSVP token is kept. Check-ins record attendance
+-- per occurrence, keyed by the occurrence's RFC 3339 UTC start, so a weekly
+-- study group yields one row per member per week.
+
+ALTER TABLE events ADD COLUMN capacity INTEGER CHECK (capacity IS NULL OR capacity > 0);
+
+CREATE TABLE event_rsvps (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_id INTEGER NOT NULL,
+    member_id INTEGER NOT NULL,
+    token_hash TEXT UNIQUE NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('going', 'maybe', 'not_going')),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    UNIQUE (event_id, member_id),
+    FOREIGN KEY (event_id) REFERENCES events (id) ON DELETE CASCADE,
+    FOREIGN KEY (member_id) REFERENCES members (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_event_rsvps_status ON event_rsvps(event_id, status);
+
+CREATE TABLE event_check_ins (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_id INTEGER NOT NULL,
+    member_id INTEGER NOT NULL,
+    occurrence_starts_at TEXT NOT NULL,
+    checked_in_at TEXT NOT NULL,
+    UNIQUE (event_id, member_id, occurrence_starts_at),
+    FOREIGN KEY (event_id) REFERENCES events (id) ON DELETE CASCADE,
+    FOREIGN KEY (member_id) REFERENCES members (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_event_check_ins_occurrence ON event_check_ins(occurrence_starts_at);
+CREATE INDEX idx_event_check_ins_member ON event_check_ins(member_id);
@@ -0,0 +1,69 @@
+-- Migration: Advocacy courses, their status history and petition signatures
+-- Created: 2026-10-18
+--
+-- courses.status is the latest entry in course_status_changes; the API writes
+-- both together. A signature with a NULL course_id is on the petition itself,
+-- otherwise it backs that course. identity is the signer's lowercased campus
+-- email or GitHub username, prefixed "email:" or "github:", and is unique per
+-- course (and once for the petition); it is never returned by the API.
+
+CREATE TABLE courses (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    slug TEXT UNIQUE NOT NULL,
+    course_code TEXT NOT NULL,
+    course_name TEXT NOT NULL,
+    department TEXT NOT NULL,
+    professor TEXT,
+    description TEXT,
+    status TEXT NOT NULL DEFAULT 'Pending' CHECK (status IN ('Approved', 'UnderReview', 'Pending', 'Rejected')),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE TABLE course_status_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    course_id INTEGER NOT NULL,
+    status TEXT NOT NULL CHECK (status IN ('Approved', 'UnderReview', 'Pending', 'Rejected')),
+    note TEXT,
+    changed_at TEXT NOT NULL,
+    FOREIGN KEY (course_id) REFERENCES courses (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_course_status_changes_course ON course_status_changes(course_id, changed_at);
+
+CREATE TABLE petition_signatures (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    course_id INTEGER,
+    name TEXT NOT NULL,
+    identity TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (course_id) REFERENCES courses (id) ON DELETE CASCADE
+);
+
+-- COALESCE so the petition's own signatures (NULL course_id) are deduplicated too
+CREATE UNIQUE INDEX idx_petition_signatures_identity ON petition_signatures(COALESCE(course_id, 0), identity);
+CREATE INDEX idx_petition_signatures_course ON petition_signatures(course_id);
+
+-- Courses previously hardcoded on the advocacy page. Their supporter counts
+-- were placeholders and are not carried over.
+INSERT INTO courses (slug, course_code, course_name, department, status, created_at, updated_at) VALUES
+('cs-311', 'CS 311', 'Computer Architecture', 'Computer Science', 'UnderReview', '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+('cs-211', 'CS 211', 'Programming & Algorithms II', 'Computer Science', 'Pending', '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+('cs-370', 'CS 370', 'Parallel Programming', 'Computer Science', 'Approved', '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+('cs-340', 'CS 340', 'Operating Systems', 'Computer Science', 'UnderReview', '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z'),
+('eece-344', 'EECE 344', 'Embedded Systems', 'Electrical Engineering', 'Pending', '2026-10-18T00:00:00Z', '2026-10-18T00:00:00Z');
+
+-- CS 370 was approved in Fall 2024; the exact day was not recorded, so the
+-- milestone is dated to the end of that term. Other courses start their
+-- history at their current status.
+INSERT INTO course_status_changes (course_id, status, note, changed_at)
+SELECT id, status,
+       CASE WHEN slug = 'cs-370' THEN 'Approved for Rust submissions by the CS department' END,
+       CASE WHEN slug = 'cs-370' THEN '2024-12-20T08:00:00Z' ELSE created_at END
+FROM courses;
+
+UPDATE courses
+SET description = 'After presenting the benefits of Rust''s fearless concurrency and demonstrating how it prevents '
+    || 'data races at compile time, the CS department approved Rust for CS 370 submissions. Students using '
+    || 'Rust reported fewer debugging hours and better understanding of parallel programming concepts.'
+WHERE slug = 'cs-370';
@@ -0,0 +1,12 @@
+-- Migration: Link members to GitHub accounts for sign-in
+-- Created: 2026-10-18
+--
+-- Signing in with GitHub links the account to the member with the same
+-- github_username, or creates a member if there is none. The numeric GitHub
+-- id is stored on first sign-in so the link survives a username change.
+-- Sessions live in the CACHE KV namespace, not here.
+
+ALTER TABLE members ADD COLUMN github_id INTEGER;
+ALTER TABLE members ADD COLUMN avatar_url TEXT;
+
+CREATE UNIQUE INDEX idx_members_github_id ON members(github_id);
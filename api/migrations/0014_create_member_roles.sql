@@ -0,0 +1,34 @@
+-- Migration: Member roles and the role audit trail
+-- Created: 2026-10-18
+--
+-- Roles gate the write endpoints: officers (President, VicePresident,
+-- Secretary, Treasurer) manage everything, project leads edit the projects
+-- they lead and mentors curate issues. 'Member' is implicit and never stored.
+-- Every grant and revocation is recorded in role_changes with who made it.
+-- The member's name and username are copied so the trail outlives the member.
+
+CREATE TABLE member_roles (
+    member_id INTEGER NOT NULL,
+    role TEXT NOT NULL CHECK (role IN ('President', 'VicePresident', 'Secretary', 'Treasurer',
+                                       'ProjectLead', 'Mentor', 'Alumni')),
+    granted_at TEXT NOT NULL,
+    PRIMARY KEY (member_id, role),
+    FOREIGN KEY (member_id) REFERENCES members (id) ON DELETE CASCADE
+);
+
+CREATE TABLE role_changes (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    uuid TEXT UNIQUE NOT NULL,
+    member_id INTEGER,
+    member_name TEXT NOT NULL,
+    member_github TEXT,
+    role TEXT NOT NULL,
+    action TEXT NOT NULL CHECK (action IN ('granted', 'revoked')),
+    changed_by TEXT NOT NULL,
+    reason TEXT,
+    changed_at TEXT NOT NULL,
+    FOREIGN KEY (member_id) REFERENCES members (id) ON DELETE SET NULL
+);
+
+CREATE INDEX idx_role_changes_member ON role_changes(member_id);
+CREATE INDEX idx_role_changes_changed_at ON role_changes(changed_at);
@@ -0,0 +1,20 @@
+-- Migration: Member profiles and privacy settings
+-- Created: 2026-10-18
+--
+-- Members write their own bio and skills and choose who sees their profile:
+-- everyone ('public'), signed-in members ('members') or nobody but
+-- themselves ('hidden'). show_attendance controls whether the events and
+-- workshops they attended appear on it. Existing members were already shown
+-- on project and blog pages, so they start out public.
+--
+-- email stays private whatever the settings; it is only used to match
+-- workshop registrations, which are made by email, to the member.
+
+ALTER TABLE members ADD COLUMN bio TEXT;
+ALTER TABLE members ADD COLUMN skills TEXT NOT NULL DEFAULT '[]'; -- JSON array
+ALTER TABLE members ADD COLUMN profile_visibility TEXT NOT NULL DEFAULT 'public'
+    CHECK (profile_visibility IN ('public', 'members', 'hidden'));
+ALTER TABLE members ADD COLUMN show_attendance INTEGER NOT NULL DEFAULT 1;
+
+CREATE INDEX idx_members_name ON members(name);
+CREATE INDEX idx_workshop_registrations_email ON workshop_registrations(email);
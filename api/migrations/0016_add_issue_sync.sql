@@ -0,0 +1,39 @@
+-- Migration: Sync good first issues from GitHub
+-- Created: 2026-10-18
+--
+-- A scheduled job pulls open issues labelled "good first issue" or "help
+-- wanted" from the repositories in the ISSUE_SYNC_REPOS var and upserts them
+-- here by github_url. Synced rows have source 'github'; each run stamps the
+-- ones it saw with synced_at, and synced issues in a repo that the run did
+-- not see were resolved (or unlabelled) upstream, so they are closed.
+--
+-- Issues entered by hand keep source 'manual' and are never touched by the
+-- sync. Editing a synced issue through the API turns it into a manual one,
+-- so a mentor's curation is not overwritten on the next run.
+--
+-- Only open issues are listed or searchable.
+
+ALTER TABLE issues ADD COLUMN source TEXT NOT NULL DEFAULT 'manual' CHECK (source IN ('manual', 'github'));
+ALTER TABLE issues ADD COLUMN state TEXT NOT NULL DEFAULT 'open' CHECK (state IN ('open', 'closed'));
+ALTER TABLE issues ADD COLUMN synced_at TEXT;
+ALTER TABLE issues ADD COLUMN closed_at TEXT;
+
+CREATE INDEX idx_issues_state ON issues(state, created_at);
+CREATE INDEX idx_issues_sync ON issues(source, repo, synced_at);
+
+DROP TRIGGER issues_search_insert;
+DROP TRIGGER issues_search_update;
+
+CREATE TRIGGER issues_search_insert AFTER INSERT ON issues WHEN NEW.state = 'open' BEGIN
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    VALUES ('Issue', NEW.uuid, NEW.github_url, NEW.title, NEW.description,
+            NEW.repo || ' ' || coalesce((SELECT group_concat(value, ' ') FROM json_each(NEW.tags)), ''));
+END;
+
+CREATE TRIGGER issues_search_update AFTER UPDATE ON issues BEGIN
+    DELETE FROM search_index WHERE kind = 'Issue' AND ref_id = OLD.uuid;
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    SELECT 'Issue', NEW.uuid, NEW.github_url, NEW.title, NEW.description,
+           NEW.repo || ' ' || coalesce((SELECT group_concat(value, ' ') FROM json_each(NEW.tags)), '')
+    WHERE NEW.state = 'open';
+END;
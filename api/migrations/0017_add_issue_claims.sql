@@ -0,0 +1,48 @@
+-- Migration: Issue claims and the contribution lifecycle
+-- Created: 2026-10-18
+--
+-- A contribution now starts when a member claims an issue and moves
+-- claimed -> pr_opened -> merged. A claim with no PR by claim_expires_at
+-- becomes expired, and the member (or a mentor) can release a claim or an
+-- open PR; either frees the issue for someone else. At most one active
+-- (claimed or pr_opened) contribution holds an issue at a time.
+--
+-- Until a PR is opened there is no pr_url or merged_at, and SQLite can only
+-- drop NOT NULL by rebuilding the table. Existing rows are merged PRs that
+-- were recorded by hand, so they carry over as 'merged' with no issue.
+-- Timestamps stay RFC 3339 UTC text so they compare as text.
+
+PRAGMA defer_foreign_keys = true;
+
+CREATE TABLE contributions_new (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    uuid TEXT UNIQUE NOT NULL, -- For public API
+    member_id INTEGER NOT NULL,
+    issue_id INTEGER,
+    state TEXT NOT NULL CHECK (state IN ('claimed', 'pr_opened', 'merged', 'expired', 'released')),
+    repo TEXT NOT NULL,
+    pr_url TEXT UNIQUE,
+    title TEXT NOT NULL, -- The issue's title until a PR is opened, then the PR's
+    claimed_at TEXT,
+    claim_expires_at TEXT, -- Cleared once a PR is opened
+    pr_opened_at TEXT,
+    merged_at TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (member_id) REFERENCES members (id) ON DELETE CASCADE,
+    FOREIGN KEY (issue_id) REFERENCES issues (id) ON DELETE SET NULL,
+    CHECK (state NOT IN ('pr_opened', 'merged') OR pr_url IS NOT NULL),
+    CHECK (state != 'merged' OR merged_at IS NOT NULL)
+);
+
+INSERT INTO contributions_new (id, uuid, member_id, state, repo, pr_url, title, merged_at, created_at, updated_at)
+SELECT id, uuid, member_id, 'merged', repo, pr_url, title, merged_at, created_at, created_at
+FROM contributions;
+
+DROP TABLE contributions;
+ALTER TABLE contributions_new RENAME TO contributions;
+
+CREATE INDEX idx_contributions_merged_at ON contributions(merged_at);
+CREATE INDEX idx_contributions_member ON contributions(member_id, created_at);
+CREATE UNIQUE INDEX idx_contributions_active_issue ON contributions(issue_id) WHERE state IN ('claimed', 'pr_opened');
+CREATE INDEX idx_contributions_claim_expiry ON contributions(claim_expires_at) WHERE state = 'claimed';
@@ -0,0 +1,42 @@
+-- Migration: Blog post drafts, review and scheduled publishing
+-- Created: 2026-10-18
+--
+-- Posts move draft -> in_review -> scheduled -> published -> archived, and
+-- only published posts are listed, served, liked or searchable. New posts
+-- start as drafts; the posts already here were public, so they stay
+-- published. A scheduled post goes live when the cron reaches published_at.
+--
+-- An officer may assign a reviewer, who can send the post back to draft or
+-- approve it. Authors share unpublished posts through a preview link: only
+-- the token's hash is stored, and the link stops working at
+-- preview_expires_at or when a new link replaces it.
+
+ALTER TABLE blog_posts ADD COLUMN status TEXT NOT NULL DEFAULT 'published'
+    CHECK (status IN ('draft', 'in_review', 'scheduled', 'published', 'archived'));
+ALTER TABLE blog_posts ADD COLUMN reviewer_id INTEGER REFERENCES members (id) ON DELETE SET NULL;
+ALTER TABLE blog_posts ADD COLUMN preview_token_hash TEXT;
+ALTER TABLE blog_posts ADD COLUMN preview_expires_at TEXT;
+
+CREATE INDEX idx_blog_posts_status ON blog_posts(status, published_at);
+CREATE INDEX idx_blog_posts_reviewer ON blog_posts(reviewer_id, status);
+CREATE UNIQUE INDEX idx_blog_posts_preview ON blog_posts(preview_token_hash);
+
+DROP TRIGGER blog_posts_search_insert;
+DROP TRIGGER blog_posts_search_update;
+
+CREATE TRIGGER blog_posts_search_insert AFTER INSERT ON blog_posts WHEN NEW.status = 'published' BEGIN
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    VALUES ('BlogPost', NEW.id, '/blog/' || NEW.slug, NEW.title,
+            NEW.excerpt || char(10) || coalesce(NEW.body_text, ''),
+            (SELECT group_concat(value, ' ') FROM json_each(NEW.tags)));
+END;
+
+-- Only searchable columns and status, so view and like counters do not rewrite the index
+CREATE TRIGGER blog_posts_search_update AFTER UPDATE OF title, slug, excerpt, tags, body_text, status ON blog_posts BEGIN
+    DELETE FROM search_index WHERE kind = 'BlogPost' AND ref_id = OLD.id;
+    INSERT INTO search_index (kind, ref_id, url, title, body, tags)
+    SELECT 'BlogPost', NEW.id, '/blog/' || NEW.slug, NEW.title,
+           NEW.excerpt || char(10) || coalesce(NEW.body_text, ''),
+           (SELECT group_concat(value, ' ') FROM json_each(NEW.tags))
+    WHERE NEW.status = 'published';
+END;
@@ -0,0 +1,36 @@
+-- Migration: Blog post revision history
+-- Created: 2026-10-18
+--
+-- Every save of a post stores an immutable revision: the metadata as it was
+-- sent (BlogPostInput JSON) and the markdown fetched from markdown_url at the
+-- time, or NULL if it could not be fetched. Revisions are numbered per post
+-- from 1 and are only ever inserted; restoring an old one adds a new revision
+-- that records which one it came from.
+--
+-- markdown_hash is the hex SHA-256 of the markdown. The API serves each
+-- snapshot by its hash, so a restored post whose markdown_url has since been
+-- overwritten can point at the snapshot instead.
+--
+-- published_at is when a revision went live: at save time if the post was
+-- already published, otherwise when the post is next published. A post's
+-- updated_at is read from its latest published revision. Posts saved before
+-- this migration have no revisions until their next save and keep the
+-- updated_at stored on blog_posts.
+
+CREATE TABLE blog_post_revisions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    post_id TEXT NOT NULL,
+    number INTEGER NOT NULL,
+    metadata TEXT NOT NULL, -- BlogPostInput as JSON
+    markdown TEXT,
+    markdown_hash TEXT,
+    restored_from INTEGER, -- Revision number this one was restored from
+    created_by TEXT NOT NULL, -- Actor label, e.g. '@octocat' or 'admin token'
+    created_at TEXT NOT NULL,
+    published_at TEXT,
+    FOREIGN KEY (post_id) REFERENCES blog_posts (id) ON DELETE CASCADE,
+    UNIQUE (post_id, number)
+);
+
+CREATE INDEX idx_blog_post_revisions_published ON blog_post_revisions(post_id, published_at);
+CREATE INDEX idx_blog_post_revisions_markdown ON blog_post_revisions(markdown_hash);
@@ -0,0 +1,57 @@
+-- Migration: Blog series as their own resource
+-- Created: 2026-10-18
+--
+-- Series used to be copied onto every post as series_title and
+-- series_total_parts, so the parts of a series only agreed with each other
+-- by convention. A series now has its own row with a slug, and posts point
+-- at it with series_id and keep their series_part. planned_parts is how many
+-- parts the series is meant to have; while it is NULL, "part N of M" counts
+-- the published parts instead.
+--
+-- Existing series are created from the distinct titles already on posts.
+-- Their slugs are the lowercased title with spaces turned into hyphens and
+-- common punctuation removed. Revisions store BlogPostInput as JSON, so
+-- their series is rewritten to the new shape that names the series by slug.
+
+CREATE TABLE blog_series (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    slug TEXT UNIQUE NOT NULL,
+    title TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    planned_parts INTEGER CHECK (planned_parts IS NULL OR planned_parts > 0),
+    created_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP,
+    updated_at TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+ALTER TABLE blog_posts ADD COLUMN series_id INTEGER REFERENCES blog_series (id) ON DELETE SET NULL;
+
+INSERT INTO blog_series (slug, title, planned_parts)
+SELECT replace(replace(replace(replace(replace(replace(lower(trim(series_title)),
+           ' ', '-'), ':', ''), ',', ''), '''', ''), '.', ''), '?', ''),
+       trim(series_title),
+       max(series_total_parts)
+FROM blog_posts
+WHERE series_title IS NOT NULL AND trim(series_title) != ''
+GROUP BY trim(series_title);
+
+UPDATE blog_posts
+SET series_id = (SELECT s.id FROM blog_series s WHERE s.title = trim(blog_posts.series_title))
+WHERE series_title IS NOT NULL;
+
+UPDATE blog_posts SET series_part = NULL WHERE series_id IS NULL;
+
+UPDATE blog_post_revisions
+SET metadata = json_set(metadata, '$.series', json_object(
+        'slug', (SELECT s.slug FROM blog_series s WHERE s.title = trim(json_extract(metadata, '$.series.title'))),
+        'part', json_extract(metadata, '$.series.part')))
+WHERE trim(json_extract(metadata, '$.series.title')) IN (SELECT title FROM blog_series);
+
+UPDATE blog_post_revisions
+SET metadata = json_set(metadata, '$.series', json('null'))
+WHERE json_type(metadata, '$.series') = 'object' AND json_extract(metadata, '$.series.slug') IS NULL;
+
+DROP INDEX idx_blog_posts_series;
+ALTER TABLE blog_posts DROP COLUMN series_title;
+ALTER TABLE blog_posts DROP COLUMN series_total_parts;
+
+CREATE INDEX idx_blog_posts_series ON blog_posts(series_id, series_part);
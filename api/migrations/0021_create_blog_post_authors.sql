@@ -0,0 +1,23 @@
+-- Migration: Blog post co-authors
+-- Created: 2026-10-18
+--
+-- Every member credited on a post has a row here. The lead author stays on
+-- blog_posts (author_name, author_github) and sits at position 0 when they
+-- are a member; co-authors follow from position 1 in byline order. Author
+-- pages list a member's posts from this table, so existing posts are linked
+-- to the member named in author_github.
+
+CREATE TABLE blog_post_authors (
+    post_id TEXT NOT NULL,
+    member_id INTEGER NOT NULL,
+    position INTEGER NOT NULL, -- 0 for the lead author, then byline order
+    PRIMARY KEY (post_id, member_id),
+    FOREIGN KEY (post_id) REFERENCES blog_posts (id) ON DELETE CASCADE,
+    FOREIGN KEY (member_id) REFERENCES members (id) ON DELETE CASCADE
+);
+
+CREATE INDEX idx_blog_post_authors_member ON blog_post_authors(member_id);
+
+INSERT INTO blog_post_authors (post_id, member_id, position)
+SELECT b.id, m.id, 0
+FROM blog_posts b JOIN members m ON m.github_username = b.author_github COLLATE NOCASE;
@@ -0,0 +1,15 @@
+-- Migration: Idempotent blog view flushes
+-- Created: 2026-10-18
+--
+-- Views are counted in KV, one counter per post per five-minute window, and
+-- the scheduled flush adds the counters of closed windows to
+-- blog_posts.views. Each counter it applies gets a row here in the same
+-- batch, and the count is only added when that row is new, so a flush that
+-- fails before deleting its KV keys does not count them again on the next
+-- run. Rows are pruned once the KV counters they guard have expired.
+
+CREATE TABLE blog_view_flushes (
+    window_id INTEGER NOT NULL, -- unix time divided by the window length
+    slug TEXT NOT NULL,
+    PRIMARY KEY (window_id, slug)
+);
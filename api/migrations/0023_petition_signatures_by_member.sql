@@ -0,0 +1,17 @@
+-- Migration: Petition signatures from signed-in members only
+-- Created: 2026-10-18
+--
+-- Signatures used to be keyed on a campus email or GitHub username typed
+-- into the form, which nobody verified. Signing now needs a session, and
+-- identity is "member:<members.id>". Signatures whose GitHub username
+-- belongs to a member move to that member; the rest cannot be tied to
+-- anyone and are removed.
+
+UPDATE OR IGNORE petition_signatures
+SET identity = 'member:' || (
+    SELECT m.id FROM members m
+    WHERE 'github:' || lower(m.github_username) = petition_signatures.identity
+)
+WHERE identity IN (SELECT 'github:' || lower(github_username) FROM members WHERE github_username IS NOT NULL);
+
+DELETE FROM petition_signatures WHERE identity NOT LIKE 'member:%';
@@ -0,0 +1,13 @@
+-- Migration: Link workshop registrations to the member who made them
+-- Created: 2026-10-18
+--
+-- Registrations made while signed in record the member, and member profiles
+-- list workshops by that link rather than by matching the registration's
+-- email to the member's. Anyone can register under any address, so an email
+-- match said nothing about who attended. Earlier registrations have no
+-- member and drop off profiles.
+
+ALTER TABLE workshop_registrations ADD COLUMN member_id INTEGER REFERENCES members (id) ON DELETE SET NULL;
+
+DROP INDEX idx_workshop_registrations_email;
+CREATE INDEX idx_workshop_registrations_member ON workshop_registrations(member_id);
@@ -0,0 +1,85 @@
+-- Migration: Store every created_at/updated_at in one format
+-- Created: 2026-10-18
+--
+-- List endpoints page by created_at, comparing it as text, but rows were
+-- written in three formats: SQLite's CURRENT_TIMESTAMP ('2026-10-18
+-- 12:00:00'), RFC 3339 with nanoseconds and '+00:00', and RFC 3339 in whole
+-- seconds with 'Z'. Mixed formats sort out of order and make a cursor from
+-- one skip or repeat rows of another. The API now writes only the last form;
+-- this rewrites the rest to match. blog_posts.updated_at is the author's own
+-- date and is kept as entered.
+
+UPDATE members SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE members SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', updated_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', updated_at) IS NOT NULL AND updated_at != strftime('%Y-%m-%dT%H:%M:%SZ', updated_at);
+
+UPDATE events SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE events SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', updated_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', updated_at) IS NOT NULL AND updated_at != strftime('%Y-%m-%dT%H:%M:%SZ', updated_at);
+
+UPDATE issues SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE issues SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', updated_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', updated_at) IS NOT NULL AND updated_at != strftime('%Y-%m-%dT%H:%M:%SZ', updated_at);
+
+UPDATE projects SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE projects SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', updated_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', updated_at) IS NOT NULL AND updated_at != strftime('%Y-%m-%dT%H:%M:%SZ', updated_at);
+
+UPDATE project_contributors SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE blog_posts SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE blog_post_likes SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE blog_post_revisions SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE blog_series SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE blog_series SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', updated_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', updated_at) IS NOT NULL AND updated_at != strftime('%Y-%m-%dT%H:%M:%SZ', updated_at);
+
+UPDATE terms SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE workshops SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE workshops SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', updated_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', updated_at) IS NOT NULL AND updated_at != strftime('%Y-%m-%dT%H:%M:%SZ', updated_at);
+
+UPDATE workshop_registrations SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE event_rsvps SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE event_rsvps SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', updated_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', updated_at) IS NOT NULL AND updated_at != strftime('%Y-%m-%dT%H:%M:%SZ', updated_at);
+
+UPDATE courses SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE courses SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', updated_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', updated_at) IS NOT NULL AND updated_at != strftime('%Y-%m-%dT%H:%M:%SZ', updated_at);
+
+UPDATE petition_signatures SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE contributions SET created_at = strftime('%Y-%m-%dT%H:%M:%SZ', created_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', created_at) IS NOT NULL AND created_at != strftime('%Y-%m-%dT%H:%M:%SZ', created_at);
+
+UPDATE contributions SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', updated_at)
+WHERE strftime('%Y-%m-%dT%H:%M:%SZ', updated_at) IS NOT NULL AND updated_at != strftime('%Y-%m-%dT%H:%M:%SZ', updated_at);
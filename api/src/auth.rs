@@ -0,0 +1,141 @@
+//! Who may call the write endpoints. Requests are authorized either by the
+//! `ADMIN_TOKEN` bearer secret (scripts, bootstrapping the first officer) or
+//! by a signed-in member's roles.
+
+use worker::{Env, Request};
+
+use crate::database::DatabaseService;
+use crate::error::database_error;
+use crate::models::*;
+use crate::session;
+
+/// Name of the Worker secret holding the admin bearer token.
+/// Set with: wrangler secret put ADMIN_TOKEN
+const ADMIN_TOKEN_BINDING: &str = "ADMIN_TOKEN";
+
+/// Check the request's `Authorization: Bearer <token>` header against the
+/// `ADMIN_TOKEN` secret. Fails closed if the secret is not configured.
+pub fn is_admin(req: &Request, env: &Env) -> bool {
+    let expected = match env.secret(ADMIN_TOKEN_BINDING) {
+        Ok(secret) => secret.to_string(),
+        Err(_) => return false,
+    };
+    if expected.is_empty() {
+        return false;
+    }
+
+    match bearer_token(req) {
+        Some(token) => constant_time_eq(token.as_bytes(), expected.as_bytes()),
+        None => false,
+    }
+}
+
+/// What a write endpoint requires of its caller
+pub enum Access<'a> {
+    /// Officers only: events, workshops, terms, courses, blog, roles, ...
+    Officer,
+    /// Issue curation: officers and mentors
+    Issues,
+    /// One project, by id: officers and the project lead who leads it
+    Project(&'a str),
+}
+
+/// Who made an authorized request, as recorded in audit trails
+pub enum Actor {
+    /// Holder of the admin bearer token
+    AdminToken,
+    Member {
+        github_username: String,
+        roles: Vec<MemberRole>,
+    },
+}
+
+impl Actor {
+    /// `@username`, or `admin token`
+    pub fn label(&self) -> String {
+        match self {
+            Actor::AdminToken => "admin token".to_string(),
+            Actor::Member { github_username, .. } => format!("@{}", github_username),
+        }
+    }
+
+    fn has_role(&self, role: MemberRole) -> bool {
+        match self {
+            Actor::AdminToken => false,
+            Actor::Member { roles, .. } => roles.contains(&role),
+        }
+    }
+
+    fn is_officer(&self) -> bool {
+        match self {
+            Actor::AdminToken => true,
+            Actor::Member { roles, .. } => roles.iter().any(MemberRole::is_officer),
+        }
+    }
+}
+
+/// Guard for write handlers: `Ok` carries who is acting, `Err` the 401 or
+/// 403 to return. The admin token passes every check; members pass through
+/// their roles.
+pub async fn authorize(req: &Request, env: &Env, access: Access<'_>) -> Result<Actor, ApiError> {
+    if is_admin(req, env) {
+        return Ok(Actor::AdminToken);
+    }
+
+    let session = match session::current(req, env).await {
+        Ok(Some(session)) => session,
+        Ok(None) => return Err(ApiError::unauthorized("Sign in, or send a valid admin bearer token")),
+        Err(e) => {
+            web_sys::console::error_1(&format!("Failed to load session: {:?}", e).into());
+            return Err(ApiError::internal("Unable to check your session at this time"));
+        }
+    };
+    let db = env.d1("DB").map_err(|e| database_error("open database", e))?;
+    let roles = DatabaseService::get_member_roles(&db, session.member_id)
+        .await
+        .map_err(|e| database_error("load roles", e))?;
+    let actor = Actor::Member {
+        github_username: session.github_username,
+        roles,
+    };
+
+    let allowed = actor.is_officer()
+        || match access {
+            Access::Officer => false,
+            Access::Issues => actor.has_role(MemberRole::Mentor),
+            Access::Project(project_id) => {
+                actor.has_role(MemberRole::ProjectLead)
+                    && DatabaseService::is_project_leader(&db, project_id, session.member_id)
+                        .await
+                        .map_err(|e| database_error("check project lead", e))?
+            }
+        };
+    if allowed {
+        Ok(actor)
+    } else {
+        Err(ApiError::forbidden("Your roles do not allow this change"))
+    }
+}
+
+/// Extract the token from an `Authorization: Bearer <token>` header
+pub fn bearer_token(req: &Request) -> Option<String> {
+    let header = req.headers().get("Authorization").ok()??;
+    let (scheme, token) = header.split_once(' ')?;
+    if !scheme.eq_ignore_ascii_case("bearer") {
+        return None;
+    }
+    let token = token.trim();
+    if token.is_empty() {
+        None
+    } else {
+        Some(token.to_string())
+    }
+}
+
+/// Compare two byte strings without short-circuiting on the first mismatch
+pub(crate) fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
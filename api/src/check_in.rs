@@ -0,0 +1,41 @@
+//! Door codes for event check-in. An organiser issues a code for an event and
+//! shows it (or its QR payload) at the door; members redeem it while it is
+//! valid. Each event has one live code in the CACHE KV namespace, so issuing
+//! a new one retires the old. Only the code's hash is stored.
+
+use chrono::{Duration, Utc};
+use worker::{kv::KvStore, Result};
+
+use crate::models::*;
+use crate::tokens;
+
+const CODE_PREFIX: &str = "check_in_code:";
+
+/// How long a code can be redeemed after it is issued
+const CODE_TTL_SECS: u64 = 15 * 60;
+
+fn code_key(event_id: &str) -> String {
+    format!("{}{}", CODE_PREFIX, event_id)
+}
+
+/// Issue a fresh code for an event, replacing any earlier one. The QR
+/// payload opens the check-in page on `site_url`.
+pub async fn issue(kv: &KvStore, event_id: &str, site_url: &str) -> Result<CheckInCode> {
+    let code = tokens::new_short_code();
+    kv.put(&code_key(event_id), tokens::token_hash(&code))?
+        .expiration_ttl(CODE_TTL_SECS)
+        .execute()
+        .await?;
+    Ok(CheckInCode {
+        qr_payload: format!("{}/check-in?event={}&code={}", site_url, event_id, code),
+        expires_at: Utc::now() + Duration::seconds(CODE_TTL_SECS as i64),
+        code,
+    })
+}
+
+/// Whether `code` is the event's live code. Case and surrounding whitespace
+/// are ignored since people type it from a screen.
+pub async fn is_valid(kv: &KvStore, event_id: &str, code: &str) -> Result<bool> {
+    let stored = kv.get(&code_key(event_id)).text().await?;
+    Ok(stored.is_some_and(|hash| hash == tokens::token_hash(&code.trim().to_uppercase())))
+}
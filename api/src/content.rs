@@ -0,0 +1,381 @@
+//! Blog content: markdown and images uploaded to R2, fetching markdown from
+//! wherever a post points, hashing revision snapshots and flattening
+//! markdown to plain text for the search index
+
+use markdown::mdast::Node;
+use markdown::ParseOptions;
+use sha2::{Digest, Sha256};
+use worker::{Bucket, Fetch, HttpMetadata, Url};
+
+use crate::models::*;
+
+/// Name of the R2 bucket binding in wrangler.toml
+pub const BUCKET_BINDING: &str = "CONTENT";
+
+/// Cache header for stored content: keys are content hashes, so a key's
+/// bytes never change
+pub const IMMUTABLE_CACHE_CONTROL: &str = "public, max-age=31536000, immutable";
+
+/// Fetch a post's markdown source. Failures are logged and yield `None`, so
+/// callers can fall back to the excerpt.
+pub async fn fetch_markdown(markdown_url: &str) -> Option<String> {
+    let url = Url::parse(markdown_url).ok()?;
+    let mut response = match Fetch::Url(url).send().await {
+        Ok(response) if response.status_code() == 200 => response,
+        Ok(response) => {
+            web_sys::console::warn_1(
+                &format!("Markdown fetch for {} returned {}", markdown_url, response.status_code()).into(),
+            );
+            return None;
+        }
+        Err(e) => {
+            web_sys::console::warn_1(&format!("Markdown fetch for {} failed: {:?}", markdown_url, e).into());
+            return None;
+        }
+    };
+    response.text().await.ok()
+}
+
+/// Hex SHA-256 of a markdown snapshot, naming it at `/v1/blog/markdown/{hash}`
+pub fn markdown_hash(markdown: &str) -> String {
+    sha256_hex(markdown.as_bytes())
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+/// Largest upload of any kind, in bytes
+pub const MAX_UPLOAD_BYTES: usize = 5 * 1024 * 1024;
+
+/// A kind of file authors may upload. SVG is left out because it can carry
+/// scripts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentKind {
+    Markdown,
+    Png,
+    Jpeg,
+    Gif,
+    Webp,
+}
+
+impl ContentKind {
+    const ALL: [ContentKind; 5] = [
+        ContentKind::Markdown,
+        ContentKind::Png,
+        ContentKind::Jpeg,
+        ContentKind::Gif,
+        ContentKind::Webp,
+    ];
+
+    /// The kind a `Content-Type` header names, ignoring parameters such as charset
+    pub fn from_mime(content_type: &str) -> Option<ContentKind> {
+        let mime = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+        match mime.as_str() {
+            "text/markdown" | "text/x-markdown" => Some(ContentKind::Markdown),
+            "image/png" => Some(ContentKind::Png),
+            "image/jpeg" => Some(ContentKind::Jpeg),
+            "image/gif" => Some(ContentKind::Gif),
+            "image/webp" => Some(ContentKind::Webp),
+            _ => None,
+        }
+    }
+
+    /// The kind a storage key's extension names
+    pub fn from_key(key: &str) -> Option<ContentKind> {
+        let (_, extension) = key.rsplit_once('.')?;
+        Self::ALL.into_iter().find(|kind| kind.extension() == extension)
+    }
+
+    /// `Content-Type` the file is served with
+    pub fn mime(self) -> &'static str {
+        match self {
+            ContentKind::Markdown => "text/markdown; charset=utf-8",
+            ContentKind::Png => "image/png",
+            ContentKind::Jpeg => "image/jpeg",
+            ContentKind::Gif => "image/gif",
+            ContentKind::Webp => "image/webp",
+        }
+    }
+
+    fn extension(self) -> &'static str {
+        match self {
+            ContentKind::Markdown => "md",
+            ContentKind::Png => "png",
+            ContentKind::Jpeg => "jpg",
+            ContentKind::Gif => "gif",
+            ContentKind::Webp => "webp",
+        }
+    }
+
+    /// Largest accepted upload, in bytes
+    pub fn max_bytes(self) -> usize {
+        match self {
+            ContentKind::Markdown => 1024 * 1024,
+            _ => MAX_UPLOAD_BYTES,
+        }
+    }
+
+    /// Whether the bytes really are this kind, so a mislabelled upload is
+    /// rejected instead of served under the wrong type
+    fn matches(self, bytes: &[u8]) -> bool {
+        match self {
+            ContentKind::Markdown => std::str::from_utf8(bytes).is_ok_and(|text| !text.contains('\0')),
+            ContentKind::Png => bytes.starts_with(b"\x89PNG\r\n\x1a\n"),
+            ContentKind::Jpeg => bytes.starts_with(&[0xFF, 0xD8, 0xFF]),
+            ContentKind::Gif => bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a"),
+            ContentKind::Webp => bytes.len() >= 12 && bytes.starts_with(b"RIFF") && &bytes[8..12] == b"WEBP",
+        }
+    }
+}
+
+/// Whether `key` is shaped like a key `store_upload` makes, so lookups for
+/// anything else can be refused without touching storage
+pub fn is_content_key(key: &str) -> bool {
+    match key.split_once('.') {
+        Some((hash, _)) => {
+            hash.len() == 64
+                && hash.bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+                && ContentKind::from_key(key).is_some()
+        }
+        None => false,
+    }
+}
+
+/// A file read back from storage
+pub struct StoredContent {
+    pub bytes: Vec<u8>,
+    pub content_type: String,
+    pub etag: String,
+}
+
+/// Where uploads are kept. Handlers only use this trait, so tests can swap
+/// the R2 bucket for an in-memory map.
+pub trait ContentStore {
+    async fn contains(&self, key: &str) -> worker::Result<bool>;
+    async fn get(&self, key: &str) -> worker::Result<Option<StoredContent>>;
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> worker::Result<()>;
+}
+
+impl ContentStore for Bucket {
+    async fn contains(&self, key: &str) -> worker::Result<bool> {
+        Ok(self.head(key).await?.is_some())
+    }
+
+    async fn get(&self, key: &str) -> worker::Result<Option<StoredContent>> {
+        let Some(object) = Bucket::get(self, key).execute().await? else {
+            return Ok(None);
+        };
+        let content_type = object.http_metadata().content_type.unwrap_or_default();
+        let etag = object.http_etag();
+        let bytes = match object.body() {
+            Some(body) => body.bytes().await?,
+            None => Vec::new(),
+        };
+        Ok(Some(StoredContent { bytes, content_type, etag }))
+    }
+
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> worker::Result<()> {
+        let metadata = HttpMetadata {
+            content_type: Some(content_type.to_string()),
+            cache_control: Some(IMMUTABLE_CACHE_CONTROL.to_string()),
+            ..HttpMetadata::default()
+        };
+        Bucket::put(self, key, bytes).http_metadata(metadata).execute().await?;
+        Ok(())
+    }
+}
+
+/// Check an upload's type and size and store it under the hash of its bytes.
+/// Content that is already stored is not written again. `base_url` is the
+/// API origin the returned URL points at.
+pub async fn store_upload<S: ContentStore>(
+    store: &S,
+    content_type: &str,
+    bytes: Vec<u8>,
+    base_url: &str,
+) -> Result<ContentUpload, ApiError> {
+    let Some(kind) = ContentKind::from_mime(content_type) else {
+        return Err(ApiError::new(
+            ErrorCode::UnsupportedMediaType,
+            "Upload markdown (text/markdown) or a PNG, JPEG, GIF or WebP image",
+        ));
+    };
+    if bytes.is_empty() {
+        return Err(ApiError::validation(vec!["The upload is empty".to_string()]));
+    }
+    if bytes.len() > kind.max_bytes() {
+        return Err(ApiError::new(
+            ErrorCode::PayloadTooLarge,
+            format!("{} uploads are limited to {} KiB", kind.mime(), kind.max_bytes() / 1024),
+        ));
+    }
+    if !kind.matches(&bytes) {
+        return Err(ApiError::validation(vec![format!("The file is not valid {}", kind.mime())]));
+    }
+
+    let key = format!("{}.{}", sha256_hex(&bytes), kind.extension());
+    let size = bytes.len() as u64;
+    let deduplicated = store.contains(&key).await.map_err(|e| storage_error("check content", e))?;
+    if !deduplicated {
+        store.put(&key, bytes, kind.mime()).await.map_err(|e| storage_error("store content", e))?;
+    }
+    Ok(ContentUpload {
+        url: format!("{}/v1/content/{}", base_url, key),
+        key,
+        content_type: kind.mime().to_string(),
+        size,
+        deduplicated,
+    })
+}
+
+/// Turn an R2 failure into an `ApiError`, logging the underlying cause
+pub fn storage_error(action: &str, e: worker::Error) -> ApiError {
+    web_sys::console::error_1(&format!("Failed to {}: {:?}", action, e).into());
+    ApiError::internal(format!("Unable to {} at this time", action))
+}
+
+/// The readable text of a markdown document, one line per block, with
+/// markup, link targets and raw HTML dropped
+pub fn plain_text(source: &str) -> String {
+    let mut text = String::new();
+    match markdown::to_mdast(source, &ParseOptions::gfm()) {
+        Ok(root) => push_text(&root, &mut text),
+        Err(_) => text.push_str(source),
+    }
+    text.trim().to_string()
+}
+
+fn push_text(node: &Node, text: &mut String) {
+    match node {
+        Node::Text(node) => text.push_str(&node.value),
+        Node::InlineCode(node) => text.push_str(&node.value),
+        Node::Code(node) => {
+            text.push_str(&node.value);
+            text.push('\n');
+        }
+        Node::Image(node) => text.push_str(&node.alt),
+        Node::Break(_) => text.push('\n'),
+        Node::Html(_) => {}
+        node => {
+            for child in node.children().into_iter().flatten() {
+                push_text(child, text);
+            }
+            if matches!(
+                node,
+                Node::Paragraph(_) | Node::Heading(_) | Node::TableCell(_) | Node::TableRow(_) | Node::ListItem(_)
+            ) {
+                text.push('\n');
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use std::cell::{Cell, RefCell};
+    use std::collections::HashMap;
+
+    const BASE_URL: &str = "https://api.example.test";
+    const PNG: &[u8] = b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR";
+
+    /// Stands in for the R2 bucket
+    #[derive(Default)]
+    struct MemoryStore {
+        objects: RefCell<HashMap<String, (Vec<u8>, String)>>,
+        puts: Cell<usize>,
+    }
+
+    impl ContentStore for MemoryStore {
+        async fn contains(&self, key: &str) -> worker::Result<bool> {
+            Ok(self.objects.borrow().contains_key(key))
+        }
+
+        async fn get(&self, key: &str) -> worker::Result<Option<StoredContent>> {
+            Ok(self.objects.borrow().get(key).map(|(bytes, content_type)| StoredContent {
+                bytes: bytes.clone(),
+                content_type: content_type.clone(),
+                etag: format!("\"{}\"", key),
+            }))
+        }
+
+        async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> worker::Result<()> {
+            self.puts.set(self.puts.get() + 1);
+            self.objects.borrow_mut().insert(key.to_string(), (bytes, content_type.to_string()));
+            Ok(())
+        }
+    }
+
+    fn upload(store: &MemoryStore, content_type: &str, bytes: &[u8]) -> Result<ContentUpload, ApiError> {
+        block_on(store_upload(store, content_type, bytes.to_vec(), BASE_URL))
+    }
+
+    fn status(result: Result<ContentUpload, ApiError>) -> u16 {
+        result.expect_err("upload should be rejected").code.status()
+    }
+
+    #[test]
+    fn stores_under_content_hash_and_deduplicates() {
+        let store = MemoryStore::default();
+        let first = upload(&store, "text/markdown; charset=utf-8", b"# Hello").unwrap();
+        assert!(!first.deduplicated);
+        assert!(is_content_key(&first.key));
+        assert!(first.key.ends_with(".md"));
+        assert_eq!(first.url, format!("{}/v1/content/{}", BASE_URL, first.key));
+        assert_eq!(first.size, 7);
+
+        let again = upload(&store, "text/x-markdown", b"# Hello").unwrap();
+        assert!(again.deduplicated);
+        assert_eq!(again.key, first.key);
+        assert_eq!(store.puts.get(), 1);
+
+        let stored = block_on(ContentStore::get(&store, &first.key)).unwrap().unwrap();
+        assert_eq!(stored.bytes, b"# Hello");
+        assert_eq!(stored.content_type, ContentKind::Markdown.mime());
+
+        let image = upload(&store, "image/png", PNG).unwrap();
+        assert!(!image.deduplicated);
+        assert!(image.key.ends_with(".png"));
+        assert_eq!(store.puts.get(), 2);
+    }
+
+    #[test]
+    fn rejects_unsupported_types() {
+        let store = MemoryStore::default();
+        for content_type in ["", "image/svg+xml", "text/html", "application/octet-stream"] {
+            assert_eq!(status(upload(&store, content_type, b"<svg></svg>")), 415, "{}", content_type);
+        }
+        assert!(store.objects.borrow().is_empty());
+    }
+
+    #[test]
+    fn rejects_oversize_files() {
+        let store = MemoryStore::default();
+        let markdown = vec![b'a'; ContentKind::Markdown.max_bytes() + 1];
+        assert_eq!(status(upload(&store, "text/markdown", &markdown)), 413);
+
+        let mut image = PNG.to_vec();
+        image.resize(ContentKind::Png.max_bytes() + 1, 0);
+        assert_eq!(status(upload(&store, "image/png", &image)), 413);
+
+        let mut largest = PNG.to_vec();
+        largest.resize(ContentKind::Png.max_bytes(), 0);
+        assert!(upload(&store, "image/png", &largest).is_ok());
+    }
+
+    #[test]
+    fn rejects_empty_and_mislabelled_files() {
+        let store = MemoryStore::default();
+        assert_eq!(status(upload(&store, "text/markdown", b"")), 422);
+        assert_eq!(status(upload(&store, "image/png", b"")), 422);
+
+        assert_eq!(status(upload(&store, "image/png", b"GIF89a not a png")), 422);
+        assert_eq!(status(upload(&store, "image/jpeg", PNG)), 422);
+        assert_eq!(status(upload(&store, "image/webp", b"RIFF\0\0\0\0WAVE")), 422);
+        assert_eq!(status(upload(&store, "text/markdown", b"\xff\xfe not utf-8")), 422);
+        assert_eq!(status(upload(&store, "text/markdown", b"nul\0byte")), 422);
+        assert!(store.objects.borrow().is_empty());
+    }
+}
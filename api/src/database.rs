@@ -1,20 +1,243 @@
-use worker::{query, Result, D1Database};
+use worker::{query, Result, D1Database, D1PreparedStatement};
+use wasm_bindgen::JsValue;
+use crate::content;
+use crate::github::GitHubUser;
 use crate::models::*;
+use crate::pagination::{Cursor, PageRequest};
+use crate::search;
+use crate::tokens;
+use crate::views::ViewCounter;
 use serde::Deserialize;
+use std::collections::HashMap;
+use chrono::{DateTime, Duration, NaiveDate, SecondsFormat, Utc};
 
 pub struct DatabaseService;
 
+/// Default and maximum length of the `GET /v1/events` window
+pub const DEFAULT_EVENT_WINDOW_DAYS: i64 = 90;
+pub const MAX_EVENT_WINDOW_DAYS: i64 = 366;
+
+/// How term dates are stored
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Filters accepted by `GET /v1/events`. Recurring events are expanded into
+/// the occurrences that overlap `[from, to)`.
+pub struct EventFilter {
+    pub event_type: Option<EventType>,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+}
+
+impl Default for EventFilter {
+    fn default() -> Self {
+        let from = Utc::now();
+        EventFilter {
+            event_type: None,
+            from,
+            to: from + Duration::days(DEFAULT_EVENT_WINDOW_DAYS),
+        }
+    }
+}
+
+/// Filters accepted by `GET /v1/issues`
+#[derive(Default)]
+pub struct IssueFilter {
+    pub difficulty: Option<DifficultyLevel>,
+    pub repo: Option<String>,
+}
+
+/// Filters accepted by `GET /v1/projects`
+#[derive(Default)]
+pub struct ProjectFilter {
+    pub status: Option<ProjectStatus>,
+    pub contributors_needed: Option<bool>,
+}
+
+/// Filters accepted by `GET /v1/workshops`
+#[derive(Default)]
+pub struct WorkshopFilter {
+    /// `true` for workshops that have not started yet, `false` for the rest
+    pub upcoming: Option<bool>,
+    /// Only workshops starting within `[start, end)`, e.g. a term's bounds
+    pub starts_within: Option<(DateTime<Utc>, DateTime<Utc>)>,
+}
+
+/// Filters accepted by `GET /v1/blog` and the editorial queue
+#[derive(Default)]
+pub struct BlogPostFilter {
+    /// Defaults to published, the only status public callers may see
+    pub status: BlogPostStatus,
+    pub post_type: Option<BlogPostType>,
+    pub category: Option<BlogCategory>,
+    pub difficulty: Option<DifficultyLevel>,
+    /// Only posts this member wrote or co-wrote, by GitHub username
+    pub author: Option<String>,
+    /// Only posts carrying this tag
+    pub tag: Option<String>,
+    /// Only posts published within `[start, end)`, e.g. a term's bounds
+    pub published_within: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    /// Only posts assigned to this reviewer, by GitHub username
+    pub reviewer: Option<String>,
+    /// Only posts this member wrote, co-wrote or reviews, by GitHub username
+    pub involving: Option<String>,
+}
+
+/// Filters accepted by `GET /v1/members`
+#[derive(Default)]
+pub struct MemberFilter {
+    pub role: Option<MemberRole>,
+    /// Only members listing this skill (case-insensitive)
+    pub skill: Option<String>,
+    /// Whether the viewer is signed in, which also lists members who show
+    /// their profile to members only
+    pub signed_in: bool,
+}
+
+/// An open issue found on GitHub by the issue sync
+pub struct SyncedIssue {
+    pub input: IssueInput,
+    /// When the issue was opened on GitHub; the board sorts by it
+    pub opened_at: DateTime<Utc>,
+}
+
+/// A member looked up by GitHub username, with the settings that decide who
+/// may see their profile
+pub struct MemberRecord {
+    pub id: i64,
+    pub visibility: ProfileVisibility,
+    pub show_attendance: bool,
+    pub summary: MemberSummary,
+}
+
+/// A contribution with the member who owns it, for deciding who may move it along
+pub struct ContributionRecord {
+    pub member_id: i64,
+    pub contribution: Contribution,
+}
+
+/// What a blog post save stores besides the post's metadata
+pub struct NewRevision<'a> {
+    /// The markdown at the post's `markdown_url`, if it could be fetched
+    pub markdown: Option<&'a str>,
+    /// Actor label of whoever saved it
+    pub created_by: &'a str,
+    /// Revision number being restored, if any
+    pub restored_from: Option<u32>,
+}
+
+/// How many blog posts and contributions a profile shows
+const PROFILE_LIST_LIMIT: u32 = 50;
+
+const EVENT_COLUMNS: &str = "uuid, title, description, starts_at, ends_at, timezone, recurrence, location, event_type, capacity,
+                    (SELECT COUNT(*) FROM event_rsvps r WHERE r.event_id = events.id AND r.status = 'going') AS going,
+                    (SELECT COUNT(*) FROM event_rsvps r WHERE r.event_id = events.id AND r.status = 'maybe') AS maybe,
+                    created_at";
+
+/// Whether a `going` RSVP still fits event `e`, not counting member `m`'s own
+const RSVP_HAS_ROOM: &str = "(e.capacity IS NULL OR (SELECT COUNT(*) FROM event_rsvps r
+                              WHERE r.event_id = e.id AND r.status = 'going' AND r.member_id != m.id) < e.capacity)";
+
+const RSVP_COLUMNS: &str = "e.uuid AS event_uuid, m.name, m.github_username, r.status, r.updated_at";
+
+const CHECK_IN_COLUMNS: &str = "e.uuid AS event_uuid, m.name, m.github_username, c.occurrence_starts_at, c.checked_in_at";
+
+const TERM_COLUMNS: &str = "slug, name, starts_on, ends_on";
+
+const WORKSHOP_COLUMNS: &str = "w.uuid, w.title, w.description, w.level, w.starts_at, w.ends_at, w.location,
+                    w.instructor, w.topics, w.prerequisites, w.materials_url, w.capacity,
+                    (SELECT COUNT(*) FROM workshop_registrations r WHERE r.workshop_id = w.id AND r.status = 'registered') AS registered,
+                    (SELECT COUNT(*) FROM workshop_registrations r WHERE r.workshop_id = w.id AND r.status = 'waitlisted') AS waitlisted";
+
+/// Move waitlisted registrations into free seats, oldest first. Binds the
+/// workshop uuid twice.
+const PROMOTE_WAITLIST: &str = "UPDATE workshop_registrations SET status = 'registered'
+     WHERE id IN (
+         SELECT r.id FROM workshop_registrations r
+         WHERE r.workshop_id = (SELECT id FROM workshops WHERE uuid = ?1) AND r.status = 'waitlisted'
+         ORDER BY r.id
+         LIMIT (SELECT MAX(0, w.capacity - (SELECT COUNT(*) FROM workshop_registrations x
+                                             WHERE x.workshop_id = w.id AND x.status = 'registered'))
+                FROM workshops w WHERE w.uuid = ?1)
+     )";
+
+const COURSE_COLUMNS: &str = "c.slug, c.course_code, c.course_name, c.department, c.professor, c.description, c.status,
+                    (SELECT COUNT(*) FROM petition_signatures s WHERE s.course_id = c.id) AS supporters_count";
+
+/// Everything public about a member. `email` is deliberately absent.
+const MEMBER_COLUMNS: &str = "m.id, m.name, m.github_username, m.avatar_url, m.bio, m.skills, m.profile_visibility,
+                    m.show_attendance, m.created_at";
+
+const CONTRIBUTION_COLUMNS: &str = "c.uuid, c.member_id, m.name AS member_name, m.github_username AS member_github,
+                    i.uuid AS issue_uuid, c.state, c.repo, c.pr_url, c.title, c.claimed_at, c.claim_expires_at,
+                    c.pr_opened_at, c.merged_at, c.created_at";
+
+/// Contributions with their member and claimed issue, for `CONTRIBUTION_COLUMNS`
+const CONTRIBUTION_TABLES: &str = "contributions c JOIN members m ON c.member_id = m.id
+                    LEFT JOIN issues i ON c.issue_id = i.id";
+
+/// The claimer is only named when their profile is public
+const ISSUE_COLUMNS: &str = "i.uuid, i.title, i.description, i.repo, i.github_url, i.difficulty, i.tags, i.created_at,
+                    c.state AS claim_state, c.claim_expires_at,
+                    CASE WHEN m.profile_visibility = 'public' THEN m.github_username END AS claimed_by";
+
+/// Issues with their active claim, if any, for `ISSUE_COLUMNS`
+const ISSUE_TABLES: &str = "issues i
+                    LEFT JOIN contributions c ON c.issue_id = i.id AND c.state IN ('claimed', 'pr_opened')
+                    LEFT JOIN members m ON c.member_id = m.id";
+
+const PROJECT_COLUMNS: &str = "p.uuid, p.name, p.description, p.github_url,
+                    m.name as leader_name, m.github_username as leader_github,
+                    p.status, p.tech_stack, p.contributors_needed, p.skills_needed, p.created_at";
+
+const BLOG_POST_COLUMNS: &str = "id, title, slug, excerpt, post_type, category, tags, author_name, author_github,
+                    (SELECT json_group_array(json_object('name', name, 'github_username', github_username,
+                                                         'avatar_url', avatar_url))
+                     FROM (SELECT m.name, m.github_username, m.avatar_url
+                           FROM blog_post_authors a JOIN members m ON m.id = a.member_id
+                           WHERE a.post_id = blog_posts.id AND a.position > 0
+                           ORDER BY a.position)) AS co_authors,
+                    difficulty_level, estimated_read_time, published_at,
+                    coalesce((SELECT max(r.published_at) FROM blog_post_revisions r WHERE r.post_id = blog_posts.id),
+                             updated_at) AS updated_at,
+                    views, likes, markdown_url,
+                    (SELECT s.slug FROM blog_series s WHERE s.id = blog_posts.series_id) AS series_slug,
+                    (SELECT s.title FROM blog_series s WHERE s.id = blog_posts.series_id) AS series_title,
+                    series_part,
+                    (SELECT coalesce(s.planned_parts,
+                                     (SELECT count(*) FROM blog_posts p WHERE p.series_id = s.id AND p.status = 'published'))
+                     FROM blog_series s WHERE s.id = blog_posts.series_id) AS series_total_parts,
+                    external_links, status,
+                    (SELECT github_username FROM members WHERE members.id = blog_posts.reviewer_id) AS reviewer_github";
+
+/// A series with its published part count, from `blog_series s`
+const BLOG_SERIES_COLUMNS: &str = "s.slug, s.title, s.description, s.planned_parts,
+                    (SELECT count(*) FROM blog_posts p WHERE p.series_id = s.id AND p.status = 'published') AS published_parts";
+
+/// A series id by slug, for setting `series_id`
+const BLOG_SERIES_ID_BY_SLUG: &str = "(SELECT id FROM blog_series WHERE slug = ?)";
+
+/// Posts a member is credited on, lead or co-author, by GitHub username
+const BLOG_POST_IDS_BY_AUTHOR: &str = "(SELECT a.post_id FROM blog_post_authors a JOIN members m ON m.id = a.member_id
+                    WHERE m.github_username = ? COLLATE NOCASE)";
+
+/// A member id by GitHub username, for matching `reviewer_id`
+const MEMBER_ID_BY_GITHUB: &str = "(SELECT id FROM members WHERE github_username = ? COLLATE NOCASE)";
+
 // Helper structs for database rows
 #[derive(Deserialize)]
 struct EventRow {
     uuid: String,
     title: String,
     description: String,
-    date: String,
-    time: String,
+    starts_at: String,
+    ends_at: String,
+    timezone: String,
+    recurrence: Option<String>,
     location: String,
     event_type: String,
-    recurring: i32,
+    capacity: Option<i32>,
+    going: i32,
+    maybe: i32,
     created_at: String,
 }
 
@@ -28,6 +251,9 @@ struct IssueRow {
     difficulty: String,
     tags: String,
     created_at: String,
+    claim_state: Option<String>,
+    claim_expires_at: Option<String>,
+    claimed_by: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -45,6 +271,235 @@ struct ProjectRow {
     created_at: String,
 }
 
+#[derive(Deserialize)]
+struct TermRow {
+    slug: String,
+    name: String,
+    starts_on: String,
+    ends_on: String,
+}
+
+#[derive(Deserialize)]
+struct BlogSeriesRow {
+    slug: String,
+    title: String,
+    description: String,
+    planned_parts: Option<u32>,
+    published_parts: u32,
+}
+
+#[derive(Deserialize)]
+struct BlogSeriesEntryRow {
+    part: u32,
+    title: String,
+    slug: String,
+    excerpt: String,
+    published_at: String,
+    estimated_read_time: u32,
+}
+
+#[derive(Deserialize)]
+struct WorkshopRow {
+    uuid: String,
+    title: String,
+    description: String,
+    level: String,
+    starts_at: String,
+    ends_at: String,
+    location: String,
+    instructor: String,
+    topics: String,
+    prerequisites: String,
+    materials_url: Option<String>,
+    capacity: i32,
+    registered: i32,
+    waitlisted: i32,
+}
+
+#[derive(Deserialize)]
+struct RegistrationRow {
+    workshop_uuid: String,
+    name: String,
+    status: String,
+    created_at: String,
+    waitlist_position: Option<i32>,
+}
+
+#[derive(Deserialize)]
+struct RegistrantRow {
+    name: String,
+    email: String,
+    status: String,
+    created_at: String,
+}
+
+#[derive(Deserialize)]
+struct RsvpRow {
+    event_uuid: String,
+    name: String,
+    github_username: Option<String>,
+    status: String,
+    updated_at: String,
+}
+
+#[derive(Deserialize)]
+struct CheckInRow {
+    event_uuid: String,
+    name: String,
+    github_username: Option<String>,
+    occurrence_starts_at: String,
+    checked_in_at: String,
+}
+
+#[derive(Deserialize)]
+struct CourseRow {
+    slug: String,
+    course_code: String,
+    course_name: String,
+    department: String,
+    professor: Option<String>,
+    description: Option<String>,
+    status: String,
+    supporters_count: i32,
+}
+
+#[derive(Deserialize)]
+struct CourseStatusChangeRow {
+    slug: String,
+    status: String,
+    note: Option<String>,
+    changed_at: String,
+}
+
+#[derive(Deserialize)]
+struct PetitionSummaryRow {
+    total_supporters: i32,
+    petition_signatures: i32,
+    courses_targeted: i32,
+    courses_approved: i32,
+}
+
+#[derive(Deserialize)]
+struct CurrentUserRow {
+    name: String,
+    github_username: Option<String>,
+    avatar_url: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct MemberRow {
+    id: i64,
+    name: String,
+    github_username: String,
+    avatar_url: Option<String>,
+    bio: Option<String>,
+    skills: String,
+    profile_visibility: String,
+    show_attendance: i32,
+    created_at: String,
+}
+
+#[derive(Deserialize)]
+struct MemberRoleRow {
+    github_username: String,
+    role: String,
+}
+
+#[derive(Deserialize)]
+struct RoleRow {
+    role: String,
+}
+
+#[derive(Deserialize)]
+struct RoleChangeRow {
+    uuid: String,
+    member_name: String,
+    member_github: Option<String>,
+    role: String,
+    action: String,
+    changed_by: String,
+    reason: Option<String>,
+    changed_at: String,
+}
+
+#[derive(Deserialize)]
+struct CountRow {
+    count: i32,
+}
+
+#[derive(Deserialize)]
+struct ContributionRow {
+    uuid: String,
+    member_id: i64,
+    member_name: String,
+    member_github: Option<String>,
+    issue_uuid: Option<String>,
+    state: String,
+    repo: String,
+    pr_url: Option<String>,
+    title: String,
+    claimed_at: Option<String>,
+    claim_expires_at: Option<String>,
+    pr_opened_at: Option<String>,
+    merged_at: Option<String>,
+    created_at: String,
+}
+
+#[derive(Deserialize)]
+struct ContributorRow {
+    project_uuid: String,
+    name: String,
+    github_username: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct SearchRow {
+    kind: String,
+    ref_id: String,
+    url: String,
+    title: String,
+    title_marked: String,
+    snippet_marked: String,
+    score: f64,
+}
+
+const BLOG_REVISION_SUMMARY_COLUMNS: &str =
+    "id, number, json_extract(metadata, '$.title') AS title, created_by, created_at, published_at, restored_from";
+
+#[derive(Deserialize)]
+struct BlogRevisionSummaryRow {
+    id: i64,
+    number: u32,
+    title: String,
+    created_by: String,
+    created_at: String,
+    published_at: Option<String>,
+    restored_from: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct BlogRevisionRow {
+    number: u32,
+    metadata: String,
+    markdown: Option<String>,
+    created_by: String,
+    created_at: String,
+    published_at: Option<String>,
+    restored_from: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct MarkdownSourceRow {
+    id: String,
+    markdown_url: String,
+}
+
+#[derive(Deserialize)]
+struct LikeRow {
+    likes: i32,
+    liked: i32,
+}
+
 #[derive(Deserialize)]
 struct BlogPostRow {
     id: String,
@@ -56,6 +511,7 @@ struct BlogPostRow {
     tags: String,
     author_name: String,
     author_github: Option<String>,
+    co_authors: String,
     difficulty_level: Option<String>,
     estimated_read_time: i32,
     published_at: String,
@@ -63,344 +519,2399 @@ struct BlogPostRow {
     views: i32,
     likes: i32,
     markdown_url: String,
+    series_slug: Option<String>,
     series_title: Option<String>,
     series_part: Option<i32>,
     series_total_parts: Option<i32>,
     external_links: Option<String>,
+    status: String,
+    reviewer_github: Option<String>,
 }
 
-impl DatabaseService {
-    pub async fn get_events(db: &D1Database) -> Result<Vec<Event>> {
-        let stmt = query!(db, "SELECT uuid, title, description, date, time, location, event_type, recurring, created_at FROM events ORDER BY created_at DESC");
-        let results = stmt.all().await?;
+impl DatabaseService {
+    /// Upcoming events (or occurrences of recurring ones) in `filter`'s
+    /// window, ordered by start time. The cursor is the occurrence start.
+    /// One-off events are paged in SQL; recurring rows have no stored
+    /// occurrences, so they are fetched separately and expanded in memory
+    pub async fn get_events(db: &D1Database, filter: &EventFilter, page: &PageRequest) -> Result<Page<Event>> {
+        let type_condition = |conditions: &mut Vec<String>, binds: &mut Vec<JsValue>| {
+            if let Some(event_type) = &filter.event_type {
+                conditions.push("event_type = ?".to_string());
+                binds.push(JsValue::from_str(event_type_to_db(event_type)));
+            }
+        };
+
+        let mut conditions = vec![
+            "recurrence IS NULL".to_string(),
+            "starts_at < ?".to_string(),
+            "ends_at > ?".to_string(),
+        ];
+        let mut binds = vec![
+            JsValue::from_str(&db_timestamp(filter.to)),
+            JsValue::from_str(&db_timestamp(filter.from)),
+        ];
+        type_condition(&mut conditions, &mut binds);
+        page.push_keyset("starts_at", "uuid", &mut conditions, &mut binds);
+        let tail = page.order_and_limit("starts_at", "uuid", &mut binds);
+        let sql = format!("SELECT {} FROM events {} {}", EVENT_COLUMNS, where_clause(&conditions), tail);
+        let one_off = db.prepare(&sql).bind(&binds)?.all().await?.results::<EventRow>()?;
+
+        let mut conditions = vec!["recurrence IS NOT NULL".to_string(), "starts_at < ?".to_string()];
+        let mut binds = vec![JsValue::from_str(&db_timestamp(filter.to))];
+        type_condition(&mut conditions, &mut binds);
+        let sql = format!("SELECT {} FROM events {}", EVENT_COLUMNS, where_clause(&conditions));
+        let recurring = db.prepare(&sql).bind(&binds)?.all().await?.results::<EventRow>()?;
+
+        let events = one_off.into_iter().chain(recurring).map(Self::event_from_row).collect();
+        Ok(Self::page_of_occurrences(events, filter, page))
+    }
+
+    /// Expand recurring events over the window, merge them with the page of
+    /// one-off events, sort chronologically and apply the keyset cursor in memory
+    fn page_of_occurrences(events: Vec<Event>, filter: &EventFilter, page: &PageRequest) -> Page<Event> {
+        let cursor_of = |event: &Event| Cursor {
+            sort_key: db_timestamp(event.starts_at),
+            id: event.id.clone(),
+        };
+
+        let mut occurrences: Vec<Event> = events
+            .iter()
+            .flat_map(|event| event.occurrences(filter.from, filter.to))
+            .filter(|event| {
+                let cursor = cursor_of(event);
+                page.is_after_cursor(&cursor.sort_key, &cursor.id)
+            })
+            .collect();
+        occurrences.sort_by(|a, b| (a.starts_at, &a.id).cmp(&(b.starts_at, &b.id)));
+        if page.descending {
+            occurrences.reverse();
+        }
+        occurrences.truncate(page.limit as usize + 1);
+
+        page.to_page(occurrences, cursor_of, |event| event)
+    }
+
+    pub async fn get_issues(db: &D1Database, filter: &IssueFilter, page: &PageRequest) -> Result<Page<Issue>> {
+        let mut conditions = vec!["i.state = 'open'".to_string()];
+        let mut binds = Vec::new();
+        if let Some(difficulty) = &filter.difficulty {
+            conditions.push("i.difficulty = ?".to_string());
+            binds.push(JsValue::from_str(&difficulty.to_string()));
+        }
+        if let Some(repo) = &filter.repo {
+            conditions.push("i.repo = ?".to_string());
+            binds.push(JsValue::from_str(repo));
+        }
+        page.push_keyset("i.created_at", "i.uuid", &mut conditions, &mut binds);
+        let tail = page.order_and_limit("i.created_at", "i.uuid", &mut binds);
+        let sql = format!("SELECT {} FROM {} {} {}", ISSUE_COLUMNS, ISSUE_TABLES, where_clause(&conditions), tail);
+
+        let results = db.prepare(&sql).bind(&binds)?.all().await?;
+
+        Ok(page.to_page(
+            results.results::<IssueRow>()?,
+            |row| Cursor { sort_key: row.created_at.clone(), id: row.uuid.clone() },
+            Self::issue_from_row,
+        ))
+    }
+
+    pub async fn get_projects(db: &D1Database, filter: &ProjectFilter, page: &PageRequest) -> Result<Page<Project>> {
+        let mut conditions = Vec::new();
+        let mut binds = Vec::new();
+        if let Some(status) = &filter.status {
+            conditions.push("p.status = ?".to_string());
+            binds.push(JsValue::from_str(project_status_to_db(status)));
+        }
+        if let Some(contributors_needed) = filter.contributors_needed {
+            conditions.push("p.contributors_needed = ?".to_string());
+            binds.push(JsValue::from_f64(contributors_needed as i32 as f64));
+        }
+        page.push_keyset("p.created_at", "p.uuid", &mut conditions, &mut binds);
+        let tail = page.order_and_limit("p.created_at", "p.uuid", &mut binds);
+        let sql = format!(
+            "SELECT {} FROM projects p JOIN members m ON p.leader_id = m.id {} {}",
+            PROJECT_COLUMNS,
+            where_clause(&conditions),
+            tail
+        );
+
+        let results = db.prepare(&sql).bind(&binds)?.all().await?;
+
+        let mut projects = page.to_page(
+            results.results::<ProjectRow>()?,
+            |row| Cursor { sort_key: row.created_at.clone(), id: row.uuid.clone() },
+            Self::project_from_row,
+        );
+        Self::load_contributors(db, &mut projects.items).await?;
+        Ok(projects)
+    }
+
+    /// Club statistics for `term`. Counts that depend on time (workshops
+    /// held) only include what has happened by now.
+    pub async fn get_stats(db: &D1Database, term: &Term) -> Result<Stats> {
+        let (start, end) = term.bounds();
+        let start_bind = JsValue::from_str(&db_timestamp(start));
+        let end_bind = JsValue::from_str(&db_timestamp(end));
+
+        let statements = vec![
+            db.prepare("SELECT COUNT(*) AS count FROM members WHERE created_at < ?")
+                .bind(std::slice::from_ref(&end_bind))?,
+            db.prepare(
+                "SELECT COUNT(*) AS count FROM contributions
+                 WHERE state = 'merged' AND merged_at >= ? AND merged_at < ?",
+            )
+                .bind(&[start_bind.clone(), end_bind.clone()])?,
+            db.prepare(
+                "SELECT COUNT(*) AS count FROM projects p
+                 WHERE p.created_at < ?
+                   AND (p.status != 'Archived' OR p.updated_at >= ?)
+                   AND EXISTS (SELECT 1 FROM project_contributors pc WHERE pc.project_id = p.id)",
+            )
+            .bind(&[end_bind.clone(), start_bind.clone()])?,
+            db.prepare(
+                "SELECT COUNT(*) AS count FROM event_check_ins
+                 WHERE occurrence_starts_at >= ? AND occurrence_starts_at < ?",
+            )
+            .bind(&[start_bind.clone(), end_bind.clone()])?,
+            db.prepare(
+                "SELECT COUNT(DISTINCT member_id) AS count FROM event_check_ins
+                 WHERE occurrence_starts_at >= ? AND occurrence_starts_at < ?",
+            )
+            .bind(&[start_bind.clone(), end_bind.clone()])?,
+        ];
+        let counts = db
+            .batch(statements)
+            .await?
+            .iter()
+            .map(|result| {
+                let rows = result.results::<CountRow>()?;
+                Ok(rows.first().map_or(0, |row| row.count.max(0) as u32))
+            })
+            .collect::<Result<Vec<u32>>>()?;
+
+        let held_until = end.min(Utc::now());
+        let sql = format!(
+            "SELECT {} FROM events
+             WHERE event_type = 'Workshop' AND starts_at < ? AND (recurrence IS NOT NULL OR ends_at > ?)",
+            EVENT_COLUMNS
+        );
+        let rows = db
+            .prepare(&sql)
+            .bind(&[end_bind, start_bind])?
+            .all()
+            .await?
+            .results::<EventRow>()?;
+        let workshops_held = rows
+            .into_iter()
+            .map(Self::event_from_row)
+            .flat_map(|event| event.occurrences(start, held_until))
+            .filter(|session| session.starts_at >= start)
+            .count();
+
+        Ok(Stats {
+            semester: term.name.clone(),
+            term: term.clone(),
+            active_members: counts.first().copied().unwrap_or(0),
+            prs_merged_this_semester: counts.get(1).copied().unwrap_or(0),
+            workshops_held: workshops_held as u32,
+            projects_contributed_to: counts.get(2).copied().unwrap_or(0),
+            event_check_ins: counts.get(3).copied().unwrap_or(0),
+            event_attendees: counts.get(4).copied().unwrap_or(0),
+        })
+    }
+
+    /// All terms, newest first
+    pub async fn get_terms(db: &D1Database) -> Result<Vec<Term>> {
+        let sql = format!("SELECT {} FROM terms ORDER BY starts_on DESC", TERM_COLUMNS);
+        let rows = db.prepare(&sql).all().await?.results::<TermRow>()?;
+        Ok(rows.into_iter().map(Self::term_from_row).collect())
+    }
+
+    pub async fn get_term(db: &D1Database, slug: &str) -> Result<Option<Term>> {
+        let sql = format!("SELECT {} FROM terms WHERE slug = ?", TERM_COLUMNS);
+        let row = db.prepare(&sql).bind(&[slug.into()])?.first::<TermRow>(None).await?;
+        Ok(row.map(Self::term_from_row))
+    }
+
+    /// The latest term that has started by `today`. Between terms this is the
+    /// one that just ended, so breaks still show the last term's numbers.
+    pub async fn get_current_term(db: &D1Database, today: NaiveDate) -> Result<Option<Term>> {
+        let sql = format!(
+            "SELECT {} FROM terms WHERE starts_on <= ? ORDER BY starts_on DESC LIMIT 1",
+            TERM_COLUMNS
+        );
+        let today = today.format(DATE_FORMAT).to_string();
+        let row = db.prepare(&sql).bind(&[today.into()])?.first::<TermRow>(None).await?;
+        Ok(row.map(Self::term_from_row))
+    }
+
+    pub async fn create_term(db: &D1Database, input: &TermInput) -> Result<Option<Term>> {
+        let mut binds = term_bind_values(input);
+        binds.push(JsValue::from_str(&db_timestamp(Utc::now())));
+        db.prepare("INSERT INTO terms (slug, name, starts_on, ends_on, created_at) VALUES (?, ?, ?, ?, ?)")
+            .bind(&binds)?
+            .run()
+            .await?;
+        Self::get_term(db, &input.slug).await
+    }
+
+    /// Replace a term's fields, including its slug. Returns `None` if no term
+    /// has this slug.
+    pub async fn update_term(db: &D1Database, slug: &str, input: &TermInput) -> Result<Option<Term>> {
+        let mut binds = term_bind_values(input);
+        binds.push(JsValue::from_str(slug));
+        let result = db
+            .prepare("UPDATE terms SET slug = ?, name = ?, starts_on = ?, ends_on = ? WHERE slug = ?")
+            .bind(&binds)?
+            .run()
+            .await?;
+        if changed_rows(&result) == 0 {
+            return Ok(None);
+        }
+        Self::get_term(db, &input.slug).await
+    }
+
+    /// Delete a term. Returns `false` if no term has this slug.
+    pub async fn delete_term(db: &D1Database, slug: &str) -> Result<bool> {
+        let stmt = query!(db, "DELETE FROM terms WHERE slug = ?", slug);
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    pub async fn get_workshops(db: &D1Database, filter: &WorkshopFilter, page: &PageRequest) -> Result<Page<Workshop>> {
+        let mut conditions = Vec::new();
+        let mut binds = Vec::new();
+        if let Some(upcoming) = filter.upcoming {
+            conditions.push(if upcoming { "w.starts_at > ?" } else { "w.starts_at <= ?" }.to_string());
+            binds.push(JsValue::from_str(&db_timestamp(Utc::now())));
+        }
+        if let Some((start, end)) = filter.starts_within {
+            conditions.push("w.starts_at >= ? AND w.starts_at < ?".to_string());
+            binds.push(JsValue::from_str(&db_timestamp(start)));
+            binds.push(JsValue::from_str(&db_timestamp(end)));
+        }
+        page.push_keyset("w.starts_at", "w.uuid", &mut conditions, &mut binds);
+        let tail = page.order_and_limit("w.starts_at", "w.uuid", &mut binds);
+        let sql = format!("SELECT {} FROM workshops w {} {}", WORKSHOP_COLUMNS, where_clause(&conditions), tail);
+
+        let rows = db.prepare(&sql).bind(&binds)?.all().await?.results::<WorkshopRow>()?;
+        Ok(page.to_page(
+            rows,
+            |row| Cursor { sort_key: row.starts_at.clone(), id: row.uuid.clone() },
+            Self::workshop_from_row,
+        ))
+    }
+
+    pub async fn get_workshop(db: &D1Database, id: &str) -> Result<Option<Workshop>> {
+        let sql = format!("SELECT {} FROM workshops w WHERE w.uuid = ?", WORKSHOP_COLUMNS);
+        let row = db.prepare(&sql).bind(&[id.into()])?.first::<WorkshopRow>(None).await?;
+        Ok(row.map(Self::workshop_from_row))
+    }
+
+    pub async fn create_workshop(db: &D1Database, input: &WorkshopInput) -> Result<Option<Workshop>> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = db_timestamp(Utc::now());
+        let mut binds = vec![JsValue::from_str(&id)];
+        binds.extend(workshop_bind_values(input)?);
+        binds.push(JsValue::from_str(&now));
+        binds.push(JsValue::from_str(&now));
+
+        db.prepare(
+            "INSERT INTO workshops (uuid, title, description, level, starts_at, ends_at, location, instructor,
+                                    topics, prerequisites, materials_url, capacity, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&binds)?
+        .run()
+        .await?;
+        Self::get_workshop(db, &id).await
+    }
+
+    /// Replace a workshop's fields. Raising the capacity moves waitlisted
+    /// registrations into the new seats; lowering it never removes anyone who
+    /// already holds a seat. Returns `None` if no workshop has this id.
+    pub async fn update_workshop(db: &D1Database, id: &str, input: &WorkshopInput) -> Result<Option<Workshop>> {
+        let mut binds = workshop_bind_values(input)?;
+        binds.push(JsValue::from_str(&db_timestamp(Utc::now())));
+        binds.push(JsValue::from_str(id));
+
+        let results = db
+            .batch(vec![
+                db.prepare(
+                    "UPDATE workshops
+                     SET title = ?, description = ?, level = ?, starts_at = ?, ends_at = ?, location = ?,
+                         instructor = ?, topics = ?, prerequisites = ?, materials_url = ?, capacity = ?, updated_at = ?
+                     WHERE uuid = ?",
+                )
+                .bind(&binds)?,
+                db.prepare(PROMOTE_WAITLIST).bind(&[id.into()])?,
+            ])
+            .await?;
+        if results.first().map_or(0, changed_rows) == 0 {
+            return Ok(None);
+        }
+        Self::get_workshop(db, id).await
+    }
+
+    /// Delete a workshop and its registrations. Returns `false` if no workshop has this id.
+    pub async fn delete_workshop(db: &D1Database, id: &str) -> Result<bool> {
+        let stmt = query!(db, "DELETE FROM workshops WHERE uuid = ?", id);
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// Register for a workshop that has not started yet, on behalf of
+    /// `member_id` when signed in. The seat check and the insert are one
+    /// statement, so concurrent registrations cannot overbook. Returns `None`
+    /// if the workshop does not exist or has started.
+    pub async fn register_for_workshop(
+        db: &D1Database,
+        id: &str,
+        input: &WorkshopRegistrationInput,
+        token: &str,
+        member_id: Option<i64>,
+    ) -> Result<Option<WorkshopRegistration>> {
+        let stmt = query!(
+            db,
+            "INSERT INTO workshop_registrations (workshop_id, member_id, name, email, token_hash, status, created_at)
+             SELECT w.id, ?, ?, ?, ?,
+                    CASE WHEN (SELECT COUNT(*) FROM workshop_registrations r
+                               WHERE r.workshop_id = w.id AND r.status = 'registered') < w.capacity
+                         THEN 'registered' ELSE 'waitlisted' END,
+                    ?
+             FROM workshops w
+             WHERE w.uuid = ? AND w.starts_at > ?",
+            member_id,
+            input.name.trim(),
+            input.email.trim().to_lowercase(),
+            tokens::token_hash(token),
+            db_timestamp(Utc::now()),
+            id,
+            db_timestamp(Utc::now())
+        );
+        let result = stmt?.run().await?;
+        if changed_rows(&result) == 0 {
+            return Ok(None);
+        }
+        Self::get_workshop_registration(db, id, token).await
+    }
+
+    /// Look up a registration by its confirmation token
+    pub async fn get_workshop_registration(db: &D1Database, id: &str, token: &str) -> Result<Option<WorkshopRegistration>> {
+        let stmt = query!(
+            db,
+            "SELECT w.uuid AS workshop_uuid, r.name, r.status, r.created_at,
+                    CASE WHEN r.status = 'waitlisted' THEN
+                        (SELECT COUNT(*) FROM workshop_registrations q
+                         WHERE q.workshop_id = r.workshop_id AND q.status = 'waitlisted' AND q.id <= r.id)
+                    END AS waitlist_position
+             FROM workshop_registrations r JOIN workshops w ON r.workshop_id = w.id
+             WHERE r.token_hash = ? AND w.uuid = ?",
+            tokens::token_hash(token),
+            id
+        );
+        let Some(row) = stmt?.first::<RegistrationRow>(None).await? else {
+            return Ok(None);
+        };
+        let Some(workshop) = Self::get_workshop(db, &row.workshop_uuid).await? else {
+            return Ok(None);
+        };
+        Ok(Some(WorkshopRegistration {
+            token: token.to_string(),
+            name: row.name,
+            status: registration_status_from_db(&row.status),
+            waitlist_position: row.waitlist_position.map(|position| position.max(0) as u32),
+            registered_at: parse_timestamp(&row.created_at),
+            workshop,
+        }))
+    }
+
+    /// Cancel a registration and give any freed seat to the waitlist.
+    /// Returns `false` if the token does not match a registration for this workshop.
+    pub async fn cancel_workshop_registration(db: &D1Database, id: &str, token: &str) -> Result<bool> {
+        let hash = tokens::token_hash(token);
+        let results = db
+            .batch(vec![
+                db.prepare(
+                    "DELETE FROM workshop_registrations
+                     WHERE token_hash = ? AND workshop_id = (SELECT id FROM workshops WHERE uuid = ?)",
+                )
+                .bind(&[hash.into(), id.into()])?,
+                db.prepare(PROMOTE_WAITLIST).bind(&[id.into()])?,
+            ])
+            .await?;
+        Ok(results.first().map_or(0, changed_rows) > 0)
+    }
+
+    /// Everyone registered for a workshop, seated first, each group in signup order
+    pub async fn get_workshop_registrants(db: &D1Database, id: &str) -> Result<Vec<WorkshopRegistrant>> {
+        let stmt = query!(
+            db,
+            "SELECT r.name, r.email, r.status, r.created_at
+             FROM workshop_registrations r JOIN workshops w ON r.workshop_id = w.id
+             WHERE w.uuid = ?
+             ORDER BY r.status = 'waitlisted', r.id",
+            id
+        );
+        let rows = stmt?.all().await?.results::<RegistrantRow>()?;
+        Ok(rows
+            .into_iter()
+            .map(|row| WorkshopRegistrant {
+                name: row.name,
+                email: row.email,
+                status: registration_status_from_db(&row.status),
+                registered_at: parse_timestamp(&row.created_at),
+            })
+            .collect())
+    }
+
+    /// All advocacy courses with their supporter counts and status history
+    pub async fn get_courses(db: &D1Database) -> Result<Vec<Course>> {
+        let sql = format!("SELECT {} FROM courses c ORDER BY c.course_code", COURSE_COLUMNS);
+        let rows = db.prepare(&sql).all().await?.results::<CourseRow>()?;
+        let mut courses: Vec<Course> = rows.into_iter().map(Self::course_from_row).collect();
+        Self::load_course_history(db, &mut courses).await?;
+        Ok(courses)
+    }
+
+    pub async fn get_course(db: &D1Database, slug: &str) -> Result<Option<Course>> {
+        let sql = format!("SELECT {} FROM courses c WHERE c.slug = ?", COURSE_COLUMNS);
+        let row = db.prepare(&sql).bind(&[slug.into()])?.first::<CourseRow>(None).await?;
+        let mut courses: Vec<Course> = row.map(Self::course_from_row).into_iter().collect();
+        Self::load_course_history(db, &mut courses).await?;
+        Ok(courses.pop())
+    }
+
+    /// Fill in `history` for every course with a single query, tagging each
+    /// change with the term its date falls in
+    async fn load_course_history(db: &D1Database, courses: &mut [Course]) -> Result<()> {
+        if courses.is_empty() {
+            return Ok(());
+        }
+        let slugs: Vec<&str> = courses.iter().map(|course| course.slug.as_str()).collect();
+        let stmt = query!(
+            db,
+            "SELECT c.slug, h.status, h.note, h.changed_at
+             FROM course_status_changes h JOIN courses c ON c.id = h.course_id
+             WHERE c.slug IN (SELECT value FROM json_each(?))
+             ORDER BY h.changed_at, h.id",
+            serde_json::to_string(&slugs)?
+        );
+        let rows = stmt?.all().await?.results::<CourseStatusChangeRow>()?;
+        let terms = Self::get_terms(db).await?;
+
+        let mut by_course: HashMap<String, Vec<CourseStatusChange>> = HashMap::new();
+        for row in rows {
+            let changed_at = parse_timestamp(&row.changed_at);
+            by_course.entry(row.slug).or_default().push(CourseStatusChange {
+                status: course_status_from_db(&row.status),
+                note: row.note,
+                changed_at,
+                term: terms.iter().find(|term| term.contains(changed_at)).cloned(),
+            });
+        }
+        for course in courses {
+            course.history = by_course.remove(&course.slug).unwrap_or_default();
+        }
+        Ok(())
+    }
+
+    /// Add a course, starting its history at `Pending`
+    pub async fn create_course(db: &D1Database, input: &CourseInput) -> Result<Option<Course>> {
+        let now = db_timestamp(Utc::now());
+        let mut binds = course_bind_values(input);
+        binds.push(JsValue::from_str(&now));
+        binds.push(JsValue::from_str(&now));
+
+        db.batch(vec![
+            db.prepare(
+                "INSERT INTO courses (slug, course_code, course_name, department, professor, description,
+                                      created_at, updated_at)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+            )
+            .bind(&binds)?,
+            db.prepare(
+                "INSERT INTO course_status_changes (course_id, status, changed_at)
+                 SELECT id, status, created_at FROM courses WHERE slug = ?",
+            )
+            .bind(&[input.slug.as_str().into()])?,
+        ])
+        .await?;
+        Self::get_course(db, &input.slug).await
+    }
+
+    /// Replace a course's descriptive fields; the slug may change. Status
+    /// only moves through `set_course_status`. Returns `None` if no course has this slug.
+    pub async fn update_course(db: &D1Database, slug: &str, input: &CourseInput) -> Result<Option<Course>> {
+        let mut binds = course_bind_values(input);
+        binds.push(JsValue::from_str(&db_timestamp(Utc::now())));
+        binds.push(JsValue::from_str(slug));
+
+        let result = db
+            .prepare(
+                "UPDATE courses
+                 SET slug = ?, course_code = ?, course_name = ?, department = ?, professor = ?, description = ?,
+                     updated_at = ?
+                 WHERE slug = ?",
+            )
+            .bind(&binds)?
+            .run()
+            .await?;
+        if changed_rows(&result) == 0 {
+            return Ok(None);
+        }
+        Self::get_course(db, &input.slug).await
+    }
+
+    /// Delete a course with its history and signatures. Returns `false` if no course has this slug.
+    pub async fn delete_course(db: &D1Database, slug: &str) -> Result<bool> {
+        let stmt = query!(db, "DELETE FROM courses WHERE slug = ?", slug);
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// Record a status change. The course's current status follows the
+    /// latest change, so back-dated milestones do not override it.
+    /// Returns `None` if no course has this slug.
+    pub async fn set_course_status(db: &D1Database, slug: &str, input: &CourseStatusInput) -> Result<Option<Course>> {
+        let changed_at = db_timestamp(input.changed_at.unwrap_or_else(Utc::now));
+        let results = db
+            .batch(vec![
+                db.prepare(
+                    "INSERT INTO course_status_changes (course_id, status, note, changed_at)
+                     SELECT id, ?, ?, ? FROM courses WHERE slug = ?",
+                )
+                .bind(&[
+                    course_status_to_db(input.status).into(),
+                    optional_str(input.note.as_deref()),
+                    changed_at.as_str().into(),
+                    slug.into(),
+                ])?,
+                db.prepare(
+                    "UPDATE courses
+                     SET status = (SELECT h.status FROM course_status_changes h
+                                   WHERE h.course_id = courses.id ORDER BY h.changed_at DESC, h.id DESC LIMIT 1),
+                         updated_at = ?
+                     WHERE slug = ?",
+                )
+                .bind(&[db_timestamp(Utc::now()).into(), slug.into()])?,
+            ])
+            .await?;
+        if results.first().map_or(0, changed_rows) == 0 {
+            return Ok(None);
+        }
+        Self::get_course(db, slug).await
+    }
+
+    /// Sign the petition itself (`course` is `None`) or back a course that
+    /// is still open, as a member and under their name. Returns `false` if
+    /// the member already signed, or the course does not exist or has been
+    /// decided.
+    pub async fn sign_petition(db: &D1Database, course: Option<&str>, member_id: i64) -> Result<bool> {
+        let now = db_timestamp(Utc::now());
+        let identity = format!("member:{}", member_id);
+        let stmt = match course {
+            Some(slug) => query!(
+                db,
+                "INSERT OR IGNORE INTO petition_signatures (course_id, name, identity, created_at)
+                 SELECT c.id, m.name, ?, ? FROM courses c, members m
+                 WHERE c.slug = ? AND c.status IN ('Pending', 'UnderReview') AND m.id = ?",
+                &identity,
+                &now,
+                slug,
+                member_id
+            ),
+            None => query!(
+                db,
+                "INSERT OR IGNORE INTO petition_signatures (course_id, name, identity, created_at)
+                 SELECT NULL, m.name, ?, ? FROM members m WHERE m.id = ?",
+                &identity,
+                &now,
+                member_id
+            ),
+        };
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    pub async fn get_petition_summary(db: &D1Database) -> Result<PetitionSummary> {
+        let row = db
+            .prepare(
+                "SELECT (SELECT COUNT(DISTINCT identity) FROM petition_signatures) AS total_supporters,
+                        (SELECT COUNT(*) FROM petition_signatures WHERE course_id IS NULL) AS petition_signatures,
+                        (SELECT COUNT(*) FROM courses) AS courses_targeted,
+                        (SELECT COUNT(*) FROM courses WHERE status = 'Approved') AS courses_approved",
+            )
+            .first::<PetitionSummaryRow>(None)
+            .await?;
+        let count = |value: i32| value.max(0) as u32;
+        Ok(row.map_or(
+            PetitionSummary {
+                total_supporters: 0,
+                petition_signatures: 0,
+                courses_targeted: 0,
+                courses_approved: 0,
+            },
+            |row| PetitionSummary {
+                total_supporters: count(row.total_supporters),
+                petition_signatures: count(row.petition_signatures),
+                courses_targeted: count(row.courses_targeted),
+                courses_approved: count(row.courses_approved),
+            },
+        ))
+    }
+
+    pub async fn get_contribution(db: &D1Database, id: &str) -> Result<Option<Contribution>> {
+        Ok(Self::get_contribution_record(db, id).await?.map(|record| record.contribution))
+    }
+
+    pub async fn get_contribution_record(db: &D1Database, id: &str) -> Result<Option<ContributionRecord>> {
+        let sql = format!("SELECT {} FROM {} WHERE c.uuid = ?", CONTRIBUTION_COLUMNS, CONTRIBUTION_TABLES);
+        let row = db.prepare(&sql).bind(&[id.into()])?.first::<ContributionRow>(None).await?;
+        Ok(row.map(|row| ContributionRecord {
+            member_id: row.member_id,
+            contribution: contribution_from_row(row),
+        }))
+    }
+
+    /// A member's claims and contributions, in every state
+    pub async fn get_member_contributions(db: &D1Database, member_id: i64, page: &PageRequest) -> Result<Page<Contribution>> {
+        let mut conditions = vec!["c.member_id = ?".to_string()];
+        let mut binds = vec![JsValue::from_f64(member_id as f64)];
+        page.push_keyset("c.created_at", "c.uuid", &mut conditions, &mut binds);
+        let tail = page.order_and_limit("c.created_at", "c.uuid", &mut binds);
+        let sql = format!(
+            "SELECT {} FROM {} {} {}",
+            CONTRIBUTION_COLUMNS,
+            CONTRIBUTION_TABLES,
+            where_clause(&conditions),
+            tail
+        );
+        let rows = db.prepare(&sql).bind(&binds)?.all().await?.results::<ContributionRow>()?;
+        Ok(page.to_page(
+            rows,
+            |row| Cursor { sort_key: row.created_at.clone(), id: row.uuid.clone() },
+            contribution_from_row,
+        ))
+    }
+
+    /// Claim an open issue for a member until `expires_at`, unless they
+    /// already hold `max_claims` claims without a PR. Lapsed claims are
+    /// expired first, so an issue whose claim ran out can be taken. Returns
+    /// `None` if the issue is missing, closed or already claimed, or the
+    /// member is at the limit.
+    pub async fn claim_issue(
+        db: &D1Database,
+        issue_id: &str,
+        member_id: i64,
+        expires_at: DateTime<Utc>,
+        max_claims: u32,
+    ) -> Result<Option<Contribution>> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = db_timestamp(Utc::now());
+        let results = db
+            .batch(vec![
+                Self::expire_claims_statement(db, &now)?,
+                // The partial unique index on active claims turns a second claim into a no-op
+                db.prepare(
+                    "INSERT OR IGNORE INTO contributions
+                         (uuid, member_id, issue_id, state, repo, title, claimed_at, claim_expires_at, created_at, updated_at)
+                     SELECT ?1, ?2, id, 'claimed', repo, title, ?3, ?4, ?3, ?3
+                     FROM issues
+                     WHERE uuid = ?5 AND state = 'open'
+                       AND (SELECT COUNT(*) FROM contributions WHERE member_id = ?2 AND state = 'claimed') < ?6",
+                )
+                .bind(&[
+                    id.as_str().into(),
+                    JsValue::from_f64(member_id as f64),
+                    now.as_str().into(),
+                    db_timestamp(expires_at).into(),
+                    issue_id.into(),
+                    JsValue::from_f64(max_claims as f64),
+                ])?,
+            ])
+            .await?;
+        if results.get(1).map_or(0, changed_rows) == 0 {
+            return Ok(None);
+        }
+        Self::get_contribution(db, &id).await
+    }
+
+    /// How many live claims without a PR a member holds
+    pub async fn count_active_claims(db: &D1Database, member_id: i64) -> Result<u32> {
+        let stmt = query!(
+            db,
+            "SELECT COUNT(*) AS count FROM contributions
+             WHERE member_id = ? AND state = 'claimed' AND claim_expires_at > ?",
+            member_id,
+            db_timestamp(Utc::now())
+        );
+        Ok(stmt?.first::<CountRow>(None).await?.map_or(0, |row| row.count.max(0) as u32))
+    }
+
+    /// Record the PR for a live claim; the claim no longer expires.
+    /// Returns `false` if the contribution is not a live claim.
+    pub async fn open_pull_request(db: &D1Database, id: &str, input: &PullRequestInput) -> Result<bool> {
+        let now = db_timestamp(Utc::now());
+        let stmt = query!(
+            db,
+            "UPDATE contributions
+             SET state = 'pr_opened', pr_url = ?1, title = ?2, pr_opened_at = ?3, claim_expires_at = NULL, updated_at = ?3
+             WHERE uuid = ?4 AND state = 'claimed' AND claim_expires_at > ?3",
+            input.pr_url.trim(),
+            input.title.trim(),
+            &now,
+            id
+        );
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// Mark an opened PR as merged. Returns `false` if no PR is open for the contribution.
+    pub async fn merge_contribution(db: &D1Database, id: &str, merged_at: DateTime<Utc>) -> Result<bool> {
+        let stmt = query!(
+            db,
+            "UPDATE contributions SET state = 'merged', merged_at = ?, updated_at = ?
+             WHERE uuid = ? AND state = 'pr_opened'",
+            db_timestamp(merged_at),
+            db_timestamp(Utc::now()),
+            id
+        );
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// Give up a claim or an open PR, freeing the issue. Returns `false` if
+    /// the contribution no longer holds its issue.
+    pub async fn release_contribution(db: &D1Database, id: &str) -> Result<bool> {
+        let stmt = query!(
+            db,
+            "UPDATE contributions SET state = 'released', claim_expires_at = NULL, updated_at = ?
+             WHERE uuid = ? AND state IN ('claimed', 'pr_opened')",
+            db_timestamp(Utc::now()),
+            id
+        );
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// Expire claims that reached their deadline without a PR. Returns how many lapsed.
+    pub async fn expire_claims(db: &D1Database) -> Result<usize> {
+        let result = Self::expire_claims_statement(db, &db_timestamp(Utc::now()))?.run().await?;
+        Ok(changed_rows(&result))
+    }
+
+    fn expire_claims_statement(db: &D1Database, now: &str) -> Result<D1PreparedStatement> {
+        db.prepare(
+            "UPDATE contributions SET state = 'expired', updated_at = ?1
+             WHERE state = 'claimed' AND claim_expires_at <= ?1",
+        )
+        .bind(&[now.into()])
+    }
+
+    pub async fn create_contribution(db: &D1Database, input: &ContributionInput, member_id: i64) -> Result<Option<Contribution>> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let stmt = query!(
+            db,
+            "INSERT INTO contributions (uuid, member_id, state, repo, pr_url, title, merged_at, created_at, updated_at)
+             VALUES (?1, ?2, 'merged', ?3, ?4, ?5, ?6, ?7, ?7)",
+            &id,
+            member_id,
+            &input.repo,
+            &input.pr_url,
+            &input.title,
+            db_timestamp(input.merged_at),
+            db_timestamp(Utc::now())
+        );
+        stmt?.run().await?;
+        Self::get_contribution(db, &id).await
+    }
+
+    /// Delete a contribution. Returns `false` if no contribution has this id.
+    pub async fn delete_contribution(db: &D1Database, id: &str) -> Result<bool> {
+        let stmt = query!(db, "DELETE FROM contributions WHERE uuid = ?", id);
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    pub async fn get_blog_posts(db: &D1Database, filter: &BlogPostFilter, page: &PageRequest) -> Result<Page<BlogPost>> {
+        let mut conditions = vec!["status = ?".to_string()];
+        let mut binds = vec![JsValue::from_str(blog_status_to_db(filter.status))];
+        if let Some(post_type) = &filter.post_type {
+            conditions.push("post_type = ?".to_string());
+            binds.push(JsValue::from_str(blog_post_type_to_db(post_type)));
+        }
+        if let Some(category) = &filter.category {
+            conditions.push("category = ?".to_string());
+            binds.push(JsValue::from_str(blog_category_to_db(category)));
+        }
+        if let Some(difficulty) = &filter.difficulty {
+            conditions.push("difficulty_level = ?".to_string());
+            binds.push(JsValue::from_str(difficulty_to_blog_db(difficulty)));
+        }
+        if let Some(author) = &filter.author {
+            conditions.push(format!("id IN {}", BLOG_POST_IDS_BY_AUTHOR));
+            binds.push(JsValue::from_str(author));
+        }
+        if let Some(tag) = &filter.tag {
+            conditions.push("EXISTS (SELECT 1 FROM json_each(blog_posts.tags) WHERE json_each.value = ?)".to_string());
+            binds.push(JsValue::from_str(tag));
+        }
+        if let Some((start, end)) = filter.published_within {
+            conditions.push("datetime(published_at) >= datetime(?) AND datetime(published_at) < datetime(?)".to_string());
+            binds.push(JsValue::from_str(&db_timestamp(start)));
+            binds.push(JsValue::from_str(&db_timestamp(end)));
+        }
+        if let Some(reviewer) = &filter.reviewer {
+            conditions.push(format!("reviewer_id = {}", MEMBER_ID_BY_GITHUB));
+            binds.push(JsValue::from_str(reviewer));
+        }
+        if let Some(member) = &filter.involving {
+            conditions.push(format!("(id IN {} OR reviewer_id = {})", BLOG_POST_IDS_BY_AUTHOR, MEMBER_ID_BY_GITHUB));
+            binds.push(JsValue::from_str(member));
+            binds.push(JsValue::from_str(member));
+        }
+        page.push_keyset("published_at", "id", &mut conditions, &mut binds);
+        let tail = page.order_and_limit("published_at", "id", &mut binds);
+        let sql = format!("SELECT {} FROM blog_posts {} {}", BLOG_POST_COLUMNS, where_clause(&conditions), tail);
+
+        let results = db.prepare(&sql).bind(&binds)?.all().await?;
+        let rows = results.results::<BlogPostRow>().unwrap_or_default();
+
+        Ok(page.to_page(
+            rows,
+            |row| Cursor { sort_key: row.published_at.clone(), id: row.id.clone() },
+            Self::blog_post_from_row,
+        ))
+    }
+
+    pub async fn get_blog_posts_by_ids(db: &D1Database, ids: &[String]) -> Result<Vec<BlogPost>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let query_str = format!(
+            "SELECT {}
+             FROM blog_posts
+             WHERE id IN ({}) AND status = 'published'
+             ORDER BY published_at DESC",
+            BLOG_POST_COLUMNS, placeholders
+        );
+
+        // Convert IDs to JsValue for binding
+        let bind_values: Vec<wasm_bindgen::JsValue> = ids.iter()
+            .map(|id| wasm_bindgen::JsValue::from_str(id))
+            .collect();
+
+        let stmt = db.prepare(&query_str).bind(&bind_values)?;
+        let results = stmt.all().await?;
+        let mut posts = Vec::new();
+
+        if let Ok(rows) = results.results::<BlogPostRow>() {
+            for row in rows {
+                let post = Self::blog_post_from_row(row);
+                posts.push(post);
+            }
+        }
+
+        Ok(posts)
+    }
+
+    /// A post by slug in any status; public callers must check it is published
+    pub async fn get_blog_post_by_slug(db: &D1Database, slug: &str) -> Result<Option<BlogPost>> {
+        let sql = format!("SELECT {} FROM blog_posts WHERE slug = ?", BLOG_POST_COLUMNS);
+        let result = db.prepare(&sql).bind(&[slug.into()])?.first::<BlogPostRow>(None).await?;
+
+        if let Some(row) = result {
+            let post = Self::blog_post_from_row(row);
+            Ok(Some(post))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// All series, including ones with nothing published yet, by title
+    pub async fn get_blog_series_list(db: &D1Database) -> Result<Vec<BlogSeriesSummary>> {
+        let sql = format!("SELECT {} FROM blog_series s ORDER BY s.title COLLATE NOCASE", BLOG_SERIES_COLUMNS);
+        let rows = db.prepare(&sql).all().await?.results::<BlogSeriesRow>()?;
+        Ok(rows.into_iter().map(Self::blog_series_from_row).collect())
+    }
+
+    pub async fn get_blog_series_summary(db: &D1Database, slug: &str) -> Result<Option<BlogSeriesSummary>> {
+        let sql = format!("SELECT {} FROM blog_series s WHERE s.slug = ?", BLOG_SERIES_COLUMNS);
+        let row = db.prepare(&sql).bind(&[slug.into()])?.first::<BlogSeriesRow>(None).await?;
+        Ok(row.map(Self::blog_series_from_row))
+    }
+
+    /// A series with its published parts, ordered by part number
+    pub async fn get_blog_series(db: &D1Database, slug: &str) -> Result<Option<BlogSeriesDetail>> {
+        let Some(series) = Self::get_blog_series_summary(db, slug).await? else {
+            return Ok(None);
+        };
+        let stmt = query!(
+            db,
+            "SELECT p.series_part AS part, p.title, p.slug, p.excerpt, p.published_at, p.estimated_read_time
+             FROM blog_posts p JOIN blog_series s ON s.id = p.series_id
+             WHERE s.slug = ? AND p.status = 'published'
+             ORDER BY p.series_part, p.published_at",
+            slug
+        );
+        let rows = stmt?.all().await?.results::<BlogSeriesEntryRow>()?;
+        Ok(Some(BlogSeriesDetail {
+            slug: series.slug,
+            title: series.title,
+            description: series.description,
+            planned_parts: series.planned_parts,
+            parts: rows
+                .into_iter()
+                .map(|row| BlogSeriesEntry {
+                    part: row.part,
+                    title: row.title,
+                    slug: row.slug,
+                    excerpt: row.excerpt,
+                    published_at: row.published_at,
+                    estimated_read_time: row.estimated_read_time,
+                })
+                .collect(),
+        }))
+    }
+
+    /// Slug of another post, in any status, that already holds `part` of the series
+    pub async fn get_blog_series_part_holder(
+        db: &D1Database,
+        series: &str,
+        part: u32,
+        except_post_id: &str,
+    ) -> Result<Option<String>> {
+        let stmt = query!(
+            db,
+            "SELECT p.slug FROM blog_posts p JOIN blog_series s ON s.id = p.series_id
+             WHERE s.slug = ? AND p.series_part = ? AND p.id != ? LIMIT 1",
+            series,
+            part,
+            except_post_id
+        );
+        let row = stmt?.first::<serde_json::Value>(None).await?;
+        Ok(row.and_then(|r| r.get("slug").and_then(|v| v.as_str()).map(str::to_string)))
+    }
+
+    pub async fn create_blog_series(db: &D1Database, input: &BlogSeriesInput) -> Result<Option<BlogSeriesDetail>> {
+        let now = db_timestamp(Utc::now());
+        let mut binds = blog_series_bind_values(input);
+        binds.push(JsValue::from_str(&now));
+        binds.push(JsValue::from_str(&now));
+        db.prepare(
+            "INSERT INTO blog_series (slug, title, description, planned_parts, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&binds)?
+        .run()
+        .await?;
+        Self::get_blog_series(db, &input.slug).await
+    }
+
+    /// Replace a series' fields, including its slug; its posts follow. Returns
+    /// `None` if no series has this slug.
+    pub async fn update_blog_series(db: &D1Database, slug: &str, input: &BlogSeriesInput) -> Result<Option<BlogSeriesDetail>> {
+        let mut binds = blog_series_bind_values(input);
+        binds.push(JsValue::from_str(&db_timestamp(Utc::now())));
+        binds.push(JsValue::from_str(slug));
+        let result = db
+            .prepare("UPDATE blog_series SET slug = ?, title = ?, description = ?, planned_parts = ?, updated_at = ? WHERE slug = ?")
+            .bind(&binds)?
+            .run()
+            .await?;
+        if changed_rows(&result) == 0 {
+            return Ok(None);
+        }
+        Self::get_blog_series(db, &input.slug).await
+    }
+
+    /// Delete a series; its posts stay and leave the series. Returns `false`
+    /// if no series has this slug.
+    pub async fn delete_blog_series(db: &D1Database, slug: &str) -> Result<bool> {
+        let stmt = query!(db, "DELETE FROM blog_series WHERE slug = ?", slug);
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// Like count and whether `visitor` has liked the post. `None` if no post has this slug.
+    pub async fn get_like_status(db: &D1Database, slug: &str, visitor: &str) -> Result<Option<LikeStatus>> {
+        let stmt = query!(
+            db,
+            "SELECT b.likes,
+                    EXISTS (SELECT 1 FROM blog_post_likes l WHERE l.post_id = b.id AND l.visitor_hash = ?) AS liked
+             FROM blog_posts b
+             WHERE b.slug = ? AND b.status = 'published'",
+            visitor,
+            slug
+        );
+        let row = stmt?.first::<LikeRow>(None).await?;
+        Ok(row.map(|row| LikeStatus {
+            likes: row.likes.max(0) as u32,
+            liked: row.liked != 0,
+        }))
+    }
+
+    /// Record a like from `visitor`. Liking twice is a no-op: the count moves
+    /// by `changes()` of the insert, in the same batch, so it cannot drift from
+    /// the like rows.
+    pub async fn like_blog_post(db: &D1Database, slug: &str, visitor: &str) -> Result<Option<LikeStatus>> {
+        let post = Self::get_blog_post_by_slug(db, slug).await?;
+        let Some(post) = post.filter(|post| post.status == BlogPostStatus::Published) else {
+            return Ok(None);
+        };
+        db.batch(vec![
+            query!(
+                db,
+                "INSERT OR IGNORE INTO blog_post_likes (post_id, visitor_hash, created_at) VALUES (?, ?, ?)",
+                &post.id,
+                visitor,
+                db_timestamp(Utc::now())
+            )?,
+            query!(db, "UPDATE blog_posts SET likes = likes + changes() WHERE id = ?", &post.id)?,
+        ])
+        .await?;
+        Self::get_like_status(db, slug, visitor).await
+    }
+
+    /// Remove `visitor`'s like, if any. As with likes, the count moves by the
+    /// rows the delete removed.
+    pub async fn unlike_blog_post(db: &D1Database, slug: &str, visitor: &str) -> Result<Option<LikeStatus>> {
+        let post = Self::get_blog_post_by_slug(db, slug).await?;
+        let Some(post) = post.filter(|post| post.status == BlogPostStatus::Published) else {
+            return Ok(None);
+        };
+        db.batch(vec![
+            query!(
+                db,
+                "DELETE FROM blog_post_likes WHERE post_id = ? AND visitor_hash = ?",
+                &post.id,
+                visitor
+            )?,
+            query!(db, "UPDATE blog_posts SET likes = MAX(likes - changes(), 0) WHERE id = ?", &post.id)?,
+        ])
+        .await?;
+        Self::get_like_status(db, slug, visitor).await
+    }
+
+    /// Ranked matches for an FTS5 `query` (see `search::fts_query`). Titles
+    /// weigh most, then tags, then body text.
+    pub async fn search(
+        db: &D1Database,
+        query: &str,
+        kind: Option<SearchResultKind>,
+        limit: u32,
+    ) -> Result<Vec<SearchResult>> {
+        let mut conditions = vec!["search_index MATCH ?".to_string()];
+        let mut binds = vec![JsValue::from_str(query)];
+        if let Some(kind) = &kind {
+            conditions.push("kind = ?".to_string());
+            binds.push(JsValue::from_str(search_kind_to_db(kind)));
+        }
+        binds.push(JsValue::from_f64(limit as f64));
+
+        let sql = format!(
+            "SELECT kind, ref_id, url, title,
+                    highlight(search_index, 3, char(2), char(3)) AS title_marked,
+                    snippet(search_index, 4, char(2), char(3), '…', 24) AS snippet_marked,
+                    -bm25(search_index, 0.0, 0.0, 0.0, 10.0, 1.0, 4.0) AS score
+             FROM search_index
+             {}
+             ORDER BY score DESC
+             LIMIT ?",
+            where_clause(&conditions)
+        );
+        let rows = db.prepare(&sql).bind(&binds)?.all().await?.results::<SearchRow>()?;
+
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| {
+                let kind = match row.kind.as_str() {
+                    "BlogPost" => SearchResultKind::BlogPost,
+                    "Issue" => SearchResultKind::Issue,
+                    "Project" => SearchResultKind::Project,
+                    "Event" => SearchResultKind::Event,
+                    _ => return None,
+                };
+                Some(SearchResult {
+                    kind,
+                    id: row.ref_id,
+                    title: row.title,
+                    title_html: search::marked_html(&row.title_marked),
+                    snippet_html: search::marked_html(&row.snippet_marked),
+                    url: row.url,
+                    score: row.score,
+                })
+            })
+            .collect())
+    }
+
+    /// Id and markdown location of every blog post, for rebuilding the search index
+    pub async fn get_blog_post_markdown_sources(db: &D1Database) -> Result<Vec<(String, String)>> {
+        let rows = db
+            .prepare("SELECT id, markdown_url FROM blog_posts ORDER BY id")
+            .all()
+            .await?
+            .results::<MarkdownSourceRow>()?;
+        Ok(rows.into_iter().map(|row| (row.id, row.markdown_url)).collect())
+    }
+
+    /// Store a post's markdown as plain text; a trigger re-indexes it for search
+    pub async fn set_blog_post_body_text(db: &D1Database, id: &str, body_text: &str) -> Result<()> {
+        let stmt = query!(db, "UPDATE blog_posts SET body_text = ? WHERE id = ?", body_text, id);
+        stmt?.run().await?;
+        Ok(())
+    }
+
+    /// Whether `slug` names a published post, for the view beacon
+    pub async fn is_published_blog_post(db: &D1Database, slug: &str) -> Result<bool> {
+        let stmt = query!(
+            db,
+            "SELECT COUNT(*) AS count FROM blog_posts WHERE slug = ? AND status = 'published'",
+            slug
+        );
+        Ok(stmt?.first::<CountRow>(None).await?.is_some_and(|row| row.count > 0))
+    }
+
+    /// Add flushed view counters to their posts in a single batch. Each
+    /// counter is applied once: its `blog_view_flushes` row is inserted just
+    /// before the update, which adds the count only if that insert changed a
+    /// row. Markers from before `prune_before` are dropped in the same batch.
+    /// Returns the number of views added.
+    pub async fn add_blog_post_views(db: &D1Database, counters: &[ViewCounter], prune_before: u64) -> Result<u32> {
+        let mut statements = vec![
+            db.prepare("DELETE FROM blog_view_flushes WHERE window_id < ?")
+                .bind(&[JsValue::from_f64(prune_before as f64)])?,
+        ];
+        for counter in counters {
+            statements.push(
+                db.prepare("INSERT OR IGNORE INTO blog_view_flushes (window_id, slug) VALUES (?, ?)")
+                    .bind(&[JsValue::from_f64(counter.window as f64), JsValue::from_str(&counter.slug)])?,
+            );
+            statements.push(
+                db.prepare("UPDATE blog_posts SET views = views + ? * changes() WHERE slug = ?")
+                    .bind(&[JsValue::from_f64(counter.count as f64), JsValue::from_str(&counter.slug)])?,
+            );
+        }
+        let results = db.batch(statements).await?;
+        // Results after the prune come in (marker, update) pairs
+        Ok(counters
+            .iter()
+            .zip(results.iter().skip(1).step_by(2))
+            .filter(|(_, marker)| changed_rows(marker) > 0)
+            .map(|(counter, _)| counter.count)
+            .sum())
+    }
+
+    /// Unexpanded events for calendar feeds: every recurring series plus
+    /// one-off events that end after `since`, in start order
+    pub async fn get_calendar_events(db: &D1Database, since: DateTime<Utc>) -> Result<Vec<Event>> {
+        let sql = format!(
+            "SELECT {} FROM events WHERE recurrence IS NOT NULL OR ends_at > ? ORDER BY starts_at, uuid",
+            EVENT_COLUMNS
+        );
+        let results = db
+            .prepare(&sql)
+            .bind(&[JsValue::from_str(&db_timestamp(since))])?
+            .all()
+            .await?;
+        let rows = results.results::<EventRow>()?;
+        Ok(rows.into_iter().map(Self::event_from_row).collect())
+    }
+
+    pub async fn get_event(db: &D1Database, id: &str) -> Result<Option<Event>> {
+        let sql = format!("SELECT {} FROM events WHERE uuid = ?", EVENT_COLUMNS);
+        let row = db.prepare(&sql).bind(&[id.into()])?.first::<EventRow>(None).await?;
+        Ok(row.map(Self::event_from_row))
+    }
+
+    pub async fn create_event(db: &D1Database, input: &EventInput) -> Result<Option<Event>> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = db_timestamp(Utc::now());
+        let mut binds = vec![JsValue::from_str(&id)];
+        binds.extend(event_bind_values(input));
+        binds.push(JsValue::from_str(&now));
+        binds.push(JsValue::from_str(&now));
+
+        db.prepare(
+            "INSERT INTO events (uuid, title, description, starts_at, ends_at, timezone, recurrence, location, event_type,
+                                 capacity, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )
+        .bind(&binds)?
+        .run()
+        .await?;
+        Self::get_event(db, &id).await
+    }
+
+    /// Replace an event's fields. Returns `None` if no event has this id.
+    pub async fn update_event(db: &D1Database, id: &str, input: &EventInput) -> Result<Option<Event>> {
+        let mut binds = event_bind_values(input);
+        binds.push(JsValue::from_str(&db_timestamp(Utc::now())));
+        binds.push(JsValue::from_str(id));
+
+        let result = db
+            .prepare(
+                "UPDATE events
+                 SET title = ?, description = ?, starts_at = ?, ends_at = ?, timezone = ?, recurrence = ?,
+                     location = ?, event_type = ?, capacity = ?, updated_at = ?
+                 WHERE uuid = ?",
+            )
+            .bind(&binds)?
+            .run()
+            .await?;
+        if changed_rows(&result) == 0 {
+            return Ok(None);
+        }
+        Self::get_event(db, id).await
+    }
+
+    /// Delete an event. Returns `false` if no event has this id.
+    pub async fn delete_event(db: &D1Database, id: &str) -> Result<bool> {
+        let stmt = query!(db, "DELETE FROM events WHERE uuid = ?", id);
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// RSVP to an event on a member's behalf. The capacity check and the
+    /// insert are one statement, so concurrent RSVPs cannot overbook. Returns
+    /// `None` if the event does not exist or has no room for another `Going`.
+    pub async fn create_event_rsvp(
+        db: &D1Database,
+        id: &str,
+        member_id: i64,
+        status: RsvpStatus,
+        token: &str,
+    ) -> Result<Option<EventRsvp>> {
+        let sql = format!(
+            "INSERT INTO event_rsvps (event_id, member_id, token_hash, status, created_at, updated_at)
+             SELECT e.id, m.id, ?1, ?2, ?3, ?3
+             FROM events e, members m
+             WHERE e.uuid = ?4 AND m.id = ?5 AND (?2 != 'going' OR {})",
+            RSVP_HAS_ROOM
+        );
+        let stmt = query!(
+            db,
+            &sql,
+            tokens::token_hash(token),
+            rsvp_status_to_db(status),
+            db_timestamp(Utc::now()),
+            id,
+            member_id
+        );
+        let result = stmt?.run().await?;
+        if changed_rows(&result) == 0 {
+            return Ok(None);
+        }
+        Self::get_event_rsvp(db, id, token).await
+    }
+
+    /// Look up an RSVP by its confirmation token
+    pub async fn get_event_rsvp(db: &D1Database, id: &str, token: &str) -> Result<Option<EventRsvp>> {
+        let sql = format!(
+            "SELECT {} FROM event_rsvps r
+             JOIN events e ON r.event_id = e.id JOIN members m ON r.member_id = m.id
+             WHERE r.token_hash = ? AND e.uuid = ?",
+            RSVP_COLUMNS
+        );
+        let stmt = query!(db, &sql, tokens::token_hash(token), id);
+        let Some(row) = stmt?.first::<RsvpRow>(None).await? else {
+            return Ok(None);
+        };
+        let Some(event) = Self::get_event(db, &row.event_uuid).await? else {
+            return Ok(None);
+        };
+        let rsvp = rsvp_from_row(row);
+        Ok(Some(EventRsvp {
+            token: token.to_string(),
+            member: rsvp.member,
+            status: rsvp.status,
+            responded_at: rsvp.responded_at,
+            event,
+        }))
+    }
+
+    /// Change an RSVP's status, with the same capacity check as creating one.
+    /// Returns `None` if the token does not match or the event is full.
+    pub async fn update_event_rsvp(db: &D1Database, id: &str, token: &str, status: RsvpStatus) -> Result<Option<EventRsvp>> {
+        let sql = format!(
+            "UPDATE event_rsvps SET status = ?1, updated_at = ?2
+             WHERE token_hash = ?3 AND event_id = (SELECT id FROM events WHERE uuid = ?4)
+               AND (?1 != 'going' OR status = 'going' OR EXISTS (
+                   SELECT 1 FROM events e, members m
+                   WHERE e.id = event_rsvps.event_id AND m.id = event_rsvps.member_id AND {}))",
+            RSVP_HAS_ROOM
+        );
+        let stmt = query!(
+            db,
+            &sql,
+            rsvp_status_to_db(status),
+            db_timestamp(Utc::now()),
+            tokens::token_hash(token),
+            id
+        );
+        let result = stmt?.run().await?;
+        if changed_rows(&result) == 0 {
+            return Ok(None);
+        }
+        Self::get_event_rsvp(db, id, token).await
+    }
+
+    /// Withdraw an RSVP. Returns `false` if the token does not match an RSVP for this event.
+    pub async fn delete_event_rsvp(db: &D1Database, id: &str, token: &str) -> Result<bool> {
+        let stmt = query!(
+            db,
+            "DELETE FROM event_rsvps WHERE token_hash = ? AND event_id = (SELECT id FROM events WHERE uuid = ?)",
+            tokens::token_hash(token),
+            id
+        );
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// Every RSVP to an event: going, then maybe, then not going, each in response order
+    pub async fn get_event_rsvps(db: &D1Database, id: &str) -> Result<Vec<Rsvp>> {
+        let sql = format!(
+            "SELECT {} FROM event_rsvps r
+             JOIN events e ON r.event_id = e.id JOIN members m ON r.member_id = m.id
+             WHERE e.uuid = ?
+             ORDER BY CASE r.status WHEN 'going' THEN 0 WHEN 'maybe' THEN 1 ELSE 2 END, r.updated_at",
+            RSVP_COLUMNS
+        );
+        let rows = query!(db, &sql, id)?.all().await?.results::<RsvpRow>()?;
+        Ok(rows.into_iter().map(rsvp_from_row).collect())
+    }
+
+    /// Record that a member attended the occurrence starting at
+    /// `occurrence_starts_at`. Returns `None` if the event does not exist.
+    /// Checking in twice to the same occurrence is a UNIQUE violation.
+    pub async fn create_check_in(
+        db: &D1Database,
+        id: &str,
+        member_id: i64,
+        occurrence_starts_at: DateTime<Utc>,
+    ) -> Result<Option<CheckIn>> {
+        let occurrence = db_timestamp(occurrence_starts_at);
+        let stmt = query!(
+            db,
+            "INSERT INTO event_check_ins (event_id, member_id, occurrence_starts_at, checked_in_at)
+             SELECT e.id, ?, ?, ? FROM events e WHERE e.uuid = ?",
+            member_id,
+            &occurrence,
+            db_timestamp(Utc::now()),
+            id
+        );
+        let result = stmt?.run().await?;
+        if changed_rows(&result) == 0 {
+            return Ok(None);
+        }
+
+        let sql = format!(
+            "SELECT {} FROM event_check_ins c
+             JOIN events e ON c.event_id = e.id JOIN members m ON c.member_id = m.id
+             WHERE e.uuid = ? AND c.member_id = ? AND c.occurrence_starts_at = ?",
+            CHECK_IN_COLUMNS
+        );
+        let row = query!(db, &sql, id, member_id, &occurrence)?.first::<CheckInRow>(None).await?;
+        Ok(row.map(check_in_from_row))
+    }
+
+    /// Every check-in to an event, latest occurrence first
+    pub async fn get_event_check_ins(db: &D1Database, id: &str) -> Result<Vec<CheckIn>> {
+        let sql = format!(
+            "SELECT {} FROM event_check_ins c
+             JOIN events e ON c.event_id = e.id JOIN members m ON c.member_id = m.id
+             WHERE e.uuid = ?
+             ORDER BY c.occurrence_starts_at DESC, c.checked_in_at",
+            CHECK_IN_COLUMNS
+        );
+        let rows = query!(db, &sql, id)?.all().await?.results::<CheckInRow>()?;
+        Ok(rows.into_iter().map(check_in_from_row).collect())
+    }
+
+    pub async fn get_issue(db: &D1Database, id: &str) -> Result<Option<Issue>> {
+        let sql = format!("SELECT {} FROM {} WHERE i.uuid = ?", ISSUE_COLUMNS, ISSUE_TABLES);
+        let row = db.prepare(&sql).bind(&[id.into()])?.first::<IssueRow>(None).await?;
+        Ok(row.map(Self::issue_from_row))
+    }
+
+    pub async fn create_issue(db: &D1Database, input: &IssueInput) -> Result<Option<Issue>> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = db_timestamp(Utc::now());
+        let tags = serde_json::to_string(&input.tags)?;
+        let stmt = query!(
+            db,
+            "INSERT INTO issues (uuid, title, description, repo, github_url, difficulty, tags, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            &id,
+            &input.title,
+            &input.description,
+            &input.repo,
+            &input.github_url,
+            input.difficulty.to_string(),
+            &tags,
+            &now,
+            &now
+        );
+        stmt?.run().await?;
+        Self::get_issue(db, &id).await
+    }
+
+    /// Replace an issue's fields. Returns `None` if no issue has this id. A
+    /// synced issue becomes a manual one, so the next sync leaves it alone.
+    pub async fn update_issue(db: &D1Database, id: &str, input: &IssueInput) -> Result<Option<Issue>> {
+        let tags = serde_json::to_string(&input.tags)?;
+        let stmt = query!(
+            db,
+            "UPDATE issues
+             SET title = ?, description = ?, repo = ?, github_url = ?, difficulty = ?, tags = ?, updated_at = ?,
+                 source = 'manual'
+             WHERE uuid = ?",
+            &input.title,
+            &input.description,
+            &input.repo,
+            &input.github_url,
+            input.difficulty.to_string(),
+            &tags,
+            db_timestamp(Utc::now()),
+            id
+        );
+        let result = stmt?.run().await?;
+        if changed_rows(&result) == 0 {
+            return Ok(None);
+        }
+        Self::get_issue(db, id).await
+    }
+
+    /// Insert or refresh issues synced from GitHub, keyed on `github_url`,
+    /// and stamp them with `synced_at`. Manually entered issues with the same
+    /// URL are left as they are. Returns how many rows were written.
+    pub async fn upsert_synced_issues(db: &D1Database, issues: &[SyncedIssue], synced_at: DateTime<Utc>) -> Result<usize> {
+        if issues.is_empty() {
+            return Ok(0);
+        }
+        let synced_at = db_timestamp(synced_at);
+        let mut statements = Vec::with_capacity(issues.len());
+        for issue in issues {
+            let input = &issue.input;
+            statements.push(
+                db.prepare(
+                    "INSERT INTO issues (uuid, title, description, repo, github_url, difficulty, tags, source, state,
+                                         synced_at, created_at, updated_at)
+                     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, 'github', 'open', ?8, ?9, ?8)
+                     ON CONFLICT (github_url) DO UPDATE SET
+                         title = excluded.title, description = excluded.description, repo = excluded.repo,
+                         difficulty = excluded.difficulty, tags = excluded.tags, state = 'open', closed_at = NULL,
+                         synced_at = excluded.synced_at, updated_at = excluded.updated_at
+                     WHERE issues.source = 'github'",
+                )
+                .bind(&[
+                    JsValue::from_str(&uuid::Uuid::new_v4().to_string()),
+                    JsValue::from_str(&input.title),
+                    JsValue::from_str(&input.description),
+                    JsValue::from_str(&input.repo),
+                    JsValue::from_str(&input.github_url),
+                    JsValue::from_str(&input.difficulty.to_string()),
+                    JsValue::from_str(&serde_json::to_string(&input.tags)?),
+                    JsValue::from_str(&synced_at),
+                    JsValue::from_str(&db_timestamp(issue.opened_at)),
+                ])?,
+            );
+        }
+        let results = db.batch(statements).await?;
+        Ok(results.iter().map(changed_rows).sum())
+    }
+
+    /// Close the open synced issues in `repo` that the sync started at
+    /// `synced_at` did not see. Returns how many were closed.
+    pub async fn close_unsynced_issues(db: &D1Database, repo: &str, synced_at: DateTime<Utc>) -> Result<usize> {
+        let synced_at = db_timestamp(synced_at);
+        let stmt = query!(
+            db,
+            "UPDATE issues SET state = 'closed', closed_at = ?1, updated_at = ?1
+             WHERE source = 'github' AND state = 'open' AND repo = ?2 AND (synced_at IS NULL OR synced_at < ?1)",
+            &synced_at,
+            repo
+        );
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result))
+    }
+
+    /// Delete an issue. Returns `false` if no issue has this id.
+    pub async fn delete_issue(db: &D1Database, id: &str) -> Result<bool> {
+        let stmt = query!(db, "DELETE FROM issues WHERE uuid = ?", id);
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// Link a GitHub account to its member on sign-in. Matches the stored
+    /// GitHub id first, then an unlinked row with the same username. Returns
+    /// the member's row id, or `None` if the account belongs to no member;
+    /// officers add members, signing in does not.
+    pub async fn link_github_member(db: &D1Database, user: &GitHubUser) -> Result<Option<i64>> {
+        let now = db_timestamp(Utc::now());
+        let stmt = query!(
+            db,
+            "SELECT id FROM members
+             WHERE github_id = ?1 OR (github_id IS NULL AND github_username = ?2 COLLATE NOCASE)
+             ORDER BY github_id IS NULL
+             LIMIT 1",
+            user.id,
+            &user.login
+        );
+        let existing = stmt?.first::<serde_json::Value>(None).await?;
+
+        let Some(member_id) = existing.and_then(|r| r.get("id").and_then(|v| v.as_i64())) else {
+            return Ok(None);
+        };
+
+        // Follow username changes on GitHub. blog_posts.author_github points
+        // at the old username without ON UPDATE CASCADE, so it moves in the
+        // same transaction with the key check left until commit.
+        let member = JsValue::from_f64(member_id as f64);
+        db.batch(vec![
+            db.prepare("PRAGMA defer_foreign_keys = true"),
+            db.prepare(
+                "UPDATE blog_posts SET author_github = ?1
+                 WHERE author_github = (SELECT github_username FROM members WHERE id = ?2) AND author_github != ?1",
+            )
+            .bind(&[user.login.as_str().into(), member.clone()])?,
+            db.prepare("UPDATE members SET github_id = ?, github_username = ?, avatar_url = ?, updated_at = ? WHERE id = ?")
+                .bind(&[
+                    JsValue::from_f64(user.id as f64),
+                    user.login.as_str().into(),
+                    optional_str(user.avatar_url.as_deref()),
+                    now.as_str().into(),
+                    member,
+                ])?,
+        ])
+        .await?;
+        Ok(Some(member_id))
+    }
+
+    /// The member directory, by name. Members without a GitHub username have
+    /// no profile to link to and are left out, as are profiles the viewer
+    /// may not see.
+    pub async fn get_members(db: &D1Database, filter: &MemberFilter, page: &PageRequest) -> Result<Page<MemberSummary>> {
+        let mut conditions = vec!["m.github_username IS NOT NULL".to_string()];
+        let mut binds = Vec::new();
+        conditions.push(if filter.signed_in {
+            "m.profile_visibility IN ('public', 'members')".to_string()
+        } else {
+            "m.profile_visibility = 'public'".to_string()
+        });
+        // Every member implicitly holds Member, so it filters nothing out
+        if let Some(role) = filter.role.filter(|role| *role != MemberRole::Member) {
+            conditions.push("EXISTS (SELECT 1 FROM member_roles r WHERE r.member_id = m.id AND r.role = ?)".to_string());
+            binds.push(JsValue::from_str(role_to_db(role)));
+        }
+        if let Some(skill) = &filter.skill {
+            conditions.push("EXISTS (SELECT 1 FROM json_each(m.skills) WHERE json_each.value = ? COLLATE NOCASE)".to_string());
+            binds.push(JsValue::from_str(skill));
+        }
+        page.push_keyset("m.name", "m.github_username", &mut conditions, &mut binds);
+        let tail = page.order_and_limit("m.name", "m.github_username", &mut binds);
+        let sql = format!("SELECT {} FROM members m {} {}", MEMBER_COLUMNS, where_clause(&conditions), tail);
+
+        let rows = db.prepare(&sql).bind(&binds)?.all().await?.results::<MemberRow>()?;
+        let mut members = page.to_page(
+            rows,
+            |row| Cursor { sort_key: row.name.clone(), id: row.github_username.clone() },
+            |row| member_record_from_row(row).summary,
+        );
+        Self::load_member_roles(db, &mut members.items).await?;
+        Ok(members)
+    }
+
+    /// Fill in `roles` for every member with a single query
+    async fn load_member_roles(db: &D1Database, members: &mut [MemberSummary]) -> Result<()> {
+        if members.is_empty() {
+            return Ok(());
+        }
+        let usernames: Vec<&str> = members.iter().map(|member| member.github_username.as_str()).collect();
+        let stmt = query!(
+            db,
+            "SELECT m.github_username, r.role
+             FROM member_roles r JOIN members m ON m.id = r.member_id
+             WHERE m.github_username IN (SELECT value FROM json_each(?))",
+            serde_json::to_string(&usernames)?
+        );
+        let rows = stmt?.all().await?.results::<MemberRoleRow>()?;
+
+        let mut by_member: HashMap<String, Vec<MemberRole>> = HashMap::new();
+        for row in rows {
+            by_member.entry(row.github_username).or_default().push(role_from_db(&row.role));
+        }
+        for member in members {
+            let mut roles = by_member.remove(&member.github_username).unwrap_or_default();
+            roles.sort_by_key(|role| *role as u8);
+            member.roles = roles;
+        }
+        Ok(())
+    }
+
+    /// Look up a member by GitHub username, ignoring case
+    pub async fn get_member(db: &D1Database, github_username: &str) -> Result<Option<MemberRecord>> {
+        let sql = format!("SELECT {} FROM members m WHERE m.github_username = ? COLLATE NOCASE", MEMBER_COLUMNS);
+        let Some(row) = db.prepare(&sql).bind(&[github_username.into()])?.first::<MemberRow>(None).await? else {
+            return Ok(None);
+        };
+        let mut record = member_record_from_row(row);
+        record.summary.roles = Self::get_member_roles(db, record.id).await?;
+        Ok(Some(record))
+    }
+
+    /// Everything a member has done in the club, for their profile page.
+    /// Attendance is left out when the member hides it.
+    pub async fn get_member_profile(db: &D1Database, record: MemberRecord) -> Result<MemberProfile> {
+        let github = record.summary.github_username.clone();
+        let recent = PageRequest { limit: PROFILE_LIST_LIMIT, ..PageRequest::default() };
+        let posts_filter = BlogPostFilter { author: Some(github.clone()), ..BlogPostFilter::default() };
+        let blog_posts = Self::get_blog_posts(db, &posts_filter, &recent).await?.items;
+
+        let sql = format!(
+            "SELECT {} FROM projects p JOIN members m ON p.leader_id = m.id
+             WHERE p.leader_id = ?1 OR p.id IN (SELECT project_id FROM project_contributors WHERE member_id = ?1)
+             ORDER BY p.created_at DESC, p.uuid DESC",
+            PROJECT_COLUMNS
+        );
+        let rows = db
+            .prepare(&sql)
+            .bind(&[JsValue::from_f64(record.id as f64)])?
+            .all()
+            .await?
+            .results::<ProjectRow>()?;
+        let mut projects: Vec<Project> = rows.into_iter().map(Self::project_from_row).collect();
+        Self::load_contributors(db, &mut projects).await?;
+        let (projects_led, projects_contributed): (Vec<Project>, Vec<Project>) = projects
+            .into_iter()
+            .partition(|project| project.leader.github_username.as_deref() == Some(github.as_str()));
+
+        let sql = format!(
+            "SELECT {} FROM {}
+             WHERE c.member_id = ? AND c.state = 'merged'
+             ORDER BY c.merged_at DESC, c.uuid DESC
+             LIMIT ?",
+            CONTRIBUTION_COLUMNS, CONTRIBUTION_TABLES
+        );
+        let contributions = db
+            .prepare(&sql)
+            .bind(&[JsValue::from_f64(record.id as f64), JsValue::from_f64(PROFILE_LIST_LIMIT as f64)])?
+            .all()
+            .await?
+            .results::<ContributionRow>()?
+            .into_iter()
+            .map(contribution_from_row)
+            .collect();
+
+        let (workshops_attended, events_attended) = if record.show_attendance {
+            // Only registrations made while signed in name the member; a
+            // seat held until the workshop ended counts as attending
+            let sql = format!(
+                "SELECT {} FROM workshops w
+                 WHERE w.ends_at <= ?1 AND EXISTS (
+                     SELECT 1 FROM workshop_registrations r
+                     WHERE r.workshop_id = w.id AND r.status = 'registered' AND r.member_id = ?2
+                 )
+                 ORDER BY w.starts_at DESC",
+                WORKSHOP_COLUMNS
+            );
+            let workshops = db
+                .prepare(&sql)
+                .bind(&[JsValue::from_str(&db_timestamp(Utc::now())), JsValue::from_f64(record.id as f64)])?
+                .all()
+                .await?
+                .results::<WorkshopRow>()?
+                .into_iter()
+                .map(Self::workshop_from_row)
+                .collect();
+            let stmt = query!(db, "SELECT COUNT(*) AS count FROM event_check_ins WHERE member_id = ?", record.id);
+            let check_ins = stmt?.first::<CountRow>(None).await?.map_or(0, |row| row.count);
+            (workshops, Some(check_ins as u32))
+        } else {
+            (Vec::new(), None)
+        };
+
+        Ok(MemberProfile {
+            member: record.summary,
+            blog_posts,
+            projects_led,
+            projects_contributed,
+            workshops_attended,
+            events_attended,
+            contributions,
+        })
+    }
+
+    /// A member's own profile settings
+    pub async fn get_profile_settings(db: &D1Database, member_id: i64) -> Result<Option<ProfileSettings>> {
+        let sql = format!("SELECT {} FROM members m WHERE m.id = ?", MEMBER_COLUMNS);
+        let row = db
+            .prepare(&sql)
+            .bind(&[JsValue::from_f64(member_id as f64)])?
+            .first::<MemberRow>(None)
+            .await?;
+        Ok(row.map(|row| {
+            let record = member_record_from_row(row);
+            ProfileSettings {
+                bio: record.summary.bio,
+                skills: record.summary.skills,
+                visibility: record.visibility,
+                show_attendance: record.show_attendance,
+            }
+        }))
+    }
+
+    /// Save a member's profile settings. Returns `false` if the member no
+    /// longer exists.
+    pub async fn update_profile_settings(db: &D1Database, member_id: i64, settings: &ProfileSettings) -> Result<bool> {
+        let bio = settings.bio.as_deref().map(str::trim).filter(|bio| !bio.is_empty());
+        let skills: Vec<&str> = settings.skills.iter().map(|skill| skill.trim()).collect();
+        let stmt = query!(
+            db,
+            "UPDATE members SET bio = ?, skills = ?, profile_visibility = ?, show_attendance = ?, updated_at = ? WHERE id = ?",
+            bio,
+            serde_json::to_string(&skills)?,
+            visibility_to_db(settings.visibility),
+            settings.show_attendance as i32,
+            db_timestamp(Utc::now()),
+            member_id
+        );
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// The signed-in member with row id `member_id`
+    pub async fn get_current_user(db: &D1Database, member_id: i64) -> Result<Option<CurrentUser>> {
+        let stmt = query!(db, "SELECT name, github_username, avatar_url FROM members WHERE id = ?", member_id);
+        let Some(row) = stmt?.first::<CurrentUserRow>(None).await? else {
+            return Ok(None);
+        };
+        Ok(Some(CurrentUser {
+            member: Member {
+                name: row.name,
+                github_username: row.github_username,
+            },
+            avatar_url: row.avatar_url,
+            roles: Self::get_member_roles(db, member_id).await?,
+        }))
+    }
 
-        let mut events = Vec::new();
+    /// Roles granted to a member, in `MemberRole` order
+    pub async fn get_member_roles(db: &D1Database, member_id: i64) -> Result<Vec<MemberRole>> {
+        let stmt = query!(db, "SELECT role FROM member_roles WHERE member_id = ?", member_id);
+        let rows = stmt?.all().await?.results::<RoleRow>()?;
+        let mut roles: Vec<MemberRole> = rows.iter().map(|row| role_from_db(&row.role)).collect();
+        roles.sort_by_key(|role| *role as u8);
+        Ok(roles)
+    }
 
-        // Parse the results
-        if let Ok(rows) = results.results::<EventRow>() {
-            for row in rows {
-                let event = Event {
-                    id: row.uuid,
-                    title: row.title,
-                    description: row.description,
-                    date: row.date,
-                    time: row.time,
-                    location: row.location,
-                    event_type: match row.event_type.as_str() {
-                        "Workshop" => EventType::Workshop,
-                        "StudyGroup" => EventType::StudyGroup,
-                        "Seminar" => EventType::Seminar,
-                        "Hackathon" => EventType::Hackathon,
-                        "Panel" => EventType::Panel,
-                        "Networking" => EventType::Networking,
-                        _ => EventType::Workshop,
-                    },
-                    recurring: row.recurring != 0,
-                    created_at: chrono::DateTime::parse_from_rfc3339(&row.created_at)
-                        .unwrap_or_default()
-                        .with_timezone(&chrono::Utc),
-                };
-                events.push(event);
-            }
-            return Ok(events);
-        }
-
-        // Fallback to sample data if database is empty
-        let events = vec![
-            Event {
-                id: "550e8400-e29b-41d4-a716-446655440001".to_string(),
-                title: "Spring 2025 Workshop #1: Rust Fundamentals".to_string(),
-                description: "Learn Rust fundamentals including ownership, borrowing, and building your first CLI tool. Perfect for beginners!".to_string(),
-                date: "February 15".to_string(),
-                time: "1:00 PM - 5:00 PM".to_string(),
-                location: "Engineering Building, Room 301".to_string(),
-                event_type: EventType::Workshop,
-                recurring: false,
-                created_at: chrono::Utc::now(),
-            },
-            Event {
-                id: "550e8400-e29b-41d4-a716-446655440002".to_string(),
-                title: "Weekly Study Group".to_string(),
-                description: "Join us for collaborative learning and project work. Bring your Rust questions!".to_string(),
-                date: "Every Friday".to_string(),
-                time: "3:00 PM - 5:00 PM".to_string(),
-                location: "Library, Study Room B".to_string(),
-                event_type: EventType::StudyGroup,
-                recurring: true,
-                created_at: chrono::Utc::now(),
-            },
-            Event {
-                id: "550e8400-e29b-41d4-a716-446655440003".to_string(),
-                title: "Industry Guest Speaker: WebAssembly in Production".to_string(),
-                description: "Learn how major companies are using Rust and WebAssembly in production environments.".to_string(),
-                date: "March 8".to_string(),
-                time: "6:00 PM - 8:00 PM".to_string(),
-                location: "Auditorium A, Student Center".to_string(),
-                event_type: EventType::Seminar,
-                recurring: false,
-                created_at: chrono::Utc::now(),
-            },
-        ];
-        Ok(events)
+    /// Grant a role and record it in the audit trail. Returns `false` if the
+    /// member already held it, in which case nothing is recorded.
+    pub async fn grant_role(
+        db: &D1Database,
+        member_id: i64,
+        role: MemberRole,
+        changed_by: &str,
+        reason: Option<&str>,
+    ) -> Result<bool> {
+        let role = role_to_db(role);
+        let now = db_timestamp(Utc::now());
+        // The audit row goes first so its NOT EXISTS sees the roles as they were
+        let results = db
+            .batch(vec![
+                db.prepare(
+                    "INSERT INTO role_changes (uuid, member_id, member_name, member_github, role, action, changed_by, reason, changed_at)
+                     SELECT ?1, m.id, m.name, m.github_username, ?2, 'granted', ?3, ?4, ?5
+                     FROM members m
+                     WHERE m.id = ?6 AND NOT EXISTS (SELECT 1 FROM member_roles r WHERE r.member_id = ?6 AND r.role = ?2)",
+                )
+                .bind(&[
+                    JsValue::from_str(&uuid::Uuid::new_v4().to_string()),
+                    JsValue::from_str(role),
+                    JsValue::from_str(changed_by),
+                    optional_str(reason),
+                    JsValue::from_str(&now),
+                    JsValue::from_f64(member_id as f64),
+                ])?,
+                db.prepare("INSERT OR IGNORE INTO member_roles (member_id, role, granted_at) VALUES (?, ?, ?)")
+                    .bind(&[JsValue::from_f64(member_id as f64), JsValue::from_str(role), JsValue::from_str(&now)])?,
+            ])
+            .await?;
+        Ok(results.first().map(changed_rows).unwrap_or(0) > 0)
     }
 
-    pub async fn get_issues(db: &D1Database) -> Result<Vec<Issue>> {
-        let stmt = query!(db, "SELECT uuid, title, description, repo, github_url, difficulty, tags, created_at FROM issues ORDER BY created_at DESC");
-        let results = stmt.all().await?;
+    /// Revoke a role and record it in the audit trail. Returns `false` if the
+    /// member did not hold it.
+    pub async fn revoke_role(
+        db: &D1Database,
+        member_id: i64,
+        role: MemberRole,
+        changed_by: &str,
+        reason: Option<&str>,
+    ) -> Result<bool> {
+        let role = role_to_db(role);
+        let results = db
+            .batch(vec![
+                db.prepare(
+                    "INSERT INTO role_changes (uuid, member_id, member_name, member_github, role, action, changed_by, reason, changed_at)
+                     SELECT ?1, m.id, m.name, m.github_username, ?2, 'revoked', ?3, ?4, ?5
+                     FROM members m
+                     WHERE m.id = ?6 AND EXISTS (SELECT 1 FROM member_roles r WHERE r.member_id = ?6 AND r.role = ?2)",
+                )
+                .bind(&[
+                    JsValue::from_str(&uuid::Uuid::new_v4().to_string()),
+                    JsValue::from_str(role),
+                    JsValue::from_str(changed_by),
+                    optional_str(reason),
+                    JsValue::from_str(&db_timestamp(Utc::now())),
+                    JsValue::from_f64(member_id as f64),
+                ])?,
+                db.prepare("DELETE FROM member_roles WHERE member_id = ? AND role = ?")
+                    .bind(&[JsValue::from_f64(member_id as f64), JsValue::from_str(role)])?,
+            ])
+            .await?;
+        Ok(results.first().map(changed_rows).unwrap_or(0) > 0)
+    }
+
+    /// The role audit trail, newest first by default, optionally for one member
+    pub async fn get_role_changes(
+        db: &D1Database,
+        member_github: Option<&str>,
+        page: &PageRequest,
+    ) -> Result<Page<RoleChange>> {
+        let mut conditions = Vec::new();
+        let mut binds = Vec::new();
+        if let Some(github) = member_github {
+            conditions.push("member_github = ? COLLATE NOCASE".to_string());
+            binds.push(JsValue::from_str(github));
+        }
+        page.push_keyset("changed_at", "uuid", &mut conditions, &mut binds);
+        let tail = page.order_and_limit("changed_at", "uuid", &mut binds);
+        let sql = format!(
+            "SELECT uuid, member_name, member_github, role, action, changed_by, reason, changed_at
+             FROM role_changes {} {}",
+            where_clause(&conditions),
+            tail
+        );
 
-        let mut issues = Vec::new();
+        let rows = db.prepare(&sql).bind(&binds)?.all().await?.results::<RoleChangeRow>()?;
+        Ok(page.to_page(
+            rows,
+            |row| Cursor { sort_key: row.changed_at.clone(), id: row.uuid.clone() },
+            role_change_from_row,
+        ))
+    }
 
-        // Parse the results
-        if let Ok(rows) = results.results::<IssueRow>() {
-            for row in rows {
-                let issue = Issue {
-                    id: row.uuid,
-                    title: row.title,
-                    description: row.description,
-                    repo: row.repo,
-                    github_url: row.github_url,
-                    difficulty: match row.difficulty.as_str() {
-                        "Easy" => DifficultyLevel::Easy,
-                        "Medium" => DifficultyLevel::Medium,
-                        "Hard" => DifficultyLevel::Hard,
-                        _ => DifficultyLevel::Easy,
-                    },
-                    tags: serde_json::from_str(&row.tags).unwrap_or_default(),
-                    created_at: chrono::DateTime::parse_from_rfc3339(&row.created_at)
-                        .unwrap_or_default()
-                        .with_timezone(&chrono::Utc),
-                };
-                issues.push(issue);
-            }
-            return Ok(issues);
-        }
-
-        // Fallback to sample data if database is empty
-        let issues = vec![
-            Issue {
-                id: "550e8400-e29b-41d4-a716-446655440010".to_string(),
-                title: "Add documentation for async patterns".to_string(),
-                description: "We need comprehensive documentation covering async/await patterns in Rust. This would help newcomers understand concurrent programming.".to_string(),
-                repo: "rust-lang/reference".to_string(),
-                github_url: "https://github.com/rust-lang/reference/issues/123".to_string(),
-                difficulty: DifficultyLevel::Easy,
-                tags: vec!["documentation".to_string(), "async".to_string(), "good-first-issue".to_string()],
-                created_at: chrono::Utc::now(),
-            },
-            Issue {
-                id: "550e8400-e29b-41d4-a716-446655440011".to_string(),
-                title: "Improve error message for trait bound errors".to_string(),
-                description: "Current error messages for complex trait bounds can be confusing. We need clearer, more actionable error messages.".to_string(),
-                repo: "rust-lang/rust".to_string(),
-                github_url: "https://github.com/rust-lang/rust/issues/456".to_string(),
-                difficulty: DifficultyLevel::Medium,
-                tags: vec!["diagnostics".to_string(), "error-messages".to_string(), "good-first-issue".to_string()],
-                created_at: chrono::Utc::now(),
-            },
-        ];
-        Ok(issues)
+    /// Whether a member leads the project with this id
+    pub async fn is_project_leader(db: &D1Database, project_id: &str, member_id: i64) -> Result<bool> {
+        let stmt = query!(db, "SELECT COUNT(*) AS count FROM projects WHERE uuid = ? AND leader_id = ?", project_id, member_id);
+        let row = stmt?.first::<CountRow>(None).await?;
+        Ok(row.is_some_and(|row| row.count > 0))
+    }
+
+    /// Look up a member's internal row id from their GitHub username
+    pub async fn get_member_id_by_github(db: &D1Database, github_username: &str) -> Result<Option<i64>> {
+        let stmt = query!(db, "SELECT id FROM members WHERE github_username = ?", github_username);
+        let row = stmt?.first::<serde_json::Value>(None).await?;
+        Ok(row.and_then(|r| r.get("id").and_then(|v| v.as_i64())))
     }
 
-    pub async fn get_projects(db: &D1Database) -> Result<Vec<Project>> {
+    /// The usernames in `github_usernames` that no member has, ignoring case
+    pub async fn get_unknown_members(db: &D1Database, github_usernames: &[String]) -> Result<Vec<String>> {
         let stmt = query!(
             db,
-            "SELECT p.uuid, p.name, p.description, p.github_url,
-                    m.name as leader_name, m.github_username as leader_github,
-                    p.status, p.tech_stack, p.contributors_needed, p.skills_needed, p.created_at
-             FROM projects p
-             JOIN members m ON p.leader_id = m.id
-             ORDER BY p.created_at DESC"
+            "SELECT value AS github_username FROM json_each(?)
+             WHERE NOT EXISTS (SELECT 1 FROM members m WHERE m.github_username = value COLLATE NOCASE)",
+            serde_json::to_string(github_usernames)?
         );
-        let results = stmt.all().await?;
-
-        let mut projects = Vec::new();
+        let rows = stmt?.all().await?.results::<serde_json::Value>()?;
+        Ok(rows
+            .into_iter()
+            .filter_map(|row| row.get("github_username").and_then(|v| v.as_str()).map(str::to_string))
+            .collect())
+    }
 
-        // Parse the results
-        if let Ok(rows) = results.results::<ProjectRow>() {
-            for row in rows {
-                let project = Project {
-                    id: row.uuid,
-                    name: row.name,
-                    description: row.description,
-                    github_url: row.github_url,
-                    leader: Member {
-                        name: row.leader_name,
-                        github_username: row.leader_github,
-                    },
-                    contributors: vec![], // TODO: Load contributors with separate query
-                    status: match row.status.as_str() {
-                        "Planning" => ProjectStatus::Planning,
-                        "Active" => ProjectStatus::Active,
-                        "InDevelopment" => ProjectStatus::InDevelopment,
-                        "Beta" => ProjectStatus::Beta,
-                        "Completed" => ProjectStatus::Completed,
-                        "Archived" => ProjectStatus::Archived,
-                        _ => ProjectStatus::Planning,
-                    },
-                    tech_stack: serde_json::from_str(&row.tech_stack).unwrap_or_default(),
-                    contributors_needed: row.contributors_needed != 0,
-                    skills_needed: serde_json::from_str(&row.skills_needed).unwrap_or_default(),
-                    created_at: chrono::DateTime::parse_from_rfc3339(&row.created_at)
-                        .unwrap_or_default()
-                        .with_timezone(&chrono::Utc),
-                };
-                projects.push(project);
-            }
-            return Ok(projects);
-        }
-
-        // Fallback to sample data if database is empty
-        let projects = vec![
-            Project {
-                id: "550e8400-e29b-41d4-a716-446655440020".to_string(),
-                name: "The Rust Club Website".to_string(),
-                description: "Official website for The Rust Club built with Leptos and deployed on Cloudflare Pages.".to_string(),
-                github_url: "https://github.com/rust-club/website".to_string(),
-                leader: Member {
-                    name: "Alex Chen".to_string(),
-                    github_username: Some("alexcodes".to_string()),
-                },
-                contributors: vec![
-                    Member {
-                        name: "Jordan Smith".to_string(),
-                        github_username: Some("jordandev".to_string()),
-                    },
-                    Member {
-                        name: "Sam Wilson".to_string(),
-                        github_username: Some("samw".to_string()),
-                    },
-                ],
-                status: ProjectStatus::Active,
-                tech_stack: vec!["Leptos".to_string(), "Trunk".to_string(), "CSS".to_string(), "Cloudflare".to_string()],
-                contributors_needed: true,
-                skills_needed: vec!["Frontend".to_string(), "CSS".to_string(), "Design".to_string()],
-                created_at: chrono::Utc::now(),
-            },
-            Project {
-                id: "550e8400-e29b-41d4-a716-446655440021".to_string(),
-                name: "RustBot Discord Bot".to_string(),
-                description: "A Discord bot for The Rust Club server with moderation, event management, and learning resources.".to_string(),
-                github_url: "https://github.com/rust-club/rustbot".to_string(),
-                leader: Member {
-                    name: "Taylor Rodriguez".to_string(),
-                    github_username: Some("taylorr".to_string()),
-                },
-                contributors: vec![
-                    Member {
-                        name: "Casey Johnson".to_string(),
-                        github_username: Some("caseyjay".to_string()),
-                    },
-                ],
-                status: ProjectStatus::InDevelopment,
-                tech_stack: vec!["Rust".to_string(), "Serenity".to_string(), "SQLite".to_string()],
-                contributors_needed: true,
-                skills_needed: vec!["Backend".to_string(), "Discord API".to_string(), "Database".to_string()],
-                created_at: chrono::Utc::now(),
-            },
-        ];
-        Ok(projects)
+    pub async fn get_project(db: &D1Database, id: &str) -> Result<Option<Project>> {
+        let sql = format!(
+            "SELECT {} FROM projects p JOIN members m ON p.leader_id = m.id WHERE p.uuid = ?",
+            PROJECT_COLUMNS
+        );
+        let row = db.prepare(&sql).bind(&[id.into()])?.first::<ProjectRow>(None).await?;
+        let mut projects: Vec<Project> = row.map(Self::project_from_row).into_iter().collect();
+        Self::load_contributors(db, &mut projects).await?;
+        Ok(projects.pop())
     }
 
-    pub async fn get_member_count(db: &D1Database) -> Result<u32> {
-        let stmt = query!(db, "SELECT COUNT(*) as count FROM members");
-        let result = stmt.first::<serde_json::Value>(None).await?;
+    /// Fill in `contributors` for every project with a single query. Ids are
+    /// bound as one JSON array so the page size is not capped by D1's bind limit.
+    async fn load_contributors(db: &D1Database, projects: &mut [Project]) -> Result<()> {
+        if projects.is_empty() {
+            return Ok(());
+        }
+        let ids: Vec<&str> = projects.iter().map(|project| project.id.as_str()).collect();
+        let stmt = query!(
+            db,
+            "SELECT p.uuid AS project_uuid, m.name, m.github_username
+             FROM project_contributors pc
+             JOIN projects p ON p.id = pc.project_id
+             JOIN members m ON m.id = pc.member_id
+             WHERE p.uuid IN (SELECT value FROM json_each(?))
+             ORDER BY pc.created_at, pc.id",
+            serde_json::to_string(&ids)?
+        );
+        let rows = stmt?.all().await?.results::<ContributorRow>()?;
 
-        if let Some(row) = result {
-            if let Some(count) = row.get("count").and_then(|v| v.as_u64()) {
-                return Ok(count as u32);
-            }
+        let mut by_project: HashMap<String, Vec<Member>> = HashMap::new();
+        for row in rows {
+            by_project.entry(row.project_uuid).or_default().push(Member {
+                name: row.name,
+                github_username: row.github_username,
+            });
         }
+        for project in projects {
+            project.contributors = by_project.remove(&project.id).unwrap_or_default();
+        }
+        Ok(())
+    }
 
-        // Fallback count if database is empty
-        Ok(9)
+    /// Add a member to a project's contributors. Returns `false` if they
+    /// already are one, or if no project has this id.
+    pub async fn add_project_contributor(db: &D1Database, project_id: &str, member_id: i64) -> Result<bool> {
+        let stmt = query!(
+            db,
+            "INSERT OR IGNORE INTO project_contributors (project_id, member_id, created_at)
+             SELECT id, ?, ? FROM projects WHERE uuid = ?",
+            member_id,
+            db_timestamp(Utc::now()),
+            project_id
+        );
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
     }
 
-    pub async fn get_blog_posts(db: &D1Database) -> Result<Vec<BlogPost>> {
+    /// Remove a member from a project's contributors. Returns `false` if they were not one.
+    pub async fn remove_project_contributor(db: &D1Database, project_id: &str, member_id: i64) -> Result<bool> {
         let stmt = query!(
             db,
-            "SELECT id, title, slug, excerpt, post_type, category, tags, author_name, author_github,
-                    difficulty_level, estimated_read_time, published_at, updated_at, views, likes,
-                    markdown_url, series_title, series_part, series_total_parts, external_links
-             FROM blog_posts
-             ORDER BY published_at DESC"
+            "DELETE FROM project_contributors
+             WHERE member_id = ? AND project_id = (SELECT id FROM projects WHERE uuid = ?)",
+            member_id,
+            project_id
         );
-        let results = stmt.all().await?;
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
 
-        let mut posts = Vec::new();
+    pub async fn create_project(db: &D1Database, input: &ProjectInput, leader_id: i64) -> Result<Option<Project>> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let now = db_timestamp(Utc::now());
+        let tech_stack = serde_json::to_string(&input.tech_stack)?;
+        let skills_needed = serde_json::to_string(&input.skills_needed)?;
+        let stmt = query!(
+            db,
+            "INSERT INTO projects (uuid, name, description, github_url, leader_id, status, tech_stack, contributors_needed, skills_needed, created_at, updated_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            &id,
+            &input.name,
+            &input.description,
+            &input.github_url,
+            leader_id,
+            project_status_to_db(&input.status),
+            &tech_stack,
+            input.contributors_needed as i32,
+            &skills_needed,
+            &now,
+            &now
+        );
+        stmt?.run().await?;
+        Self::get_project(db, &id).await
+    }
 
-        if let Ok(rows) = results.results::<BlogPostRow>() {
-            for row in rows {
-                let post = Self::blog_post_from_row(row);
-                posts.push(post);
-            }
+    /// Replace a project's fields. Returns `None` if no project has this id.
+    pub async fn update_project(db: &D1Database, id: &str, input: &ProjectInput, leader_id: i64) -> Result<Option<Project>> {
+        let tech_stack = serde_json::to_string(&input.tech_stack)?;
+        let skills_needed = serde_json::to_string(&input.skills_needed)?;
+        let stmt = query!(
+            db,
+            "UPDATE projects
+             SET name = ?, description = ?, github_url = ?, leader_id = ?, status = ?, tech_stack = ?,
+                 contributors_needed = ?, skills_needed = ?, updated_at = ?
+             WHERE uuid = ?",
+            &input.name,
+            &input.description,
+            &input.github_url,
+            leader_id,
+            project_status_to_db(&input.status),
+            &tech_stack,
+            input.contributors_needed as i32,
+            &skills_needed,
+            db_timestamp(Utc::now()),
+            id
+        );
+        let result = stmt?.run().await?;
+        if changed_rows(&result) == 0 {
+            return Ok(None);
         }
+        Self::get_project(db, id).await
+    }
 
-        Ok(posts)
+    /// Delete a project. Returns `false` if no project has this id.
+    pub async fn delete_project(db: &D1Database, id: &str) -> Result<bool> {
+        let stmt = query!(db, "DELETE FROM projects WHERE uuid = ?", id);
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
     }
 
-    pub async fn get_blog_posts_by_ids(db: &D1Database, ids: &[String]) -> Result<Vec<BlogPost>> {
-        if ids.is_empty() {
-            return Ok(Vec::new());
-        }
+    /// Create a draft post and its first revision
+    pub async fn create_blog_post(db: &D1Database, input: &BlogPostInput, revision: &NewRevision<'_>) -> Result<Option<BlogPost>> {
+        let id = uuid::Uuid::new_v4().to_string();
+        let mut values = vec![JsValue::from_str(&id)];
+        values.extend(blog_post_bind_values(input)?);
 
-        let placeholders = ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
-        let query_str = format!(
-            "SELECT id, title, slug, excerpt, post_type, category, tags, author_name, author_github,
-                    difficulty_level, estimated_read_time, published_at, updated_at, views, likes,
-                    markdown_url, series_title, series_part, series_total_parts, external_links
-             FROM blog_posts
-             WHERE id IN ({})
-             ORDER BY published_at DESC",
-            placeholders
+        let sql = format!(
+            "INSERT INTO blog_posts (id, title, slug, excerpt, post_type, category, tags, author_name, author_github,
+                difficulty_level, estimated_read_time, published_at, updated_at, markdown_url,
+                series_id, series_part, external_links, status, created_at)
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, {}, ?, ?, 'draft', ?)",
+            BLOG_SERIES_ID_BY_SLUG
         );
+        values.push(JsValue::from_str(&db_timestamp(Utc::now())));
+        let stmt = db.prepare(&sql).bind(&values)?;
+        let mut statements = vec![stmt];
+        statements.extend(blog_author_statements(db, &id, input)?);
+        statements.push(blog_revision_statement(db, &id, input, revision)?);
+        db.batch(statements).await?;
+        Self::get_blog_post_by_slug(db, &input.slug).await
+    }
 
-        // Convert IDs to JsValue for binding
-        let bind_values: Vec<wasm_bindgen::JsValue> = ids.iter()
-            .map(|id| wasm_bindgen::JsValue::from_str(id))
-            .collect();
-
-        let stmt = db.prepare(&query_str).bind(&bind_values)?;
-        let results = stmt.all().await?;
-        let mut posts = Vec::new();
+    /// Replace a blog post's fields, keeping its view and like counters, and
+    /// store the result as a new revision. Returns `None` if no post has this id.
+    pub async fn update_blog_post(
+        db: &D1Database,
+        id: &str,
+        input: &BlogPostInput,
+        revision: &NewRevision<'_>,
+    ) -> Result<Option<BlogPost>> {
+        let mut values = blog_post_bind_values(input)?;
+        values.push(JsValue::from_str(id));
 
-        if let Ok(rows) = results.results::<BlogPostRow>() {
-            for row in rows {
-                let post = Self::blog_post_from_row(row);
-                posts.push(post);
-            }
+        let sql = format!(
+            "UPDATE blog_posts
+             SET title = ?, slug = ?, excerpt = ?, post_type = ?, category = ?, tags = ?, author_name = ?,
+                 author_github = ?, difficulty_level = ?, estimated_read_time = ?, published_at = ?,
+                 updated_at = ?, markdown_url = ?, series_id = {}, series_part = ?, external_links = ?
+             WHERE id = ?",
+            BLOG_SERIES_ID_BY_SLUG
+        );
+        let stmt = db.prepare(&sql).bind(&values)?;
+        let mut statements = vec![stmt];
+        statements.extend(blog_author_statements(db, id, input)?);
+        statements.push(blog_revision_statement(db, id, input, revision)?);
+        let results = db.batch(statements).await?;
+        if results.first().map_or(0, changed_rows) == 0 {
+            return Ok(None);
         }
+        Self::get_blog_post_by_slug(db, &input.slug).await
+    }
 
-        Ok(posts)
+    /// A markdown snapshot by its hash, from whichever revision stored it
+    pub async fn get_revision_markdown(db: &D1Database, hash: &str) -> Result<Option<String>> {
+        let stmt = query!(
+            db,
+            "SELECT markdown FROM blog_post_revisions WHERE markdown_hash = ? AND markdown IS NOT NULL LIMIT 1",
+            hash
+        );
+        let row = stmt?.first::<serde_json::Value>(None).await?;
+        Ok(row.and_then(|r| r.get("markdown").and_then(|v| v.as_str()).map(str::to_string)))
     }
 
-    pub async fn get_blog_post_by_slug(db: &D1Database, slug: &str) -> Result<Option<BlogPost>> {
+    /// A post's revisions, newest first unless the page asks otherwise
+    pub async fn get_blog_revisions(db: &D1Database, post_id: &str, page: &PageRequest) -> Result<Page<BlogRevisionSummary>> {
+        let mut conditions = vec!["post_id = ?".to_string()];
+        let mut binds = vec![JsValue::from_str(post_id)];
+        page.push_keyset("number", "id", &mut conditions, &mut binds);
+        let tail = page.order_and_limit("number", "id", &mut binds);
+        let sql = format!(
+            "SELECT {} FROM blog_post_revisions {} {}",
+            BLOG_REVISION_SUMMARY_COLUMNS,
+            where_clause(&conditions),
+            tail
+        );
+        let rows = db.prepare(&sql).bind(&binds)?.all().await?.results::<BlogRevisionSummaryRow>()?;
+        Ok(page.to_page(
+            rows,
+            |row| Cursor { sort_key: row.number.to_string(), id: row.id.to_string() },
+            |row| BlogRevisionSummary {
+                number: row.number,
+                title: row.title,
+                created_by: row.created_by,
+                created_at: parse_timestamp(&row.created_at),
+                published_at: row.published_at.as_deref().map(parse_timestamp),
+                restored_from: row.restored_from,
+            },
+        ))
+    }
+
+    /// One revision of a post, with its metadata and markdown
+    pub async fn get_blog_revision(db: &D1Database, post_id: &str, number: u32) -> Result<Option<BlogRevision>> {
         let stmt = query!(
             db,
-            "SELECT id, title, slug, excerpt, post_type, category, tags, author_name, author_github,
-                    difficulty_level, estimated_read_time, published_at, updated_at, views, likes,
-                    markdown_url, series_title, series_part, series_total_parts, external_links
-             FROM blog_posts
-             WHERE slug = ?",
-            slug
+            "SELECT number, metadata, markdown, created_by, created_at, published_at, restored_from
+             FROM blog_post_revisions WHERE post_id = ? AND number = ?",
+            post_id,
+            number
         );
-        let result = stmt?.first::<BlogPostRow>(None).await?;
+        let Some(row) = stmt?.first::<BlogRevisionRow>(None).await? else {
+            return Ok(None);
+        };
+        Ok(Some(BlogRevision {
+            number: row.number,
+            created_by: row.created_by,
+            created_at: parse_timestamp(&row.created_at),
+            published_at: row.published_at.as_deref().map(parse_timestamp),
+            restored_from: row.restored_from,
+            post: serde_json::from_str(&row.metadata)?,
+            markdown: row.markdown,
+        }))
+    }
 
-        if let Some(row) = result {
-            let post = Self::blog_post_from_row(row);
-            Ok(Some(post))
-        } else {
-            Ok(None)
+    /// Move a post from `from` to `to`, setting `published_at` when given.
+    /// Returns `false` if the post is no longer in `from`.
+    pub async fn set_blog_post_status(
+        db: &D1Database,
+        id: &str,
+        from: BlogPostStatus,
+        to: BlogPostStatus,
+        published_at: Option<DateTime<Utc>>,
+    ) -> Result<bool> {
+        let published_at = published_at.map(db_timestamp);
+        let mut statements = vec![db
+            .prepare("UPDATE blog_posts SET status = ?, published_at = coalesce(?, published_at) WHERE id = ? AND status = ?")
+            .bind(&[
+                blog_status_to_db(to).into(),
+                optional_str(published_at.as_deref()),
+                id.into(),
+                blog_status_to_db(from).into(),
+            ])?];
+        if to == BlogPostStatus::Published {
+            statements.push(
+                db.prepare(format!(
+                    "{} AND post_id = ?2 AND (SELECT status FROM blog_posts WHERE id = ?2) = 'published'",
+                    PUBLISH_LATEST_REVISION
+                ))
+                    .bind(&[db_timestamp(Utc::now()).into(), id.into()])?,
+            );
         }
+        let results = db.batch(statements).await?;
+        Ok(results.first().map_or(0, changed_rows) > 0)
+    }
+
+    /// Publish scheduled posts whose time has come. Returns how many went live.
+    pub async fn publish_scheduled_posts(db: &D1Database) -> Result<usize> {
+        let now = db_timestamp(Utc::now());
+        let results = db
+            .batch(vec![
+                // Mark the revisions first, while the posts still read as scheduled
+                db.prepare(format!(
+                    "{} AND post_id IN (SELECT id FROM blog_posts
+                                        WHERE status = 'scheduled' AND datetime(published_at) <= datetime(?1))",
+                    PUBLISH_LATEST_REVISION
+                ))
+                .bind(&[now.as_str().into()])?,
+                db.prepare(
+                    "UPDATE blog_posts SET status = 'published'
+                     WHERE status = 'scheduled' AND datetime(published_at) <= datetime(?)",
+                )
+                .bind(&[now.as_str().into()])?,
+            ])
+            .await?;
+        Ok(results.get(1).map_or(0, changed_rows))
+    }
+
+    /// Assign a reviewer, or clear it with `None`. Returns `false` if no post has this id.
+    pub async fn set_blog_post_reviewer(db: &D1Database, id: &str, reviewer_id: Option<i64>) -> Result<bool> {
+        let reviewer_id = reviewer_id.map(|id| JsValue::from_f64(id as f64)).unwrap_or(JsValue::NULL);
+        let result = db
+            .prepare("UPDATE blog_posts SET reviewer_id = ? WHERE id = ?")
+            .bind(&[reviewer_id, id.into()])?
+            .run()
+            .await?;
+        Ok(changed_rows(&result) > 0)
     }
 
-    pub async fn increment_blog_post_views(db: &D1Database, post_id: &str) -> Result<()> {
+    /// Store the hash of a new preview token, replacing any earlier one.
+    /// Returns `false` if no post has this id.
+    pub async fn set_blog_post_preview(db: &D1Database, id: &str, token_hash: &str, expires_at: DateTime<Utc>) -> Result<bool> {
         let stmt = query!(
             db,
-            "UPDATE blog_posts SET views = views + 1 WHERE id = ?",
-            post_id
+            "UPDATE blog_posts SET preview_token_hash = ?, preview_expires_at = ? WHERE id = ?",
+            token_hash,
+            db_timestamp(expires_at),
+            id
         );
-        let _ = stmt?.run().await?;
-        Ok(())
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    /// The post a preview token was made for, in any status, unless the link expired
+    pub async fn get_blog_post_by_preview(db: &D1Database, token_hash: &str) -> Result<Option<BlogPost>> {
+        let sql = format!(
+            "SELECT {} FROM blog_posts WHERE preview_token_hash = ? AND preview_expires_at > ?",
+            BLOG_POST_COLUMNS
+        );
+        let row = db
+            .prepare(&sql)
+            .bind(&[token_hash.into(), db_timestamp(Utc::now()).into()])?
+            .first::<BlogPostRow>(None)
+            .await?;
+        Ok(row.map(Self::blog_post_from_row))
+    }
+
+    /// Delete a blog post. Returns `false` if no post has this id.
+    pub async fn delete_blog_post(db: &D1Database, id: &str) -> Result<bool> {
+        let stmt = query!(db, "DELETE FROM blog_posts WHERE id = ?", id);
+        let result = stmt?.run().await?;
+        Ok(changed_rows(&result) > 0)
+    }
+
+    fn workshop_from_row(row: WorkshopRow) -> Workshop {
+        Workshop {
+            id: row.uuid,
+            title: row.title,
+            description: row.description,
+            level: match row.level.as_str() {
+                "Medium" => DifficultyLevel::Medium,
+                "Hard" => DifficultyLevel::Hard,
+                _ => DifficultyLevel::Easy,
+            },
+            starts_at: parse_timestamp(&row.starts_at),
+            ends_at: parse_timestamp(&row.ends_at),
+            location: row.location,
+            instructor: row.instructor,
+            topics: serde_json::from_str(&row.topics).unwrap_or_default(),
+            prerequisites: serde_json::from_str(&row.prerequisites).unwrap_or_default(),
+            materials_url: row.materials_url,
+            max_participants: row.capacity.max(0) as u32,
+            current_participants: row.registered.max(0) as u32,
+            waitlisted: row.waitlisted.max(0) as u32,
+        }
+    }
+
+    fn course_from_row(row: CourseRow) -> Course {
+        Course {
+            slug: row.slug,
+            course_code: row.course_code,
+            course_name: row.course_name,
+            department: row.department,
+            professor: row.professor,
+            description: row.description,
+            status: course_status_from_db(&row.status),
+            supporters_count: row.supporters_count.max(0) as u32,
+            history: Vec::new(),
+        }
+    }
+
+    fn blog_series_from_row(row: BlogSeriesRow) -> BlogSeriesSummary {
+        BlogSeriesSummary {
+            slug: row.slug,
+            title: row.title,
+            description: row.description,
+            planned_parts: row.planned_parts,
+            published_parts: row.published_parts,
+        }
+    }
+
+    fn term_from_row(row: TermRow) -> Term {
+        Term {
+            slug: row.slug,
+            name: row.name,
+            starts_on: NaiveDate::parse_from_str(&row.starts_on, DATE_FORMAT).unwrap_or_default(),
+            ends_on: NaiveDate::parse_from_str(&row.ends_on, DATE_FORMAT).unwrap_or_default(),
+        }
+    }
+
+    fn event_from_row(row: EventRow) -> Event {
+        Event {
+            id: row.uuid,
+            title: row.title,
+            description: row.description,
+            starts_at: parse_timestamp(&row.starts_at),
+            ends_at: parse_timestamp(&row.ends_at),
+            timezone: row.timezone,
+            recurrence: row.recurrence,
+            location: row.location,
+            event_type: match row.event_type.as_str() {
+                "Workshop" => EventType::Workshop,
+                "StudyGroup" => EventType::StudyGroup,
+                "Seminar" => EventType::Seminar,
+                "Hackathon" => EventType::Hackathon,
+                "Panel" => EventType::Panel,
+                "Networking" => EventType::Networking,
+                _ => EventType::Workshop,
+            },
+            capacity: row.capacity.map(|capacity| capacity.max(0) as u32),
+            going: row.going.max(0) as u32,
+            maybe: row.maybe.max(0) as u32,
+            created_at: parse_timestamp(&row.created_at),
+        }
+    }
+
+    fn issue_from_row(row: IssueRow) -> Issue {
+        let claim_expires_at = row.claim_expires_at.as_deref().map(parse_timestamp);
+        // A lapsed claim holds the issue only until the next sweep
+        let claimed = match row.claim_state.as_deref() {
+            Some("claimed") => claim_expires_at.is_some_and(|expires_at| expires_at > Utc::now()),
+            Some(_) => true,
+            None => false,
+        };
+        Issue {
+            id: row.uuid,
+            title: row.title,
+            description: row.description,
+            repo: row.repo,
+            github_url: row.github_url,
+            difficulty: match row.difficulty.as_str() {
+                "Easy" => DifficultyLevel::Easy,
+                "Medium" => DifficultyLevel::Medium,
+                "Hard" => DifficultyLevel::Hard,
+                _ => DifficultyLevel::Easy,
+            },
+            tags: serde_json::from_str(&row.tags).unwrap_or_default(),
+            claimed,
+            claimed_by: if claimed { row.claimed_by } else { None },
+            claim_expires_at: if claimed { claim_expires_at } else { None },
+            created_at: parse_timestamp(&row.created_at),
+        }
+    }
+
+    fn project_from_row(row: ProjectRow) -> Project {
+        Project {
+            id: row.uuid,
+            name: row.name,
+            description: row.description,
+            github_url: row.github_url,
+            leader: Member {
+                name: row.leader_name,
+                github_username: row.leader_github,
+            },
+            contributors: vec![], // Filled in by load_contributors
+            status: match row.status.as_str() {
+                "Planning" => ProjectStatus::Planning,
+                "Active" => ProjectStatus::Active,
+                "InDevelopment" => ProjectStatus::InDevelopment,
+                "Beta" => ProjectStatus::Beta,
+                "Completed" => ProjectStatus::Completed,
+                "Archived" => ProjectStatus::Archived,
+                _ => ProjectStatus::Planning,
+            },
+            tech_stack: serde_json::from_str(&row.tech_stack).unwrap_or_default(),
+            contributors_needed: row.contributors_needed != 0,
+            skills_needed: serde_json::from_str(&row.skills_needed).unwrap_or_default(),
+            created_at: parse_timestamp(&row.created_at),
+        }
     }
 
     fn blog_post_from_row(row: BlogPostRow) -> BlogPost {
@@ -436,6 +2947,7 @@ impl DatabaseService {
             tags: serde_json::from_str(&row.tags).unwrap_or_default(),
             author_name: row.author_name,
             author_github: row.author_github,
+            co_authors: serde_json::from_str(&row.co_authors).unwrap_or_default(),
             difficulty_level: row.difficulty_level.map(|d| match d.as_str() {
                 "easy" => DifficultyLevel::Easy,
                 "medium" => DifficultyLevel::Medium,
@@ -448,18 +2960,475 @@ impl DatabaseService {
             views: row.views as u32,
             likes: row.likes as u32,
             markdown_url: row.markdown_url,
-            series: if let Some(title) = row.series_title {
-                Some(BlogSeries {
+            series: match (row.series_slug, row.series_title) {
+                (Some(slug), Some(title)) => Some(BlogSeries {
+                    slug,
                     title,
                     part: row.series_part.unwrap_or(1) as u32,
                     total_parts: row.series_total_parts.map(|t| t as u32),
-                })
-            } else {
-                None
+                }),
+                _ => None,
             },
             external_links: row.external_links
                 .and_then(|links| serde_json::from_str(&links).ok())
                 .unwrap_or_default(),
+            status: blog_status_from_db(&row.status),
+            reviewer: row.reviewer_github,
         }
     }
-}
\ No newline at end of file
+}
+
+fn where_clause(conditions: &[String]) -> String {
+    if conditions.is_empty() {
+        String::new()
+    } else {
+        format!("WHERE {}", conditions.join(" AND "))
+    }
+}
+
+/// Number of rows touched by an INSERT/UPDATE/DELETE
+fn changed_rows(result: &worker::D1Result) -> usize {
+    result
+        .meta()
+        .ok()
+        .flatten()
+        .and_then(|meta| meta.changes)
+        .unwrap_or(0)
+}
+
+/// Whether a D1 error was caused by a UNIQUE constraint
+pub fn is_unique_violation(error: &worker::Error) -> bool {
+    error.to_string().contains("UNIQUE constraint failed")
+}
+
+/// Parse a timestamp written either by us (RFC 3339) or by SQLite's CURRENT_TIMESTAMP
+fn parse_timestamp(value: &str) -> chrono::DateTime<chrono::Utc> {
+    chrono::DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .or_else(|_| {
+            chrono::NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").map(|dt| dt.and_utc())
+        })
+        .unwrap_or_default()
+}
+
+fn event_type_to_db(event_type: &EventType) -> &'static str {
+    match event_type {
+        EventType::Workshop => "Workshop",
+        EventType::StudyGroup => "StudyGroup",
+        EventType::Seminar => "Seminar",
+        EventType::Hackathon => "Hackathon",
+        EventType::Panel => "Panel",
+        EventType::Networking => "Networking",
+    }
+}
+
+fn search_kind_to_db(kind: &SearchResultKind) -> &'static str {
+    match kind {
+        SearchResultKind::BlogPost => "BlogPost",
+        SearchResultKind::Issue => "Issue",
+        SearchResultKind::Project => "Project",
+        SearchResultKind::Event => "Event",
+    }
+}
+
+fn project_status_to_db(status: &ProjectStatus) -> &'static str {
+    match status {
+        ProjectStatus::Planning => "Planning",
+        ProjectStatus::Active => "Active",
+        ProjectStatus::InDevelopment => "InDevelopment",
+        ProjectStatus::Beta => "Beta",
+        ProjectStatus::Completed => "Completed",
+        ProjectStatus::Archived => "Archived",
+    }
+}
+
+fn blog_post_type_to_db(post_type: &BlogPostType) -> &'static str {
+    match post_type {
+        BlogPostType::Tutorial => "tutorial",
+        BlogPostType::Guide => "guide",
+        BlogPostType::ShowAndTell => "show_and_tell",
+        BlogPostType::TechTalk => "tech_talk",
+        BlogPostType::News => "news",
+        BlogPostType::Review => "review",
+    }
+}
+
+fn blog_category_to_db(category: &BlogCategory) -> &'static str {
+    match category {
+        BlogCategory::Fundamentals => "fundamentals",
+        BlogCategory::WebDevelopment => "web_development",
+        BlogCategory::SystemsProgramming => "systems_programming",
+        BlogCategory::GameDevelopment => "game_development",
+        BlogCategory::CLI => "cli",
+        BlogCategory::DataScience => "data_science",
+        BlogCategory::Blockchain => "blockchain",
+        BlogCategory::Performance => "performance",
+        BlogCategory::Testing => "testing",
+        BlogCategory::Deployment => "deployment",
+        BlogCategory::Career => "career",
+        BlogCategory::Community => "community",
+    }
+}
+
+fn blog_status_to_db(status: BlogPostStatus) -> &'static str {
+    match status {
+        BlogPostStatus::Draft => "draft",
+        BlogPostStatus::InReview => "in_review",
+        BlogPostStatus::Scheduled => "scheduled",
+        BlogPostStatus::Published => "published",
+        BlogPostStatus::Archived => "archived",
+    }
+}
+
+fn blog_status_from_db(status: &str) -> BlogPostStatus {
+    match status {
+        "draft" => BlogPostStatus::Draft,
+        "in_review" => BlogPostStatus::InReview,
+        "scheduled" => BlogPostStatus::Scheduled,
+        "archived" => BlogPostStatus::Archived,
+        _ => BlogPostStatus::Published,
+    }
+}
+
+fn difficulty_to_blog_db(level: &DifficultyLevel) -> &'static str {
+    match level {
+        DifficultyLevel::Easy => "easy",
+        DifficultyLevel::Medium => "medium",
+        DifficultyLevel::Hard => "hard",
+    }
+}
+
+/// Timestamps as stored in every table, so they compare and sort as text:
+/// RFC 3339, UTC, whole seconds
+fn db_timestamp(value: DateTime<Utc>) -> String {
+    value.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Bind values for the event columns shared by INSERT and UPDATE, in
+/// `title .. event_type` order. Recurrence rules are stored in canonical form.
+fn event_bind_values(input: &EventInput) -> Vec<JsValue> {
+    let recurrence = input
+        .recurrence
+        .as_deref()
+        .and_then(|rule| rule.parse::<RecurrenceRule>().ok())
+        .map(|rule| rule.to_string());
+    vec![
+        JsValue::from_str(&input.title),
+        JsValue::from_str(&input.description),
+        JsValue::from_str(&db_timestamp(input.starts_at)),
+        JsValue::from_str(&db_timestamp(input.ends_at)),
+        JsValue::from_str(&input.timezone),
+        optional_str(recurrence.as_deref()),
+        JsValue::from_str(&input.location),
+        JsValue::from_str(event_type_to_db(&input.event_type)),
+        input
+            .capacity
+            .map(|capacity| JsValue::from_f64(capacity as f64))
+            .unwrap_or(JsValue::NULL),
+    ]
+}
+
+fn course_status_to_db(status: CourseStatus) -> &'static str {
+    match status {
+        CourseStatus::Approved => "Approved",
+        CourseStatus::UnderReview => "UnderReview",
+        CourseStatus::Pending => "Pending",
+        CourseStatus::Rejected => "Rejected",
+    }
+}
+
+fn course_status_from_db(status: &str) -> CourseStatus {
+    match status {
+        "Approved" => CourseStatus::Approved,
+        "UnderReview" => CourseStatus::UnderReview,
+        "Rejected" => CourseStatus::Rejected,
+        _ => CourseStatus::Pending,
+    }
+}
+
+/// Bind values for the course columns shared by INSERT and UPDATE, in
+/// `slug .. description` order
+fn course_bind_values(input: &CourseInput) -> Vec<JsValue> {
+    vec![
+        JsValue::from_str(&input.slug),
+        JsValue::from_str(input.course_code.trim()),
+        JsValue::from_str(input.course_name.trim()),
+        JsValue::from_str(input.department.trim()),
+        optional_str(input.professor.as_deref()),
+        optional_str(input.description.as_deref()),
+    ]
+}
+
+fn rsvp_status_to_db(status: RsvpStatus) -> &'static str {
+    match status {
+        RsvpStatus::Going => "going",
+        RsvpStatus::Maybe => "maybe",
+        RsvpStatus::NotGoing => "not_going",
+    }
+}
+
+fn role_to_db(role: MemberRole) -> &'static str {
+    match role {
+        MemberRole::President => "President",
+        MemberRole::VicePresident => "VicePresident",
+        MemberRole::Secretary => "Secretary",
+        MemberRole::Treasurer => "Treasurer",
+        MemberRole::ProjectLead => "ProjectLead",
+        MemberRole::Mentor => "Mentor",
+        MemberRole::Member => "Member",
+        MemberRole::Alumni => "Alumni",
+    }
+}
+
+fn role_from_db(role: &str) -> MemberRole {
+    match role {
+        "President" => MemberRole::President,
+        "VicePresident" => MemberRole::VicePresident,
+        "Secretary" => MemberRole::Secretary,
+        "Treasurer" => MemberRole::Treasurer,
+        "ProjectLead" => MemberRole::ProjectLead,
+        "Mentor" => MemberRole::Mentor,
+        "Alumni" => MemberRole::Alumni,
+        _ => MemberRole::Member,
+    }
+}
+
+fn role_change_from_row(row: RoleChangeRow) -> RoleChange {
+    RoleChange {
+        id: row.uuid,
+        member: Member {
+            name: row.member_name,
+            github_username: row.member_github,
+        },
+        role: role_from_db(&row.role),
+        action: if row.action == "revoked" { RoleAction::Revoked } else { RoleAction::Granted },
+        changed_by: row.changed_by,
+        reason: row.reason,
+        changed_at: parse_timestamp(&row.changed_at),
+    }
+}
+
+fn visibility_to_db(visibility: ProfileVisibility) -> &'static str {
+    match visibility {
+        ProfileVisibility::Public => "public",
+        ProfileVisibility::Members => "members",
+        ProfileVisibility::Hidden => "hidden",
+    }
+}
+
+fn visibility_from_db(visibility: &str) -> ProfileVisibility {
+    match visibility {
+        "members" => ProfileVisibility::Members,
+        "hidden" => ProfileVisibility::Hidden,
+        _ => ProfileVisibility::Public,
+    }
+}
+
+/// Roles are filled in separately
+fn member_record_from_row(row: MemberRow) -> MemberRecord {
+    MemberRecord {
+        id: row.id,
+        visibility: visibility_from_db(&row.profile_visibility),
+        show_attendance: row.show_attendance != 0,
+        summary: MemberSummary {
+            name: row.name,
+            github_username: row.github_username,
+            avatar_url: row.avatar_url,
+            bio: row.bio,
+            skills: serde_json::from_str(&row.skills).unwrap_or_default(),
+            roles: Vec::new(),
+            joined_at: parse_timestamp(&row.created_at),
+        },
+    }
+}
+
+fn contribution_from_row(row: ContributionRow) -> Contribution {
+    let claim_expires_at = row.claim_expires_at.as_deref().map(parse_timestamp);
+    let state = match row.state.as_str() {
+        // Lapsed claims read as expired before the sweep catches up with them
+        "claimed" if claim_expires_at.is_some_and(|expires_at| expires_at <= Utc::now()) => ContributionState::Expired,
+        "claimed" => ContributionState::Claimed,
+        "pr_opened" => ContributionState::PrOpened,
+        "expired" => ContributionState::Expired,
+        "released" => ContributionState::Released,
+        _ => ContributionState::Merged,
+    };
+    Contribution {
+        id: row.uuid,
+        member: Member {
+            name: row.member_name,
+            github_username: row.member_github,
+        },
+        issue_id: row.issue_uuid,
+        state,
+        repo: row.repo,
+        pr_url: row.pr_url,
+        title: row.title,
+        claimed_at: row.claimed_at.as_deref().map(parse_timestamp),
+        claim_expires_at,
+        pr_opened_at: row.pr_opened_at.as_deref().map(parse_timestamp),
+        merged_at: row.merged_at.as_deref().map(parse_timestamp),
+    }
+}
+
+fn rsvp_status_from_db(status: &str) -> RsvpStatus {
+    match status {
+        "going" => RsvpStatus::Going,
+        "maybe" => RsvpStatus::Maybe,
+        _ => RsvpStatus::NotGoing,
+    }
+}
+
+fn rsvp_from_row(row: RsvpRow) -> Rsvp {
+    Rsvp {
+        member: Member {
+            name: row.name,
+            github_username: row.github_username,
+        },
+        status: rsvp_status_from_db(&row.status),
+        responded_at: parse_timestamp(&row.updated_at),
+    }
+}
+
+fn check_in_from_row(row: CheckInRow) -> CheckIn {
+    CheckIn {
+        event_id: row.event_uuid,
+        member: Member {
+            name: row.name,
+            github_username: row.github_username,
+        },
+        occurrence_starts_at: parse_timestamp(&row.occurrence_starts_at),
+        checked_in_at: parse_timestamp(&row.checked_in_at),
+    }
+}
+
+fn registration_status_from_db(status: &str) -> RegistrationStatus {
+    match status {
+        "waitlisted" => RegistrationStatus::Waitlisted,
+        _ => RegistrationStatus::Registered,
+    }
+}
+
+/// Bind values for the workshop columns shared by INSERT and UPDATE, in
+/// `title .. capacity` order
+fn workshop_bind_values(input: &WorkshopInput) -> Result<Vec<JsValue>> {
+    Ok(vec![
+        JsValue::from_str(&input.title),
+        JsValue::from_str(&input.description),
+        JsValue::from_str(&input.level.to_string()),
+        JsValue::from_str(&db_timestamp(input.starts_at)),
+        JsValue::from_str(&db_timestamp(input.ends_at)),
+        JsValue::from_str(&input.location),
+        JsValue::from_str(&input.instructor),
+        JsValue::from_str(&serde_json::to_string(&input.topics)?),
+        JsValue::from_str(&serde_json::to_string(&input.prerequisites)?),
+        optional_str(input.materials_url.as_deref()),
+        JsValue::from_f64(input.max_participants as f64),
+    ])
+}
+
+/// Bind values for the term columns shared by INSERT and UPDATE, in
+/// `slug .. ends_on` order
+fn blog_series_bind_values(input: &BlogSeriesInput) -> Vec<JsValue> {
+    vec![
+        JsValue::from_str(&input.slug),
+        JsValue::from_str(&input.title),
+        JsValue::from_str(&input.description),
+        input.planned_parts.map(|n| JsValue::from_f64(n as f64)).unwrap_or(JsValue::NULL),
+    ]
+}
+
+fn term_bind_values(input: &TermInput) -> Vec<JsValue> {
+    vec![
+        JsValue::from_str(&input.slug),
+        JsValue::from_str(&input.name),
+        JsValue::from_str(&input.starts_on.format(DATE_FORMAT).to_string()),
+        JsValue::from_str(&input.ends_on.format(DATE_FORMAT).to_string()),
+    ]
+}
+
+/// Mark a post's latest revision as live at `?1`, unless it already went
+/// live. Callers append the condition choosing the posts.
+const PUBLISH_LATEST_REVISION: &str = "UPDATE blog_post_revisions SET published_at = ?1
+     WHERE published_at IS NULL
+       AND number = (SELECT max(r.number) FROM blog_post_revisions r WHERE r.post_id = blog_post_revisions.post_id)";
+
+/// Replace the members credited on a post: the lead author, if a member, at
+/// position 0 and co-authors after them. Usernames that are not members are
+/// skipped; callers check them first.
+fn blog_author_statements(db: &D1Database, post_id: &str, input: &BlogPostInput) -> Result<Vec<D1PreparedStatement>> {
+    let clear = query!(db, "DELETE FROM blog_post_authors WHERE post_id = ?", post_id)?;
+    let insert = db
+        .prepare(
+            "INSERT OR IGNORE INTO blog_post_authors (post_id, member_id, position)
+             SELECT ?1, m.id, a.position
+             FROM (SELECT ?2 AS github, 0 AS position
+                   UNION ALL SELECT value, key + 1 FROM json_each(?3)) a
+             JOIN members m ON m.github_username = a.github COLLATE NOCASE
+             WHERE EXISTS (SELECT 1 FROM blog_posts WHERE id = ?1)",
+        )
+        .bind(&[
+            post_id.into(),
+            optional_str(input.author_github.as_deref()),
+            serde_json::to_string(&input.co_authors)?.into(),
+        ])?;
+    Ok(vec![clear, insert])
+}
+
+/// Insert the next revision of a post. It counts as published straight away
+/// if the post already is.
+fn blog_revision_statement(
+    db: &D1Database,
+    post_id: &str,
+    input: &BlogPostInput,
+    revision: &NewRevision<'_>,
+) -> Result<D1PreparedStatement> {
+    let restored_from = revision.restored_from.map(|n| JsValue::from_f64(n as f64)).unwrap_or(JsValue::NULL);
+    db.prepare(
+        "INSERT INTO blog_post_revisions
+             (post_id, number, metadata, markdown, markdown_hash, restored_from, created_by, created_at, published_at)
+         SELECT ?1, coalesce((SELECT max(number) FROM blog_post_revisions WHERE post_id = ?1), 0) + 1,
+                ?2, ?3, ?4, ?5, ?6, ?7, CASE WHEN status = 'published' THEN ?7 END
+         FROM blog_posts WHERE id = ?1",
+    )
+    .bind(&[
+        post_id.into(),
+        serde_json::to_string(input)?.into(),
+        optional_str(revision.markdown),
+        optional_str(revision.markdown.map(content::markdown_hash).as_deref()),
+        restored_from,
+        revision.created_by.into(),
+        db_timestamp(Utc::now()).into(),
+    ])
+}
+
+fn optional_str(value: Option<&str>) -> JsValue {
+    value.map(JsValue::from_str).unwrap_or(JsValue::NULL)
+}
+
+/// Bind values for the blog post columns shared by INSERT and UPDATE, in
+/// `title .. external_links` order
+fn blog_post_bind_values(input: &BlogPostInput) -> Result<Vec<JsValue>> {
+    let tags = serde_json::to_string(&input.tags)?;
+    let external_links = serde_json::to_string(&input.external_links)?;
+    let series = input.series.as_ref();
+
+    Ok(vec![
+        JsValue::from_str(&input.title),
+        JsValue::from_str(&input.slug),
+        JsValue::from_str(&input.excerpt),
+        JsValue::from_str(blog_post_type_to_db(&input.post_type)),
+        JsValue::from_str(blog_category_to_db(&input.category)),
+        JsValue::from_str(&tags),
+        JsValue::from_str(&input.author_name),
+        optional_str(input.author_github.as_deref()),
+        optional_str(input.difficulty_level.as_ref().map(difficulty_to_blog_db)),
+        JsValue::from_f64(input.estimated_read_time as f64),
+        JsValue::from_str(&input.published_at),
+        optional_str(input.updated_at.as_deref()),
+        JsValue::from_str(&input.markdown_url),
+        optional_str(series.map(|s| s.slug.as_str())),
+        series.map(|s| JsValue::from_f64(s.part as f64)).unwrap_or(JsValue::NULL),
+        JsValue::from_str(&external_links),
+    ])
+}
@@ -0,0 +1,55 @@
+//! Differences between two revisions of a blog post: which metadata fields
+//! changed, and a line diff of the markdown
+
+use serde_json::Value;
+use similar::{ChangeTag, TextDiff};
+
+use crate::models::*;
+
+/// Compare two revisions; `older` and `newer` only label the direction
+pub fn revision_diff(older: &BlogRevision, newer: &BlogRevision) -> BlogRevisionDiff {
+    let markdown = match (&older.markdown, &newer.markdown) {
+        (None, None) => Vec::new(),
+        (before, after) => line_diff(before.as_deref().unwrap_or(""), after.as_deref().unwrap_or("")),
+    };
+    BlogRevisionDiff {
+        from: older.number,
+        to: newer.number,
+        fields: field_changes(&older.post, &newer.post),
+        markdown,
+        markdown_missing: older.markdown.is_none() || newer.markdown.is_none(),
+    }
+}
+
+/// Fields whose JSON values differ, by field name
+fn field_changes(before: &BlogPostInput, after: &BlogPostInput) -> Vec<BlogFieldChange> {
+    let (Ok(Value::Object(before)), Ok(Value::Object(after))) = (serde_json::to_value(before), serde_json::to_value(after))
+    else {
+        return Vec::new();
+    };
+    before
+        .iter()
+        .filter_map(|(field, old)| {
+            let new = after.get(field).unwrap_or(&Value::Null);
+            (old != new).then(|| BlogFieldChange {
+                field: field.clone(),
+                before: old.to_string(),
+                after: new.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn line_diff(before: &str, after: &str) -> Vec<DiffLine> {
+    TextDiff::from_lines(before, after)
+        .iter_all_changes()
+        .map(|change| DiffLine {
+            op: match change.tag() {
+                ChangeTag::Equal => DiffOp::Unchanged,
+                ChangeTag::Insert => DiffOp::Added,
+                ChangeTag::Delete => DiffOp::Removed,
+            },
+            text: change.value().trim_end_matches(['\n', '\r']).to_string(),
+        })
+        .collect()
+}
@@ -0,0 +1,44 @@
+use worker::{Response, Result, RouteContext};
+
+use crate::database;
+use crate::models::ApiError;
+
+/// Per-request data threaded through the router to every handler
+pub struct RequestInfo {
+    /// Cloudflare ray id when available, otherwise a generated UUID
+    pub request_id: String,
+}
+
+impl RequestInfo {
+    pub fn from_request(req: &worker::Request) -> Self {
+        let request_id = req
+            .headers()
+            .get("cf-ray")
+            .ok()
+            .flatten()
+            .unwrap_or_else(|| uuid::Uuid::new_v4().to_string());
+        RequestInfo { request_id }
+    }
+}
+
+/// Render an `ApiError` as a JSON response with its mapped HTTP status
+pub fn render_error(mut error: ApiError, request_id: &str) -> Result<Response> {
+    error.request_id = request_id.to_string();
+    let status = error.status();
+    Ok(Response::from_json(&error)?.with_status(status))
+}
+
+/// Shorthand for handlers: `return error_response(&ctx, ApiError::not_found("..."))`
+pub fn error_response(ctx: &RouteContext<RequestInfo>, error: ApiError) -> Result<Response> {
+    render_error(error, &ctx.data.request_id)
+}
+
+/// Turn a D1 failure into an `ApiError`, logging the underlying cause.
+/// UNIQUE constraint failures become `conflict`; everything else is `internal`.
+pub fn database_error(action: &str, e: worker::Error) -> ApiError {
+    if database::is_unique_violation(&e) {
+        return ApiError::conflict("A record with the same unique field already exists");
+    }
+    web_sys::console::error_1(&format!("Failed to {}: {:?}", action, e).into());
+    ApiError::internal(format!("Unable to {} at this time", action))
+}
@@ -0,0 +1,166 @@
+//! RSS 2.0 and Atom 1.0 rendering for the blog
+
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+
+use crate::models::*;
+
+const SITE_URL: &str = "https://chico.rs";
+
+/// Channel-level metadata for a feed
+pub struct FeedInfo {
+    pub title: String,
+    pub description: String,
+    /// Absolute URL the feed itself is served from
+    pub self_url: String,
+}
+
+/// Rendered HTML bodies keyed by post id, for feeds that include full content
+pub type PostContent = HashMap<String, String>;
+
+pub fn post_url(post: &BlogPost) -> String {
+    format!("{}/blog/{}", SITE_URL, post.slug)
+}
+
+pub fn rss(info: &FeedInfo, posts: &[BlogPost], content: &PostContent) -> String {
+    let mut xml = String::new();
+    xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push('\n');
+    xml.push_str(concat!(
+        r#"<rss version="2.0" xmlns:atom="http://www.w3.org/2005/Atom" "#,
+        r#"xmlns:content="http://purl.org/rss/1.0/modules/content/" "#,
+        r#"xmlns:dc="http://purl.org/dc/elements/1.1/">"#
+    ));
+    xml.push_str("<channel>");
+    element(&mut xml, "title", &info.title);
+    element(&mut xml, "link", &format!("{}/blog", SITE_URL));
+    element(&mut xml, "description", &info.description);
+    element(&mut xml, "language", "en-us");
+    element(&mut xml, "lastBuildDate", &last_updated(posts).to_rfc2822());
+    xml.push_str(&format!(
+        r#"<atom:link href="{}" rel="self" type="application/rss+xml"/>"#,
+        escape(&info.self_url)
+    ));
+
+    for post in posts {
+        let link = post_url(post);
+        xml.push_str("<item>");
+        element(&mut xml, "title", &post.title);
+        element(&mut xml, "link", &link);
+        xml.push_str(&format!(r#"<guid isPermaLink="true">{}</guid>"#, escape(&link)));
+        element(&mut xml, "description", &post.excerpt);
+        element(&mut xml, "dc:creator", &post.author_name);
+        for co_author in &post.co_authors {
+            element(&mut xml, "dc:creator", &co_author.name);
+        }
+        element(&mut xml, "category", &post.category.to_string());
+        for tag in &post.tags {
+            element(&mut xml, "category", tag);
+        }
+        element(&mut xml, "pubDate", &parse_time(&post.published_at).to_rfc2822());
+        if let Some(html) = content.get(&post.id) {
+            element(&mut xml, "content:encoded", html);
+        }
+        xml.push_str("</item>");
+    }
+
+    xml.push_str("</channel></rss>\n");
+    xml
+}
+
+pub fn atom(info: &FeedInfo, posts: &[BlogPost], content: &PostContent) -> String {
+    let mut xml = String::new();
+    xml.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    xml.push('\n');
+    xml.push_str(r#"<feed xmlns="http://www.w3.org/2005/Atom">"#);
+    element(&mut xml, "id", &info.self_url);
+    element(&mut xml, "title", &info.title);
+    element(&mut xml, "subtitle", &info.description);
+    element(&mut xml, "updated", &last_updated(posts).to_rfc3339());
+    xml.push_str(&format!(r#"<link rel="self" type="application/atom+xml" href="{}"/>"#, escape(&info.self_url)));
+    xml.push_str(&format!(r#"<link rel="alternate" type="text/html" href="{}/blog"/>"#, SITE_URL));
+
+    for post in posts {
+        let link = post_url(post);
+        let published = parse_time(&post.published_at);
+        xml.push_str("<entry>");
+        element(&mut xml, "id", &format!("tag:chico.rs,2025:blog/{}", post.id));
+        element(&mut xml, "title", &post.title);
+        xml.push_str(&format!(r#"<link rel="alternate" type="text/html" href="{}"/>"#, escape(&link)));
+        element(&mut xml, "published", &published.to_rfc3339());
+        element(&mut xml, "updated", &updated_time(post).to_rfc3339());
+        xml.push_str("<author>");
+        element(&mut xml, "name", &post.author_name);
+        if let Some(github) = &post.author_github {
+            element(&mut xml, "uri", &format!("https://github.com/{}", github));
+        }
+        xml.push_str("</author>");
+        for co_author in &post.co_authors {
+            xml.push_str("<author>");
+            element(&mut xml, "name", &co_author.name);
+            element(&mut xml, "uri", &format!("https://github.com/{}", co_author.github_username));
+            xml.push_str("</author>");
+        }
+        xml.push_str(&format!(
+            r#"<category term="{}" label="{}"/>"#,
+            escape(&category_slug(&post.category)),
+            escape(&post.category.to_string())
+        ));
+        for tag in &post.tags {
+            xml.push_str(&format!(r#"<category term="{}"/>"#, escape(tag)));
+        }
+        xml.push_str(&format!(r#"<summary type="text">{}</summary>"#, escape(&post.excerpt)));
+        if let Some(html) = content.get(&post.id) {
+            xml.push_str(&format!(r#"<content type="html">{}</content>"#, escape(html)));
+        }
+        xml.push_str("</entry>");
+    }
+
+    xml.push_str("</feed>\n");
+    xml
+}
+
+/// Serde name of a category, as accepted by `?category=` and the feed URLs
+pub fn category_slug(category: &BlogCategory) -> String {
+    serde_json::to_value(category)
+        .ok()
+        .and_then(|value| value.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn element(xml: &mut String, name: &str, text: &str) {
+    xml.push_str(&format!("<{name}>{}</{name}>", escape(text), name = name));
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn parse_time(value: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(value)
+        .map(|time| time.with_timezone(&Utc))
+        .unwrap_or_default()
+}
+
+fn updated_time(post: &BlogPost) -> DateTime<Utc> {
+    post.updated_at
+        .as_deref()
+        .map(parse_time)
+        .unwrap_or_else(|| parse_time(&post.published_at))
+}
+
+/// Newest update across the feed, or now for an empty feed
+fn last_updated(posts: &[BlogPost]) -> DateTime<Utc> {
+    posts.iter().map(updated_time).max().unwrap_or_else(Utc::now)
+}
@@ -0,0 +1,212 @@
+//! GitHub OAuth for member sign-in, and the REST calls behind the issue
+//! sync. The OAuth and REST base URLs come from Worker vars so a local stub
+//! can stand in for github.com in development.
+
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use wasm_bindgen::JsValue;
+use worker::{Env, Fetch, Headers, Method, Request, RequestInit, Result};
+
+const DEFAULT_OAUTH_URL: &str = "https://github.com";
+const DEFAULT_API_URL: &str = "https://api.github.com";
+
+/// GitHub rejects API requests without a User-Agent
+const USER_AGENT: &str = "chico-rs-api";
+
+/// Issues per page, GitHub's maximum
+const ISSUES_PER_PAGE: usize = 100;
+
+/// Stop following pages after this many, so one busy label cannot use up
+/// the job's subrequests
+const MAX_ISSUE_PAGES: u32 = 5;
+
+fn env_var(env: &Env, name: &str) -> Option<String> {
+    env.var(name).map(|v| v.to_string()).ok().filter(|v| !v.is_empty())
+}
+
+/// Base of the REST API, from the `GITHUB_API_URL` var
+pub fn api_url(env: &Env) -> String {
+    env_var(env, "GITHUB_API_URL")
+        .unwrap_or_else(|| DEFAULT_API_URL.to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Where to reach GitHub and which OAuth app to sign in with
+pub struct GitHubConfig {
+    /// Base of the OAuth endpoints, e.g. `https://github.com`
+    pub oauth_url: String,
+    /// Base of the REST API, e.g. `https://api.github.com`
+    pub api_url: String,
+    pub client_id: String,
+    pub client_secret: String,
+    /// Callback URL registered with the OAuth app
+    pub redirect_url: String,
+}
+
+impl GitHubConfig {
+    /// Read the config from Worker vars and secrets. `None` if the OAuth app
+    /// is not configured, so sign-in can be reported as unavailable.
+    pub fn from_env(env: &Env) -> Option<Self> {
+        Some(GitHubConfig {
+            oauth_url: env_var(env, "GITHUB_OAUTH_URL")
+                .unwrap_or_else(|| DEFAULT_OAUTH_URL.to_string())
+                .trim_end_matches('/')
+                .to_string(),
+            api_url: api_url(env),
+            client_id: env_var(env, "GITHUB_CLIENT_ID")?,
+            client_secret: env.secret("GITHUB_CLIENT_SECRET").ok()?.to_string(),
+            redirect_url: env_var(env, "GITHUB_REDIRECT_URL")?,
+        })
+    }
+
+    /// Page on GitHub that asks the user to authorize the app
+    pub fn authorize_url(&self, state: &str) -> Result<String> {
+        let url = worker::Url::parse_with_params(
+            &format!("{}/login/oauth/authorize", self.oauth_url),
+            &[
+                ("client_id", self.client_id.as_str()),
+                ("redirect_uri", self.redirect_url.as_str()),
+                ("state", state),
+                // Profile only; sign-in needs no repository access
+                ("scope", "read:user"),
+                ("allow_signup", "true"),
+            ],
+        )?;
+        Ok(url.to_string())
+    }
+}
+
+/// The GitHub account that signed in
+#[derive(Debug, Deserialize)]
+pub struct GitHubUser {
+    /// Numeric id; stable across username changes
+    pub id: i64,
+    pub login: String,
+    pub avatar_url: Option<String>,
+}
+
+/// GitHub answers a bad code with 200 and an `error` field
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: Option<String>,
+    error: Option<String>,
+}
+
+fn headers() -> Result<Headers> {
+    let mut headers = Headers::new();
+    headers.set("Accept", "application/json")?;
+    headers.set("User-Agent", USER_AGENT)?;
+    Ok(headers)
+}
+
+/// Trade the code from the OAuth callback for an access token. `None` if
+/// GitHub rejects the code, e.g. because it expired or was already used.
+pub async fn exchange_code(config: &GitHubConfig, code: &str) -> Result<Option<String>> {
+    let mut headers = headers()?;
+    headers.set("Content-Type", "application/json")?;
+    let body = serde_json::json!({
+        "client_id": config.client_id,
+        "client_secret": config.client_secret,
+        "code": code,
+        "redirect_uri": config.redirect_url,
+    });
+
+    let mut init = RequestInit::new();
+    init.with_method(Method::Post)
+        .with_headers(headers)
+        .with_body(Some(JsValue::from_str(&body.to_string())));
+    let request = Request::new_with_init(&format!("{}/login/oauth/access_token", config.oauth_url), &init)?;
+    let mut response = Fetch::Request(request).send().await?;
+    if response.status_code() != 200 {
+        return Err(worker::Error::RustError(format!("GitHub token exchange returned {}", response.status_code())));
+    }
+
+    let token: TokenResponse = response.json().await?;
+    if let Some(error) = token.error {
+        web_sys::console::warn_1(&format!("GitHub rejected OAuth code: {}", error).into());
+    }
+    Ok(token.access_token)
+}
+
+/// The account an access token belongs to
+pub async fn fetch_user(config: &GitHubConfig, access_token: &str) -> Result<GitHubUser> {
+    let mut headers = headers()?;
+    headers.set("Authorization", &format!("Bearer {}", access_token))?;
+
+    let mut init = RequestInit::new();
+    init.with_method(Method::Get).with_headers(headers);
+    let request = Request::new_with_init(&format!("{}/user", config.api_url), &init)?;
+    let mut response = Fetch::Request(request).send().await?;
+    if response.status_code() != 200 {
+        return Err(worker::Error::RustError(format!("GitHub user lookup returned {}", response.status_code())));
+    }
+    response.json().await
+}
+
+/// A label on a GitHub issue
+#[derive(Debug, Deserialize)]
+pub struct GitHubLabel {
+    pub name: String,
+}
+
+/// An issue from a repository's issue list
+#[derive(Debug, Deserialize)]
+pub struct GitHubIssue {
+    pub html_url: String,
+    pub title: String,
+    pub body: Option<String>,
+    pub labels: Vec<GitHubLabel>,
+    pub created_at: DateTime<Utc>,
+    /// Set when the item is a pull request, which the issues API also lists
+    pub pull_request: Option<serde_json::Value>,
+}
+
+/// The open issues carrying one label
+pub struct OpenIssues {
+    pub issues: Vec<GitHubIssue>,
+    /// `false` when the listing stopped at `MAX_ISSUE_PAGES` and GitHub may
+    /// have more
+    pub complete: bool,
+}
+
+/// Open issues (not pull requests) in `repo` carrying `label`. `token` is
+/// optional; without one GitHub allows 60 requests an hour.
+pub async fn fetch_open_issues(api_url: &str, token: Option<&str>, repo: &str, label: &str) -> Result<OpenIssues> {
+    let mut issues = Vec::new();
+    for page in 1..=MAX_ISSUE_PAGES {
+        let url = worker::Url::parse_with_params(
+            &format!("{}/repos/{}/issues", api_url, repo),
+            &[
+                ("state", "open"),
+                ("labels", label),
+                ("per_page", &ISSUES_PER_PAGE.to_string()),
+                ("page", &page.to_string()),
+            ],
+        )?;
+        let mut headers = headers()?;
+        if let Some(token) = token {
+            headers.set("Authorization", &format!("Bearer {}", token))?;
+        }
+
+        let mut init = RequestInit::new();
+        init.with_method(Method::Get).with_headers(headers);
+        let request = Request::new_with_init(url.as_str(), &init)?;
+        let mut response = Fetch::Request(request).send().await?;
+        if response.status_code() != 200 {
+            return Err(worker::Error::RustError(format!(
+                "GitHub issue list for {} returned {}",
+                repo,
+                response.status_code()
+            )));
+        }
+
+        let batch: Vec<GitHubIssue> = response.json().await?;
+        let last_page = batch.len() < ISSUES_PER_PAGE;
+        issues.extend(batch.into_iter().filter(|issue| issue.pull_request.is_none()));
+        if last_page {
+            return Ok(OpenIssues { issues, complete: true });
+        }
+    }
+    Ok(OpenIssues { issues, complete: false })
+}
@@ -0,0 +1,206 @@
+//! RFC 5545 (iCalendar) rendering for club events
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::collections::BTreeMap;
+
+use crate::models::*;
+
+const PRODUCT_ID: &str = "-//The Rust Club//chico.rs API//EN";
+const UID_DOMAIN: &str = "chico.rs";
+const EVENTS_PAGE_URL: &str = "https://chico.rs/events";
+
+/// Render `events` as a VCALENDAR. `name` becomes the calendar's display name
+/// in clients that honour `X-WR-CALNAME`.
+pub fn calendar(events: &[Event], name: &str) -> String {
+    let mut lines = Lines::default();
+    lines.push("BEGIN:VCALENDAR");
+    lines.push("VERSION:2.0");
+    lines.push(&format!("PRODID:{}", PRODUCT_ID));
+    lines.push("CALSCALE:GREGORIAN");
+    lines.push("METHOD:PUBLISH");
+    lines.push(&format!("X-WR-CALNAME:{}", escape_text(name)));
+    lines.push("REFRESH-INTERVAL;VALUE=DURATION:PT1H");
+    lines.push("X-PUBLISHED-TTL:PT1H");
+
+    // One VTIMEZONE per zone referenced by a TZID, built for the earliest year used
+    let mut zones: BTreeMap<String, (Tz, i32)> = BTreeMap::new();
+    for event in events {
+        let tz = event.tz();
+        let year = event.starts_at.with_timezone(&tz).year();
+        zones
+            .entry(tz.name().to_string())
+            .and_modify(|(_, first_year)| *first_year = (*first_year).min(year))
+            .or_insert((tz, year));
+    }
+    for (tz, year) in zones.values() {
+        vtimezone(&mut lines, *tz, *year);
+    }
+
+    let stamp = Utc::now();
+    for event in events {
+        vevent(&mut lines, event, stamp);
+    }
+
+    lines.push("END:VCALENDAR");
+    lines.finish()
+}
+
+fn vevent(lines: &mut Lines, event: &Event, stamp: DateTime<Utc>) {
+    let tz = event.tz();
+    lines.push("BEGIN:VEVENT");
+    lines.push(&format!("UID:{}@{}", event.id, UID_DOMAIN));
+    lines.push(&format!("DTSTAMP:{}", utc_time(stamp)));
+    lines.push(&format!("CREATED:{}", utc_time(event.created_at)));
+    lines.push(&format!("DTSTART;TZID={}:{}", tz.name(), local_time(event.starts_at, tz)));
+    lines.push(&format!("DTEND;TZID={}:{}", tz.name(), local_time(event.ends_at, tz)));
+    if let Some(rule) = event.recurrence_rule() {
+        lines.push(&format!("RRULE:{}", rule));
+    }
+    lines.push(&format!("SUMMARY:{}", escape_text(&event.title)));
+    lines.push(&format!("DESCRIPTION:{}", escape_text(&event.description)));
+    lines.push(&format!("LOCATION:{}", escape_text(&event.location)));
+    lines.push(&format!("CATEGORIES:{}", escape_text(&event.event_type.to_string())));
+    lines.push(&format!("URL:{}", EVENTS_PAGE_URL));
+    lines.push("STATUS:CONFIRMED");
+    lines.push("END:VEVENT");
+}
+
+/// Describe `tz`'s offsets from `year` onward. Zones with DST get a STANDARD
+/// and a DAYLIGHT rule recurring on the same weekday-of-month every year.
+fn vtimezone(lines: &mut Lines, tz: Tz, year: i32) {
+    lines.push("BEGIN:VTIMEZONE");
+    lines.push(&format!("TZID:{}", tz.name()));
+
+    let transitions = offset_transitions(tz, year);
+    if transitions.is_empty() {
+        let start = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap();
+        let offset = offset_seconds(tz, start);
+        lines.push("BEGIN:STANDARD");
+        lines.push(&format!("DTSTART:{}0101T000000", year));
+        lines.push(&format!("TZOFFSETFROM:{}", format_offset(offset)));
+        lines.push(&format!("TZOFFSETTO:{}", format_offset(offset)));
+        lines.push(&format!("TZNAME:{}", tz.offset_from_utc_datetime(&start.naive_utc())));
+        lines.push("END:STANDARD");
+    }
+
+    for (at, from, to) in transitions {
+        // DTSTART is the wall-clock time the change happens, in the old offset
+        let onset = (at + Duration::seconds(from as i64)).naive_utc();
+        let component = if to > from { "DAYLIGHT" } else { "STANDARD" };
+        lines.push(&format!("BEGIN:{}", component));
+        lines.push(&format!("DTSTART:{}", onset.format("%Y%m%dT%H%M%S")));
+        lines.push(&format!("TZOFFSETFROM:{}", format_offset(from)));
+        lines.push(&format!("TZOFFSETTO:{}", format_offset(to)));
+        lines.push(&format!("TZNAME:{}", tz.offset_from_utc_datetime(&at.naive_utc())));
+        lines.push(&format!(
+            "RRULE:FREQ=YEARLY;BYMONTH={};BYDAY={}{}",
+            onset.month(),
+            weekday_ordinal(onset.date()),
+            weekday_code(onset.weekday())
+        ));
+        lines.push(&format!("END:{}", component));
+    }
+
+    lines.push("END:VTIMEZONE");
+}
+
+/// `(instant, offset before, offset after)` for each UTC offset change in `year`
+fn offset_transitions(tz: Tz, year: i32) -> Vec<(DateTime<Utc>, i32, i32)> {
+    let mut transitions = Vec::new();
+    let mut at = Utc.with_ymd_and_hms(year, 1, 1, 0, 0, 0).unwrap();
+    let end = Utc.with_ymd_and_hms(year + 1, 1, 1, 0, 0, 0).unwrap();
+    let mut offset = offset_seconds(tz, at);
+
+    // Offsets change on the hour or half hour in every zone the club would use
+    while at < end {
+        let next = at + Duration::minutes(30);
+        let next_offset = offset_seconds(tz, next);
+        if next_offset != offset {
+            transitions.push((next, offset, next_offset));
+            offset = next_offset;
+        }
+        at = next;
+    }
+    transitions
+}
+
+fn offset_seconds(tz: Tz, at: DateTime<Utc>) -> i32 {
+    tz.offset_from_utc_datetime(&at.naive_utc()).fix().local_minus_utc()
+}
+
+/// `+HHMM` / `-HHMM` as used by TZOFFSETFROM and TZOFFSETTO
+fn format_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.abs() / 60;
+    format!("{}{:02}{:02}", sign, minutes / 60, minutes % 60)
+}
+
+/// BYDAY ordinal for a date: 1..4 counting from the start of the month, or
+/// -1 when it falls in the month's last seven days (e.g. "last Sunday")
+fn weekday_ordinal(date: NaiveDate) -> i32 {
+    let next_month = if date.month() == 12 {
+        NaiveDate::from_ymd_opt(date.year() + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(date.year(), date.month() + 1, 1)
+    };
+    let days_in_month = next_month
+        .map(|first| (first - Duration::days(1)).day())
+        .unwrap_or(31);
+
+    if date.day() + 7 > days_in_month {
+        -1
+    } else {
+        ((date.day() - 1) / 7 + 1) as i32
+    }
+}
+
+fn utc_time(value: DateTime<Utc>) -> String {
+    value.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+fn local_time(value: DateTime<Utc>, tz: Tz) -> String {
+    value.with_timezone(&tz).format("%Y%m%dT%H%M%S").to_string()
+}
+
+/// Escape a TEXT value: backslash, semicolon, comma and newlines
+fn escape_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Content lines joined with CRLF and folded at 75 octets
+#[derive(Default)]
+struct Lines {
+    output: String,
+}
+
+impl Lines {
+    fn push(&mut self, line: &str) {
+        let mut width = 0;
+        for c in line.chars() {
+            let len = c.len_utf8();
+            if width + len > 75 {
+                self.output.push_str("\r\n ");
+                width = 1;
+            }
+            self.output.push(c);
+            width += len;
+        }
+        self.output.push_str("\r\n");
+    }
+
+    fn finish(self) -> String {
+        self.output
+    }
+}
@@ -0,0 +1,160 @@
+//! Keeps the good-first-issues board in step with GitHub. The scheduled job
+//! lists the open issues carrying one of `SYNC_LABELS` in every repository
+//! named by the `ISSUE_SYNC_REPOS` var, upserts them into `issues` and closes
+//! the synced issues that are no longer listed, unless the listing was cut
+//! short. GitHub is reached through
+//! `GITHUB_API_URL`, so the job can run against a local mock.
+
+use chrono::Utc;
+use std::collections::BTreeMap;
+use worker::{Env, Result};
+
+use crate::database::{DatabaseService, SyncedIssue};
+use crate::github::{self, GitHubIssue};
+use crate::models::*;
+
+/// Labels that put an issue on the board
+const SYNC_LABELS: [&str; 2] = ["good first issue", "help wanted"];
+
+/// Longest description kept from an issue body, in characters
+const MAX_DESCRIPTION_CHARS: usize = 280;
+
+/// What one sync run did
+pub struct SyncReport {
+    pub repos: usize,
+    /// Issues inserted or refreshed
+    pub upserted: usize,
+    /// Issues closed because they are no longer open and labelled upstream
+    pub closed: usize,
+    /// Repositories that could not be fetched; their issues were left alone
+    pub failed: Vec<String>,
+    /// Repositories with more issues than one run lists; what was listed was
+    /// upserted, but none of their issues were closed
+    pub truncated: Vec<String>,
+}
+
+/// Repositories to sync, from the comma-separated `ISSUE_SYNC_REPOS` var,
+/// e.g. `rust-lang/rustlings, tokio-rs/tokio`
+fn sync_repos(env: &Env) -> Vec<String> {
+    env.var("ISSUE_SYNC_REPOS")
+        .map(|v| v.to_string())
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|repo| repo.split('/').filter(|part| !part.is_empty()).count() == 2)
+        .map(str::to_string)
+        .collect()
+}
+
+/// Difficulty from the repository's labels. Rust projects commonly mark it
+/// with `E-easy`/`E-medium`/`E-hard`; otherwise a good first issue is easy
+/// and a plain help wanted issue is medium.
+fn difficulty(labels: &[String]) -> DifficultyLevel {
+    // Whole words only, so "hardware" does not read as hard
+    let has = |word: &str| {
+        labels
+            .iter()
+            .any(|label| label.split(|c: char| !c.is_alphanumeric()).any(|part| part == word))
+    };
+    if has("hard") || has("advanced") {
+        DifficultyLevel::Hard
+    } else if has("medium") || has("intermediate") {
+        DifficultyLevel::Medium
+    } else if has("easy") || has("beginner") || labels.iter().any(|label| label == "good first issue") {
+        DifficultyLevel::Easy
+    } else {
+        DifficultyLevel::Medium
+    }
+}
+
+/// Labels as tags, e.g. "good first issue" becomes "good-first-issue"
+fn tags(labels: &[String]) -> Vec<String> {
+    labels.iter().map(|label| label.split_whitespace().collect::<Vec<_>>().join("-")).collect()
+}
+
+/// First paragraph of the issue body, shortened to fit a card
+fn description(body: Option<&str>) -> String {
+    let paragraph = body
+        .unwrap_or_default()
+        .split("\n\n")
+        .map(|paragraph| paragraph.split_whitespace().collect::<Vec<_>>().join(" "))
+        .find(|paragraph| !paragraph.is_empty() && !paragraph.starts_with("<!--"));
+    match paragraph {
+        Some(text) if text.chars().count() > MAX_DESCRIPTION_CHARS => {
+            let cut: String = text.chars().take(MAX_DESCRIPTION_CHARS - 1).collect();
+            format!("{}…", cut.trim_end())
+        }
+        Some(text) => text,
+        None => "See the issue on GitHub for details.".to_string(),
+    }
+}
+
+fn synced_issue(repo: &str, issue: GitHubIssue) -> SyncedIssue {
+    let labels: Vec<String> = issue.labels.into_iter().map(|label| label.name.to_lowercase()).collect();
+    SyncedIssue {
+        input: IssueInput {
+            title: issue.title,
+            description: description(issue.body.as_deref()),
+            repo: repo.to_string(),
+            github_url: issue.html_url,
+            difficulty: difficulty(&labels),
+            tags: tags(&labels),
+        },
+        opened_at: issue.created_at,
+    }
+}
+
+/// Open issues in `repo` carrying any sync label, once each, and whether
+/// every label was listed in full
+async fn fetch_repo(api_url: &str, token: Option<&str>, repo: &str) -> Result<(Vec<SyncedIssue>, bool)> {
+    let mut by_url = BTreeMap::new();
+    let mut complete = true;
+    for label in SYNC_LABELS {
+        let listed = github::fetch_open_issues(api_url, token, repo, label).await?;
+        complete &= listed.complete;
+        for issue in listed.issues {
+            by_url.entry(issue.html_url.clone()).or_insert(issue);
+        }
+    }
+    let issues = by_url.into_values().map(|issue| synced_issue(repo, issue)).collect();
+    Ok((issues, complete))
+}
+
+/// Run one sync. A repository that fails to fetch is reported and skipped
+/// without closing its issues, so a GitHub outage does not empty the board.
+/// One listed only in part has its issues upserted but none closed, since an
+/// issue missing from the list may just be on a page that was not fetched.
+pub async fn sync_issues(env: &Env) -> Result<SyncReport> {
+    let db = env.d1("DB")?;
+    let api_url = github::api_url(env);
+    // Optional; raises GitHub's rate limit from 60 to 5,000 requests an hour
+    let token = env.secret("GITHUB_SYNC_TOKEN").ok().map(|s| s.to_string()).filter(|s| !s.is_empty());
+    let started_at = Utc::now();
+
+    let repos = sync_repos(env);
+    let mut report = SyncReport {
+        repos: repos.len(),
+        upserted: 0,
+        closed: 0,
+        failed: Vec::new(),
+        truncated: Vec::new(),
+    };
+    for repo in &repos {
+        let (issues, complete) = match fetch_repo(&api_url, token.as_deref(), repo).await {
+            Ok(listed) => listed,
+            Err(e) => {
+                web_sys::console::warn_1(&format!("Failed to fetch issues for {}: {:?}", repo, e).into());
+                report.failed.push(repo.clone());
+                continue;
+            }
+        };
+        report.upserted += DatabaseService::upsert_synced_issues(&db, &issues, started_at).await?;
+        if !complete {
+            web_sys::console::warn_1(&format!("Issue list for {} was cut short; closing nothing", repo).into());
+            report.truncated.push(repo.clone());
+            continue;
+        }
+        report.closed += DatabaseService::close_unsynced_issues(&db, repo, started_at).await?;
+    }
+    Ok(report)
+}
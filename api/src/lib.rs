@@ -1,31 +1,172 @@
 use worker::*;
-use serde_json::json;
-use utoipa::OpenApi;
+use utoipa::openapi::security::{ApiKey, ApiKeyValue, HttpAuthScheme, HttpBuilder, SecurityScheme};
+use utoipa::{Modify, OpenApi};
 
+mod auth;
+mod check_in;
+mod content;
 mod models;
 mod routes;
 mod database;
+mod diff;
+mod error;
+mod feeds;
+mod github;
+mod ics;
+mod issue_sync;
+mod pagination;
+mod rate_limit;
+mod scheduled;
+mod search;
+mod session;
+mod stats;
+mod tokens;
+mod views;
+mod visitor;
 
+use error::{error_response, RequestInfo};
 use models::*;
 
 #[derive(OpenApi)]
 #[openapi(
     paths(
+        routes::github_sign_in,
+        routes::github_callback,
+        routes::get_me,
+        routes::sign_out,
+        routes::get_members,
+        routes::get_member,
+        routes::get_profile_settings,
+        routes::update_profile_settings,
         routes::get_stats,
+        routes::get_terms,
+        routes::get_term,
         routes::get_events,
+        routes::get_event,
+        routes::create_event_rsvp,
+        routes::get_event_rsvp,
+        routes::update_event_rsvp,
+        routes::delete_event_rsvp,
+        routes::check_in_to_event,
+        routes::get_events_calendar,
+        routes::get_event_calendar,
+        routes::get_workshops,
+        routes::get_workshop,
+        routes::register_for_workshop,
+        routes::get_workshop_registration,
+        routes::cancel_workshop_registration,
+        routes::get_courses,
+        routes::get_course,
+        routes::support_course,
+        routes::get_petition_summary,
+        routes::sign_petition,
         routes::get_issues,
         routes::get_projects,
+        routes::get_project,
         routes::get_blog_posts,
         routes::get_featured_blog_posts,
         routes::get_blog_post_by_slug,
-        routes::health_check
+        routes::get_blog_series_list,
+        routes::get_blog_series,
+        routes::get_author,
+        routes::get_blog_rss,
+        routes::get_blog_atom,
+        routes::get_blog_category_rss,
+        routes::get_blog_category_atom,
+        routes::get_blog_tag_rss,
+        routes::get_blog_tag_atom,
+        routes::get_like_status,
+        routes::like_blog_post,
+        routes::unlike_blog_post,
+        routes::record_blog_view,
+        routes::search,
+        routes::health_check,
+        routes::create_event,
+        routes::replace_event,
+        routes::patch_event,
+        routes::delete_event,
+        routes::create_check_in_code,
+        routes::get_event_rsvps,
+        routes::get_event_check_ins,
+        routes::create_issue,
+        routes::replace_issue,
+        routes::patch_issue,
+        routes::delete_issue,
+        routes::sync_issues,
+        routes::create_project,
+        routes::replace_project,
+        routes::patch_project,
+        routes::delete_project,
+        routes::add_project_contributor,
+        routes::remove_project_contributor,
+        routes::create_workshop,
+        routes::replace_workshop,
+        routes::patch_workshop,
+        routes::delete_workshop,
+        routes::get_workshop_registrants,
+        routes::create_term,
+        routes::replace_term,
+        routes::delete_term,
+        routes::create_course,
+        routes::replace_course,
+        routes::set_course_status,
+        routes::delete_course,
+        routes::create_contribution,
+        routes::delete_contribution,
+        routes::create_blog_post,
+        routes::replace_blog_post,
+        routes::patch_blog_post,
+        routes::delete_blog_post,
+        routes::create_blog_series,
+        routes::replace_blog_series,
+        routes::delete_blog_series,
+        routes::reindex_search,
+        routes::get_roles,
+        routes::grant_role,
+        routes::revoke_role,
+        routes::get_role_changes,
+        routes::claim_issue,
+        routes::open_pull_request,
+        routes::merge_contribution,
+        routes::release_contribution,
+        routes::get_my_contributions,
+        routes::get_editorial_posts,
+        routes::set_blog_post_status,
+        routes::assign_blog_reviewer,
+        routes::create_blog_preview,
+        routes::get_blog_preview,
+        routes::get_blog_revisions,
+        routes::get_blog_revision,
+        routes::diff_blog_revisions,
+        routes::restore_blog_revision,
+        routes::get_revision_markdown,
+        routes::upload_content,
+        routes::get_content
     ),
     components(
-        schemas(Stats, Event, Issue, Project, Member, EventType, DifficultyLevel, ProjectStatus, HealthCheck, BlogPost, BlogPostType, BlogCategory, BlogSeries, ExternalLink)
+        schemas(Stats, Term, Event, Issue, Project, Member, EventType, DifficultyLevel, ProjectStatus, HealthCheck, BlogPost, BlogPostType, BlogCategory, BlogSeries, BlogSeriesPart, BlogAuthor, ExternalLink,
+                EventInput, IssueInput, ProjectInput, ContributorInput, TermInput, Contribution, ContributionInput, BlogPostInput, ApiError, ErrorCode, LikeStatus,
+                SearchResult, SearchResultKind, SearchResults, Workshop, WorkshopInput, RegistrationStatus,
+                WorkshopRegistration, WorkshopRegistrationInput, WorkshopRegistrant, RsvpStatus, EventRsvp, Rsvp,
+                RsvpInput, RsvpChange, CheckInCode, CheckIn, CheckInInput, Course, CourseStatus, CourseStatusChange,
+                CourseInput, CourseStatusInput, PetitionSummary, CurrentUser,
+                MemberRole, RoleAction, RoleChange, RoleGrantInput, ProfileVisibility, MemberSummary, MemberProfile,
+                ProfileSettings, ContributionState, PullRequestInput, MergeInput, BlogPostStatus, BlogStatusInput,
+                ReviewerInput, BlogPreviewLink, BlogRevisionSummary, BlogRevision, BlogFieldChange, DiffOp, DiffLine,
+                BlogRevisionDiff, ContentUpload, BlogSeriesSummary, BlogSeriesEntry, BlogSeriesDetail, BlogSeriesInput,
+                AuthorProfile)
     ),
+    modifiers(&SecurityAddon),
     tags(
         (name = "public", description = "Public API endpoints"),
         (name = "blog", description = "Blog and tutorial endpoints"),
+        (name = "admin", description = "Authenticated content management endpoints"),
+        (name = "auth", description = "GitHub sign-in and member sessions"),
+        (name = "members", description = "Member directory, profiles and privacy settings"),
+        (name = "roles", description = "Member roles and their audit trail; officers only"),
+        (name = "contributions", description = "Issue claims and the pull requests that follow them"),
+        (name = "editorial", description = "Blog drafts, review, scheduled publishing, preview links and revision history"),
+        (name = "content", description = "Markdown and images for blog posts, stored in R2"),
         (name = "health", description = "Health and status endpoints")
     ),
     info(
@@ -44,12 +185,38 @@ use models::*;
 )]
 struct ApiDoc;
 
-fn cors_headers() -> Headers {
+/// Registers the admin bearer token scheme referenced by write endpoints and
+/// the session cookie set by GitHub sign-in
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearer_auth",
+            SecurityScheme::Http(HttpBuilder::new().scheme(HttpAuthScheme::Bearer).build()),
+        );
+        components.add_security_scheme(
+            "session_cookie",
+            SecurityScheme::ApiKey(ApiKey::Cookie(ApiKeyValue::new(session::COOKIE_NAME))),
+        );
+    }
+}
+
+/// CORS headers for a response. The website's own origin may send the
+/// session cookie, which browsers only allow when the origin is echoed back
+/// instead of `*`.
+fn cors_headers(credentialed_origin: Option<&str>) -> Headers {
     let mut headers = Headers::new();
-    // Allow both chico.rs domains - in production you might want to check the Origin header
-    // and dynamically set this based on the request
-    headers.set("Access-Control-Allow-Origin", "*").unwrap(); // Allow all origins for now
-    headers.set("Access-Control-Allow-Methods", "GET, POST, PUT, DELETE, OPTIONS").unwrap();
+    match credentialed_origin {
+        Some(origin) => {
+            headers.set("Access-Control-Allow-Origin", origin).unwrap();
+            headers.set("Access-Control-Allow-Credentials", "true").unwrap();
+        }
+        None => headers.set("Access-Control-Allow-Origin", "*").unwrap(),
+    }
+    headers.set("Vary", "Origin").unwrap();
+    headers.set("Access-Control-Allow-Methods", "GET, POST, PUT, PATCH, DELETE, OPTIONS").unwrap();
     headers.set("Access-Control-Allow-Headers", "Content-Type, Authorization").unwrap();
     headers.set("Access-Control-Max-Age", "86400").unwrap();
     headers
@@ -59,7 +226,11 @@ fn cors_headers() -> Headers {
 pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
     console_error_panic_hook::set_once();
 
-    let router = Router::new();
+    let info = RequestInfo::from_request(&req);
+    let request_id = info.request_id.clone();
+    let site_url = session::site_url(&env);
+    let origin = req.headers().get("Origin").ok().flatten().filter(|origin| *origin == site_url);
+    let router = Router::with_data(info);
 
     router
         // Health and info endpoints
@@ -67,39 +238,141 @@ pub async fn main(req: Request, env: Env, _ctx: Context) -> Result<Response> {
         .get("/", routes::api_info)
 
         // API v1 endpoints
+        .get_async("/v1/auth/github", routes::github_sign_in)
+        .get_async("/v1/auth/github/callback", routes::github_callback)
+        .post_async("/v1/auth/sign-out", routes::sign_out)
+        .get_async("/v1/me", routes::get_me)
+        .get_async("/v1/me/profile", routes::get_profile_settings)
+        .put_async("/v1/me/profile", routes::update_profile_settings)
+        .get_async("/v1/me/contributions", routes::get_my_contributions)
+        .get_async("/v1/members", routes::get_members)
+        .get_async("/v1/members/:github", routes::get_member)
         .get_async("/v1/stats", routes::get_stats)
+        .get_async("/v1/terms", routes::get_terms)
+        .get_async("/v1/terms/:slug", routes::get_term)
         .get_async("/v1/events", routes::get_events)
+        .get_async("/v1/events.ics", routes::get_events_calendar)
+        .get_async("/v1/events/:id", routes::get_event)
+        .get_async("/v1/events/:id/ics", routes::get_event_calendar)
+        .post_async("/v1/events/:id/rsvps", routes::create_event_rsvp)
+        .get_async("/v1/events/:id/rsvps/:token", routes::get_event_rsvp)
+        .put_async("/v1/events/:id/rsvps/:token", routes::update_event_rsvp)
+        .delete_async("/v1/events/:id/rsvps/:token", routes::delete_event_rsvp)
+        .post_async("/v1/events/:id/check-ins", routes::check_in_to_event)
+        .get_async("/v1/workshops", routes::get_workshops)
+        .get_async("/v1/workshops/:id", routes::get_workshop)
+        .post_async("/v1/workshops/:id/registrations", routes::register_for_workshop)
+        .get_async("/v1/workshops/:id/registrations/:token", routes::get_workshop_registration)
+        .delete_async("/v1/workshops/:id/registrations/:token", routes::cancel_workshop_registration)
+        .get_async("/v1/courses", routes::get_courses)
+        .get_async("/v1/courses/:slug", routes::get_course)
+        .post_async("/v1/courses/:slug/signatures", routes::support_course)
+        .get_async("/v1/petition", routes::get_petition_summary)
+        .post_async("/v1/petition/signatures", routes::sign_petition)
         .get_async("/v1/issues", routes::get_issues)
         .get_async("/v1/projects", routes::get_projects)
+        .get_async("/v1/projects/:id", routes::get_project)
         .get_async("/v1/blog", routes::get_blog_posts)
         .get_async("/v1/blog/featured", routes::get_featured_blog_posts)
+        .get_async("/v1/blog/editorial", routes::get_editorial_posts)
+        .get_async("/v1/blog/preview/:token", routes::get_blog_preview)
+        .get_async("/v1/blog/markdown/:hash", routes::get_revision_markdown)
+        .get_async("/v1/blog/series", routes::get_blog_series_list)
+        .get_async("/v1/blog/series/:slug", routes::get_blog_series)
+        .get_async("/v1/blog/rss.xml", routes::get_blog_rss)
+        .get_async("/v1/blog/atom.xml", routes::get_blog_atom)
+        .get_async("/v1/blog/categories/:category/rss.xml", routes::get_blog_category_rss)
+        .get_async("/v1/blog/categories/:category/atom.xml", routes::get_blog_category_atom)
+        .get_async("/v1/blog/tags/:tag/rss.xml", routes::get_blog_tag_rss)
+        .get_async("/v1/blog/tags/:tag/atom.xml", routes::get_blog_tag_atom)
         .get_async("/v1/blog/:slug", routes::get_blog_post_by_slug)
+        .get_async("/v1/blog/:slug/like", routes::get_like_status)
+        .post_async("/v1/blog/:slug/like", routes::like_blog_post)
+        .delete_async("/v1/blog/:slug/like", routes::unlike_blog_post)
+        .post_async("/v1/blog/:slug/view", routes::record_blog_view)
+        .get_async("/v1/blog/:slug/revisions", routes::get_blog_revisions)
+        .get_async("/v1/blog/:slug/revisions/diff", routes::diff_blog_revisions)
+        .get_async("/v1/blog/:slug/revisions/:number", routes::get_blog_revision)
+        .get_async("/v1/authors/:github", routes::get_author)
+        .get_async("/v1/content/:key", routes::get_content)
+        .get_async("/v1/search", routes::search)
+
+        // Admin write endpoints (bearer token required)
+        .post_async("/v1/events", routes::create_event)
+        .put_async("/v1/events/:id", routes::replace_event)
+        .patch_async("/v1/events/:id", routes::patch_event)
+        .delete_async("/v1/events/:id", routes::delete_event)
+        .post_async("/v1/events/:id/check-in-codes", routes::create_check_in_code)
+        .get_async("/v1/events/:id/rsvps", routes::get_event_rsvps)
+        .get_async("/v1/events/:id/check-ins", routes::get_event_check_ins)
+        .post_async("/v1/issues", routes::create_issue)
+        .post_async("/v1/issues/sync", routes::sync_issues)
+        .put_async("/v1/issues/:id", routes::replace_issue)
+        .patch_async("/v1/issues/:id", routes::patch_issue)
+        .delete_async("/v1/issues/:id", routes::delete_issue)
+        .post_async("/v1/projects", routes::create_project)
+        .put_async("/v1/projects/:id", routes::replace_project)
+        .patch_async("/v1/projects/:id", routes::patch_project)
+        .delete_async("/v1/projects/:id", routes::delete_project)
+        .post_async("/v1/projects/:id/contributors", routes::add_project_contributor)
+        .delete_async("/v1/projects/:id/contributors/:github", routes::remove_project_contributor)
+        .post_async("/v1/workshops", routes::create_workshop)
+        .put_async("/v1/workshops/:id", routes::replace_workshop)
+        .patch_async("/v1/workshops/:id", routes::patch_workshop)
+        .delete_async("/v1/workshops/:id", routes::delete_workshop)
+        .get_async("/v1/workshops/:id/registrations", routes::get_workshop_registrants)
+        .post_async("/v1/terms", routes::create_term)
+        .put_async("/v1/terms/:slug", routes::replace_term)
+        .delete_async("/v1/terms/:slug", routes::delete_term)
+        .post_async("/v1/courses", routes::create_course)
+        .put_async("/v1/courses/:slug", routes::replace_course)
+        .post_async("/v1/courses/:slug/status", routes::set_course_status)
+        .delete_async("/v1/courses/:slug", routes::delete_course)
+        .post_async("/v1/contributions", routes::create_contribution)
+        .delete_async("/v1/contributions/:id", routes::delete_contribution)
+        .post_async("/v1/issues/:id/claim", routes::claim_issue)
+        .post_async("/v1/contributions/:id/pull-request", routes::open_pull_request)
+        .post_async("/v1/contributions/:id/merge", routes::merge_contribution)
+        .post_async("/v1/contributions/:id/release", routes::release_contribution)
+        .post_async("/v1/blog", routes::create_blog_post)
+        .put_async("/v1/blog/:slug", routes::replace_blog_post)
+        .patch_async("/v1/blog/:slug", routes::patch_blog_post)
+        .delete_async("/v1/blog/:slug", routes::delete_blog_post)
+        .post_async("/v1/blog/series", routes::create_blog_series)
+        .put_async("/v1/blog/series/:slug", routes::replace_blog_series)
+        .delete_async("/v1/blog/series/:slug", routes::delete_blog_series)
+        .post_async("/v1/blog/:slug/status", routes::set_blog_post_status)
+        .put_async("/v1/blog/:slug/reviewer", routes::assign_blog_reviewer)
+        .post_async("/v1/blog/:slug/preview", routes::create_blog_preview)
+        .post_async("/v1/blog/:slug/revisions/:number/restore", routes::restore_blog_revision)
+        .post_async("/v1/search/reindex", routes::reindex_search)
+        .post_async("/v1/content", routes::upload_content)
+        .get_async("/v1/members/:github/roles", routes::get_roles)
+        .post_async("/v1/members/:github/roles", routes::grant_role)
+        .delete_async("/v1/members/:github/roles/:role", routes::revoke_role)
+        .get_async("/v1/roles/changes", routes::get_role_changes)
 
         // Documentation endpoints
         .get("/docs", routes::serve_swagger_ui)
         .get("/openapi.json", routes::serve_openapi_spec)
 
-        // CORS preflight
-        .options("/*path", |_req, _ctx| {
-            Ok(Response::empty()?.with_headers(cors_headers()))
-        })
+        // CORS preflight; the headers are added with everyone else's below
+        .options("/*path", |_req, _ctx| Response::empty())
 
         // Catch-all 404
-        .or_else_any_method("/*path", |_req, _ctx| {
-            let response = json!({
-                "error": "Not Found",
-                "message": "The requested endpoint does not exist",
-                "docs": "https://api.chico.rs/docs"
-            });
-            Ok(Response::from_json(&response)?
-                .with_status(404)
-                .with_headers(cors_headers()))
+        .or_else_any_method("/*path", |_req, ctx| {
+            error_response(&ctx, ApiError::not_found("The requested endpoint does not exist"))
         })
 
         .run(req, env)
         .await
-        .map(|response| {
-            // Add CORS headers to all responses
-            response.with_headers(cors_headers())
+        .map(|mut response| {
+            // Add CORS headers to all responses, keeping the handler's own headers
+            let headers = response.headers_mut();
+            for (name, value) in cors_headers(origin.as_deref()).entries() {
+                let _ = headers.set(&name, &value);
+            }
+            let _ = headers.set("X-Request-Id", &request_id);
+            response
         })
 }
\ No newline at end of file
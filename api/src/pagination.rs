@@ -0,0 +1,189 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::de::DeserializeOwned;
+use wasm_bindgen::JsValue;
+use worker::Url;
+
+use crate::models::Page;
+
+pub const DEFAULT_LIMIT: u32 = 20;
+pub const MAX_LIMIT: u32 = 100;
+
+/// Position of the last row on a page: its sort column value plus its
+/// unique id as a tiebreaker
+pub struct Cursor {
+    pub sort_key: String,
+    pub id: String,
+}
+
+impl Cursor {
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!("{}\n{}", self.sort_key, self.id))
+    }
+
+    pub fn decode(value: &str) -> Option<Cursor> {
+        let bytes = URL_SAFE_NO_PAD.decode(value).ok()?;
+        let text = String::from_utf8(bytes).ok()?;
+        let (sort_key, id) = text.split_once('\n')?;
+        Some(Cursor {
+            sort_key: sort_key.to_string(),
+            id: id.to_string(),
+        })
+    }
+}
+
+/// `?limit=&cursor=&order=` parameters shared by every list endpoint
+pub struct PageRequest {
+    pub limit: u32,
+    pub cursor: Option<Cursor>,
+    pub descending: bool,
+}
+
+impl Default for PageRequest {
+    fn default() -> Self {
+        PageRequest {
+            limit: DEFAULT_LIMIT,
+            cursor: None,
+            descending: true,
+        }
+    }
+}
+
+impl PageRequest {
+    pub fn from_url(url: &Url) -> Result<PageRequest, String> {
+        Self::parse(url, PageRequest::default())
+    }
+
+    /// Like `from_url`, but ascending unless `?order=desc` is given
+    pub fn from_url_ascending(url: &Url) -> Result<PageRequest, String> {
+        Self::parse(url, PageRequest { descending: false, ..PageRequest::default() })
+    }
+
+    fn parse(url: &Url, mut page: PageRequest) -> Result<PageRequest, String> {
+
+        if let Some(limit) = query_param(url, "limit") {
+            let limit = limit
+                .parse::<u32>()
+                .map_err(|_| "limit must be a positive integer".to_string())?;
+            page.limit = limit.clamp(1, MAX_LIMIT);
+        }
+        if let Some(cursor) = query_param(url, "cursor") {
+            page.cursor = Some(Cursor::decode(&cursor).ok_or_else(|| "cursor is invalid".to_string())?);
+        }
+        if let Some(order) = query_param(url, "order") {
+            page.descending = match order.as_str() {
+                "desc" => true,
+                "asc" => false,
+                _ => return Err("order must be 'asc' or 'desc'".to_string()),
+            };
+        }
+
+        Ok(page)
+    }
+
+    /// Append the keyset condition for this page to a WHERE clause
+    pub fn push_keyset(&self, sort_column: &str, id_column: &str, conditions: &mut Vec<String>, binds: &mut Vec<JsValue>) {
+        if let Some(cursor) = &self.cursor {
+            let op = if self.descending { "<" } else { ">" };
+            conditions.push(format!(
+                "({sort} {op} ? OR ({sort} = ? AND {id} {op} ?))",
+                sort = sort_column,
+                id = id_column,
+                op = op
+            ));
+            binds.push(JsValue::from_str(&cursor.sort_key));
+            binds.push(JsValue::from_str(&cursor.sort_key));
+            binds.push(JsValue::from_str(&cursor.id));
+        }
+    }
+
+    /// In-memory equivalent of `push_keyset` for lists that cannot be paged in SQL
+    pub fn is_after_cursor(&self, sort_key: &str, id: &str) -> bool {
+        match &self.cursor {
+            Some(cursor) => {
+                let position = (sort_key, id);
+                let cursor = (cursor.sort_key.as_str(), cursor.id.as_str());
+                if self.descending { position < cursor } else { position > cursor }
+            }
+            None => true,
+        }
+    }
+
+    /// `ORDER BY ... LIMIT ?` tail of the query. One extra row is fetched so
+    /// we know whether a next page exists.
+    pub fn order_and_limit(&self, sort_column: &str, id_column: &str, binds: &mut Vec<JsValue>) -> String {
+        let direction = if self.descending { "DESC" } else { "ASC" };
+        binds.push(JsValue::from_f64((self.limit + 1) as f64));
+        format!(
+            "ORDER BY {sort} {dir}, {id} {dir} LIMIT ?",
+            sort = sort_column,
+            id = id_column,
+            dir = direction
+        )
+    }
+
+    /// Trim the extra row fetched by `order_and_limit` and turn the rest into a page
+    pub fn to_page<R, T>(
+        &self,
+        mut rows: Vec<R>,
+        cursor_of: impl Fn(&R) -> Cursor,
+        map: impl Fn(R) -> T,
+    ) -> Page<T> {
+        let has_more = rows.len() > self.limit as usize;
+        rows.truncate(self.limit as usize);
+        let next_cursor = if has_more {
+            rows.last().map(|row| cursor_of(row).encode())
+        } else {
+            None
+        };
+
+        Page {
+            items: rows.into_iter().map(map).collect(),
+            next_cursor,
+        }
+    }
+}
+
+pub fn query_param(url: &Url, name: &str) -> Option<String> {
+    url.query_pairs()
+        .find(|(key, value)| key == name && !value.is_empty())
+        .map(|(_, value)| value.into_owned())
+}
+
+/// Parse a query parameter holding a shared enum variant name, e.g. `?difficulty=Easy`
+pub fn enum_param<T: DeserializeOwned>(url: &Url, name: &str) -> Result<Option<T>, String> {
+    query_param(url, name)
+        .map(|value| parse_enum(name, &value))
+        .transpose()
+}
+
+/// Parse a shared enum from its variant name; `name` labels the error message
+pub fn parse_enum<T: DeserializeOwned>(name: &str, value: &str) -> Result<T, String> {
+    serde_json::from_value(serde_json::Value::String(value.to_string()))
+        .map_err(|_| format!("{} '{}' is not a recognised value", name, value))
+}
+
+pub fn bool_param(url: &Url, name: &str) -> Result<Option<bool>, String> {
+    match query_param(url, name).as_deref() {
+        Some("true") | Some("1") => Ok(Some(true)),
+        Some("false") | Some("0") => Ok(Some(false)),
+        Some(_) => Err(format!("{} must be true or false", name)),
+        None => Ok(None),
+    }
+}
+
+/// Parse an RFC 3339 timestamp or a plain `YYYY-MM-DD` date (midnight UTC)
+pub fn time_param(url: &Url, name: &str) -> Result<Option<DateTime<Utc>>, String> {
+    let Some(value) = query_param(url, name) else {
+        return Ok(None);
+    };
+    if let Ok(time) = DateTime::parse_from_rfc3339(&value) {
+        return Ok(Some(time.with_timezone(&Utc)));
+    }
+    NaiveDate::parse_from_str(&value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|time| Some(time.and_utc()))
+        .ok_or_else(|| format!("{} must be an RFC 3339 timestamp or YYYY-MM-DD date", name))
+}
@@ -0,0 +1,82 @@
+use chrono::Utc;
+use worker::{kv::KvStore, Result};
+
+/// Fixed-window request limit backed by the CACHE KV namespace. KV is
+/// eventually consistent, so the limit is approximate under bursts across
+/// data centres; it is meant to blunt abuse, not to meter precisely.
+pub struct RateLimit {
+    /// Namespace for the counter keys, e.g. "like"
+    pub scope: &'static str,
+    /// Requests allowed per window
+    pub max: u32,
+    pub window_secs: u64,
+}
+
+/// Like and unlike requests per IP address
+pub const LIKES: RateLimit = RateLimit {
+    scope: "like",
+    max: 30,
+    window_secs: 60,
+};
+
+/// View beacons per IP address
+pub const VIEWS: RateLimit = RateLimit {
+    scope: "view",
+    max: 60,
+    window_secs: 60,
+};
+
+/// Workshop registrations per IP address
+pub const REGISTRATIONS: RateLimit = RateLimit {
+    scope: "registration",
+    max: 10,
+    window_secs: 60 * 60,
+};
+
+/// RSVPs and RSVP changes per IP address
+pub const RSVPS: RateLimit = RateLimit {
+    scope: "rsvp",
+    max: 30,
+    window_secs: 60 * 60,
+};
+
+/// Check-in attempts per IP address. Generous, since a whole room often
+/// shares one campus address; against ~30-bit codes that expire in 15
+/// minutes it still makes guessing hopeless.
+pub const CHECK_INS: RateLimit = RateLimit {
+    scope: "check_in",
+    max: 120,
+    window_secs: 10 * 60,
+};
+
+/// Petition signatures per IP address
+pub const SIGNATURES: RateLimit = RateLimit {
+    scope: "signature",
+    max: 20,
+    window_secs: 60 * 60,
+};
+
+impl RateLimit {
+    /// Count a request from `key` and report whether it is within the limit
+    pub async fn allow(&self, kv: &KvStore, key: &str) -> Result<bool> {
+        let window = Utc::now().timestamp() as u64 / self.window_secs;
+        let counter = format!("ratelimit:{}:{}:{}", self.scope, key, window);
+
+        let count = kv
+            .get(&counter)
+            .text()
+            .await?
+            .and_then(|value| value.parse::<u32>().ok())
+            .unwrap_or(0);
+        if count >= self.max {
+            return Ok(false);
+        }
+
+        // KV rejects TTLs under 60 seconds
+        kv.put(&counter, (count + 1).to_string())?
+            .expiration_ttl(self.window_secs.max(60))
+            .execute()
+            .await?;
+        Ok(true)
+    }
+}
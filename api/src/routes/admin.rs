@@ -0,0 +1,1661 @@
+use worker::*;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+use crate::auth::{self, Access};
+use crate::check_in;
+use crate::content;
+use crate::issue_sync;
+use crate::database::{DatabaseService, NewRevision};
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::session;
+use crate::stats;
+
+use super::attendance::current_occurrence;
+use super::authors::check_co_authors;
+use super::series::check_series_part;
+
+pub(super) async fn parse_body<T: DeserializeOwned>(req: &mut Request) -> std::result::Result<T, String> {
+    req.json::<T>()
+        .await
+        .map_err(|e| format!("Invalid JSON body: {}", e))
+}
+
+/// Apply a JSON merge patch (RFC 7396) to an existing record
+fn apply_patch<T: Serialize + DeserializeOwned>(current: T, patch: Value) -> std::result::Result<T, String> {
+    if !patch.is_object() {
+        return Err("PATCH body must be a JSON object".to_string());
+    }
+    let mut merged = serde_json::to_value(current).map_err(|e| e.to_string())?;
+    merge_patch(&mut merged, patch);
+    serde_json::from_value(merged).map_err(|e| format!("Invalid patch: {}", e))
+}
+
+fn merge_patch(target: &mut Value, patch: Value) {
+    match patch {
+        Value::Object(fields) => {
+            if !target.is_object() {
+                *target = Value::Object(Default::default());
+            }
+            if let Value::Object(target_fields) = target {
+                for (key, value) in fields {
+                    if value.is_null() {
+                        target_fields.remove(&key);
+                    } else {
+                        merge_patch(target_fields.entry(key).or_insert(Value::Null), value);
+                    }
+                }
+            }
+        }
+        other => *target = other,
+    }
+}
+
+/// Drop cached `/v1/stats` after a write that changes what they count. The
+/// write has already succeeded, so a KV failure is only logged.
+pub(super) async fn invalidate_stats(ctx: &RouteContext<RequestInfo>) {
+    let result = match ctx.env.kv("CACHE") {
+        Ok(kv) => stats::invalidate(&kv).await,
+        Err(e) => Err(e),
+    };
+    if let Err(e) = result {
+        web_sys::console::warn_1(&format!("Failed to invalidate cached stats: {:?}", e).into());
+    }
+}
+
+// Events
+
+/// Create an event
+#[utoipa::path(
+    post,
+    path = "/v1/events",
+    request_body = EventInput,
+    responses(
+        (status = 201, description = "Event created", body = Event),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn create_event(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<EventInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::create_event(&db, &input).await {
+        Ok(Some(event)) => {
+            invalidate_stats(&ctx).await;
+            Ok(Response::from_json(&event)?.with_status(201))
+        }
+        Ok(None) => error_response(&ctx, ApiError::internal("Event was not stored")),
+        Err(e) => error_response(&ctx, database_error("create event", e)),
+    }
+}
+
+/// Replace an event
+#[utoipa::path(
+    put,
+    path = "/v1/events/{id}",
+    params(
+        ("id" = String, Path, description = "Event id")
+    ),
+    request_body = EventInput,
+    responses(
+        (status = 200, description = "Event updated", body = Event),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Event not found", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn replace_event(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let input = match parse_body::<EventInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    save_event(&ctx, &db, id, input).await
+}
+
+/// Partially update an event
+#[utoipa::path(
+    patch,
+    path = "/v1/events/{id}",
+    params(
+        ("id" = String, Path, description = "Event id")
+    ),
+    request_body(content = Object, description = "JSON merge patch of EventInput fields"),
+    responses(
+        (status = 200, description = "Event updated", body = Event),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Event not found", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn patch_event(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let patch = match parse_body::<Value>(&mut req).await {
+        Ok(patch) => patch,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    let current = match DatabaseService::get_event(&db, id).await {
+        Ok(Some(event)) => EventInput::from(event),
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Event not found")),
+        Err(e) => return error_response(&ctx, database_error("update event", e)),
+    };
+    let input = match apply_patch(current, patch) {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    save_event(&ctx, &db, id, input).await
+}
+
+async fn save_event(ctx: &RouteContext<RequestInfo>, db: &D1Database, id: &str, input: EventInput) -> Result<Response> {
+    if let Err(details) = input.validate() {
+        return error_response(ctx, ApiError::validation(details));
+    }
+    match DatabaseService::update_event(db, id, &input).await {
+        Ok(Some(event)) => {
+            invalidate_stats(ctx).await;
+            Response::from_json(&event)
+        }
+        Ok(None) => error_response(ctx, ApiError::not_found("Event not found")),
+        Err(e) => error_response(ctx, database_error("update event", e)),
+    }
+}
+
+/// Delete an event
+#[utoipa::path(
+    delete,
+    path = "/v1/events/{id}",
+    params(
+        ("id" = String, Path, description = "Event id")
+    ),
+    responses(
+        (status = 204, description = "Event deleted"),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Event not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn delete_event(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::delete_event(&db, id).await {
+        Ok(true) => {
+            invalidate_stats(&ctx).await;
+            Ok(Response::empty()?.with_status(204))
+        }
+        Ok(false) => error_response(&ctx, ApiError::not_found("Event not found")),
+        Err(e) => error_response(&ctx, database_error("delete event", e)),
+    }
+}
+
+/// Issue a check-in code for an event that is on now. Issuing a new code
+/// retires the previous one.
+#[utoipa::path(
+    post,
+    path = "/v1/events/{id}/check-in-codes",
+    params(
+        ("id" = String, Path, description = "Event id")
+    ),
+    responses(
+        (status = 201, description = "Code to show at the door, with a QR payload", body = CheckInCode),
+        (status = 400, description = "The event is not on right now", body = ApiError),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Event not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn create_check_in_code(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_event(&db, id).await {
+        Ok(Some(event)) if current_occurrence(&event).is_none() => {
+            return error_response(&ctx, ApiError::bad_request("This event is not on right now"))
+        }
+        Ok(Some(_)) => {}
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Event not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch event", e)),
+    }
+    let kv = ctx.env.kv("CACHE")?;
+    let code = check_in::issue(&kv, id, &session::site_url(&ctx.env)).await?;
+    Ok(Response::from_json(&code)?.with_status(201))
+}
+
+/// List an event's RSVPs
+#[utoipa::path(
+    get,
+    path = "/v1/events/{id}/rsvps",
+    params(
+        ("id" = String, Path, description = "Event id")
+    ),
+    responses(
+        (status = 200, description = "Going, then maybe, then not going, each in response order", body = [Rsvp]),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Event not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn get_event_rsvps(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_event(&db, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Event not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch event", e)),
+    }
+    match DatabaseService::get_event_rsvps(&db, id).await {
+        Ok(rsvps) => Response::from_json(&rsvps),
+        Err(e) => error_response(&ctx, database_error("fetch RSVPs", e)),
+    }
+}
+
+/// List who checked in to an event
+#[utoipa::path(
+    get,
+    path = "/v1/events/{id}/check-ins",
+    params(
+        ("id" = String, Path, description = "Event id")
+    ),
+    responses(
+        (status = 200, description = "Check-ins, latest occurrence first", body = [CheckIn]),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Event not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn get_event_check_ins(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_event(&db, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Event not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch event", e)),
+    }
+    match DatabaseService::get_event_check_ins(&db, id).await {
+        Ok(check_ins) => Response::from_json(&check_ins),
+        Err(e) => error_response(&ctx, database_error("fetch check-ins", e)),
+    }
+}
+
+// Issues
+
+/// Create an issue
+#[utoipa::path(
+    post,
+    path = "/v1/issues",
+    request_body = IssueInput,
+    responses(
+        (status = 201, description = "Issue created", body = Issue),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 409, description = "An issue with this GitHub URL already exists", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn create_issue(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Issues).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<IssueInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::create_issue(&db, &input).await {
+        Ok(Some(issue)) => Ok(Response::from_json(&issue)?.with_status(201)),
+        Ok(None) => error_response(&ctx, ApiError::internal("Issue was not stored")),
+        Err(e) => error_response(&ctx, database_error("create issue", e)),
+    }
+}
+
+/// Replace an issue
+#[utoipa::path(
+    put,
+    path = "/v1/issues/{id}",
+    params(
+        ("id" = String, Path, description = "Issue id")
+    ),
+    request_body = IssueInput,
+    responses(
+        (status = 200, description = "Issue updated", body = Issue),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Issue not found", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn replace_issue(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Issues).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let input = match parse_body::<IssueInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    save_issue(&ctx, &db, id, input).await
+}
+
+/// Partially update an issue
+#[utoipa::path(
+    patch,
+    path = "/v1/issues/{id}",
+    params(
+        ("id" = String, Path, description = "Issue id")
+    ),
+    request_body(content = Object, description = "JSON merge patch of IssueInput fields"),
+    responses(
+        (status = 200, description = "Issue updated", body = Issue),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Issue not found", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn patch_issue(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Issues).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let patch = match parse_body::<Value>(&mut req).await {
+        Ok(patch) => patch,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    let current = match DatabaseService::get_issue(&db, id).await {
+        Ok(Some(issue)) => IssueInput::from(issue),
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Issue not found")),
+        Err(e) => return error_response(&ctx, database_error("update issue", e)),
+    };
+    let input = match apply_patch(current, patch) {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    save_issue(&ctx, &db, id, input).await
+}
+
+async fn save_issue(ctx: &RouteContext<RequestInfo>, db: &D1Database, id: &str, input: IssueInput) -> Result<Response> {
+    if let Err(details) = input.validate() {
+        return error_response(ctx, ApiError::validation(details));
+    }
+    match DatabaseService::update_issue(db, id, &input).await {
+        Ok(Some(issue)) => Response::from_json(&issue),
+        Ok(None) => error_response(ctx, ApiError::not_found("Issue not found")),
+        Err(e) => error_response(ctx, database_error("update issue", e)),
+    }
+}
+
+/// Delete an issue
+#[utoipa::path(
+    delete,
+    path = "/v1/issues/{id}",
+    params(
+        ("id" = String, Path, description = "Issue id")
+    ),
+    responses(
+        (status = 204, description = "Issue deleted"),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Issue not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn delete_issue(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Issues).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::delete_issue(&db, id).await {
+        Ok(true) => Ok(Response::empty()?.with_status(204)),
+        Ok(false) => error_response(&ctx, ApiError::not_found("Issue not found")),
+        Err(e) => error_response(&ctx, database_error("delete issue", e)),
+    }
+}
+
+/// Sync issues from GitHub now instead of waiting for the hourly job
+#[utoipa::path(
+    post,
+    path = "/v1/issues/sync",
+    responses(
+        (status = 200, description = "Repositories synced, issues upserted and closed, repositories GitHub could not list, and repositories listed only in part (nothing closed)", body = Object),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn sync_issues(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Issues).await {
+        return error_response(&ctx, e);
+    }
+    match issue_sync::sync_issues(&ctx.env).await {
+        Ok(report) => Response::from_json(&serde_json::json!({
+            "repos": report.repos,
+            "upserted": report.upserted,
+            "closed": report.closed,
+            "failed": report.failed,
+            "truncated": report.truncated,
+        })),
+        Err(e) => error_response(&ctx, database_error("sync issues", e)),
+    }
+}
+
+// Projects
+
+/// Create a project
+#[utoipa::path(
+    post,
+    path = "/v1/projects",
+    request_body = ProjectInput,
+    responses(
+        (status = 201, description = "Project created", body = Project),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 409, description = "A project with this GitHub URL already exists", body = ApiError),
+        (status = 422, description = "Validation failed or unknown leader", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn create_project(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<ProjectInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    let leader_id = match DatabaseService::get_member_id_by_github(&db, &input.leader_github).await {
+        Ok(Some(leader_id)) => leader_id,
+        Ok(None) => return error_response(&ctx, ApiError::validation(vec![format!("leader_github '{}' is not a club member", input.leader_github)])),
+        Err(e) => return error_response(&ctx, database_error("create project", e)),
+    };
+
+    match DatabaseService::create_project(&db, &input, leader_id).await {
+        Ok(Some(project)) => {
+            invalidate_stats(&ctx).await;
+            Ok(Response::from_json(&project)?.with_status(201))
+        }
+        Ok(None) => error_response(&ctx, ApiError::internal("Project was not stored")),
+        Err(e) => error_response(&ctx, database_error("create project", e)),
+    }
+}
+
+/// Replace a project
+#[utoipa::path(
+    put,
+    path = "/v1/projects/{id}",
+    params(
+        ("id" = String, Path, description = "Project id")
+    ),
+    request_body = ProjectInput,
+    responses(
+        (status = 200, description = "Project updated", body = Project),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Project not found", body = ApiError),
+        (status = 422, description = "Validation failed or unknown leader", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn replace_project(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Project(id)).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<ProjectInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    save_project(&ctx, &db, id, input).await
+}
+
+/// Partially update a project
+#[utoipa::path(
+    patch,
+    path = "/v1/projects/{id}",
+    params(
+        ("id" = String, Path, description = "Project id")
+    ),
+    request_body(content = Object, description = "JSON merge patch of ProjectInput fields"),
+    responses(
+        (status = 200, description = "Project updated", body = Project),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Project not found", body = ApiError),
+        (status = 422, description = "Validation failed or unknown leader", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn patch_project(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Project(id)).await {
+        return error_response(&ctx, e);
+    }
+    let patch = match parse_body::<Value>(&mut req).await {
+        Ok(patch) => patch,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    let current = match DatabaseService::get_project(&db, id).await {
+        Ok(Some(project)) => ProjectInput::from(project),
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Project not found")),
+        Err(e) => return error_response(&ctx, database_error("update project", e)),
+    };
+    let input = match apply_patch(current, patch) {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    save_project(&ctx, &db, id, input).await
+}
+
+async fn save_project(ctx: &RouteContext<RequestInfo>, db: &D1Database, id: &str, input: ProjectInput) -> Result<Response> {
+    if let Err(details) = input.validate() {
+        return error_response(ctx, ApiError::validation(details));
+    }
+    let leader_id = match DatabaseService::get_member_id_by_github(db, &input.leader_github).await {
+        Ok(Some(leader_id)) => leader_id,
+        Ok(None) => return error_response(ctx, ApiError::validation(vec![format!("leader_github '{}' is not a club member", input.leader_github)])),
+        Err(e) => return error_response(ctx, database_error("update project", e)),
+    };
+    match DatabaseService::update_project(db, id, &input, leader_id).await {
+        Ok(Some(project)) => {
+            invalidate_stats(ctx).await;
+            Response::from_json(&project)
+        }
+        Ok(None) => error_response(ctx, ApiError::not_found("Project not found")),
+        Err(e) => error_response(ctx, database_error("update project", e)),
+    }
+}
+
+/// Delete a project
+#[utoipa::path(
+    delete,
+    path = "/v1/projects/{id}",
+    params(
+        ("id" = String, Path, description = "Project id")
+    ),
+    responses(
+        (status = 204, description = "Project deleted"),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Project not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn delete_project(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::delete_project(&db, id).await {
+        Ok(true) => {
+            invalidate_stats(&ctx).await;
+            Ok(Response::empty()?.with_status(204))
+        }
+        Ok(false) => error_response(&ctx, ApiError::not_found("Project not found")),
+        Err(e) => error_response(&ctx, database_error("delete project", e)),
+    }
+}
+
+/// Add a contributor to a project. Adding an existing contributor is a no-op.
+#[utoipa::path(
+    post,
+    path = "/v1/projects/{id}/contributors",
+    params(
+        ("id" = String, Path, description = "Project id")
+    ),
+    request_body = ContributorInput,
+    responses(
+        (status = 201, description = "Contributor added", body = Project),
+        (status = 200, description = "Member was already a contributor", body = Project),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Project not found", body = ApiError),
+        (status = 422, description = "Validation failed or not a club member", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn add_project_contributor(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Project(id)).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<ContributorInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_project(&db, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Project not found")),
+        Err(e) => return error_response(&ctx, database_error("add contributor", e)),
+    }
+    let member_id = match DatabaseService::get_member_id_by_github(&db, &input.github_username).await {
+        Ok(Some(member_id)) => member_id,
+        Ok(None) => return error_response(&ctx, ApiError::validation(vec![format!("github_username '{}' is not a club member", input.github_username)])),
+        Err(e) => return error_response(&ctx, database_error("add contributor", e)),
+    };
+    let added = match DatabaseService::add_project_contributor(&db, id, member_id).await {
+        Ok(added) => added,
+        Err(e) => return error_response(&ctx, database_error("add contributor", e)),
+    };
+    if added {
+        invalidate_stats(&ctx).await;
+    }
+    match DatabaseService::get_project(&db, id).await {
+        Ok(Some(project)) => Ok(Response::from_json(&project)?.with_status(if added { 201 } else { 200 })),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Project not found")),
+        Err(e) => error_response(&ctx, database_error("add contributor", e)),
+    }
+}
+
+/// Remove a contributor from a project
+#[utoipa::path(
+    delete,
+    path = "/v1/projects/{id}/contributors/{github}",
+    params(
+        ("id" = String, Path, description = "Project id"),
+        ("github" = String, Path, description = "Contributor's GitHub username")
+    ),
+    responses(
+        (status = 204, description = "Contributor removed"),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Project, member or contributor not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn remove_project_contributor(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Project(id)).await {
+        return error_response(&ctx, e);
+    }
+    let github = ctx.param("github").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    let member_id = match DatabaseService::get_member_id_by_github(&db, github).await {
+        Ok(Some(member_id)) => member_id,
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Member not found")),
+        Err(e) => return error_response(&ctx, database_error("remove contributor", e)),
+    };
+    match DatabaseService::remove_project_contributor(&db, id, member_id).await {
+        Ok(true) => {
+            invalidate_stats(&ctx).await;
+            Ok(Response::empty()?.with_status(204))
+        }
+        Ok(false) => error_response(&ctx, ApiError::not_found("Member is not a contributor to this project")),
+        Err(e) => error_response(&ctx, database_error("remove contributor", e)),
+    }
+}
+
+// Workshops
+
+/// Create a workshop
+#[utoipa::path(
+    post,
+    path = "/v1/workshops",
+    request_body = WorkshopInput,
+    responses(
+        (status = 201, description = "Workshop created", body = Workshop),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn create_workshop(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<WorkshopInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::create_workshop(&db, &input).await {
+        Ok(Some(workshop)) => Ok(Response::from_json(&workshop)?.with_status(201)),
+        Ok(None) => error_response(&ctx, ApiError::internal("Workshop was not stored")),
+        Err(e) => error_response(&ctx, database_error("create workshop", e)),
+    }
+}
+
+/// Replace a workshop
+#[utoipa::path(
+    put,
+    path = "/v1/workshops/{id}",
+    params(
+        ("id" = String, Path, description = "Workshop id")
+    ),
+    request_body = WorkshopInput,
+    responses(
+        (status = 200, description = "Workshop updated", body = Workshop),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Workshop not found", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn replace_workshop(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let input = match parse_body::<WorkshopInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    save_workshop(&ctx, &db, id, input).await
+}
+
+/// Partially update a workshop
+#[utoipa::path(
+    patch,
+    path = "/v1/workshops/{id}",
+    params(
+        ("id" = String, Path, description = "Workshop id")
+    ),
+    request_body(content = Object, description = "JSON merge patch of WorkshopInput fields"),
+    responses(
+        (status = 200, description = "Workshop updated", body = Workshop),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Workshop not found", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn patch_workshop(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let patch = match parse_body::<Value>(&mut req).await {
+        Ok(patch) => patch,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    let current = match DatabaseService::get_workshop(&db, id).await {
+        Ok(Some(workshop)) => WorkshopInput::from(workshop),
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Workshop not found")),
+        Err(e) => return error_response(&ctx, database_error("update workshop", e)),
+    };
+    let input = match apply_patch(current, patch) {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    save_workshop(&ctx, &db, id, input).await
+}
+
+async fn save_workshop(ctx: &RouteContext<RequestInfo>, db: &D1Database, id: &str, input: WorkshopInput) -> Result<Response> {
+    if let Err(details) = input.validate() {
+        return error_response(ctx, ApiError::validation(details));
+    }
+    match DatabaseService::update_workshop(db, id, &input).await {
+        Ok(Some(workshop)) => Response::from_json(&workshop),
+        Ok(None) => error_response(ctx, ApiError::not_found("Workshop not found")),
+        Err(e) => error_response(ctx, database_error("update workshop", e)),
+    }
+}
+
+/// Delete a workshop and its registrations
+#[utoipa::path(
+    delete,
+    path = "/v1/workshops/{id}",
+    params(
+        ("id" = String, Path, description = "Workshop id")
+    ),
+    responses(
+        (status = 204, description = "Workshop deleted"),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Workshop not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn delete_workshop(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::delete_workshop(&db, id).await {
+        Ok(true) => Ok(Response::empty()?.with_status(204)),
+        Ok(false) => error_response(&ctx, ApiError::not_found("Workshop not found")),
+        Err(e) => error_response(&ctx, database_error("delete workshop", e)),
+    }
+}
+
+/// List a workshop's registrations, including email addresses
+#[utoipa::path(
+    get,
+    path = "/v1/workshops/{id}/registrations",
+    params(
+        ("id" = String, Path, description = "Workshop id")
+    ),
+    responses(
+        (status = 200, description = "Seated registrations, then the waitlist, each in signup order", body = [WorkshopRegistrant]),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Workshop not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn get_workshop_registrants(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_workshop(&db, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Workshop not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch workshop", e)),
+    }
+    match DatabaseService::get_workshop_registrants(&db, id).await {
+        Ok(registrants) => Response::from_json(&registrants),
+        Err(e) => error_response(&ctx, database_error("fetch registrations", e)),
+    }
+}
+
+// Terms
+
+/// Create a term
+#[utoipa::path(
+    post,
+    path = "/v1/terms",
+    request_body = TermInput,
+    responses(
+        (status = 201, description = "Term created", body = Term),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 409, description = "A term with this slug already exists", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn create_term(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<TermInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::create_term(&db, &input).await {
+        Ok(Some(term)) => Ok(Response::from_json(&term)?.with_status(201)),
+        Ok(None) => error_response(&ctx, ApiError::internal("Term was not stored")),
+        Err(e) => error_response(&ctx, database_error("create term", e)),
+    }
+}
+
+/// Replace a term
+#[utoipa::path(
+    put,
+    path = "/v1/terms/{slug}",
+    params(
+        ("slug" = String, Path, description = "Term slug")
+    ),
+    request_body = TermInput,
+    responses(
+        (status = 200, description = "Term updated", body = Term),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Term not found", body = ApiError),
+        (status = 409, description = "Another term already has the new slug", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn replace_term(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let input = match parse_body::<TermInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::update_term(&db, slug, &input).await {
+        Ok(Some(term)) => {
+            // Cached stats embed the term and were counted over its old dates
+            invalidate_stats(&ctx).await;
+            Response::from_json(&term)
+        }
+        Ok(None) => error_response(&ctx, ApiError::not_found("Term not found")),
+        Err(e) => error_response(&ctx, database_error("update term", e)),
+    }
+}
+
+/// Delete a term
+#[utoipa::path(
+    delete,
+    path = "/v1/terms/{slug}",
+    params(
+        ("slug" = String, Path, description = "Term slug")
+    ),
+    responses(
+        (status = 204, description = "Term deleted"),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Term not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn delete_term(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::delete_term(&db, slug).await {
+        Ok(true) => {
+            invalidate_stats(&ctx).await;
+            Ok(Response::empty()?.with_status(204))
+        }
+        Ok(false) => error_response(&ctx, ApiError::not_found("Term not found")),
+        Err(e) => error_response(&ctx, database_error("delete term", e)),
+    }
+}
+
+// Courses
+
+/// Add a course to the advocacy petition
+#[utoipa::path(
+    post,
+    path = "/v1/courses",
+    request_body = CourseInput,
+    responses(
+        (status = 201, description = "Course created as Pending", body = Course),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 409, description = "A course with this slug already exists", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn create_course(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<CourseInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::create_course(&db, &input).await {
+        Ok(Some(course)) => Ok(Response::from_json(&course)?.with_status(201)),
+        Ok(None) => error_response(&ctx, ApiError::internal("Course was not stored")),
+        Err(e) => error_response(&ctx, database_error("create course", e)),
+    }
+}
+
+/// Replace a course's details
+#[utoipa::path(
+    put,
+    path = "/v1/courses/{slug}",
+    params(
+        ("slug" = String, Path, description = "Course slug")
+    ),
+    request_body = CourseInput,
+    responses(
+        (status = 200, description = "Course updated", body = Course),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Course not found", body = ApiError),
+        (status = 409, description = "Another course already has the new slug", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn replace_course(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let input = match parse_body::<CourseInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::update_course(&db, slug, &input).await {
+        Ok(Some(course)) => Response::from_json(&course),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Course not found")),
+        Err(e) => error_response(&ctx, database_error("update course", e)),
+    }
+}
+
+/// Record a milestone in a course's petition, e.g. moving it to UnderReview
+#[utoipa::path(
+    post,
+    path = "/v1/courses/{slug}/status",
+    params(
+        ("slug" = String, Path, description = "Course slug")
+    ),
+    request_body = CourseStatusInput,
+    responses(
+        (status = 201, description = "Change recorded; the course with its history", body = Course),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Course not found", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn set_course_status(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let input = match parse_body::<CourseStatusInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::set_course_status(&db, slug, &input).await {
+        Ok(Some(course)) => Ok(Response::from_json(&course)?.with_status(201)),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Course not found")),
+        Err(e) => error_response(&ctx, database_error("record course status", e)),
+    }
+}
+
+/// Delete a course along with its history and supporters
+#[utoipa::path(
+    delete,
+    path = "/v1/courses/{slug}",
+    params(
+        ("slug" = String, Path, description = "Course slug")
+    ),
+    responses(
+        (status = 204, description = "Course deleted"),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Course not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn delete_course(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::delete_course(&db, slug).await {
+        Ok(true) => Ok(Response::empty()?.with_status(204)),
+        Ok(false) => error_response(&ctx, ApiError::not_found("Course not found")),
+        Err(e) => error_response(&ctx, database_error("delete course", e)),
+    }
+}
+
+// Contributions
+
+/// Record a merged pull request by a club member
+#[utoipa::path(
+    post,
+    path = "/v1/contributions",
+    request_body = ContributionInput,
+    responses(
+        (status = 201, description = "Contribution recorded", body = Contribution),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 409, description = "This pull request is already recorded", body = ApiError),
+        (status = 422, description = "Validation failed or not a club member", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn create_contribution(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<ContributionInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    let member_id = match DatabaseService::get_member_id_by_github(&db, &input.github_username).await {
+        Ok(Some(member_id)) => member_id,
+        Ok(None) => return error_response(&ctx, ApiError::validation(vec![format!("github_username '{}' is not a club member", input.github_username)])),
+        Err(e) => return error_response(&ctx, database_error("record contribution", e)),
+    };
+    match DatabaseService::create_contribution(&db, &input, member_id).await {
+        Ok(Some(contribution)) => {
+            invalidate_stats(&ctx).await;
+            Ok(Response::from_json(&contribution)?.with_status(201))
+        }
+        Ok(None) => error_response(&ctx, ApiError::internal("Contribution was not stored")),
+        Err(e) => error_response(&ctx, database_error("record contribution", e)),
+    }
+}
+
+/// Delete a recorded contribution
+#[utoipa::path(
+    delete,
+    path = "/v1/contributions/{id}",
+    params(
+        ("id" = String, Path, description = "Contribution id")
+    ),
+    responses(
+        (status = 204, description = "Contribution deleted"),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Contribution not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn delete_contribution(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::delete_contribution(&db, id).await {
+        Ok(true) => {
+            invalidate_stats(&ctx).await;
+            Ok(Response::empty()?.with_status(204))
+        }
+        Ok(false) => error_response(&ctx, ApiError::not_found("Contribution not found")),
+        Err(e) => error_response(&ctx, database_error("delete contribution", e)),
+    }
+}
+
+// Blog posts
+
+/// Create a blog post as a draft; it goes live through `/v1/blog/{slug}/status`
+#[utoipa::path(
+    post,
+    path = "/v1/blog",
+    request_body = BlogPostInput,
+    responses(
+        (status = 201, description = "Draft created", body = BlogPost),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 409, description = "A post with this slug already exists, or another post holds its series part", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn create_blog_post(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let actor = match auth::authorize(&req, &ctx.env, Access::Officer).await {
+        Ok(actor) => actor,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let input = match parse_body::<BlogPostInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    if let Err(e) = check_co_authors(&db, &input).await {
+        return error_response(&ctx, e);
+    }
+    if let Err(e) = check_series_part(&db, &input, "").await {
+        return error_response(&ctx, e);
+    }
+    let markdown = content::fetch_markdown(&input.markdown_url).await;
+    let created_by = actor.label();
+    let revision = NewRevision {
+        markdown: markdown.as_deref(),
+        created_by: &created_by,
+        restored_from: None,
+    };
+    match DatabaseService::create_blog_post(&db, &input, &revision).await {
+        Ok(Some(post)) => {
+            index_blog_post_body(&db, &post.id, markdown.as_deref()).await;
+            Ok(Response::from_json(&post)?.with_status(201))
+        }
+        Ok(None) => error_response(&ctx, ApiError::internal("Blog post was not stored")),
+        Err(e) => error_response(&ctx, database_error("create blog post", e)),
+    }
+}
+
+/// Replace a blog post
+#[utoipa::path(
+    put,
+    path = "/v1/blog/{slug}",
+    params(
+        ("slug" = String, Path, description = "Blog post slug")
+    ),
+    request_body = BlogPostInput,
+    responses(
+        (status = 200, description = "Blog post updated", body = BlogPost),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Blog post not found", body = ApiError),
+        (status = 409, description = "Another post already uses the new slug or holds its series part", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn replace_blog_post(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let actor = match auth::authorize(&req, &ctx.env, Access::Officer).await {
+        Ok(actor) => actor,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let input = match parse_body::<BlogPostInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    let post_id = match DatabaseService::get_blog_post_by_slug(&db, slug).await {
+        Ok(Some(post)) => post.id,
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => return error_response(&ctx, database_error("update blog post", e)),
+    };
+    save_blog_post(&ctx, &db, &post_id, input, &actor.label()).await
+}
+
+/// Partially update a blog post
+#[utoipa::path(
+    patch,
+    path = "/v1/blog/{slug}",
+    params(
+        ("slug" = String, Path, description = "Blog post slug")
+    ),
+    request_body(content = Object, description = "JSON merge patch of BlogPostInput fields"),
+    responses(
+        (status = 200, description = "Blog post updated", body = BlogPost),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Blog post not found", body = ApiError),
+        (status = 409, description = "Another post already uses the new slug or holds its series part", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn patch_blog_post(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let actor = match auth::authorize(&req, &ctx.env, Access::Officer).await {
+        Ok(actor) => actor,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let patch = match parse_body::<Value>(&mut req).await {
+        Ok(patch) => patch,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    let (post_id, current) = match DatabaseService::get_blog_post_by_slug(&db, slug).await {
+        Ok(Some(post)) => (post.id.clone(), BlogPostInput::from(post)),
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => return error_response(&ctx, database_error("update blog post", e)),
+    };
+    let input = match apply_patch(current, patch) {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    save_blog_post(&ctx, &db, &post_id, input, &actor.label()).await
+}
+
+/// Store an edit as the post's next revision, with the markdown as it is now
+async fn save_blog_post(
+    ctx: &RouteContext<RequestInfo>,
+    db: &D1Database,
+    post_id: &str,
+    input: BlogPostInput,
+    created_by: &str,
+) -> Result<Response> {
+    if let Err(details) = input.validate() {
+        return error_response(ctx, ApiError::validation(details));
+    }
+    if let Err(e) = check_co_authors(db, &input).await {
+        return error_response(ctx, e);
+    }
+    if let Err(e) = check_series_part(db, &input, post_id).await {
+        return error_response(ctx, e);
+    }
+    let markdown = content::fetch_markdown(&input.markdown_url).await;
+    let revision = NewRevision {
+        markdown: markdown.as_deref(),
+        created_by,
+        restored_from: None,
+    };
+    match DatabaseService::update_blog_post(db, post_id, &input, &revision).await {
+        Ok(Some(post)) => {
+            index_blog_post_body(db, &post.id, markdown.as_deref()).await;
+            Response::from_json(&post)
+        }
+        Ok(None) => error_response(ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => error_response(ctx, database_error("update blog post", e)),
+    }
+}
+
+/// Copy a post's markdown into the search index. The post is already saved,
+/// so missing markdown or a failure only leaves search matching the excerpt
+/// and is logged.
+pub(super) async fn index_blog_post_body(db: &D1Database, post_id: &str, markdown: Option<&str>) -> bool {
+    let Some(markdown) = markdown else {
+        return false;
+    };
+    match DatabaseService::set_blog_post_body_text(db, post_id, &content::plain_text(markdown)).await {
+        Ok(()) => true,
+        Err(e) => {
+            web_sys::console::warn_1(&format!("Failed to index blog post {}: {:?}", post_id, e).into());
+            false
+        }
+    }
+}
+
+/// Re-fetch every blog post's markdown into the search index
+#[utoipa::path(
+    post,
+    path = "/v1/search/reindex",
+    responses(
+        (status = 200, description = "Number of posts indexed and posts whose markdown could not be fetched", body = Object),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn reindex_search(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let db = ctx.env.d1("DB")?;
+    let sources = match DatabaseService::get_blog_post_markdown_sources(&db).await {
+        Ok(sources) => sources,
+        Err(e) => return error_response(&ctx, database_error("reindex search", e)),
+    };
+
+    let mut indexed = 0;
+    let mut failed = Vec::new();
+    for (id, markdown_url) in &sources {
+        let markdown = content::fetch_markdown(markdown_url).await;
+        if index_blog_post_body(&db, id, markdown.as_deref()).await {
+            indexed += 1;
+        } else {
+            failed.push(id.clone());
+        }
+    }
+    Response::from_json(&serde_json::json!({ "indexed": indexed, "failed": failed }))
+}
+
+/// Delete a blog post
+#[utoipa::path(
+    delete,
+    path = "/v1/blog/{slug}",
+    params(
+        ("slug" = String, Path, description = "Blog post slug")
+    ),
+    responses(
+        (status = 204, description = "Blog post deleted"),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Blog post not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn delete_blog_post(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    let post_id = match DatabaseService::get_blog_post_by_slug(&db, slug).await {
+        Ok(Some(post)) => post.id,
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => return error_response(&ctx, database_error("delete blog post", e)),
+    };
+    match DatabaseService::delete_blog_post(&db, &post_id).await {
+        Ok(true) => Ok(Response::empty()?.with_status(204)),
+        Ok(false) => error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => error_response(&ctx, database_error("delete blog post", e)),
+    }
+}
+
+// Blog series
+
+/// Create a blog series; posts join it through their `series` field
+#[utoipa::path(
+    post,
+    path = "/v1/blog/series",
+    request_body = BlogSeriesInput,
+    responses(
+        (status = 201, description = "Series created", body = BlogSeriesDetail),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 409, description = "A series with this slug already exists", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn create_blog_series(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<BlogSeriesInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::create_blog_series(&db, &input).await {
+        Ok(Some(series)) => Ok(Response::from_json(&series)?.with_status(201)),
+        Ok(None) => error_response(&ctx, ApiError::internal("Series was not stored")),
+        Err(e) => error_response(&ctx, database_error("create series", e)),
+    }
+}
+
+/// Replace a blog series. Its posts stay in it, including under a new slug.
+#[utoipa::path(
+    put,
+    path = "/v1/blog/series/{slug}",
+    params(
+        ("slug" = String, Path, description = "Series slug")
+    ),
+    request_body = BlogSeriesInput,
+    responses(
+        (status = 200, description = "Series updated", body = BlogSeriesDetail),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Series not found", body = ApiError),
+        (status = 409, description = "Another series already has the new slug", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn replace_blog_series(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let input = match parse_body::<BlogSeriesInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::update_blog_series(&db, slug, &input).await {
+        Ok(Some(series)) => Response::from_json(&series),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Series not found")),
+        Err(e) => error_response(&ctx, database_error("update series", e)),
+    }
+}
+
+/// Delete a blog series. Its posts are kept and no longer belong to a series.
+#[utoipa::path(
+    delete,
+    path = "/v1/blog/series/{slug}",
+    params(
+        ("slug" = String, Path, description = "Series slug")
+    ),
+    responses(
+        (status = 204, description = "Series deleted"),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Series not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "admin"
+)]
+pub async fn delete_blog_series(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::delete_blog_series(&db, slug).await {
+        Ok(true) => Ok(Response::empty()?.with_status(204)),
+        Ok(false) => error_response(&ctx, ApiError::not_found("Series not found")),
+        Err(e) => error_response(&ctx, database_error("delete series", e)),
+    }
+}
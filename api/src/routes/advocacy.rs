@@ -0,0 +1,170 @@
+use worker::*;
+
+use crate::database::DatabaseService;
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::rate_limit;
+use crate::session::{self, Session};
+use crate::visitor::Visitor;
+
+/// List the courses the advocacy petition covers
+#[utoipa::path(
+    get,
+    path = "/v1/courses",
+    responses(
+        (status = 200, description = "Courses by catalog code, with supporter counts and status history", body = [Course]),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_courses(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_courses(&db).await {
+        Ok(courses) => Response::from_json(&courses),
+        Err(e) => error_response(&ctx, database_error("fetch courses", e)),
+    }
+}
+
+/// Get a single course
+#[utoipa::path(
+    get,
+    path = "/v1/courses/{slug}",
+    params(
+        ("slug" = String, Path, description = "Course slug, e.g. cs-311")
+    ),
+    responses(
+        (status = 200, description = "The course", body = Course),
+        (status = 404, description = "Course not found", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_course(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_course(&db, slug).await {
+        Ok(Some(course)) => Response::from_json(&course),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Course not found")),
+        Err(e) => error_response(&ctx, database_error("fetch course", e)),
+    }
+}
+
+/// Get petition totals
+#[utoipa::path(
+    get,
+    path = "/v1/petition",
+    responses(
+        (status = 200, description = "Supporter and course counts", body = PetitionSummary),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_petition_summary(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_petition_summary(&db).await {
+        Ok(summary) => Response::from_json(&summary),
+        Err(e) => error_response(&ctx, database_error("fetch petition totals", e)),
+    }
+}
+
+/// Rate limit a signature and find who is signing. The rate limit fails
+/// open, like the other public write endpoints.
+async fn signer(req: &Request, ctx: &RouteContext<RequestInfo>) -> std::result::Result<Session, ApiError> {
+    let visitor = Visitor::from_request(req, &ctx.env);
+    let allowed = match ctx.env.kv("CACHE") {
+        Ok(kv) => rate_limit::SIGNATURES.allow(&kv, &visitor.ip_hash).await,
+        Err(e) => Err(e),
+    };
+    match allowed {
+        Ok(true) => {}
+        Ok(false) => return Err(ApiError::rate_limited("Too many signatures, try again later")),
+        Err(e) => web_sys::console::warn_1(&format!("Rate limit check failed: {:?}", e).into()),
+    }
+
+    match session::current(req, &ctx.env).await {
+        Ok(Some(session)) => Ok(session),
+        Ok(None) => Err(ApiError::unauthorized("Sign in to sign the petition")),
+        Err(e) => {
+            web_sys::console::error_1(&format!("Failed to read session: {:?}", e).into());
+            Err(ApiError::internal("Unable to read the session at this time"))
+        }
+    }
+}
+
+/// Sign the petition as the signed-in member. Each member can sign once.
+#[utoipa::path(
+    post,
+    path = "/v1/petition/signatures",
+    responses(
+        (status = 201, description = "Signed; the updated totals", body = PetitionSummary),
+        (status = 401, description = "Not signed in, or the session expired", body = ApiError),
+        (status = 409, description = "This member already signed", body = ApiError),
+        (status = 429, description = "Too many signatures from this address", body = ApiError)
+    ),
+    security(("session_cookie" = [])),
+    tag = "public"
+)]
+pub async fn sign_petition(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let session = match signer(&req, &ctx).await {
+        Ok(session) => session,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::sign_petition(&db, None, session.member_id).await {
+        Ok(true) => {}
+        Ok(false) => return error_response(&ctx, ApiError::conflict("You have already signed the petition")),
+        Err(e) => return error_response(&ctx, database_error("sign petition", e)),
+    }
+    match DatabaseService::get_petition_summary(&db).await {
+        Ok(summary) => Ok(Response::from_json(&summary)?.with_status(201)),
+        Err(e) => error_response(&ctx, database_error("fetch petition totals", e)),
+    }
+}
+
+/// Back Rust in a course that is still pending or under review, as the
+/// signed-in member
+#[utoipa::path(
+    post,
+    path = "/v1/courses/{slug}/signatures",
+    params(
+        ("slug" = String, Path, description = "Course slug, e.g. cs-311")
+    ),
+    responses(
+        (status = 201, description = "Support recorded; the course with its new count", body = Course),
+        (status = 400, description = "The course has already been decided", body = ApiError),
+        (status = 401, description = "Not signed in, or the session expired", body = ApiError),
+        (status = 404, description = "Course not found", body = ApiError),
+        (status = 409, description = "This member already backs the course", body = ApiError),
+        (status = 429, description = "Too many signatures from this address", body = ApiError)
+    ),
+    security(("session_cookie" = [])),
+    tag = "public"
+)]
+pub async fn support_course(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let slug = ctx.param("slug").map_or("", |v| v).to_string();
+    let session = match signer(&req, &ctx).await {
+        Ok(session) => session,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    let recorded = match DatabaseService::sign_petition(&db, Some(&slug), session.member_id).await {
+        Ok(recorded) => recorded,
+        Err(e) => return error_response(&ctx, database_error("record support", e)),
+    };
+    match DatabaseService::get_course(&db, &slug).await {
+        Ok(Some(course)) if recorded => Ok(Response::from_json(&course)?.with_status(201)),
+        // Nothing was recorded: the course is closed, or this member already backs it
+        Ok(Some(course)) if !matches!(course.status, CourseStatus::Pending | CourseStatus::UnderReview) => error_response(
+            &ctx,
+            ApiError::bad_request(format!("{} has already been {}", course.course_code, course.status.to_string().to_lowercase())),
+        ),
+        Ok(Some(course)) => error_response(&ctx, ApiError::conflict(format!("You already back {}", course.course_code))),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Course not found")),
+        Err(e) => error_response(&ctx, database_error("fetch course", e)),
+    }
+}
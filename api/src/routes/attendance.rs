@@ -0,0 +1,256 @@
+use chrono::{Duration, Utc};
+use worker::*;
+
+use crate::check_in;
+use crate::database::{DatabaseService, MAX_EVENT_WINDOW_DAYS};
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::rate_limit::{self, RateLimit};
+use crate::session;
+use crate::tokens;
+use crate::visitor::Visitor;
+
+use super::admin::parse_body;
+
+/// How long before an occurrence starts and after it ends members can check in
+const CHECK_IN_SLACK_MINUTES: i64 = 60;
+
+/// RSVPs stay open while the event has an occurrence that has not ended
+fn rsvps_open(event: &Event) -> bool {
+    let now = Utc::now();
+    !event.occurrences(now, now + Duration::days(MAX_EVENT_WINDOW_DAYS)).is_empty()
+}
+
+/// The occurrence members checking in now are attending, if any
+pub(super) fn current_occurrence(event: &Event) -> Option<Event> {
+    event.occurrence_near(Utc::now(), Duration::minutes(CHECK_IN_SLACK_MINUTES))
+}
+
+/// Count a request against `limit`, failing open if KV is unavailable
+async fn within_limit(ctx: &RouteContext<RequestInfo>, req: &Request, limit: &RateLimit) -> Result<bool> {
+    let visitor = Visitor::from_request(req, &ctx.env);
+    let kv = ctx.env.kv("CACHE")?;
+    match limit.allow(&kv, &visitor.ip_hash).await {
+        Ok(allowed) => Ok(allowed),
+        Err(e) => {
+            web_sys::console::warn_1(&format!("Rate limit check failed: {:?}", e).into());
+            Ok(true)
+        }
+    }
+}
+
+/// RSVP to an event as the signed-in member. Only `Going` counts against the
+/// event's capacity; for recurring events the RSVP covers the whole series.
+#[utoipa::path(
+    post,
+    path = "/v1/events/{id}/rsvps",
+    params(
+        ("id" = String, Path, description = "Event id")
+    ),
+    request_body = RsvpInput,
+    responses(
+        (status = 201, description = "RSVP recorded; keep the token to change or withdraw it", body = EventRsvp),
+        (status = 400, description = "The event is over", body = ApiError),
+        (status = 401, description = "Not signed in, or the session expired", body = ApiError),
+        (status = 404, description = "Event not found", body = ApiError),
+        (status = 409, description = "The event is full, or this member already responded", body = ApiError),
+        (status = 429, description = "Too many RSVPs from this address", body = ApiError)
+    ),
+    security(("session_cookie" = [])),
+    tag = "public"
+)]
+pub async fn create_event_rsvp(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    if !within_limit(&ctx, &req, &rate_limit::RSVPS).await? {
+        return error_response(&ctx, ApiError::rate_limited("Too many RSVPs, try again later"));
+    }
+    let Some(session) = session::current(&req, &ctx.env).await? else {
+        return error_response(&ctx, ApiError::unauthorized("Sign in to RSVP"));
+    };
+
+    let input = match parse_body::<RsvpInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    let event = match DatabaseService::get_event(&db, id).await {
+        Ok(Some(event)) => event,
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Event not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch event", e)),
+    };
+    if !rsvps_open(&event) {
+        return error_response(&ctx, ApiError::bad_request("This event is over"));
+    }
+
+    let token = tokens::new_token();
+    match DatabaseService::create_event_rsvp(&db, id, session.member_id, input.status, &token).await {
+        Ok(Some(rsvp)) => Ok(Response::from_json(&rsvp)?.with_status(201)),
+        // The capacity check is the only other way the insert can match nothing
+        Ok(None) => error_response(&ctx, ApiError::conflict("This event is full")),
+        Err(e) => error_response(&ctx, database_error("record RSVP", e)),
+    }
+}
+
+/// Check on an RSVP
+#[utoipa::path(
+    get,
+    path = "/v1/events/{id}/rsvps/{token}",
+    params(
+        ("id" = String, Path, description = "Event id"),
+        ("token" = String, Path, description = "Confirmation token returned with the RSVP")
+    ),
+    responses(
+        (status = 200, description = "The RSVP", body = EventRsvp),
+        (status = 404, description = "No RSVP with this token", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_event_rsvp(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let token = ctx.param("token").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_event_rsvp(&db, id, token).await {
+        Ok(Some(rsvp)) => Response::from_json(&rsvp),
+        Ok(None) => error_response(&ctx, ApiError::not_found("RSVP not found")),
+        Err(e) => error_response(&ctx, database_error("fetch RSVP", e)),
+    }
+}
+
+/// Change an RSVP's answer
+#[utoipa::path(
+    put,
+    path = "/v1/events/{id}/rsvps/{token}",
+    params(
+        ("id" = String, Path, description = "Event id"),
+        ("token" = String, Path, description = "Confirmation token returned with the RSVP")
+    ),
+    request_body = RsvpChange,
+    responses(
+        (status = 200, description = "RSVP updated", body = EventRsvp),
+        (status = 400, description = "The event is over", body = ApiError),
+        (status = 404, description = "No RSVP with this token", body = ApiError),
+        (status = 409, description = "The event is full", body = ApiError),
+        (status = 429, description = "Too many RSVPs from this address", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn update_event_rsvp(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let token = ctx.param("token").map_or("", |v| v);
+    if !within_limit(&ctx, &req, &rate_limit::RSVPS).await? {
+        return error_response(&ctx, ApiError::rate_limited("Too many RSVPs, try again later"));
+    }
+
+    let input = match parse_body::<RsvpChange>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::get_event_rsvp(&db, id, token).await {
+        Ok(Some(rsvp)) if !rsvps_open(&rsvp.event) => {
+            return error_response(&ctx, ApiError::bad_request("This event is over"))
+        }
+        Ok(Some(_)) => {}
+        Ok(None) => return error_response(&ctx, ApiError::not_found("RSVP not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch RSVP", e)),
+    }
+
+    match DatabaseService::update_event_rsvp(&db, id, token, input.status).await {
+        Ok(Some(rsvp)) => Response::from_json(&rsvp),
+        Ok(None) => error_response(&ctx, ApiError::conflict("This event is full")),
+        Err(e) => error_response(&ctx, database_error("update RSVP", e)),
+    }
+}
+
+/// Withdraw an RSVP
+#[utoipa::path(
+    delete,
+    path = "/v1/events/{id}/rsvps/{token}",
+    params(
+        ("id" = String, Path, description = "Event id"),
+        ("token" = String, Path, description = "Confirmation token returned with the RSVP")
+    ),
+    responses(
+        (status = 204, description = "RSVP withdrawn"),
+        (status = 404, description = "No RSVP with this token", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn delete_event_rsvp(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let token = ctx.param("token").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::delete_event_rsvp(&db, id, token).await {
+        Ok(true) => Ok(Response::empty()?.with_status(204)),
+        Ok(false) => error_response(&ctx, ApiError::not_found("RSVP not found")),
+        Err(e) => error_response(&ctx, database_error("withdraw RSVP", e)),
+    }
+}
+
+/// Check in at the door as the signed-in member, with the code organisers
+/// are showing. Attendance is recorded against the occurrence running now.
+#[utoipa::path(
+    post,
+    path = "/v1/events/{id}/check-ins",
+    params(
+        ("id" = String, Path, description = "Event id")
+    ),
+    request_body = CheckInInput,
+    responses(
+        (status = 201, description = "Attendance recorded", body = CheckIn),
+        (status = 400, description = "Wrong or expired code, or the event is not on now", body = ApiError),
+        (status = 401, description = "Not signed in, or the session expired", body = ApiError),
+        (status = 404, description = "Event not found", body = ApiError),
+        (status = 409, description = "Already checked in to this occurrence", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError),
+        (status = 429, description = "Too many attempts from this address", body = ApiError)
+    ),
+    security(("session_cookie" = [])),
+    tag = "public"
+)]
+pub async fn check_in_to_event(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    if !within_limit(&ctx, &req, &rate_limit::CHECK_INS).await? {
+        return error_response(&ctx, ApiError::rate_limited("Too many check-in attempts, try again later"));
+    }
+    let Some(session) = session::current(&req, &ctx.env).await? else {
+        return error_response(&ctx, ApiError::unauthorized("Sign in to check in"));
+    };
+
+    let input = match parse_body::<CheckInInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    let event = match DatabaseService::get_event(&db, id).await {
+        Ok(Some(event)) => event,
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Event not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch event", e)),
+    };
+    let Some(occurrence) = current_occurrence(&event) else {
+        return error_response(&ctx, ApiError::bad_request("This event is not on right now"));
+    };
+
+    let kv = ctx.env.kv("CACHE")?;
+    if !check_in::is_valid(&kv, id, &input.code).await? {
+        return error_response(&ctx, ApiError::bad_request("Wrong or expired check-in code"));
+    }
+
+    // Stats for the running term are cached briefly, so new check-ins show
+    // up within the hour without a KV sweep on every scan at the door
+    match DatabaseService::create_check_in(&db, id, session.member_id, occurrence.starts_at).await {
+        Ok(Some(check_in)) => Ok(Response::from_json(&check_in)?.with_status(201)),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Event not found")),
+        Err(e) => error_response(&ctx, database_error("record check-in", e)),
+    }
+}
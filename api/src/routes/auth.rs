@@ -0,0 +1,156 @@
+use worker::*;
+
+use crate::database::DatabaseService;
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::github::{self, GitHubConfig};
+use crate::models::*;
+use crate::pagination::query_param;
+use crate::session;
+
+/// A 302 to `location`, setting any cookies on the way
+fn redirect(location: &str, cookies: &[String]) -> Result<Response> {
+    let mut headers = Headers::new();
+    headers.set("Location", location)?;
+    headers.set("Cache-Control", "no-store")?;
+    for cookie in cookies {
+        headers.append("Set-Cookie", cookie)?;
+    }
+    Ok(Response::empty()?.with_status(302).with_headers(headers))
+}
+
+fn github_unavailable(action: &str, e: Error) -> ApiError {
+    web_sys::console::error_1(&format!("Failed to {}: {:?}", action, e).into());
+    ApiError::new(ErrorCode::ServiceUnavailable, "GitHub could not be reached, try again later")
+}
+
+/// Sign in with GitHub. Redirects to GitHub, which sends the member back
+/// through the callback.
+#[utoipa::path(
+    get,
+    path = "/v1/auth/github",
+    params(
+        ("return_to" = Option<String>, Query, description = "Site path to return to after signing in, e.g. `/events`")
+    ),
+    responses(
+        (status = 302, description = "Redirect to GitHub's authorization page"),
+        (status = 503, description = "GitHub sign-in is not configured", body = ApiError)
+    ),
+    tag = "auth"
+)]
+pub async fn github_sign_in(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let Some(config) = GitHubConfig::from_env(&ctx.env) else {
+        return error_response(&ctx, ApiError::new(ErrorCode::ServiceUnavailable, "GitHub sign-in is not configured"));
+    };
+    let url = req.url()?;
+    let return_to = session::safe_return_path(query_param(&url, "return_to").as_deref());
+
+    let kv = ctx.env.kv("CACHE")?;
+    let state = session::begin_sign_in(&kv, &return_to).await?;
+    redirect(&config.authorize_url(&state)?, &[session::state_cookie(&state)])
+}
+
+/// Where GitHub sends the member after they authorize the app. Links the
+/// account to its member, sets the session cookie and returns to the site.
+/// Accounts that belong to no member are turned away.
+#[utoipa::path(
+    get,
+    path = "/v1/auth/github/callback",
+    params(
+        ("code" = Option<String>, Query, description = "Authorization code from GitHub; absent if the member declined"),
+        ("state" = String, Query, description = "State issued by `/v1/auth/github`")
+    ),
+    responses(
+        (status = 302, description = "Signed in (or declined); redirect back to the site"),
+        (status = 400, description = "Unknown or expired state, a state issued to another browser, or GitHub rejected the code", body = ApiError),
+        (status = 403, description = "The GitHub account does not belong to a club member", body = ApiError),
+        (status = 503, description = "GitHub could not be reached or sign-in is not configured", body = ApiError)
+    ),
+    tag = "auth"
+)]
+pub async fn github_callback(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let Some(config) = GitHubConfig::from_env(&ctx.env) else {
+        return error_response(&ctx, ApiError::new(ErrorCode::ServiceUnavailable, "GitHub sign-in is not configured"));
+    };
+    let url = req.url()?;
+    let kv = ctx.env.kv("CACHE")?;
+
+    let state = query_param(&url, "state").unwrap_or_default();
+    let Some(return_to) = session::finish_sign_in(&kv, &req, &state).await? else {
+        return error_response(&ctx, ApiError::bad_request("This sign-in link has expired, please sign in again"));
+    };
+    let destination = format!("{}{}", session::site_url(&ctx.env), return_to);
+
+    // Declining on GitHub comes back without a code; just go back to the site
+    let Some(code) = query_param(&url, "code") else {
+        return redirect(&destination, &[session::clear_state_cookie()]);
+    };
+
+    let access_token = match github::exchange_code(&config, &code).await {
+        Ok(Some(token)) => token,
+        Ok(None) => return error_response(&ctx, ApiError::bad_request("GitHub did not accept the sign-in, please try again")),
+        Err(e) => return error_response(&ctx, github_unavailable("exchange OAuth code", e)),
+    };
+    let user = match github::fetch_user(&config, &access_token).await {
+        Ok(user) => user,
+        Err(e) => return error_response(&ctx, github_unavailable("fetch GitHub user", e)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    let member_id = match DatabaseService::link_github_member(&db, &user).await {
+        Ok(Some(member_id)) => member_id,
+        Ok(None) => return error_response(&ctx, ApiError::forbidden("This GitHub account is not linked to a club member")),
+        Err(e) => return error_response(&ctx, database_error("link GitHub account", e)),
+    };
+
+    let token = session::create(&kv, member_id, &user.login).await?;
+    redirect(&destination, &[session::cookie(&token), session::clear_state_cookie()])
+}
+
+/// The signed-in member
+#[utoipa::path(
+    get,
+    path = "/v1/me",
+    responses(
+        (status = 200, description = "The member behind the session cookie", body = CurrentUser),
+        (status = 401, description = "Not signed in, or the session expired", body = ApiError)
+    ),
+    security(("session_cookie" = [])),
+    tag = "auth"
+)]
+pub async fn get_me(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let Some(session) = session::current(&req, &ctx.env).await? else {
+        return error_response(&ctx, ApiError::unauthorized("Not signed in"));
+    };
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::get_current_user(&db, session.member_id).await {
+        Ok(Some(user)) => {
+            let mut response = Response::from_json(&user)?;
+            response.headers_mut().set("Cache-Control", "private, no-store")?;
+            Ok(response)
+        }
+        // The member was deleted after signing in
+        Ok(None) => error_response(&ctx, ApiError::unauthorized("Not signed in")),
+        Err(e) => error_response(&ctx, database_error("fetch member", e)),
+    }
+}
+
+/// Sign out, ending the session on the server as well as clearing the cookie
+#[utoipa::path(
+    post,
+    path = "/v1/auth/sign-out",
+    responses(
+        (status = 204, description = "Signed out; also returned when no one was signed in")
+    ),
+    tag = "auth"
+)]
+pub async fn sign_out(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Some(token) = session::session_token(&req) {
+        let kv = ctx.env.kv("CACHE")?;
+        session::destroy(&kv, &token).await?;
+    }
+
+    let mut headers = Headers::new();
+    headers.set("Set-Cookie", &session::clear_cookie())?;
+    Ok(Response::empty()?.with_status(204).with_headers(headers))
+}
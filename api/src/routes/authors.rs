@@ -0,0 +1,82 @@
+use worker::*;
+
+use crate::database::{BlogPostFilter, DatabaseService};
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::pagination::PageRequest;
+
+/// Check that every co-author of a post is a member
+pub(super) async fn check_co_authors(db: &D1Database, input: &BlogPostInput) -> std::result::Result<(), ApiError> {
+    if input.co_authors.is_empty() {
+        return Ok(());
+    }
+    match DatabaseService::get_unknown_members(db, &input.co_authors).await {
+        Ok(unknown) if unknown.is_empty() => Ok(()),
+        Ok(unknown) => Err(ApiError::validation(
+            unknown.into_iter().map(|username| format!("co_authors: {} is not a member", username)).collect(),
+        )),
+        Err(e) => Err(database_error("fetch members", e)),
+    }
+}
+
+/// A blog author's page: who they are and the posts they wrote or co-wrote.
+/// The bio is only shown if the member's profile is public.
+#[utoipa::path(
+    get,
+    path = "/v1/authors/{github}",
+    params(
+        ("github" = String, Path, description = "Author's GitHub username"),
+        ("limit" = Option<u32>, Query, description = "Page size of posts (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "posts.next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction by publication date: desc (default) or asc")
+    ),
+    responses(
+        (status = 200, description = "The author and a page of their published posts", body = AuthorProfile),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 404, description = "No member with this username has published a post", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_author(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let page = match PageRequest::from_url(&req.url()?) {
+        Ok(page) => page,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    let github = ctx.param("github").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    let record = match DatabaseService::get_member(&db, github).await {
+        Ok(Some(record)) => record,
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Author not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch author", e)),
+    };
+    let filter = BlogPostFilter {
+        author: Some(record.summary.github_username.clone()),
+        ..BlogPostFilter::default()
+    };
+    let posts = match DatabaseService::get_blog_posts(&db, &filter, &page).await {
+        Ok(posts) => posts,
+        Err(e) => return error_response(&ctx, database_error("fetch blog posts", e)),
+    };
+    // Bylines already name every author, so only members with a published
+    // post have a page
+    if posts.items.is_empty() && page.cursor.is_none() {
+        return error_response(&ctx, ApiError::not_found("Author not found"));
+    }
+
+    let bio_visible = record.visibility == ProfileVisibility::Public;
+    let member = record.summary;
+    let profile = AuthorProfile {
+        author: BlogAuthor {
+            name: member.name,
+            github_username: member.github_username,
+            avatar_url: member.avatar_url,
+        },
+        bio: member.bio.filter(|_| bio_visible),
+        posts,
+    };
+    let mut response = Response::from_json(&profile)?;
+    response.headers_mut().set("Cache-Control", "public, max-age=60")?;
+    Ok(response)
+}
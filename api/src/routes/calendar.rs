@@ -0,0 +1,70 @@
+use chrono::{Duration, Utc};
+use worker::*;
+
+use crate::database::DatabaseService;
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::ics;
+use crate::models::*;
+
+/// How far back the subscription feed keeps one-off events
+const FEED_HISTORY_DAYS: i64 = 90;
+
+fn calendar_response(body: String, disposition: &str) -> Result<Response> {
+    let mut response = Response::ok(body)?;
+    let headers = response.headers_mut();
+    headers.set("Content-Type", "text/calendar; charset=utf-8")?;
+    headers.set("Content-Disposition", disposition)?;
+    headers.set("Cache-Control", "public, max-age=3600")?;
+    Ok(response)
+}
+
+/// Subscribe to club events as an iCalendar feed
+#[utoipa::path(
+    get,
+    path = "/v1/events.ics",
+    responses(
+        (status = 200, description = "RFC 5545 calendar with every recurring event and one-off events from the last 90 days onward", content_type = "text/calendar", body = String),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_events_calendar(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let db = ctx.env.d1("DB")?;
+    let since = Utc::now() - Duration::days(FEED_HISTORY_DAYS);
+
+    match DatabaseService::get_calendar_events(&db, since).await {
+        Ok(events) => calendar_response(
+            ics::calendar(&events, "The Rust Club Events"),
+            "inline; filename=\"rust-club-events.ics\"",
+        ),
+        Err(e) => error_response(&ctx, database_error("fetch events", e)),
+    }
+}
+
+/// Download a single event as an iCalendar file
+#[utoipa::path(
+    get,
+    path = "/v1/events/{id}/ics",
+    params(
+        ("id" = String, Path, description = "Event id")
+    ),
+    responses(
+        (status = 200, description = "RFC 5545 calendar containing the event", content_type = "text/calendar", body = String),
+        (status = 404, description = "Event not found", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_event_calendar(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_event(&db, id).await {
+        Ok(Some(event)) => calendar_response(
+            ics::calendar(std::slice::from_ref(&event), &event.title),
+            &format!("attachment; filename=\"{}.ics\"", event.id),
+        ),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Event not found")),
+        Err(e) => error_response(&ctx, database_error("fetch event", e)),
+    }
+}
@@ -0,0 +1,276 @@
+use chrono::{Duration, Utc};
+use worker::*;
+
+use crate::auth::{self, Access};
+use crate::database::{ContributionRecord, DatabaseService};
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::pagination::PageRequest;
+use crate::session;
+
+use super::admin::{invalidate_stats, parse_body};
+
+/// Claim length when the `CLAIM_EXPIRY_DAYS` var is missing or invalid
+const DEFAULT_CLAIM_EXPIRY_DAYS: i64 = 14;
+
+/// Claims a member may hold at once before opening a PR for one of them, so
+/// nobody can take the whole board
+const MAX_ACTIVE_CLAIMS: u32 = 3;
+
+/// How long a claim lasts before a PR must be opened, from the `CLAIM_EXPIRY_DAYS` var
+fn claim_expiry(env: &Env) -> Duration {
+    let days = env
+        .var("CLAIM_EXPIRY_DAYS")
+        .ok()
+        .and_then(|v| v.to_string().trim().parse::<i64>().ok())
+        .filter(|days| *days > 0)
+        .unwrap_or(DEFAULT_CLAIM_EXPIRY_DAYS);
+    Duration::days(days)
+}
+
+/// The member who claimed an issue may move their contribution along;
+/// anyone else needs issue curation rights
+async fn authorize_owner(req: &Request, env: &Env, record: &ContributionRecord) -> std::result::Result<(), ApiError> {
+    if let Ok(Some(session)) = session::current(req, env).await
+        && session.member_id == record.member_id
+    {
+        return Ok(());
+    }
+    auth::authorize(req, env, Access::Issues).await.map(|_| ())
+}
+
+/// Load a contribution and check it may move to `next`
+async fn transition(
+    req: &Request,
+    ctx: &RouteContext<RequestInfo>,
+    db: &D1Database,
+    next: ContributionState,
+) -> std::result::Result<ContributionRecord, ApiError> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let record = match DatabaseService::get_contribution_record(db, id).await {
+        Ok(Some(record)) => record,
+        Ok(None) => return Err(ApiError::not_found("Contribution not found")),
+        Err(e) => return Err(database_error("fetch contribution", e)),
+    };
+    match next {
+        ContributionState::Merged => auth::authorize(req, &ctx.env, Access::Issues).await.map(|_| ())?,
+        _ => authorize_owner(req, &ctx.env, &record).await?,
+    }
+    let state = record.contribution.state;
+    if !state.can_become(next) {
+        return Err(ApiError::conflict(format!(
+            "A contribution that is {} cannot become {}",
+            state.to_string().to_lowercase(),
+            next.to_string().to_lowercase()
+        )));
+    }
+    Ok(record)
+}
+
+/// Reply with the contribution after a successful transition
+async fn updated(ctx: &RouteContext<RequestInfo>, db: &D1Database, changed: Result<bool>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    match changed {
+        Ok(true) => {}
+        // Lost a race with another transition or the expiry sweep
+        Ok(false) => return error_response(ctx, ApiError::conflict("The contribution changed; reload and try again")),
+        Err(e) => return error_response(ctx, database_error("update contribution", e)),
+    }
+    match DatabaseService::get_contribution(db, id).await {
+        Ok(Some(contribution)) => Response::from_json(&contribution),
+        Ok(None) => error_response(ctx, ApiError::not_found("Contribution not found")),
+        Err(e) => error_response(ctx, database_error("fetch contribution", e)),
+    }
+}
+
+/// Claim an issue so other members know it is taken. The claim expires
+/// after the configured number of days unless a PR is opened first. A
+/// member may hold a few claims without a PR at once.
+#[utoipa::path(
+    post,
+    path = "/v1/issues/{id}/claim",
+    params(
+        ("id" = String, Path, description = "Issue id")
+    ),
+    responses(
+        (status = 201, description = "Issue claimed", body = Contribution),
+        (status = 401, description = "Not signed in, or the session expired", body = ApiError),
+        (status = 404, description = "Issue not found", body = ApiError),
+        (status = 409, description = "Issue is already claimed or has been closed, or the member holds the most claims allowed", body = ApiError)
+    ),
+    security(("session_cookie" = [])),
+    tag = "contributions"
+)]
+pub async fn claim_issue(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let Some(session) = session::current(&req, &ctx.env).await? else {
+        return error_response(&ctx, ApiError::unauthorized("Sign in to claim an issue"));
+    };
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_issue(&db, id).await {
+        Ok(Some(_)) => {}
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Issue not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch issue", e)),
+    }
+    let expires_at = Utc::now() + claim_expiry(&ctx.env);
+    match DatabaseService::claim_issue(&db, id, session.member_id, expires_at, MAX_ACTIVE_CLAIMS).await {
+        Ok(Some(contribution)) => return Ok(Response::from_json(&contribution)?.with_status(201)),
+        Ok(None) => {}
+        Err(e) => return error_response(&ctx, database_error("claim issue", e)),
+    }
+    match DatabaseService::count_active_claims(&db, session.member_id).await {
+        Ok(count) if count >= MAX_ACTIVE_CLAIMS => error_response(
+            &ctx,
+            ApiError::conflict(format!(
+                "You already hold {} claims; open a pull request or release one first",
+                count
+            )),
+        ),
+        Ok(_) => error_response(&ctx, ApiError::conflict("This issue is already claimed or has been closed")),
+        Err(e) => error_response(&ctx, database_error("count claims", e)),
+    }
+}
+
+/// Report the pull request for a claimed issue. The claim stops expiring.
+#[utoipa::path(
+    post,
+    path = "/v1/contributions/{id}/pull-request",
+    params(
+        ("id" = String, Path, description = "Contribution id")
+    ),
+    request_body = PullRequestInput,
+    responses(
+        (status = 200, description = "PR recorded", body = Contribution),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Not the member who claimed the issue, and no role that allows this", body = ApiError),
+        (status = 404, description = "Contribution not found", body = ApiError),
+        (status = 409, description = "Contribution is not a live claim, or the PR is already recorded", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "contributions"
+)]
+pub async fn open_pull_request(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let input = match parse_body::<PullRequestInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+    let db = ctx.env.d1("DB")?;
+    let record = match transition(&req, &ctx, &db, ContributionState::PrOpened).await {
+        Ok(record) => record,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    let changed = DatabaseService::open_pull_request(&db, &record.contribution.id, &input).await;
+    updated(&ctx, &db, changed).await
+}
+
+/// Confirm that a contribution's pull request was merged
+#[utoipa::path(
+    post,
+    path = "/v1/contributions/{id}/merge",
+    params(
+        ("id" = String, Path, description = "Contribution id")
+    ),
+    request_body = MergeInput,
+    responses(
+        (status = 200, description = "Contribution merged; it now counts towards the term's stats", body = Contribution),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Contribution not found", body = ApiError),
+        (status = 409, description = "Contribution has no open PR", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "contributions"
+)]
+pub async fn merge_contribution(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let input = match parse_body::<MergeInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+    let db = ctx.env.d1("DB")?;
+    let record = match transition(&req, &ctx, &db, ContributionState::Merged).await {
+        Ok(record) => record,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    let merged_at = input.merged_at.unwrap_or_else(Utc::now);
+    let changed = DatabaseService::merge_contribution(&db, &record.contribution.id, merged_at).await;
+    if matches!(changed, Ok(true)) {
+        invalidate_stats(&ctx).await;
+    }
+    updated(&ctx, &db, changed).await
+}
+
+/// Give up a claim or an open PR so someone else can take the issue
+#[utoipa::path(
+    post,
+    path = "/v1/contributions/{id}/release",
+    params(
+        ("id" = String, Path, description = "Contribution id")
+    ),
+    responses(
+        (status = 200, description = "Contribution released", body = Contribution),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Not the member who claimed the issue, and no role that allows this", body = ApiError),
+        (status = 404, description = "Contribution not found", body = ApiError),
+        (status = 409, description = "Contribution no longer holds its issue", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "contributions"
+)]
+pub async fn release_contribution(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let db = ctx.env.d1("DB")?;
+    let record = match transition(&req, &ctx, &db, ContributionState::Released).await {
+        Ok(record) => record,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    let changed = DatabaseService::release_contribution(&db, &record.contribution.id).await;
+    updated(&ctx, &db, changed).await
+}
+
+/// The signed-in member's claims and contributions, newest first
+#[utoipa::path(
+    get,
+    path = "/v1/me/contributions",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction by claim time: desc (default) or asc")
+    ),
+    responses(
+        (status = 200, description = "Page of contributions in every state", body = Page<Contribution>),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 401, description = "Not signed in, or the session expired", body = ApiError)
+    ),
+    security(("session_cookie" = [])),
+    tag = "contributions"
+)]
+pub async fn get_my_contributions(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let Some(session) = session::current(&req, &ctx.env).await? else {
+        return error_response(&ctx, ApiError::unauthorized("Not signed in"));
+    };
+    let page = match PageRequest::from_url(&req.url()?) {
+        Ok(page) => page,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_member_contributions(&db, session.member_id, &page).await {
+        Ok(contributions) => {
+            let mut response = Response::from_json(&contributions)?;
+            response.headers_mut().set("Cache-Control", "private, no-store")?;
+            Ok(response)
+        }
+        Err(e) => error_response(&ctx, database_error("fetch contributions", e)),
+    }
+}
@@ -0,0 +1,323 @@
+use chrono::{Duration, Utc};
+use worker::*;
+
+use crate::auth::{self, Access};
+use crate::database::{BlogPostFilter, DatabaseService};
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::pagination::{enum_param, query_param, PageRequest};
+use crate::session;
+use crate::tokens;
+
+use super::admin::parse_body;
+
+/// How long a preview link works
+const PREVIEW_LINK_DAYS: i64 = 7;
+
+/// How the caller relates to a post, which decides the moves they may make
+#[derive(PartialEq)]
+enum Involvement {
+    Author,
+    Reviewer,
+    Other,
+}
+
+async fn involvement(req: &Request, env: &Env, post: &BlogPost) -> Involvement {
+    let Ok(Some(session)) = session::current(req, env).await else {
+        return Involvement::Other;
+    };
+    if post.is_author(&session.github_username) {
+        Involvement::Author
+    } else if post.reviewer.as_deref().is_some_and(|u| u.eq_ignore_ascii_case(&session.github_username)) {
+        Involvement::Reviewer
+    } else {
+        Involvement::Other
+    }
+}
+
+/// The post's authors and reviewer may act on it; anyone else needs an officer
+pub(super) async fn authorize_involved(req: &Request, env: &Env, post: &BlogPost) -> std::result::Result<(), ApiError> {
+    if involvement(req, env, post).await == Involvement::Other {
+        auth::authorize(req, env, Access::Officer).await?;
+    }
+    Ok(())
+}
+
+/// Authors submit and withdraw their drafts, and the assigned reviewer sends
+/// a post back, approves or schedules it. Every other move needs an officer.
+fn may_move(involvement: &Involvement, from: BlogPostStatus, to: BlogPostStatus) -> bool {
+    use BlogPostStatus::*;
+    match involvement {
+        Involvement::Author => matches!((from, to), (Draft, InReview) | (InReview, Draft)),
+        Involvement::Reviewer => matches!(
+            (from, to),
+            (InReview, Draft) | (InReview, Scheduled) | (InReview, Published) | (Scheduled, InReview)
+        ),
+        Involvement::Other => false,
+    }
+}
+
+pub(super) async fn load_post(ctx: &RouteContext<RequestInfo>, db: &D1Database) -> std::result::Result<BlogPost, ApiError> {
+    let slug = ctx.param("slug").map_or("", |v| v);
+    match DatabaseService::get_blog_post_by_slug(db, slug).await {
+        Ok(Some(post)) => Ok(post),
+        Ok(None) => Err(ApiError::not_found("Blog post not found")),
+        Err(e) => Err(database_error("fetch blog post", e)),
+    }
+}
+
+/// Posts in the editorial workflow. Officers see every post; other members
+/// see the posts they wrote or were asked to review.
+#[utoipa::path(
+    get,
+    path = "/v1/blog/editorial",
+    params(
+        ("status" = Option<BlogPostStatus>, Query, description = "Filter by status (default Draft)"),
+        ("reviewer" = Option<String>, Query, description = "Filter by the reviewer's GitHub username"),
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction by publication date: desc (default) or asc")
+    ),
+    responses(
+        (status = 200, description = "Page of posts", body = Page<BlogPost>),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "editorial"
+)]
+pub async fn get_editorial_posts(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let involving = if auth::authorize(&req, &ctx.env, Access::Officer).await.is_ok() {
+        None
+    } else {
+        match session::current(&req, &ctx.env).await? {
+            Some(session) => Some(session.github_username),
+            None => return error_response(&ctx, ApiError::unauthorized("Not signed in")),
+        }
+    };
+    let url = req.url()?;
+    let query = PageRequest::from_url(&url).and_then(|page| {
+        let filter = BlogPostFilter {
+            status: enum_param(&url, "status")?.unwrap_or(BlogPostStatus::Draft),
+            reviewer: query_param(&url, "reviewer"),
+            involving,
+            ..BlogPostFilter::default()
+        };
+        Ok((filter, page))
+    });
+    let (filter, page) = match query {
+        Ok(query) => query,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_blog_posts(&db, &filter, &page).await {
+        Ok(posts) => {
+            let mut response = Response::from_json(&posts)?;
+            response.headers_mut().set("Cache-Control", "private, no-store")?;
+            Ok(response)
+        }
+        Err(e) => error_response(&ctx, database_error("fetch blog posts", e)),
+    }
+}
+
+/// Move a post through the editorial workflow. Publishing makes it public
+/// immediately; scheduling publishes it at `publish_at`.
+#[utoipa::path(
+    post,
+    path = "/v1/blog/{slug}/status",
+    params(
+        ("slug" = String, Path, description = "Blog post slug")
+    ),
+    request_body = BlogStatusInput,
+    responses(
+        (status = 200, description = "Status changed", body = BlogPost),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Neither the author nor the reviewer allowed this move, and no role that allows it", body = ApiError),
+        (status = 404, description = "Blog post not found", body = ApiError),
+        (status = 409, description = "The post cannot move from its current status to this one", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "editorial"
+)]
+pub async fn set_blog_post_status(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let input = match parse_body::<BlogStatusInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+    let db = ctx.env.d1("DB")?;
+    let post = match load_post(&ctx, &db).await {
+        Ok(post) => post,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    let (from, to) = (post.status, input.status);
+    if !may_move(&involvement(&req, &ctx.env, &post).await, from, to)
+        && let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await
+    {
+        return error_response(&ctx, e);
+    }
+    if !from.can_become(to) {
+        return error_response(
+            &ctx,
+            ApiError::conflict(format!(
+                "A post that is {} cannot become {}",
+                from.to_string().to_lowercase(),
+                to.to_string().to_lowercase()
+            )),
+        );
+    }
+
+    // The publication date is when the post actually goes live; an archived
+    // post that is published again keeps its original date
+    let published_at = match (from, to) {
+        (_, BlogPostStatus::Scheduled) => input.publish_at,
+        (BlogPostStatus::Archived, _) => None,
+        (_, BlogPostStatus::Published) => Some(Utc::now()),
+        _ => None,
+    };
+    match DatabaseService::set_blog_post_status(&db, &post.id, from, to, published_at).await {
+        Ok(true) => {}
+        Ok(false) => return error_response(&ctx, ApiError::conflict("The post changed; reload and try again")),
+        Err(e) => return error_response(&ctx, database_error("update blog post", e)),
+    }
+    match DatabaseService::get_blog_post_by_slug(&db, &post.slug).await {
+        Ok(Some(post)) => Response::from_json(&post),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => error_response(&ctx, database_error("fetch blog post", e)),
+    }
+}
+
+/// Assign a member to review a post, or unassign with a null username
+#[utoipa::path(
+    put,
+    path = "/v1/blog/{slug}/reviewer",
+    params(
+        ("slug" = String, Path, description = "Blog post slug")
+    ),
+    request_body = ReviewerInput,
+    responses(
+        (status = 200, description = "Reviewer assigned", body = BlogPost),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Blog post not found", body = ApiError),
+        (status = 422, description = "Not a member, or one of the post's authors", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "editorial"
+)]
+pub async fn assign_blog_reviewer(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let input = match parse_body::<ReviewerInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    let db = ctx.env.d1("DB")?;
+    let post = match load_post(&ctx, &db).await {
+        Ok(post) => post,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    let reviewer_id = match input.github_username.as_deref().map(str::trim) {
+        None | Some("") => None,
+        Some(username) => {
+            if post.is_author(username) {
+                return error_response(&ctx, ApiError::validation(vec!["Authors cannot review their own posts".to_string()]));
+            }
+            match DatabaseService::get_member_id_by_github(&db, username).await {
+                Ok(Some(id)) => Some(id),
+                Ok(None) => {
+                    return error_response(&ctx, ApiError::validation(vec![format!("{} is not a member", username)]))
+                }
+                Err(e) => return error_response(&ctx, database_error("fetch member", e)),
+            }
+        }
+    };
+    match DatabaseService::set_blog_post_reviewer(&db, &post.id, reviewer_id).await {
+        Ok(true) => {}
+        Ok(false) => return error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => return error_response(&ctx, database_error("update blog post", e)),
+    }
+    match DatabaseService::get_blog_post_by_slug(&db, &post.slug).await {
+        Ok(Some(post)) => Response::from_json(&post),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => error_response(&ctx, database_error("fetch blog post", e)),
+    }
+}
+
+/// Make a private link to a post that anyone holding it can read, published
+/// or not. A new link replaces the post's previous one.
+#[utoipa::path(
+    post,
+    path = "/v1/blog/{slug}/preview",
+    params(
+        ("slug" = String, Path, description = "Blog post slug")
+    ),
+    responses(
+        (status = 201, description = "Preview link created", body = BlogPreviewLink),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Neither the author nor the reviewer, and no role that allows this", body = ApiError),
+        (status = 404, description = "Blog post not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "editorial"
+)]
+pub async fn create_blog_preview(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let db = ctx.env.d1("DB")?;
+    let post = match load_post(&ctx, &db).await {
+        Ok(post) => post,
+        Err(e) => return error_response(&ctx, e),
+    };
+    if let Err(e) = authorize_involved(&req, &ctx.env, &post).await {
+        return error_response(&ctx, e);
+    }
+
+    let token = tokens::new_token();
+    let expires_at = Utc::now() + Duration::days(PREVIEW_LINK_DAYS);
+    match DatabaseService::set_blog_post_preview(&db, &post.id, &tokens::token_hash(&token), expires_at).await {
+        Ok(true) => {}
+        Ok(false) => return error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => return error_response(&ctx, database_error("create preview link", e)),
+    }
+    let link = BlogPreviewLink {
+        url: format!("{}/blog/preview/{}", session::site_url(&ctx.env), token),
+        token,
+        expires_at,
+    };
+    Ok(Response::from_json(&link)?.with_status(201))
+}
+
+/// A post by preview token, in whatever status it is in
+#[utoipa::path(
+    get,
+    path = "/v1/blog/preview/{token}",
+    params(
+        ("token" = String, Path, description = "Token from the preview link")
+    ),
+    responses(
+        (status = 200, description = "Blog post", body = BlogPost),
+        (status = 404, description = "Unknown, replaced or expired preview link", body = ApiError)
+    ),
+    tag = "editorial"
+)]
+pub async fn get_blog_preview(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let token = ctx.param("token").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_blog_post_by_preview(&db, &tokens::token_hash(token)).await {
+        Ok(Some(post)) => {
+            let mut response = Response::from_json(&post)?;
+            response.headers_mut().set("Cache-Control", "private, no-store")?;
+            response.headers_mut().set("X-Robots-Tag", "noindex")?;
+            Ok(response)
+        }
+        Ok(None) => error_response(&ctx, ApiError::not_found("Preview link not found or expired")),
+        Err(e) => error_response(&ctx, database_error("fetch blog post", e)),
+    }
+}
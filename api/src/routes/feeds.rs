@@ -0,0 +1,218 @@
+use futures::future::join_all;
+use worker::*;
+
+use crate::content;
+use crate::database::{BlogPostFilter, DatabaseService};
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::feeds::{self, FeedInfo, PostContent};
+use crate::models::*;
+use crate::pagination::{bool_param, parse_enum, PageRequest};
+
+#[derive(Clone, Copy)]
+enum FeedFormat {
+    Rss,
+    Atom,
+}
+
+impl FeedFormat {
+    fn content_type(self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+        }
+    }
+}
+
+/// Build a blog feed, narrowed by the `:category` or `:tag` path parameter
+/// when the route has one. `?full=true` embeds each post's rendered markdown.
+async fn blog_feed(req: Request, ctx: RouteContext<RequestInfo>, format: FeedFormat) -> Result<Response> {
+    let url = req.url()?;
+    let query = PageRequest::from_url(&url).and_then(|page| {
+        let category = ctx
+            .param("category")
+            .map(|value| parse_enum::<BlogCategory>("category", value))
+            .transpose()?;
+        let filter = BlogPostFilter {
+            category,
+            tag: ctx.param("tag").cloned(),
+            ..Default::default()
+        };
+        let full = bool_param(&url, "full")?.unwrap_or(false);
+        Ok((filter, page, full))
+    });
+    let (filter, page, full) = match query {
+        Ok(query) => query,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    let db = ctx.env.d1("DB")?;
+
+    let posts = match DatabaseService::get_blog_posts(&db, &filter, &page).await {
+        Ok(page) => page.items,
+        Err(e) => return error_response(&ctx, database_error("fetch blog posts", e)),
+    };
+    let content = if full { rendered_content(&posts).await } else { PostContent::new() };
+
+    let (title, description) = match (&filter.category, &filter.tag) {
+        (Some(category), _) => (
+            format!("The Rust Club Blog: {}", category),
+            format!("{} posts from The Rust Club at Chico State", category),
+        ),
+        (None, Some(tag)) => (
+            format!("The Rust Club Blog: #{}", tag),
+            format!("Posts tagged '{}' from The Rust Club at Chico State", tag),
+        ),
+        (None, None) => (
+            "The Rust Club Blog".to_string(),
+            "Tutorials, guides and news from The Rust Club at Chico State".to_string(),
+        ),
+    };
+    let info = FeedInfo {
+        title,
+        description,
+        self_url: format!("{}{}", url.origin().ascii_serialization(), url.path()),
+    };
+
+    let body = match format {
+        FeedFormat::Rss => feeds::rss(&info, &posts, &content),
+        FeedFormat::Atom => feeds::atom(&info, &posts, &content),
+    };
+    let mut response = Response::ok(body)?;
+    let headers = response.headers_mut();
+    headers.set("Content-Type", format.content_type())?;
+    headers.set("Cache-Control", "public, max-age=900")?;
+    Ok(response)
+}
+
+/// Fetch and render every post's markdown in parallel. Posts whose markdown
+/// cannot be fetched are left out, so their entry falls back to the excerpt.
+async fn rendered_content(posts: &[BlogPost]) -> PostContent {
+    let rendered = join_all(posts.iter().map(|post| render_markdown(&post.markdown_url))).await;
+    posts
+        .iter()
+        .zip(rendered)
+        .filter_map(|(post, html)| html.map(|html| (post.id.clone(), html)))
+        .collect()
+}
+
+async fn render_markdown(markdown_url: &str) -> Option<String> {
+    let markdown = content::fetch_markdown(markdown_url).await?;
+    Some(markdown::to_html(&markdown))
+}
+
+/// RSS feed of blog posts
+#[utoipa::path(
+    get,
+    path = "/v1/blog/rss.xml",
+    params(
+        ("limit" = Option<u32>, Query, description = "Number of posts (default 20, max 100)"),
+        ("full" = Option<bool>, Query, description = "Include each post's rendered markdown")
+    ),
+    responses(
+        (status = 200, description = "RSS 2.0 feed", content_type = "application/rss+xml", body = String),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_blog_rss(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    blog_feed(req, ctx, FeedFormat::Rss).await
+}
+
+/// Atom feed of blog posts
+#[utoipa::path(
+    get,
+    path = "/v1/blog/atom.xml",
+    params(
+        ("limit" = Option<u32>, Query, description = "Number of posts (default 20, max 100)"),
+        ("full" = Option<bool>, Query, description = "Include each post's rendered markdown")
+    ),
+    responses(
+        (status = 200, description = "Atom 1.0 feed", content_type = "application/atom+xml", body = String),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_blog_atom(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    blog_feed(req, ctx, FeedFormat::Atom).await
+}
+
+/// RSS feed of blog posts in one category
+#[utoipa::path(
+    get,
+    path = "/v1/blog/categories/{category}/rss.xml",
+    params(
+        ("category" = BlogCategory, Path, description = "Category, e.g. WebDevelopment"),
+        ("limit" = Option<u32>, Query, description = "Number of posts (default 20, max 100)"),
+        ("full" = Option<bool>, Query, description = "Include each post's rendered markdown")
+    ),
+    responses(
+        (status = 200, description = "RSS 2.0 feed", content_type = "application/rss+xml", body = String),
+        (status = 400, description = "Unknown category or invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_blog_category_rss(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    blog_feed(req, ctx, FeedFormat::Rss).await
+}
+
+/// Atom feed of blog posts in one category
+#[utoipa::path(
+    get,
+    path = "/v1/blog/categories/{category}/atom.xml",
+    params(
+        ("category" = BlogCategory, Path, description = "Category, e.g. WebDevelopment"),
+        ("limit" = Option<u32>, Query, description = "Number of posts (default 20, max 100)"),
+        ("full" = Option<bool>, Query, description = "Include each post's rendered markdown")
+    ),
+    responses(
+        (status = 200, description = "Atom 1.0 feed", content_type = "application/atom+xml", body = String),
+        (status = 400, description = "Unknown category or invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_blog_category_atom(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    blog_feed(req, ctx, FeedFormat::Atom).await
+}
+
+/// RSS feed of blog posts with one tag
+#[utoipa::path(
+    get,
+    path = "/v1/blog/tags/{tag}/rss.xml",
+    params(
+        ("tag" = String, Path, description = "Tag, e.g. async"),
+        ("limit" = Option<u32>, Query, description = "Number of posts (default 20, max 100)"),
+        ("full" = Option<bool>, Query, description = "Include each post's rendered markdown")
+    ),
+    responses(
+        (status = 200, description = "RSS 2.0 feed", content_type = "application/rss+xml", body = String),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_blog_tag_rss(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    blog_feed(req, ctx, FeedFormat::Rss).await
+}
+
+/// Atom feed of blog posts with one tag
+#[utoipa::path(
+    get,
+    path = "/v1/blog/tags/{tag}/atom.xml",
+    params(
+        ("tag" = String, Path, description = "Tag, e.g. async"),
+        ("limit" = Option<u32>, Query, description = "Number of posts (default 20, max 100)"),
+        ("full" = Option<bool>, Query, description = "Include each post's rendered markdown")
+    ),
+    responses(
+        (status = 200, description = "Atom 1.0 feed", content_type = "application/atom+xml", body = String),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_blog_tag_atom(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    blog_feed(req, ctx, FeedFormat::Atom).await
+}
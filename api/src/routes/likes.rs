@@ -0,0 +1,103 @@
+use worker::*;
+
+use crate::database::DatabaseService;
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::rate_limit;
+use crate::visitor::Visitor;
+
+#[derive(Clone, Copy)]
+enum LikeAction {
+    Like,
+    Unlike,
+}
+
+async fn change_like(req: Request, ctx: RouteContext<RequestInfo>, action: LikeAction) -> Result<Response> {
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let visitor = Visitor::from_request(&req, &ctx.env);
+
+    let kv = ctx.env.kv("CACHE")?;
+    match rate_limit::LIKES.allow(&kv, &visitor.ip_hash).await {
+        Ok(true) => {}
+        Ok(false) => {
+            return error_response(&ctx, ApiError::rate_limited("Too many like requests, try again in a minute"))
+        }
+        // Fail open: a KV hiccup should not break liking
+        Err(e) => web_sys::console::warn_1(&format!("Rate limit check failed: {:?}", e).into()),
+    }
+
+    let db = ctx.env.d1("DB")?;
+    let result = match action {
+        LikeAction::Like => DatabaseService::like_blog_post(&db, slug, &visitor.fingerprint).await,
+        LikeAction::Unlike => DatabaseService::unlike_blog_post(&db, slug, &visitor.fingerprint).await,
+    };
+    match result {
+        Ok(Some(status)) => Response::from_json(&status),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => error_response(&ctx, database_error("update likes", e)),
+    }
+}
+
+/// Whether the current visitor has liked a blog post
+#[utoipa::path(
+    get,
+    path = "/v1/blog/{slug}/like",
+    params(
+        ("slug" = String, Path, description = "Blog post slug")
+    ),
+    responses(
+        (status = 200, description = "Like count and this visitor's like state", body = LikeStatus),
+        (status = 404, description = "Blog post not found", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_like_status(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let visitor = Visitor::from_request(&req, &ctx.env);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_like_status(&db, slug, &visitor.fingerprint).await {
+        Ok(Some(status)) => Response::from_json(&status),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => error_response(&ctx, database_error("fetch likes", e)),
+    }
+}
+
+/// Like a blog post. Repeated likes from the same visitor are ignored.
+#[utoipa::path(
+    post,
+    path = "/v1/blog/{slug}/like",
+    params(
+        ("slug" = String, Path, description = "Blog post slug")
+    ),
+    responses(
+        (status = 200, description = "Updated like count", body = LikeStatus),
+        (status = 404, description = "Blog post not found", body = ApiError),
+        (status = 429, description = "Too many like requests from this address", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn like_blog_post(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    change_like(req, ctx, LikeAction::Like).await
+}
+
+/// Remove the current visitor's like from a blog post
+#[utoipa::path(
+    delete,
+    path = "/v1/blog/{slug}/like",
+    params(
+        ("slug" = String, Path, description = "Blog post slug")
+    ),
+    responses(
+        (status = 200, description = "Updated like count", body = LikeStatus),
+        (status = 404, description = "Blog post not found", body = ApiError),
+        (status = 429, description = "Too many like requests from this address", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn unlike_blog_post(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    change_like(req, ctx, LikeAction::Unlike).await
+}
@@ -0,0 +1,161 @@
+use worker::*;
+
+use crate::database::{DatabaseService, MemberFilter};
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::pagination::{enum_param, query_param, PageRequest};
+use crate::session::{self, Session};
+
+use super::admin::parse_body;
+
+/// Whether `viewer` may see a profile. Hidden profiles and those the viewer
+/// may not see are reported as missing, so their existence does not leak.
+fn can_view(visibility: ProfileVisibility, member_id: i64, viewer: Option<&Session>) -> bool {
+    match (visibility, viewer) {
+        (ProfileVisibility::Public, _) => true,
+        (ProfileVisibility::Members, Some(_)) => true,
+        (_, Some(viewer)) => viewer.member_id == member_id,
+        (_, None) => false,
+    }
+}
+
+/// Responses that depend on who is signed in must not be shared by caches
+fn private_json<T: serde::Serialize>(value: &T) -> Result<Response> {
+    let mut response = Response::from_json(value)?;
+    response.headers_mut().set("Cache-Control", "private, no-store")?;
+    Ok(response)
+}
+
+/// The member directory
+#[utoipa::path(
+    get,
+    path = "/v1/members",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction by name: asc (default) or desc"),
+        ("role" = Option<MemberRole>, Query, description = "Only members holding this role"),
+        ("skill" = Option<String>, Query, description = "Only members listing this skill")
+    ),
+    responses(
+        (status = 200, description = "Page of members whose profiles the caller may see; members-only profiles are listed when signed in", body = Page<MemberSummary>),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "members"
+)]
+pub async fn get_members(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let url = req.url()?;
+    let viewer = session::current(&req, &ctx.env).await?;
+    let query = PageRequest::from_url_ascending(&url).and_then(|page| {
+        let filter = MemberFilter {
+            role: enum_param(&url, "role")?,
+            skill: query_param(&url, "skill"),
+            signed_in: viewer.is_some(),
+        };
+        Ok((filter, page))
+    });
+    let (filter, page) = match query {
+        Ok(query) => query,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_members(&db, &filter, &page).await {
+        Ok(members) => private_json(&members),
+        Err(e) => error_response(&ctx, database_error("fetch members", e)),
+    }
+}
+
+/// A member's profile: their posts, projects, merged PRs and, unless they
+/// hide it, what they attended
+#[utoipa::path(
+    get,
+    path = "/v1/members/{github}",
+    params(
+        ("github" = String, Path, description = "Member's GitHub username")
+    ),
+    responses(
+        (status = 200, description = "Member profile", body = MemberProfile),
+        (status = 404, description = "Member not found, or their profile is not visible to the caller", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "members"
+)]
+pub async fn get_member(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let github = ctx.param("github").map_or("", |v| v);
+    let viewer = session::current(&req, &ctx.env).await?;
+    let db = ctx.env.d1("DB")?;
+
+    let record = match DatabaseService::get_member(&db, github).await {
+        Ok(Some(record)) if can_view(record.visibility, record.id, viewer.as_ref()) => record,
+        Ok(_) => return error_response(&ctx, ApiError::not_found("Member not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch member", e)),
+    };
+    match DatabaseService::get_member_profile(&db, record).await {
+        Ok(profile) => private_json(&profile),
+        Err(e) => error_response(&ctx, database_error("fetch member profile", e)),
+    }
+}
+
+/// The signed-in member's profile settings
+#[utoipa::path(
+    get,
+    path = "/v1/me/profile",
+    responses(
+        (status = 200, description = "Bio, skills and privacy settings", body = ProfileSettings),
+        (status = 401, description = "Not signed in, or the session expired", body = ApiError)
+    ),
+    security(("session_cookie" = [])),
+    tag = "members"
+)]
+pub async fn get_profile_settings(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let Some(session) = session::current(&req, &ctx.env).await? else {
+        return error_response(&ctx, ApiError::unauthorized("Not signed in"));
+    };
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_profile_settings(&db, session.member_id).await {
+        Ok(Some(settings)) => private_json(&settings),
+        Ok(None) => error_response(&ctx, ApiError::unauthorized("Not signed in")),
+        Err(e) => error_response(&ctx, database_error("fetch profile settings", e)),
+    }
+}
+
+/// Update the signed-in member's bio, skills and privacy settings
+#[utoipa::path(
+    put,
+    path = "/v1/me/profile",
+    request_body = ProfileSettings,
+    responses(
+        (status = 200, description = "Settings saved", body = ProfileSettings),
+        (status = 401, description = "Not signed in, or the session expired", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("session_cookie" = [])),
+    tag = "members"
+)]
+pub async fn update_profile_settings(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let Some(session) = session::current(&req, &ctx.env).await? else {
+        return error_response(&ctx, ApiError::unauthorized("Not signed in"));
+    };
+    let input = match parse_body::<ProfileSettings>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::update_profile_settings(&db, session.member_id, &input).await {
+        Ok(true) => {}
+        Ok(false) => return error_response(&ctx, ApiError::unauthorized("Not signed in")),
+        Err(e) => return error_response(&ctx, database_error("save profile settings", e)),
+    }
+    match DatabaseService::get_profile_settings(&db, session.member_id).await {
+        Ok(Some(settings)) => private_json(&settings),
+        Ok(None) => error_response(&ctx, ApiError::unauthorized("Not signed in")),
+        Err(e) => error_response(&ctx, database_error("fetch profile settings", e)),
+    }
+}
@@ -1,45 +1,91 @@
 use worker::*;
 use serde_json::json;
-use chrono::Utc;
+use chrono::{Duration, Utc};
 use utoipa::OpenApi;
 
 use crate::models::*;
-use crate::database::DatabaseService;
+use crate::database::{
+    BlogPostFilter, DatabaseService, EventFilter, IssueFilter, ProjectFilter, DEFAULT_EVENT_WINDOW_DAYS,
+    MAX_EVENT_WINDOW_DAYS,
+};
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::pagination::{bool_param, enum_param, query_param, time_param, PageRequest};
+use crate::stats;
 use crate::ApiDoc;
 
-/// Get club statistics
+mod admin;
+mod advocacy;
+mod attendance;
+mod auth;
+mod authors;
+mod calendar;
+mod claims;
+mod editorial;
+mod feeds;
+mod likes;
+mod members;
+mod revisions;
+mod roles;
+mod search;
+mod series;
+mod terms;
+mod uploads;
+mod views;
+mod workshops;
+pub use admin::*;
+pub use advocacy::*;
+pub use attendance::*;
+pub use auth::*;
+pub use authors::*;
+pub use calendar::*;
+pub use claims::*;
+pub use editorial::*;
+pub use feeds::*;
+pub use likes::*;
+pub use members::*;
+pub use revisions::*;
+pub use roles::*;
+pub use search::*;
+pub use series::*;
+pub use terms::*;
+pub use uploads::*;
+pub use views::*;
+pub use workshops::*;
+
+/// Get club statistics for a term
 #[utoipa::path(
     get,
     path = "/v1/stats",
+    params(
+        ("term" = Option<String>, Query, description = "Term slug such as fall-2025 (default: the current term)")
+    ),
     responses(
-        (status = 200, description = "Club statistics", body = Stats)
+        (status = 200, description = "Club statistics", body = Stats),
+        (status = 400, description = "Unknown term", body = ApiError),
+        (status = 404, description = "No term has started yet", body = ApiError),
+        (status = 500, description = "Statistics could not be computed", body = ApiError)
     ),
     tag = "public"
 )]
-pub async fn get_stats(_req: Request, ctx: RouteContext<()>) -> Result<Response> {
+pub async fn get_stats(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let url = req.url()?;
     let kv = ctx.env.kv("CACHE")?;
     let db = ctx.env.d1("DB")?;
+    let term = match terms::term_param_or_current(&url, &db).await {
+        Ok(term) => term,
+        Err(e) => return error_response(&ctx, e),
+    };
 
-    // Try to get stats from KV cache first
-    if let Ok(Some(cached_stats)) = kv.get("club_stats").text().await {
-        if let Ok(stats) = serde_json::from_str::<Stats>(&cached_stats) {
-            return Response::from_json(&stats);
-        }
+    if let Some(stats) = stats::cached(&kv, &term).await {
+        return Response::from_json(&stats);
     }
 
-    // If not in cache, calculate from D1 and cache it
-    let member_count = DatabaseService::get_member_count(&db).await.unwrap_or(0);
-
-    let stats = Stats {
-        active_members: member_count,
-        prs_merged_this_semester: 47, // TODO: Get from GitHub API or manual input
-        workshops_held: 12, // TODO: Count from events table
-        projects_contributed_to: 8, // TODO: Count from projects table
+    let stats = match DatabaseService::get_stats(&db, &term).await {
+        Ok(stats) => stats,
+        Err(e) => return error_response(&ctx, database_error("compute stats", e)),
     };
-
-    // Cache for 1 hour
-    if let Ok(stats_json) = serde_json::to_string(&stats) {
-        let _ = kv.put("club_stats", stats_json)?.expiration_ttl(3600).execute().await;
+    if let Err(e) = stats::store(&kv, &term, &stats).await {
+        web_sys::console::warn_1(&format!("Failed to cache stats: {:?}", e).into());
     }
 
     Response::from_json(&stats)
@@ -49,26 +95,89 @@ pub async fn get_stats(_req: Request, ctx: RouteContext<()>) -> Result<Response>
 #[utoipa::path(
     get,
     path = "/v1/events",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction by start time: asc (default) or desc"),
+        ("event_type" = Option<EventType>, Query, description = "Only events of this type"),
+        ("from" = Option<String>, Query, description = "Window start, RFC 3339 or YYYY-MM-DD (default now)"),
+        ("to" = Option<String>, Query, description = "Window end, RFC 3339 or YYYY-MM-DD (default from + 90 days, at most 366 days)"),
+        ("term" = Option<String>, Query, description = "Use this term's dates as the window; cannot be combined with from/to")
+    ),
     responses(
-        (status = 200, description = "List of upcoming events", body = [Event])
+        (status = 200, description = "Page of upcoming events, with recurring events expanded into one item per occurrence", body = Page<Event>),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
     ),
     tag = "public"
 )]
-pub async fn get_events(_req: Request, ctx: RouteContext<()>) -> Result<Response> {
+pub async fn get_events(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let url = req.url()?;
     let db = ctx.env.d1("DB")?;
+    let term = match terms::term_param(&url, &db).await {
+        Ok(term) => term,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let query = PageRequest::from_url_ascending(&url).and_then(|page| {
+        let (from, to) = match &term {
+            Some(_) if query_param(&url, "from").is_some() || query_param(&url, "to").is_some() => {
+                return Err("term cannot be combined with from or to".to_string());
+            }
+            Some(term) => term.bounds(),
+            None => {
+                let from = time_param(&url, "from")?.unwrap_or_else(Utc::now);
+                let to = time_param(&url, "to")?
+                    .unwrap_or(from + Duration::days(DEFAULT_EVENT_WINDOW_DAYS));
+                (from, to)
+            }
+        };
+        if to <= from {
+            return Err("to must be after from".to_string());
+        }
+        if to - from > Duration::days(MAX_EVENT_WINDOW_DAYS) {
+            return Err(format!("the from/to window can span at most {} days", MAX_EVENT_WINDOW_DAYS));
+        }
+        let filter = EventFilter {
+            event_type: enum_param(&url, "event_type")?,
+            from,
+            to,
+        };
+        Ok((filter, page))
+    });
+    let (filter, page) = match query {
+        Ok(query) => query,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
 
-    match DatabaseService::get_events(&db).await {
+    match DatabaseService::get_events(&db, &filter, &page).await {
         Ok(events) => Response::from_json(&events),
-        Err(e) => {
-            // Log the error for debugging
-            web_sys::console::error_1(&format!("Failed to fetch events: {:?}", e).into());
+        Err(e) => error_response(&ctx, database_error("fetch events", e)),
+    }
+}
 
-            let error = json!({
-                "error": "Internal Server Error",
-                "message": "Unable to fetch events at this time"
-            });
-            Ok(Response::from_json(&error)?.with_status(500))
-        }
+/// Get a single event as stored, with its RSVP counts. Recurring events are
+/// not expanded; `starts_at` is the first occurrence.
+#[utoipa::path(
+    get,
+    path = "/v1/events/{id}",
+    params(
+        ("id" = String, Path, description = "Event id")
+    ),
+    responses(
+        (status = 200, description = "Event details", body = Event),
+        (status = 404, description = "Event not found", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_event(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_event(&db, id).await {
+        Ok(Some(event)) => Response::from_json(&event),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Event not found")),
+        Err(e) => error_response(&ctx, database_error("fetch event", e)),
     }
 }
 
@@ -76,25 +185,38 @@ pub async fn get_events(_req: Request, ctx: RouteContext<()>) -> Result<Response
 #[utoipa::path(
     get,
     path = "/v1/issues",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction: desc (default) or asc"),
+        ("difficulty" = Option<DifficultyLevel>, Query, description = "Only issues of this difficulty"),
+        ("repo" = Option<String>, Query, description = "Only issues in this repository (owner/name)")
+    ),
     responses(
-        (status = 200, description = "List of good first issues", body = [Issue])
+        (status = 200, description = "Page of open good first issues", body = Page<Issue>),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
     ),
     tag = "public"
 )]
-pub async fn get_issues(_req: Request, ctx: RouteContext<()>) -> Result<Response> {
+pub async fn get_issues(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let url = req.url()?;
+    let query = PageRequest::from_url(&url).and_then(|page| {
+        let filter = IssueFilter {
+            difficulty: enum_param(&url, "difficulty")?,
+            repo: query_param(&url, "repo"),
+        };
+        Ok((filter, page))
+    });
+    let (filter, page) = match query {
+        Ok(query) => query,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
     let db = ctx.env.d1("DB")?;
 
-    match DatabaseService::get_issues(&db).await {
+    match DatabaseService::get_issues(&db, &filter, &page).await {
         Ok(issues) => Response::from_json(&issues),
-        Err(e) => {
-            web_sys::console::error_1(&format!("Failed to fetch issues: {:?}", e).into());
-
-            let error = json!({
-                "error": "Internal Server Error",
-                "message": "Unable to fetch issues at this time"
-            });
-            Ok(Response::from_json(&error)?.with_status(500))
-        }
+        Err(e) => error_response(&ctx, database_error("fetch issues", e)),
     }
 }
 
@@ -102,25 +224,63 @@ pub async fn get_issues(_req: Request, ctx: RouteContext<()>) -> Result<Response
 #[utoipa::path(
     get,
     path = "/v1/projects",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction: desc (default) or asc"),
+        ("status" = Option<ProjectStatus>, Query, description = "Only projects with this status"),
+        ("contributors_needed" = Option<bool>, Query, description = "Only projects that are (or are not) looking for contributors")
+    ),
     responses(
-        (status = 200, description = "List of student projects", body = [Project])
+        (status = 200, description = "Page of student projects", body = Page<Project>),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
     ),
     tag = "public"
 )]
-pub async fn get_projects(_req: Request, ctx: RouteContext<()>) -> Result<Response> {
+pub async fn get_projects(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let url = req.url()?;
+    let query = PageRequest::from_url(&url).and_then(|page| {
+        let filter = ProjectFilter {
+            status: enum_param(&url, "status")?,
+            contributors_needed: bool_param(&url, "contributors_needed")?,
+        };
+        Ok((filter, page))
+    });
+    let (filter, page) = match query {
+        Ok(query) => query,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
     let db = ctx.env.d1("DB")?;
 
-    match DatabaseService::get_projects(&db).await {
+    match DatabaseService::get_projects(&db, &filter, &page).await {
         Ok(projects) => Response::from_json(&projects),
-        Err(e) => {
-            web_sys::console::error_1(&format!("Failed to fetch projects: {:?}", e).into());
+        Err(e) => error_response(&ctx, database_error("fetch projects", e)),
+    }
+}
 
-            let error = json!({
-                "error": "Internal Server Error",
-                "message": "Unable to fetch projects at this time"
-            });
-            Ok(Response::from_json(&error)?.with_status(500))
-        }
+/// Get a single project with its full contributor roster
+#[utoipa::path(
+    get,
+    path = "/v1/projects/{id}",
+    params(
+        ("id" = String, Path, description = "Project id")
+    ),
+    responses(
+        (status = 200, description = "Project details", body = Project),
+        (status = 404, description = "Project not found", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_project(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_project(&db, id).await {
+        Ok(Some(project)) => Response::from_json(&project),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Project not found")),
+        Err(e) => error_response(&ctx, database_error("fetch project", e)),
     }
 }
 
@@ -133,7 +293,7 @@ pub async fn get_projects(_req: Request, ctx: RouteContext<()>) -> Result<Respon
     ),
     tag = "health"
 )]
-pub fn health_check(_req: Request, _ctx: RouteContext<()>) -> Result<Response> {
+pub fn health_check(_req: Request, _ctx: RouteContext<RequestInfo>) -> Result<Response> {
     let health = HealthCheck {
         status: "healthy".to_string(),
         timestamp: Utc::now(),
@@ -144,7 +304,7 @@ pub fn health_check(_req: Request, _ctx: RouteContext<()>) -> Result<Response> {
 }
 
 /// API information endpoint
-pub fn api_info(_req: Request, _ctx: RouteContext<()>) -> Result<Response> {
+pub fn api_info(_req: Request, _ctx: RouteContext<RequestInfo>) -> Result<Response> {
     let info = json!({
         "name": "The Rust Club API",
         "version": "1.0.0",
@@ -153,10 +313,15 @@ pub fn api_info(_req: Request, _ctx: RouteContext<()>) -> Result<Response> {
         "endpoints": {
             "stats": "/v1/stats",
             "events": "/v1/events",
+            "events_calendar": "/v1/events.ics",
             "issues": "/v1/issues",
             "projects": "/v1/projects",
             "blog": "/v1/blog",
-            "featured_blog": "/v1/blog/featured"
+            "featured_blog": "/v1/blog/featured",
+            "blog_rss": "/v1/blog/rss.xml",
+            "blog_atom": "/v1/blog/atom.xml",
+            "search": "/v1/search",
+            "members": "/v1/members"
         },
         "repository": "https://github.com/rust-club/chico-rs"
     });
@@ -165,13 +330,13 @@ pub fn api_info(_req: Request, _ctx: RouteContext<()>) -> Result<Response> {
 }
 
 /// Serve Swagger UI
-pub fn serve_swagger_ui(_req: Request, _ctx: RouteContext<()>) -> Result<Response> {
+pub fn serve_swagger_ui(_req: Request, _ctx: RouteContext<RequestInfo>) -> Result<Response> {
     let html = include_str!("../../static/swagger-ui.html");
     Response::from_html(html)
 }
 
 /// Serve OpenAPI specification
-pub fn serve_openapi_spec(_req: Request, _ctx: RouteContext<()>) -> Result<Response> {
+pub fn serve_openapi_spec(_req: Request, _ctx: RouteContext<RequestInfo>) -> Result<Response> {
     let spec = ApiDoc::openapi();
     Response::from_json(&spec)
 }
@@ -180,25 +345,51 @@ pub fn serve_openapi_spec(_req: Request, _ctx: RouteContext<()>) -> Result<Respo
 #[utoipa::path(
     get,
     path = "/v1/blog",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction by publication date: desc (default) or asc"),
+        ("post_type" = Option<BlogPostType>, Query, description = "Only posts of this type"),
+        ("category" = Option<BlogCategory>, Query, description = "Only posts in this category"),
+        ("difficulty" = Option<DifficultyLevel>, Query, description = "Only posts at this difficulty level"),
+        ("author" = Option<String>, Query, description = "Only posts this GitHub username wrote or co-wrote"),
+        ("tag" = Option<String>, Query, description = "Only posts with this tag"),
+        ("term" = Option<String>, Query, description = "Only posts published during this term")
+    ),
     responses(
-        (status = 200, description = "List of blog posts", body = [BlogPost])
+        (status = 200, description = "Page of blog posts", body = Page<BlogPost>),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
     ),
     tag = "blog"
 )]
-pub async fn get_blog_posts(_req: Request, ctx: RouteContext<()>) -> Result<Response> {
+pub async fn get_blog_posts(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let url = req.url()?;
     let db = ctx.env.d1("DB")?;
+    let term = match terms::term_param(&url, &db).await {
+        Ok(term) => term,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let query = PageRequest::from_url(&url).and_then(|page| {
+        let filter = BlogPostFilter {
+            post_type: enum_param(&url, "post_type")?,
+            category: enum_param(&url, "category")?,
+            difficulty: enum_param(&url, "difficulty")?,
+            author: query_param(&url, "author"),
+            tag: query_param(&url, "tag"),
+            published_within: term.as_ref().map(Term::bounds),
+            ..BlogPostFilter::default()
+        };
+        Ok((filter, page))
+    });
+    let (filter, page) = match query {
+        Ok(query) => query,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
 
-    match DatabaseService::get_blog_posts(&db).await {
+    match DatabaseService::get_blog_posts(&db, &filter, &page).await {
         Ok(posts) => Response::from_json(&posts),
-        Err(e) => {
-            web_sys::console::error_1(&format!("Failed to fetch blog posts: {:?}", e).into());
-
-            let error = json!({
-                "error": "Internal Server Error",
-                "message": "Unable to fetch blog posts at this time"
-            });
-            Ok(Response::from_json(&error)?.with_status(500))
-        }
+        Err(e) => error_response(&ctx, database_error("fetch blog posts", e)),
     }
 }
 
@@ -207,11 +398,12 @@ pub async fn get_blog_posts(_req: Request, ctx: RouteContext<()>) -> Result<Resp
     get,
     path = "/v1/blog/featured",
     responses(
-        (status = 200, description = "List of featured blog posts", body = [BlogPost])
+        (status = 200, description = "List of featured blog posts", body = [BlogPost]),
+        (status = 500, description = "Database failure", body = ApiError)
     ),
     tag = "blog"
 )]
-pub async fn get_featured_blog_posts(_req: Request, ctx: RouteContext<()>) -> Result<Response> {
+pub async fn get_featured_blog_posts(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
     let kv = ctx.env.kv("CACHE")?;
     let db = ctx.env.d1("DB")?;
 
@@ -221,15 +413,7 @@ pub async fn get_featured_blog_posts(_req: Request, ctx: RouteContext<()>) -> Re
             if let Ok(featured_ids) = serde_json::from_str::<Vec<String>>(&featured_ids_json) {
                 match DatabaseService::get_blog_posts_by_ids(&db, &featured_ids).await {
                     Ok(posts) => Response::from_json(&posts),
-                    Err(e) => {
-                        web_sys::console::error_1(&format!("Failed to fetch featured posts: {:?}", e).into());
-
-                        let error = json!({
-                            "error": "Internal Server Error",
-                            "message": "Unable to fetch featured posts at this time"
-                        });
-                        Ok(Response::from_json(&error)?.with_status(500))
-                    }
+                    Err(e) => error_response(&ctx, database_error("fetch featured posts", e)),
                 }
             } else {
                 // Invalid JSON in KV, return empty array
@@ -252,35 +436,24 @@ pub async fn get_featured_blog_posts(_req: Request, ctx: RouteContext<()>) -> Re
     ),
     responses(
         (status = 200, description = "Blog post", body = BlogPost),
-        (status = 404, description = "Blog post not found")
+        (status = 404, description = "Blog post not found", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
     ),
     tag = "blog"
 )]
-pub async fn get_blog_post_by_slug(_req: Request, ctx: RouteContext<()>) -> Result<Response> {
+pub async fn get_blog_post_by_slug(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
     let slug = ctx.param("slug").map_or("", |v| v);
     let db = ctx.env.d1("DB")?;
 
     match DatabaseService::get_blog_post_by_slug(&db, slug).await {
-        Ok(Some(post)) => {
-            // Increment view count
-            let _ = DatabaseService::increment_blog_post_views(&db, &post.id).await;
-            Response::from_json(&post)
-        }
-        Ok(None) => {
-            let error = json!({
-                "error": "Not Found",
-                "message": "Blog post not found"
-            });
-            Ok(Response::from_json(&error)?.with_status(404))
-        }
-        Err(e) => {
-            web_sys::console::error_1(&format!("Failed to fetch blog post: {:?}", e).into());
-
-            let error = json!({
-                "error": "Internal Server Error",
-                "message": "Unable to fetch blog post at this time"
-            });
-            Ok(Response::from_json(&error)?.with_status(500))
+        // Reads have no side effects (views arrive via the beacon), so edges may cache them
+        Ok(Some(post)) if post.status == BlogPostStatus::Published => {
+            let mut response = Response::from_json(&post)?;
+            response.headers_mut().set("Cache-Control", "public, max-age=60")?;
+            Ok(response)
         }
+        // Unpublished posts are only reachable through a preview link
+        Ok(_) => error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => error_response(&ctx, database_error("fetch blog post", e)),
     }
 }
\ No newline at end of file
@@ -0,0 +1,271 @@
+use worker::*;
+
+use crate::auth::{self, Access};
+use crate::content;
+use crate::database::{DatabaseService, NewRevision};
+use crate::diff;
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::pagination::{query_param, PageRequest};
+
+use super::admin::index_blog_post_body;
+use super::authors::check_co_authors;
+use super::editorial::{authorize_involved, load_post};
+use super::series::check_series_part;
+
+/// Load the post named in the path and check the caller may see its history
+async fn load_involved_post(req: &Request, ctx: &RouteContext<RequestInfo>, db: &D1Database) -> std::result::Result<BlogPost, ApiError> {
+    let post = load_post(ctx, db).await?;
+    authorize_involved(req, &ctx.env, &post).await?;
+    Ok(post)
+}
+
+fn parse_number(name: &str, value: Option<&str>) -> std::result::Result<u32, ApiError> {
+    value
+        .and_then(|value| value.parse::<u32>().ok())
+        .filter(|number| *number > 0)
+        .ok_or_else(|| ApiError::bad_request(format!("{} must be a revision number", name)))
+}
+
+async fn load_revision(db: &D1Database, post: &BlogPost, number: u32) -> std::result::Result<BlogRevision, ApiError> {
+    match DatabaseService::get_blog_revision(db, &post.id, number).await {
+        Ok(Some(revision)) => Ok(revision),
+        Ok(None) => Err(ApiError::not_found(format!("Revision {} not found", number))),
+        Err(e) => Err(database_error("fetch revision", e)),
+    }
+}
+
+/// Every saved version of a post, newest first
+#[utoipa::path(
+    get,
+    path = "/v1/blog/{slug}/revisions",
+    params(
+        ("slug" = String, Path, description = "Blog post slug"),
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction by revision number: desc (default) or asc")
+    ),
+    responses(
+        (status = 200, description = "Page of revisions, without their content", body = Page<BlogRevisionSummary>),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Neither the author nor the reviewer, and no role that allows this", body = ApiError),
+        (status = 404, description = "Blog post not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "editorial"
+)]
+pub async fn get_blog_revisions(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let page = match PageRequest::from_url(&req.url()?) {
+        Ok(page) => page,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    let db = ctx.env.d1("DB")?;
+    let post = match load_involved_post(&req, &ctx, &db).await {
+        Ok(post) => post,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    match DatabaseService::get_blog_revisions(&db, &post.id, &page).await {
+        Ok(revisions) => {
+            let mut response = Response::from_json(&revisions)?;
+            response.headers_mut().set("Cache-Control", "private, no-store")?;
+            Ok(response)
+        }
+        Err(e) => error_response(&ctx, database_error("fetch revisions", e)),
+    }
+}
+
+/// One saved version of a post, with its metadata and markdown
+#[utoipa::path(
+    get,
+    path = "/v1/blog/{slug}/revisions/{number}",
+    params(
+        ("slug" = String, Path, description = "Blog post slug"),
+        ("number" = u32, Path, description = "Revision number")
+    ),
+    responses(
+        (status = 200, description = "Revision", body = BlogRevision),
+        (status = 400, description = "Invalid revision number", body = ApiError),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Neither the author nor the reviewer, and no role that allows this", body = ApiError),
+        (status = 404, description = "Blog post or revision not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "editorial"
+)]
+pub async fn get_blog_revision(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let number = match parse_number("number", ctx.param("number").map(String::as_str)) {
+        Ok(number) => number,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let db = ctx.env.d1("DB")?;
+    let post = match load_involved_post(&req, &ctx, &db).await {
+        Ok(post) => post,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    match load_revision(&db, &post, number).await {
+        Ok(revision) => {
+            let mut response = Response::from_json(&revision)?;
+            response.headers_mut().set("Cache-Control", "private, no-store")?;
+            Ok(response)
+        }
+        Err(e) => error_response(&ctx, e),
+    }
+}
+
+/// What changed between two revisions: metadata fields and a line diff of
+/// the markdown
+#[utoipa::path(
+    get,
+    path = "/v1/blog/{slug}/revisions/diff",
+    params(
+        ("slug" = String, Path, description = "Blog post slug"),
+        ("from" = u32, Query, description = "Revision to compare from, usually the older one"),
+        ("to" = u32, Query, description = "Revision to compare to")
+    ),
+    responses(
+        (status = 200, description = "Differences from `from` to `to`", body = BlogRevisionDiff),
+        (status = 400, description = "Missing or invalid revision number", body = ApiError),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Neither the author nor the reviewer, and no role that allows this", body = ApiError),
+        (status = 404, description = "Blog post or revision not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "editorial"
+)]
+pub async fn diff_blog_revisions(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let url = req.url()?;
+    let numbers = parse_number("from", query_param(&url, "from").as_deref())
+        .and_then(|from| Ok((from, parse_number("to", query_param(&url, "to").as_deref())?)));
+    let (from, to) = match numbers {
+        Ok(numbers) => numbers,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let db = ctx.env.d1("DB")?;
+    let post = match load_involved_post(&req, &ctx, &db).await {
+        Ok(post) => post,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    let older = match load_revision(&db, &post, from).await {
+        Ok(revision) => revision,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let newer = match load_revision(&db, &post, to).await {
+        Ok(revision) => revision,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let mut response = Response::from_json(&diff::revision_diff(&older, &newer))?;
+    response.headers_mut().set("Cache-Control", "private, no-store")?;
+    Ok(response)
+}
+
+/// Make an old revision current again by saving it as a new revision. The
+/// post keeps its slug and publication date. If the markdown at the old
+/// revision's `markdown_url` has changed since, the post is pointed at the
+/// revision's stored snapshot instead.
+#[utoipa::path(
+    post,
+    path = "/v1/blog/{slug}/revisions/{number}/restore",
+    params(
+        ("slug" = String, Path, description = "Blog post slug"),
+        ("number" = u32, Path, description = "Revision to restore")
+    ),
+    responses(
+        (status = 200, description = "Revision restored", body = BlogPost),
+        (status = 400, description = "Invalid revision number", body = ApiError),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Blog post or revision not found", body = ApiError),
+        (status = 409, description = "Another post now holds the revision's series part", body = ApiError),
+        (status = 422, description = "The revision no longer passes validation, e.g. its series or a co-author was deleted", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "editorial"
+)]
+pub async fn restore_blog_revision(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let actor = match auth::authorize(&req, &ctx.env, Access::Officer).await {
+        Ok(actor) => actor,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let number = match parse_number("number", ctx.param("number").map(String::as_str)) {
+        Ok(number) => number,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let db = ctx.env.d1("DB")?;
+    let post = match load_post(&ctx, &db).await {
+        Ok(post) => post,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let revision = match load_revision(&db, &post, number).await {
+        Ok(revision) => revision,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    let mut input = revision.post;
+    input.slug = post.slug;
+    input.published_at = post.published_at;
+    if let Some(markdown) = &revision.markdown
+        && content::fetch_markdown(&input.markdown_url).await.as_deref() != Some(markdown.as_str())
+    {
+        let origin = req.url()?.origin().ascii_serialization();
+        input.markdown_url = format!("{}/v1/blog/markdown/{}", origin, content::markdown_hash(markdown));
+    }
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+    if let Err(e) = check_co_authors(&db, &input).await {
+        return error_response(&ctx, e);
+    }
+    if let Err(e) = check_series_part(&db, &input, &post.id).await {
+        return error_response(&ctx, e);
+    }
+
+    let created_by = actor.label();
+    let new_revision = NewRevision {
+        markdown: revision.markdown.as_deref(),
+        created_by: &created_by,
+        restored_from: Some(number),
+    };
+    match DatabaseService::update_blog_post(&db, &post.id, &input, &new_revision).await {
+        Ok(Some(restored)) => {
+            index_blog_post_body(&db, &restored.id, revision.markdown.as_deref()).await;
+            Response::from_json(&restored)
+        }
+        Ok(None) => error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => error_response(&ctx, database_error("restore revision", e)),
+    }
+}
+
+/// A markdown snapshot stored with a revision, by its SHA-256. Restored posts
+/// whose original markdown was overwritten are served from here.
+#[utoipa::path(
+    get,
+    path = "/v1/blog/markdown/{hash}",
+    params(
+        ("hash" = String, Path, description = "Hex SHA-256 of the markdown")
+    ),
+    responses(
+        (status = 200, description = "Markdown source", content_type = "text/markdown", body = String),
+        (status = 404, description = "No revision stored this markdown", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_revision_markdown(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let hash = ctx.param("hash").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_revision_markdown(&db, hash).await {
+        Ok(Some(markdown)) => {
+            let mut response = Response::ok(markdown)?;
+            response.headers_mut().set("Content-Type", "text/markdown; charset=utf-8")?;
+            // Named by its own hash, so it can never change
+            response.headers_mut().set("Cache-Control", "public, max-age=31536000, immutable")?;
+            Ok(response)
+        }
+        Ok(None) => error_response(&ctx, ApiError::not_found("Markdown not found")),
+        Err(e) => error_response(&ctx, database_error("fetch markdown", e)),
+    }
+}
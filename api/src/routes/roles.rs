@@ -0,0 +1,181 @@
+use worker::*;
+
+use crate::auth::{self, Access};
+use crate::database::DatabaseService;
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::pagination::{parse_enum, query_param, PageRequest};
+
+use super::admin::parse_body;
+
+async fn member_id(db: &D1Database, github: &str) -> std::result::Result<i64, ApiError> {
+    match DatabaseService::get_member_id_by_github(db, github).await {
+        Ok(Some(member_id)) => Ok(member_id),
+        Ok(None) => Err(ApiError::not_found("Member not found")),
+        Err(e) => Err(database_error("fetch member", e)),
+    }
+}
+
+/// Get a member's roles
+#[utoipa::path(
+    get,
+    path = "/v1/members/{github}/roles",
+    params(
+        ("github" = String, Path, description = "Member's GitHub username")
+    ),
+    responses(
+        (status = 200, description = "Roles the member holds, most senior first", body = [MemberRole]),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Member not found", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "roles"
+)]
+pub async fn get_roles(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let github = ctx.param("github").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+    let member_id = match member_id(&db, github).await {
+        Ok(member_id) => member_id,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    match DatabaseService::get_member_roles(&db, member_id).await {
+        Ok(roles) => Response::from_json(&roles),
+        Err(e) => error_response(&ctx, database_error("fetch roles", e)),
+    }
+}
+
+/// Grant a member a role. The change is recorded in the role audit trail.
+#[utoipa::path(
+    post,
+    path = "/v1/members/{github}/roles",
+    params(
+        ("github" = String, Path, description = "Member's GitHub username")
+    ),
+    request_body = RoleGrantInput,
+    responses(
+        (status = 201, description = "Role granted; the member's roles afterwards", body = [MemberRole]),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Member not found", body = ApiError),
+        (status = 409, description = "Member already holds the role", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "roles"
+)]
+pub async fn grant_role(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let actor = match auth::authorize(&req, &ctx.env, Access::Officer).await {
+        Ok(actor) => actor,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let input = match parse_body::<RoleGrantInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+    let github = ctx.param("github").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+    let member_id = match member_id(&db, github).await {
+        Ok(member_id) => member_id,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    let reason = input.reason.as_deref().map(str::trim).filter(|r| !r.is_empty());
+    match DatabaseService::grant_role(&db, member_id, input.role, &actor.label(), reason).await {
+        Ok(true) => {}
+        Ok(false) => return error_response(&ctx, ApiError::conflict(format!("@{} is already a {}", github, input.role))),
+        Err(e) => return error_response(&ctx, database_error("grant role", e)),
+    }
+    match DatabaseService::get_member_roles(&db, member_id).await {
+        Ok(roles) => Ok(Response::from_json(&roles)?.with_status(201)),
+        Err(e) => error_response(&ctx, database_error("fetch roles", e)),
+    }
+}
+
+/// Revoke a role from a member. The change is recorded in the role audit trail.
+#[utoipa::path(
+    delete,
+    path = "/v1/members/{github}/roles/{role}",
+    params(
+        ("github" = String, Path, description = "Member's GitHub username"),
+        ("role" = MemberRole, Path, description = "Role to revoke"),
+        ("reason" = Option<String>, Query, description = "Why the role was revoked, kept in the audit trail")
+    ),
+    responses(
+        (status = 204, description = "Role revoked"),
+        (status = 400, description = "Unknown role", body = ApiError),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError),
+        (status = 404, description = "Member not found, or does not hold the role", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "roles"
+)]
+pub async fn revoke_role(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let actor = match auth::authorize(&req, &ctx.env, Access::Officer).await {
+        Ok(actor) => actor,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let role = match parse_enum::<MemberRole>("role", ctx.param("role").map_or("", |v| v)) {
+        Ok(role) => role,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    let url = req.url()?;
+    let reason = query_param(&url, "reason");
+    let github = ctx.param("github").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+    let member_id = match member_id(&db, github).await {
+        Ok(member_id) => member_id,
+        Err(e) => return error_response(&ctx, e),
+    };
+
+    match DatabaseService::revoke_role(&db, member_id, role, &actor.label(), reason.as_deref()).await {
+        Ok(true) => Ok(Response::empty()?.with_status(204)),
+        Ok(false) => error_response(&ctx, ApiError::not_found(format!("@{} is not a {}", github, role))),
+        Err(e) => error_response(&ctx, database_error("revoke role", e)),
+    }
+}
+
+/// The role audit trail: every grant and revocation, who made it and why
+#[utoipa::path(
+    get,
+    path = "/v1/roles/changes",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction by time of change: desc (default) or asc"),
+        ("member" = Option<String>, Query, description = "Only changes to this member, by GitHub username")
+    ),
+    responses(
+        (status = 200, description = "Page of role changes", body = Page<RoleChange>),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 403, description = "Signed in without a role that allows this", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "roles"
+)]
+pub async fn get_role_changes(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if let Err(e) = auth::authorize(&req, &ctx.env, Access::Officer).await {
+        return error_response(&ctx, e);
+    }
+    let url = req.url()?;
+    let page = match PageRequest::from_url(&url) {
+        Ok(page) => page,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    let member = query_param(&url, "member");
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_role_changes(&db, member.as_deref(), &page).await {
+        Ok(changes) => Response::from_json(&changes),
+        Err(e) => error_response(&ctx, database_error("fetch role changes", e)),
+    }
+}
@@ -0,0 +1,50 @@
+use worker::*;
+
+use crate::database::DatabaseService;
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::pagination::{enum_param, query_param, PageRequest};
+use crate::search;
+
+/// Search blog posts, issues, projects and events
+#[utoipa::path(
+    get,
+    path = "/v1/search",
+    params(
+        ("q" = String, Query, description = "Search text; every word must match and the last may be a prefix"),
+        ("type" = Option<SearchResultKind>, Query, description = "Only return results of this kind"),
+        ("limit" = Option<u32>, Query, description = "Maximum results (default 20, max 100)")
+    ),
+    responses(
+        (status = 200, description = "Matches, best first", body = SearchResults),
+        (status = 400, description = "Missing or invalid query", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn search(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let url = req.url()?;
+    let query = query_param(&url, "q").unwrap_or_default();
+    let params = PageRequest::from_url(&url).and_then(|page| {
+        if query.chars().count() > search::MAX_QUERY_CHARS {
+            return Err(format!("q must be at most {} characters", search::MAX_QUERY_CHARS));
+        }
+        let expression = search::fts_query(&query)
+            .ok_or_else(|| "q must contain at least one letter or digit".to_string())?;
+        let kind = enum_param::<SearchResultKind>(&url, "type")?;
+        Ok((expression, kind, page.limit))
+    });
+    let (expression, kind, limit) = match params {
+        Ok(params) => params,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::search(&db, &expression, kind, limit).await {
+        Ok(results) => {
+            let mut response = Response::from_json(&SearchResults { query, results })?;
+            response.headers_mut().set("Cache-Control", "public, max-age=60")?;
+            Ok(response)
+        }
+        Err(e) => error_response(&ctx, database_error("search", e)),
+    }
+}
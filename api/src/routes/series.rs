@@ -0,0 +1,89 @@
+use worker::*;
+
+use crate::database::DatabaseService;
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+
+/// Check the series a post is placed in: it must exist, the part must be
+/// within its planned parts, and no other post may already hold the part.
+/// `post_id` is the post being saved, or empty for a new one.
+pub(super) async fn check_series_part(
+    db: &D1Database,
+    input: &BlogPostInput,
+    post_id: &str,
+) -> std::result::Result<(), ApiError> {
+    let Some(placement) = &input.series else {
+        return Ok(());
+    };
+    let series = match DatabaseService::get_blog_series_summary(db, &placement.slug).await {
+        Ok(Some(series)) => series,
+        Ok(None) => return Err(ApiError::validation(vec![format!("series.slug: unknown series {}", placement.slug)])),
+        Err(e) => return Err(database_error("fetch series", e)),
+    };
+    if let Some(planned) = series.planned_parts.filter(|planned| placement.part > *planned) {
+        return Err(ApiError::validation(vec![format!(
+            "series.part must be at most {}, the parts planned for {}",
+            planned, series.slug
+        )]));
+    }
+    match DatabaseService::get_blog_series_part_holder(db, &placement.slug, placement.part, post_id).await {
+        Ok(None) => Ok(()),
+        Ok(Some(holder)) => Err(ApiError::conflict(format!(
+            "Part {} of {} is already {}",
+            placement.part, series.slug, holder
+        ))),
+        Err(e) => Err(database_error("fetch series", e)),
+    }
+}
+
+/// List blog series
+#[utoipa::path(
+    get,
+    path = "/v1/blog/series",
+    responses(
+        (status = 200, description = "All series by title, with how many parts are published", body = [BlogSeriesSummary]),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_blog_series_list(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_blog_series_list(&db).await {
+        Ok(series) => {
+            let mut response = Response::from_json(&series)?;
+            response.headers_mut().set("Cache-Control", "public, max-age=60")?;
+            Ok(response)
+        }
+        Err(e) => error_response(&ctx, database_error("fetch series", e)),
+    }
+}
+
+/// Get a blog series with its published parts in reading order
+#[utoipa::path(
+    get,
+    path = "/v1/blog/series/{slug}",
+    params(
+        ("slug" = String, Path, description = "Series slug, e.g. rust-fundamentals")
+    ),
+    responses(
+        (status = 200, description = "The series and its published parts", body = BlogSeriesDetail),
+        (status = 404, description = "Series not found", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn get_blog_series(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_blog_series(&db, slug).await {
+        Ok(Some(series)) => {
+            let mut response = Response::from_json(&series)?;
+            response.headers_mut().set("Cache-Control", "public, max-age=60")?;
+            Ok(response)
+        }
+        Ok(None) => error_response(&ctx, ApiError::not_found("Series not found")),
+        Err(e) => error_response(&ctx, database_error("fetch series", e)),
+    }
+}
@@ -0,0 +1,75 @@
+use worker::*;
+
+use crate::database::DatabaseService;
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::pagination::query_param;
+
+/// Resolve `?term=` for list endpoints. `Ok(None)` when the parameter is
+/// absent; an unknown term is a bad request rather than an empty page.
+pub(crate) async fn term_param(url: &Url, db: &D1Database) -> std::result::Result<Option<Term>, ApiError> {
+    let Some(slug) = query_param(url, "term") else {
+        return Ok(None);
+    };
+    match DatabaseService::get_term(db, &slug).await {
+        Ok(Some(term)) => Ok(Some(term)),
+        Ok(None) => Err(ApiError::bad_request(format!("unknown term: {}", slug))),
+        Err(e) => Err(database_error("fetch term", e)),
+    }
+}
+
+/// The term named by `?term=`, or the current one when the parameter is absent
+pub(crate) async fn term_param_or_current(url: &Url, db: &D1Database) -> std::result::Result<Term, ApiError> {
+    if let Some(term) = term_param(url, db).await? {
+        return Ok(term);
+    }
+    match DatabaseService::get_current_term(db, club_date(chrono::Utc::now())).await {
+        Ok(Some(term)) => Ok(term),
+        Ok(None) => Err(ApiError::not_found("No term has started yet")),
+        Err(e) => Err(database_error("fetch current term", e)),
+    }
+}
+
+/// List academic terms
+#[utoipa::path(
+    get,
+    path = "/v1/terms",
+    responses(
+        (status = 200, description = "All terms, newest first", body = [Term]),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_terms(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_terms(&db).await {
+        Ok(terms) => Response::from_json(&terms),
+        Err(e) => error_response(&ctx, database_error("fetch terms", e)),
+    }
+}
+
+/// Get a single term
+#[utoipa::path(
+    get,
+    path = "/v1/terms/{slug}",
+    params(
+        ("slug" = String, Path, description = "Term slug, e.g. fall-2025")
+    ),
+    responses(
+        (status = 200, description = "The term", body = Term),
+        (status = 404, description = "Term not found", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_term(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let slug = ctx.param("slug").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_term(&db, slug).await {
+        Ok(Some(term)) => Response::from_json(&term),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Term not found")),
+        Err(e) => error_response(&ctx, database_error("fetch term", e)),
+    }
+}
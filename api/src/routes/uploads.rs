@@ -0,0 +1,100 @@
+use worker::*;
+
+use crate::auth;
+use crate::content::{self, ContentKind, ContentStore};
+use crate::error::{error_response, RequestInfo};
+use crate::models::*;
+use crate::session;
+
+/// Upload a markdown file or image for a blog post. The request body is the
+/// raw file and `Content-Type` names its type. Files are stored under the
+/// hash of their bytes, so re-uploading one returns the existing URL.
+#[utoipa::path(
+    post,
+    path = "/v1/content",
+    request_body(content = Vec<u8>, description = "Raw file: markdown up to 1 MiB, or a PNG, JPEG, GIF or WebP image up to 5 MiB", content_type = "application/octet-stream"),
+    responses(
+        (status = 200, description = "Identical content was already stored", body = ContentUpload),
+        (status = 201, description = "Content stored", body = ContentUpload),
+        (status = 401, description = "Not signed in and no valid admin token", body = ApiError),
+        (status = 413, description = "File is larger than its type allows", body = ApiError),
+        (status = 415, description = "Content-Type is not an accepted type", body = ApiError),
+        (status = 422, description = "Empty file, or its bytes do not match its Content-Type", body = ApiError)
+    ),
+    security(("bearer_auth" = []), ("session_cookie" = [])),
+    tag = "content"
+)]
+pub async fn upload_content(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    if !auth::is_admin(&req, &ctx.env) && session::current(&req, &ctx.env).await?.is_none() {
+        return error_response(&ctx, ApiError::unauthorized("Sign in to upload content"));
+    }
+    // Refuse a body that says it is too big before reading it; store_upload
+    // checks the bytes actually received against the type's own limit
+    let declared = req.headers().get("Content-Length")?.and_then(|len| len.trim().parse::<u64>().ok());
+    if declared.is_some_and(|len| len > content::MAX_UPLOAD_BYTES as u64) {
+        return error_response(
+            &ctx,
+            ApiError::new(
+                ErrorCode::PayloadTooLarge,
+                format!("Uploads are limited to {} KiB", content::MAX_UPLOAD_BYTES / 1024),
+            ),
+        );
+    }
+    let content_type = req.headers().get("Content-Type")?.unwrap_or_default();
+    let bytes = req.bytes().await?;
+    let base_url = req.url()?.origin().ascii_serialization();
+    let bucket = ctx.env.bucket(content::BUCKET_BINDING)?;
+
+    match content::store_upload(&bucket, &content_type, bytes, &base_url).await {
+        Ok(upload) => {
+            let status = if upload.deduplicated { 200 } else { 201 };
+            Ok(Response::from_json(&upload)?.with_status(status))
+        }
+        Err(e) => error_response(&ctx, e),
+    }
+}
+
+/// A stored markdown file or image. Content never changes under its key, so
+/// it may be cached for good.
+#[utoipa::path(
+    get,
+    path = "/v1/content/{key}",
+    params(
+        ("key" = String, Path, description = "Storage key from the upload, e.g. `<sha256>.md`")
+    ),
+    responses(
+        (status = 200, description = "The file, served with its stored Content-Type", content_type = "application/octet-stream", body = Vec<u8>),
+        (status = 304, description = "The client's cached copy is current"),
+        (status = 404, description = "No content under this key", body = ApiError)
+    ),
+    tag = "content"
+)]
+pub async fn get_content(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let key = ctx.param("key").map_or("", |v| v);
+    if !content::is_content_key(key) {
+        return error_response(&ctx, ApiError::not_found("Content not found"));
+    }
+    let bucket = ctx.env.bucket(content::BUCKET_BINDING)?;
+    // The trait's get, not R2's builder of the same name
+    let stored = match ContentStore::get(&bucket, key).await {
+        Ok(Some(stored)) => stored,
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Content not found")),
+        Err(e) => return error_response(&ctx, content::storage_error("fetch content", e)),
+    };
+
+    let mut response = if req.headers().get("If-None-Match")?.as_deref() == Some(stored.etag.as_str()) {
+        Response::empty()?.with_status(304)
+    } else {
+        Response::from_bytes(stored.bytes)?
+    };
+    let headers = response.headers_mut();
+    let content_type = match ContentKind::from_key(key) {
+        Some(kind) => kind.mime(),
+        None => stored.content_type.as_str(),
+    };
+    headers.set("Content-Type", content_type)?;
+    headers.set("Cache-Control", content::IMMUTABLE_CACHE_CONTROL)?;
+    headers.set("ETag", &stored.etag)?;
+    headers.set("X-Content-Type-Options", "nosniff")?;
+    Ok(response)
+}
@@ -0,0 +1,59 @@
+use worker::*;
+
+use crate::database::DatabaseService;
+use crate::error::{error_response, RequestInfo};
+use crate::models::*;
+use crate::rate_limit;
+use crate::views;
+use crate::visitor::Visitor;
+
+/// Record a view of a published blog post. Bots, repeat views within 30
+/// minutes and rate-limited clients are accepted but not counted. Counts reach
+/// `BlogPost.views` when the scheduled flush runs.
+#[utoipa::path(
+    post,
+    path = "/v1/blog/{slug}/view",
+    params(
+        ("slug" = String, Path, description = "Blog post slug")
+    ),
+    responses(
+        (status = 204, description = "View received"),
+        (status = 400, description = "Malformed slug", body = ApiError),
+        (status = 404, description = "No published post with this slug", body = ApiError)
+    ),
+    tag = "blog"
+)]
+pub async fn record_blog_view(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let slug = ctx.param("slug").map_or("", |v| v);
+    if slug.is_empty()
+        || slug.len() > 200
+        || !slug.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return error_response(&ctx, ApiError::bad_request("slug is not a valid blog post slug"));
+    }
+
+    let visitor = Visitor::from_request(&req, &ctx.env);
+    if visitor.is_bot {
+        return Ok(Response::empty()?.with_status(204));
+    }
+
+    let kv = ctx.env.kv("CACHE")?;
+    if !rate_limit::VIEWS.allow(&kv, &visitor.ip_hash).await.unwrap_or(true) {
+        return Ok(Response::empty()?.with_status(204));
+    }
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::is_published_blog_post(&db, slug).await {
+        Ok(true) => {}
+        Ok(false) => return error_response(&ctx, ApiError::not_found("Blog post not found")),
+        Err(e) => {
+            web_sys::console::warn_1(&format!("Failed to check post {} for a view: {:?}", slug, e).into());
+            return Ok(Response::empty()?.with_status(204));
+        }
+    }
+    // A beacon failure must never surface to the reader, so errors are only logged
+    if let Err(e) = views::record_view(&kv, slug, &visitor.fingerprint).await {
+        web_sys::console::warn_1(&format!("Failed to record view of {}: {:?}", slug, e).into());
+    }
+
+    Ok(Response::empty()?.with_status(204))
+}
@@ -0,0 +1,205 @@
+use worker::*;
+
+use crate::database::{DatabaseService, WorkshopFilter};
+use crate::error::{database_error, error_response, RequestInfo};
+use crate::models::*;
+use crate::pagination::{bool_param, PageRequest};
+use crate::rate_limit;
+use crate::session;
+use crate::tokens;
+use crate::visitor::Visitor;
+
+use super::admin::parse_body;
+use super::terms;
+
+/// Get workshops
+#[utoipa::path(
+    get,
+    path = "/v1/workshops",
+    params(
+        ("limit" = Option<u32>, Query, description = "Page size (default 20, max 100)"),
+        ("cursor" = Option<String>, Query, description = "next_cursor from the previous page"),
+        ("order" = Option<String>, Query, description = "Sort direction by start time: desc (default) or asc"),
+        ("upcoming" = Option<bool>, Query, description = "Only workshops that have (true) or have not (false) yet to start"),
+        ("term" = Option<String>, Query, description = "Only workshops starting during this term")
+    ),
+    responses(
+        (status = 200, description = "Page of workshops with live seat counts", body = Page<Workshop>),
+        (status = 400, description = "Invalid query parameter", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_workshops(req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let url = req.url()?;
+    let db = ctx.env.d1("DB")?;
+    let term = match terms::term_param(&url, &db).await {
+        Ok(term) => term,
+        Err(e) => return error_response(&ctx, e),
+    };
+    let query = PageRequest::from_url(&url).and_then(|page| {
+        let filter = WorkshopFilter {
+            upcoming: bool_param(&url, "upcoming")?,
+            starts_within: term.as_ref().map(Term::bounds),
+        };
+        Ok((filter, page))
+    });
+    let (filter, page) = match query {
+        Ok(query) => query,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+
+    match DatabaseService::get_workshops(&db, &filter, &page).await {
+        Ok(workshops) => Response::from_json(&workshops),
+        Err(e) => error_response(&ctx, database_error("fetch workshops", e)),
+    }
+}
+
+/// Get a single workshop
+#[utoipa::path(
+    get,
+    path = "/v1/workshops/{id}",
+    params(
+        ("id" = String, Path, description = "Workshop id")
+    ),
+    responses(
+        (status = 200, description = "The workshop with live seat counts", body = Workshop),
+        (status = 404, description = "Workshop not found", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_workshop(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_workshop(&db, id).await {
+        Ok(Some(workshop)) => Response::from_json(&workshop),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Workshop not found")),
+        Err(e) => error_response(&ctx, database_error("fetch workshop", e)),
+    }
+}
+
+/// Register for a workshop. Once every seat is taken, new registrations join
+/// the waitlist and are promoted in order as seats free up. A signed-in
+/// member's registration is linked to them and shows on their profile.
+#[utoipa::path(
+    post,
+    path = "/v1/workshops/{id}/registrations",
+    params(
+        ("id" = String, Path, description = "Workshop id")
+    ),
+    request_body = WorkshopRegistrationInput,
+    responses(
+        (status = 201, description = "Registered or waitlisted; keep the token to check on or cancel the registration", body = WorkshopRegistration),
+        (status = 400, description = "Registration has closed", body = ApiError),
+        (status = 404, description = "Workshop not found", body = ApiError),
+        (status = 409, description = "This email is already registered", body = ApiError),
+        (status = 422, description = "Validation failed", body = ApiError),
+        (status = 429, description = "Too many registrations from this address", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn register_for_workshop(mut req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let visitor = Visitor::from_request(&req, &ctx.env);
+
+    let kv = ctx.env.kv("CACHE")?;
+    match rate_limit::REGISTRATIONS.allow(&kv, &visitor.ip_hash).await {
+        Ok(true) => {}
+        Ok(false) => {
+            return error_response(&ctx, ApiError::rate_limited("Too many registrations, try again later"))
+        }
+        Err(e) => web_sys::console::warn_1(&format!("Rate limit check failed: {:?}", e).into()),
+    }
+
+    let input = match parse_body::<WorkshopRegistrationInput>(&mut req).await {
+        Ok(input) => input,
+        Err(message) => return error_response(&ctx, ApiError::bad_request(message)),
+    };
+    if let Err(details) = input.validate() {
+        return error_response(&ctx, ApiError::validation(details));
+    }
+
+    let db = ctx.env.d1("DB")?;
+    match DatabaseService::get_workshop(&db, id).await {
+        Ok(Some(workshop)) if workshop.starts_at <= chrono::Utc::now() => {
+            return error_response(&ctx, ApiError::bad_request("Registration has closed"))
+        }
+        Ok(Some(_)) => {}
+        Ok(None) => return error_response(&ctx, ApiError::not_found("Workshop not found")),
+        Err(e) => return error_response(&ctx, database_error("fetch workshop", e)),
+    }
+
+    // Signing in is optional here, so a session that cannot be read only
+    // leaves the registration unlinked
+    let member_id = match session::current(&req, &ctx.env).await {
+        Ok(session) => session.map(|session| session.member_id),
+        Err(e) => {
+            web_sys::console::warn_1(&format!("Session lookup failed: {:?}", e).into());
+            None
+        }
+    };
+
+    let token = tokens::new_token();
+    match DatabaseService::register_for_workshop(&db, id, &input, &token, member_id).await {
+        Ok(Some(registration)) => Ok(Response::from_json(&registration)?.with_status(201)),
+        // The workshop started or was deleted since the check above
+        Ok(None) => error_response(&ctx, ApiError::bad_request("Registration has closed")),
+        Err(e) => error_response(&ctx, database_error("register for workshop", e)),
+    }
+}
+
+/// Check on a registration, e.g. its current waitlist position
+#[utoipa::path(
+    get,
+    path = "/v1/workshops/{id}/registrations/{token}",
+    params(
+        ("id" = String, Path, description = "Workshop id"),
+        ("token" = String, Path, description = "Confirmation token returned at registration")
+    ),
+    responses(
+        (status = 200, description = "The registration", body = WorkshopRegistration),
+        (status = 404, description = "No registration with this token", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn get_workshop_registration(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let token = ctx.param("token").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::get_workshop_registration(&db, id, token).await {
+        Ok(Some(registration)) => Response::from_json(&registration),
+        Ok(None) => error_response(&ctx, ApiError::not_found("Registration not found")),
+        Err(e) => error_response(&ctx, database_error("fetch registration", e)),
+    }
+}
+
+/// Cancel a registration. A freed seat goes to the first person on the waitlist.
+#[utoipa::path(
+    delete,
+    path = "/v1/workshops/{id}/registrations/{token}",
+    params(
+        ("id" = String, Path, description = "Workshop id"),
+        ("token" = String, Path, description = "Confirmation token returned at registration")
+    ),
+    responses(
+        (status = 204, description = "Registration cancelled"),
+        (status = 404, description = "No registration with this token", body = ApiError),
+        (status = 500, description = "Database failure", body = ApiError)
+    ),
+    tag = "public"
+)]
+pub async fn cancel_workshop_registration(_req: Request, ctx: RouteContext<RequestInfo>) -> Result<Response> {
+    let id = ctx.param("id").map_or("", |v| v);
+    let token = ctx.param("token").map_or("", |v| v);
+    let db = ctx.env.d1("DB")?;
+
+    match DatabaseService::cancel_workshop_registration(&db, id, token).await {
+        Ok(true) => Ok(Response::empty()?.with_status(204)),
+        Ok(false) => error_response(&ctx, ApiError::not_found("Registration not found")),
+        Err(e) => error_response(&ctx, database_error("cancel registration", e)),
+    }
+}
@@ -0,0 +1,70 @@
+use worker::*;
+
+use crate::database::DatabaseService;
+use crate::issue_sync;
+use crate::views;
+
+/// Cron that syncs issues from GitHub; must match wrangler.toml. Every other
+/// trigger flushes views, expires lapsed issue claims and publishes
+/// scheduled blog posts.
+const ISSUE_SYNC_CRON: &str = "17 * * * *";
+
+/// Entry point for the cron triggers in wrangler.toml
+#[event(scheduled)]
+pub async fn scheduled(event: ScheduledEvent, env: Env, _ctx: ScheduleContext) {
+    if event.cron() == ISSUE_SYNC_CRON {
+        if let Err(e) = sync_issues(&env).await {
+            web_sys::console::error_1(&format!("Issue sync failed: {:?}", e).into());
+        }
+        return;
+    }
+    if let Err(e) = flush_views(&env).await {
+        web_sys::console::error_1(&format!("View flush failed: {:?}", e).into());
+    }
+    if let Err(e) = expire_claims(&env).await {
+        web_sys::console::error_1(&format!("Claim expiry failed: {:?}", e).into());
+    }
+    if let Err(e) = publish_scheduled_posts(&env).await {
+        web_sys::console::error_1(&format!("Scheduled publishing failed: {:?}", e).into());
+    }
+}
+
+async fn flush_views(env: &Env) -> Result<()> {
+    let kv = env.kv("CACHE")?;
+    let db = env.d1("DB")?;
+    let flushed = views::flush_views(&kv, &db).await?;
+    if flushed > 0 {
+        web_sys::console::log_1(&format!("Flushed {} blog views to D1", flushed).into());
+    }
+    Ok(())
+}
+
+async fn expire_claims(env: &Env) -> Result<()> {
+    let db = env.d1("DB")?;
+    let expired = DatabaseService::expire_claims(&db).await?;
+    if expired > 0 {
+        web_sys::console::log_1(&format!("Expired {} issue claims", expired).into());
+    }
+    Ok(())
+}
+
+async fn publish_scheduled_posts(env: &Env) -> Result<()> {
+    let db = env.d1("DB")?;
+    let published = DatabaseService::publish_scheduled_posts(&db).await?;
+    if published > 0 {
+        web_sys::console::log_1(&format!("Published {} scheduled blog posts", published).into());
+    }
+    Ok(())
+}
+
+async fn sync_issues(env: &Env) -> Result<()> {
+    let report = issue_sync::sync_issues(env).await?;
+    web_sys::console::log_1(
+        &format!(
+            "Synced issues from {} repos: {} upserted, {} closed, failed: {:?}, truncated: {:?}",
+            report.repos, report.upserted, report.closed, report.failed, report.truncated
+        )
+        .into(),
+    );
+    Ok(())
+}
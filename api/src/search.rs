@@ -0,0 +1,47 @@
+//! Query parsing and result highlighting for the FTS5 `search_index` table
+
+/// Markers FTS5 wraps matched terms in; swapped for `<mark>` after escaping
+pub const MATCH_START: char = '\u{2}';
+pub const MATCH_END: char = '\u{3}';
+
+/// Longest accepted `?q=`, in characters
+pub const MAX_QUERY_CHARS: usize = 200;
+
+const MAX_TERMS: usize = 8;
+
+/// Turn free text into an FTS5 MATCH expression that requires every term.
+/// Terms are quoted so FTS5 operators and column filters in user input are
+/// matched literally, and the last term matches as a prefix so results
+/// update while the visitor types. Returns `None` when there is nothing to
+/// search for.
+pub fn fts_query(input: &str) -> Option<String> {
+    let terms: Vec<&str> = input
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .take(MAX_TERMS)
+        .collect();
+    let (last, rest) = terms.split_last()?;
+
+    let mut query: Vec<String> = rest.iter().map(|term| format!("\"{}\"", term)).collect();
+    query.push(format!("\"{}\"*", last));
+    Some(query.join(" "))
+}
+
+/// HTML-escape text returned by `highlight()` or `snippet()`, turning the
+/// match markers into `<mark>` elements
+pub fn marked_html(text: &str) -> String {
+    let mut html = String::with_capacity(text.len() + 16);
+    for c in text.chars() {
+        match c {
+            MATCH_START => html.push_str("<mark>"),
+            MATCH_END => html.push_str("</mark>"),
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+    html
+}
@@ -0,0 +1,178 @@
+//! Member sessions. Signing in sets an HttpOnly cookie holding a random
+//! token; the session itself lives in the CACHE KV namespace under the
+//! token's hash and expires on its own. Signing out deletes it early.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use worker::{kv::KvStore, Env, Request, Result};
+
+use crate::auth::constant_time_eq;
+use crate::tokens;
+
+const SESSION_PREFIX: &str = "session:";
+const STATE_PREFIX: &str = "oauth_state:";
+
+/// Name of the session cookie
+pub const COOKIE_NAME: &str = "chico_session";
+
+/// Name of the cookie tying an OAuth round trip to the browser that began it
+const STATE_COOKIE_NAME: &str = "chico_oauth_state";
+
+/// The state cookie is only sent to the sign-in routes
+const STATE_COOKIE_PATH: &str = "/v1/auth/github";
+
+/// How long a sign-in lasts
+const SESSION_TTL_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// How long the user has to finish authorizing on GitHub
+const STATE_TTL_SECS: u64 = 10 * 60;
+
+/// Website to send members back to, and the only origin allowed to make
+/// credentialed requests
+const DEFAULT_SITE_URL: &str = "https://chico.rs";
+
+/// A signed-in member
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Session {
+    /// Row id in `members`
+    pub member_id: i64,
+    pub github_username: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+fn session_key(token: &str) -> String {
+    format!("{}{}", SESSION_PREFIX, tokens::token_hash(token))
+}
+
+fn state_key(state: &str) -> String {
+    format!("{}{}", STATE_PREFIX, tokens::token_hash(state))
+}
+
+/// Base URL of the website, from the `SITE_URL` var
+pub fn site_url(env: &Env) -> String {
+    env.var("SITE_URL")
+        .map(|v| v.to_string())
+        .unwrap_or_else(|_| DEFAULT_SITE_URL.to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+/// Keep only site-relative paths, so the sign-in flow cannot be used to
+/// redirect to another host
+pub fn safe_return_path(path: Option<&str>) -> String {
+    match path {
+        Some(path) if path.starts_with('/') && !path.starts_with("//") && !path.contains('\\') => path.to_string(),
+        _ => "/".to_string(),
+    }
+}
+
+/// Start an OAuth round trip: returns the `state` to send to GitHub, which
+/// remembers where to return the member afterwards
+pub async fn begin_sign_in(kv: &KvStore, return_to: &str) -> Result<String> {
+    let state = tokens::new_token();
+    kv.put(&state_key(&state), return_to)?
+        .expiration_ttl(STATE_TTL_SECS)
+        .execute()
+        .await?;
+    Ok(state)
+}
+
+/// Redeem the `state` GitHub sent back. Each state works once; `None` if it
+/// is unknown or expired, or was not issued to this browser, so a sign-in
+/// started elsewhere cannot be finished here.
+pub async fn finish_sign_in(kv: &KvStore, req: &Request, state: &str) -> Result<Option<String>> {
+    let issued = cookie_value(req, STATE_COOKIE_NAME).unwrap_or_default();
+    if state.is_empty() || !constant_time_eq(issued.as_bytes(), state.as_bytes()) {
+        return Ok(None);
+    }
+    let key = state_key(state);
+    let return_to = kv.get(&key).text().await?;
+    if return_to.is_some() {
+        kv.delete(&key).await?;
+    }
+    Ok(return_to)
+}
+
+/// Start a session for a member. Returns the token for the cookie.
+pub async fn create(kv: &KvStore, member_id: i64, github_username: &str) -> Result<String> {
+    let token = tokens::new_token();
+    let session = Session {
+        member_id,
+        github_username: github_username.to_string(),
+        expires_at: Utc::now() + Duration::seconds(SESSION_TTL_SECS as i64),
+    };
+    kv.put(&session_key(&token), serde_json::to_string(&session)?)?
+        .expiration_ttl(SESSION_TTL_SECS)
+        .execute()
+        .await?;
+    Ok(token)
+}
+
+/// End the session behind a token
+pub async fn destroy(kv: &KvStore, token: &str) -> Result<()> {
+    kv.delete(&session_key(token)).await.map_err(worker::Error::from)?;
+    Ok(())
+}
+
+fn cookie_value(req: &Request, cookie_name: &str) -> Option<String> {
+    let cookies = req.headers().get("Cookie").ok()??;
+    cookies
+        .split(';')
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(name, _)| *name == cookie_name)
+        .map(|(_, value)| value.to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// The session token from the request's cookie, if any
+pub fn session_token(req: &Request) -> Option<String> {
+    cookie_value(req, COOKIE_NAME)
+}
+
+/// The session the request's cookie belongs to. `None` when signed out or
+/// the session has expired.
+pub async fn current(req: &Request, env: &Env) -> Result<Option<Session>> {
+    let Some(token) = session_token(req) else {
+        return Ok(None);
+    };
+    let kv = env.kv("CACHE")?;
+    let Some(stored) = kv.get(&session_key(&token)).text().await? else {
+        return Ok(None);
+    };
+    // KV expiry is eventually consistent; the stored deadline is authoritative
+    Ok(serde_json::from_str::<Session>(&stored)
+        .ok()
+        .filter(|session| session.expires_at > Utc::now()))
+}
+
+/// `Set-Cookie` value that stores a session token. The API and website are
+/// on the same site, so `SameSite=Lax` still lets the website's fetches
+/// carry it.
+pub fn cookie(token: &str) -> String {
+    format!(
+        "{}={}; Path=/; Max-Age={}; HttpOnly; Secure; SameSite=Lax",
+        COOKIE_NAME, token, SESSION_TTL_SECS
+    )
+}
+
+/// `Set-Cookie` value that removes the session cookie
+pub fn clear_cookie() -> String {
+    format!("{}=; Path=/; Max-Age=0; HttpOnly; Secure; SameSite=Lax", COOKIE_NAME)
+}
+
+/// `Set-Cookie` value that holds the OAuth `state` until GitHub sends the
+/// browser back. `SameSite=Lax` still sends it on that top-level redirect.
+pub fn state_cookie(state: &str) -> String {
+    format!(
+        "{}={}; Path={}; Max-Age={}; HttpOnly; Secure; SameSite=Lax",
+        STATE_COOKIE_NAME, state, STATE_COOKIE_PATH, STATE_TTL_SECS
+    )
+}
+
+/// `Set-Cookie` value that removes the OAuth state cookie
+pub fn clear_state_cookie() -> String {
+    format!(
+        "{}=; Path={}; Max-Age=0; HttpOnly; Secure; SameSite=Lax",
+        STATE_COOKIE_NAME, STATE_COOKIE_PATH
+    )
+}
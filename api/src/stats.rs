@@ -0,0 +1,55 @@
+//! KV caching for `/v1/stats`. Entries are keyed by term and dropped by
+//! every admin write that changes what the statistics count.
+
+use chrono::Utc;
+use worker::{kv::KvStore, Result};
+
+use crate::models::*;
+
+const CACHE_PREFIX: &str = "club_stats:";
+
+/// A running term's workshop count grows as sessions happen without any
+/// write, so it is only cached briefly
+const RUNNING_TERM_TTL_SECS: u64 = 60 * 60;
+const PAST_TERM_TTL_SECS: u64 = 7 * 24 * 60 * 60;
+
+fn cache_key(term: &Term) -> String {
+    format!("{}{}", CACHE_PREFIX, term.slug)
+}
+
+pub async fn cached(kv: &KvStore, term: &Term) -> Option<Stats> {
+    let cached = kv.get(&cache_key(term)).text().await.ok()??;
+    serde_json::from_str(&cached).ok()
+}
+
+pub async fn store(kv: &KvStore, term: &Term, stats: &Stats) -> Result<()> {
+    let ttl = if term.ended_before(Utc::now()) {
+        PAST_TERM_TTL_SECS
+    } else {
+        RUNNING_TERM_TTL_SECS
+    };
+    kv.put(&cache_key(term), serde_json::to_string(stats)?)?
+        .expiration_ttl(ttl)
+        .execute()
+        .await?;
+    Ok(())
+}
+
+/// Delete every cached term
+pub async fn invalidate(kv: &KvStore) -> Result<()> {
+    let mut cursor = None;
+    loop {
+        let mut list = kv.list().prefix(CACHE_PREFIX.to_string());
+        if let Some(cursor) = cursor.take() {
+            list = list.cursor(cursor);
+        }
+        let page = list.execute().await?;
+        for key in page.keys {
+            kv.delete(&key.name).await?;
+        }
+        match page.cursor {
+            Some(next) if !page.list_complete => cursor = Some(next),
+            _ => return Ok(()),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+//! Opaque tokens handed to visitors, such as workshop registration
+//! confirmations and event check-in codes. A token is shown once and only its hash is stored, so
+//! read access to the database is not enough to act on someone's behalf.
+
+use sha2::{Digest, Sha256};
+
+/// A fresh random token: 32 hex characters, 122 random bits
+pub fn new_token() -> String {
+    uuid::Uuid::new_v4().simple().to_string()
+}
+
+/// Characters of short codes: no 0/O, 1/I/L, so codes read back unambiguously
+const SHORT_CODE_ALPHABET: &[u8] = b"23456789ABCDEFGHJKMNPQRSTUVWXYZ";
+const SHORT_CODE_LEN: usize = 6;
+
+/// A random code short enough to type from a screen, e.g. `K7PX2M`. About 30
+/// bits, so only for values that expire quickly and are rate limited.
+pub fn new_short_code() -> String {
+    uuid::Uuid::new_v4()
+        .as_bytes()
+        .iter()
+        .take(SHORT_CODE_LEN)
+        .map(|byte| SHORT_CODE_ALPHABET[*byte as usize % SHORT_CODE_ALPHABET.len()] as char)
+        .collect()
+}
+
+/// Hex SHA-256 of a token, as stored in D1
+pub fn token_hash(token: &str) -> String {
+    Sha256::digest(token.as_bytes())
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
@@ -0,0 +1,113 @@
+//! Blog view counting. The beacon endpoint keeps one KV counter per post per
+//! five-minute window; the scheduled flush adds the counters of closed
+//! windows to `blog_posts.views`. D1 remembers which counters it applied, so
+//! a flush that fails partway can run again without counting twice.
+
+use chrono::Utc;
+use worker::{kv::KvStore, D1Database, Result};
+
+use crate::database::DatabaseService;
+
+/// Repeat views from the same visitor within this window count once
+const DEDUPE_WINDOW_SECS: u64 = 30 * 60;
+
+/// Length of a counter window; matches the `*/5` flush cron in wrangler.toml
+const WINDOW_SECS: u64 = 5 * 60;
+
+const SEEN_PREFIX: &str = "views:seen:";
+const COUNT_PREFIX: &str = "views:count:";
+
+/// Counters outlive many missed flushes, but are not kept forever
+const COUNT_TTL_SECS: u64 = 24 * 60 * 60;
+
+/// Counters read per flush, keeping a run well inside the per-invocation KV
+/// limits: each costs a read and a delete. The rest wait for the next run.
+const MAX_COUNTERS_PER_FLUSH: usize = 200;
+
+/// Views of one post in one window, ready to flush
+pub struct ViewCounter {
+    pub window: u64,
+    pub slug: String,
+    pub count: u32,
+}
+
+fn current_window() -> u64 {
+    Utc::now().timestamp().max(0) as u64 / WINDOW_SECS
+}
+
+/// Record a view of `slug` by `fingerprint`. Returns `false` when the visitor
+/// was already counted inside the de-duplication window.
+///
+/// KV has no atomic increment, so two beacons for the same post landing at
+/// the same moment may be counted once; keeping one counter per window
+/// bounds the keys a flush has to read by posts rather than by views.
+pub async fn record_view(kv: &KvStore, slug: &str, fingerprint: &str) -> Result<bool> {
+    let seen_key = format!("{}{}:{}", SEEN_PREFIX, slug, fingerprint);
+    if kv.get(&seen_key).text().await?.is_some() {
+        return Ok(false);
+    }
+    kv.put(&seen_key, "1")?
+        .expiration_ttl(DEDUPE_WINDOW_SECS)
+        .execute()
+        .await?;
+
+    let count_key = format!("{}{}:{}", COUNT_PREFIX, current_window(), slug);
+    let count = read_count(kv, &count_key).await?;
+    kv.put(&count_key, (count + 1).to_string())?
+        .expiration_ttl(COUNT_TTL_SECS)
+        .execute()
+        .await?;
+    Ok(true)
+}
+
+async fn read_count(kv: &KvStore, key: &str) -> Result<u32> {
+    Ok(kv.get(key).text().await?.and_then(|count| count.parse().ok()).unwrap_or(0))
+}
+
+/// Move counters of closed windows from KV into D1. Returns the number of
+/// views added.
+///
+/// A window still takes writes until it ends, and KV listings lag by up to
+/// a minute, so the window before the current one is left for the next run.
+pub async fn flush_views(kv: &KvStore, db: &D1Database) -> Result<u32> {
+    let current = current_window();
+    let mut keys = Vec::new();
+    let mut cursor = None;
+    while keys.len() < MAX_COUNTERS_PER_FLUSH {
+        let mut list = kv.list().prefix(COUNT_PREFIX.to_string());
+        if let Some(cursor) = cursor.take() {
+            list = list.cursor(cursor);
+        }
+        let page = list.execute().await?;
+        keys.extend(page.keys.into_iter().filter_map(|key| {
+            let (window, slug) = key.name[COUNT_PREFIX.len()..].split_once(':')?;
+            let window: u64 = window.parse().ok()?;
+            (window + 1 < current).then(|| (key.name.clone(), window, slug.to_string()))
+        }));
+        match page.cursor {
+            Some(next) if !page.list_complete => cursor = Some(next),
+            _ => break,
+        }
+    }
+    keys.truncate(MAX_COUNTERS_PER_FLUSH);
+    if keys.is_empty() {
+        return Ok(0);
+    }
+
+    let mut counters = Vec::with_capacity(keys.len());
+    for (key, window, slug) in &keys {
+        let count = read_count(kv, key).await?;
+        if count > 0 {
+            counters.push(ViewCounter { window: *window, slug: slug.clone(), count });
+        }
+    }
+
+    // Markers outlive the counters they guard, so a leftover key is never
+    // applied twice
+    let prune_before = current.saturating_sub(2 * COUNT_TTL_SECS / WINDOW_SECS);
+    let flushed = DatabaseService::add_blog_post_views(db, &counters, prune_before).await?;
+    for (key, _, _) in &keys {
+        kv.delete(key).await?;
+    }
+    Ok(flushed)
+}
@@ -0,0 +1,63 @@
+use sha2::{Digest, Sha256};
+use worker::{Env, Request};
+
+/// Name of the Worker secret mixed into visitor hashes, so stored hashes
+/// cannot be reversed by hashing every IPv4 address.
+/// Set with: wrangler secret put VISITOR_SALT
+const SALT_BINDING: &str = "VISITOR_SALT";
+
+/// Anonymous identity of the client behind a request. Only hashes are kept;
+/// the raw IP address and user agent are never stored.
+pub struct Visitor {
+    /// Hash of IP address, user agent and language, used to dedupe per visitor
+    pub fingerprint: String,
+    /// Hash of the IP address alone, used as the rate limit key
+    pub ip_hash: String,
+    /// Whether the user agent looks like a crawler, preview fetcher or script
+    pub is_bot: bool,
+}
+
+/// Case-insensitive user-agent fragments of crawlers, link previewers and
+/// HTTP libraries. Browsers never send these.
+const BOT_MARKERS: &[&str] = &[
+    "bot", "crawl", "spider", "slurp", "headless", "lighthouse", "preview", "facebookexternalhit",
+    "embedly", "curl", "wget", "python-requests", "python-urllib", "go-http-client", "okhttp",
+    "axios", "node-fetch", "java/", "libwww", "httpclient", "feedfetcher", "monitor", "uptime",
+];
+
+fn looks_like_bot(user_agent: &str) -> bool {
+    let user_agent = user_agent.to_ascii_lowercase();
+    user_agent.is_empty() || BOT_MARKERS.iter().any(|marker| user_agent.contains(marker))
+}
+
+impl Visitor {
+    pub fn from_request(req: &Request, env: &Env) -> Self {
+        let salt = env
+            .secret(SALT_BINDING)
+            .map(|secret| secret.to_string())
+            .unwrap_or_default();
+        let header = |name: &str| req.headers().get(name).ok().flatten().unwrap_or_default();
+        let ip = header("CF-Connecting-IP");
+        let user_agent = header("User-Agent");
+
+        Visitor {
+            fingerprint: hash(&[&salt, &ip, &user_agent, &header("Accept-Language")]),
+            ip_hash: hash(&[&salt, &ip]),
+            is_bot: looks_like_bot(&user_agent),
+        }
+    }
+}
+
+fn hash(parts: &[&str]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part.as_bytes());
+        // Separator so ("ab", "c") and ("a", "bc") hash differently
+        hasher.update([0u8]);
+    }
+    hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect()
+}
@@ -1,7 +1,11 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+pub mod schedule;
+
+pub use schedule::{parse_timezone, weekday_code, RecurrenceRule, DEFAULT_TIMEZONE};
+
 #[cfg(feature = "openapi")]
 use utoipa::ToSchema;
 
@@ -15,7 +19,7 @@ pub enum DifficultyLevel {
     Hard,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub enum EventType {
     Workshop,
@@ -26,7 +30,7 @@ pub enum EventType {
     Networking,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub enum ProjectStatus {
     Planning,
@@ -37,7 +41,62 @@ pub enum ProjectStatus {
     Archived,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Where a course stands on accepting Rust submissions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum CourseStatus {
+    Approved,
+    UnderReview,
+    Pending,
+    Rejected,
+}
+
+/// A club role held by a member. Every member is implicitly a `Member`; the
+/// others are granted, and a member may hold several.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum MemberRole {
+    President,
+    VicePresident,
+    Secretary,
+    Treasurer,
+    ProjectLead,
+    Mentor,
+    Member,
+    Alumni,
+}
+
+impl MemberRole {
+    /// Officers manage all club content and grant roles
+    pub fn is_officer(&self) -> bool {
+        matches!(
+            self,
+            MemberRole::President | MemberRole::VicePresident | MemberRole::Secretary | MemberRole::Treasurer
+        )
+    }
+}
+
+/// Who may see a member's profile
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum ProfileVisibility {
+    /// Anyone, and listed in the public directory
+    Public,
+    /// Signed-in members only
+    Members,
+    /// Nobody but the member themselves
+    Hidden,
+}
+
+/// Whether a role change gave or took away the role
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum RoleAction {
+    Granted,
+    Revoked,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub enum BlogPostType {
     Tutorial,
@@ -48,7 +107,7 @@ pub enum BlogPostType {
     Review,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub enum BlogCategory {
     Fundamentals,
@@ -65,6 +124,39 @@ pub enum BlogCategory {
     Community,
 }
 
+/// Where a blog post is in the editorial workflow. Only published posts
+/// are public; a scheduled post is published when its time comes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum BlogPostStatus {
+    Draft,
+    InReview,
+    Scheduled,
+    #[default]
+    Published,
+    Archived,
+}
+
+impl BlogPostStatus {
+    /// Whether a post in this status may move to `next`
+    pub fn can_become(&self, next: BlogPostStatus) -> bool {
+        use BlogPostStatus::*;
+        matches!(
+            (self, next),
+            (Draft, InReview)
+                | (Draft, Archived)
+                | (InReview, Draft)
+                | (InReview, Scheduled)
+                | (InReview, Published)
+                | (Scheduled, InReview)
+                | (Scheduled, Published)
+                | (Published, Archived)
+                | (Archived, Draft)
+                | (Archived, Published)
+        )
+    }
+}
+
 // Common structs
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -79,14 +171,29 @@ pub struct Member {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct BlogSeries {
+    /// Series slug, for `GET /v1/blog/series/{slug}`
+    pub slug: String,
     /// Series title
     pub title: String,
     /// Current part number
     pub part: u32,
-    /// Total parts in series
+    /// Total parts in series: the planned number if set, otherwise the
+    /// published parts so far
     pub total_parts: Option<u32>,
 }
 
+/// A member credited on a blog post
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogAuthor {
+    /// Member's name
+    pub name: String,
+    /// GitHub username; author pages are addressed by it
+    pub github_username: String,
+    /// GitHub avatar, as of the last sign-in
+    pub avatar_url: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct ExternalLink {
@@ -101,14 +208,37 @@ pub struct ExternalLink {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct Stats {
-    /// Number of active club members
+    /// Name of the term the numbers cover, e.g. "Fall 2025"
+    pub semester: String,
+    /// Term the numbers cover
+    pub term: Term,
+    /// Members who had joined by the end of the term
     pub active_members: u32,
-    /// PRs merged this semester by club members
+    /// PRs by club members merged during the term
     pub prs_merged_this_semester: u32,
-    /// Number of workshops held
+    /// Workshop sessions held during the term so far
     pub workshops_held: u32,
-    /// Projects club members have contributed to
+    /// Club projects with at least one contributor that were running during the term
     pub projects_contributed_to: u32,
+    /// Event check-ins recorded during the term
+    pub event_check_ins: u32,
+    /// Members who checked in to at least one event during the term
+    pub event_attendees: u32,
+}
+
+/// An academic term (semester). Events, workshops, stats and petition
+/// milestones are grouped by the term their dates fall in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Term {
+    /// URL identifier, e.g. `fall-2025`
+    pub slug: String,
+    /// Display name, e.g. "Fall 2025"
+    pub name: String,
+    /// First day of the term, in club local time
+    pub starts_on: NaiveDate,
+    /// Last day of the term (inclusive), in club local time
+    pub ends_on: NaiveDate,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -120,20 +250,145 @@ pub struct Event {
     pub title: String,
     /// Event description
     pub description: String,
-    /// Event date
-    pub date: String,
-    /// Event time
-    pub time: String,
+    /// Start time. For recurring events listed by the API this is the start
+    /// of the returned occurrence.
+    pub starts_at: DateTime<Utc>,
+    /// End time of the event or occurrence
+    pub ends_at: DateTime<Utc>,
+    /// IANA time zone the event is scheduled in, e.g. America/Los_Angeles
+    pub timezone: String,
+    /// RRULE-style recurrence, e.g. FREQ=WEEKLY;BYDAY=FR. None for one-off events
+    pub recurrence: Option<String>,
     /// Event location
     pub location: String,
     /// Event type
     pub event_type: EventType,
-    /// Whether this is a recurring event
-    pub recurring: bool,
+    /// Most people who can RSVP as going; None for no limit
+    pub capacity: Option<u32>,
+    /// RSVPs marked going. For recurring events RSVPs cover the whole series.
+    pub going: u32,
+    /// RSVPs marked maybe
+    pub maybe: u32,
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
 }
 
+impl Term {
+    /// `[start, end)` as UTC instants: local midnight on `starts_on` to local
+    /// midnight after `ends_on`
+    pub fn bounds(&self) -> (DateTime<Utc>, DateTime<Utc>) {
+        (local_midnight(self.starts_on), local_midnight(self.ends_on.succ_opt().unwrap_or(self.ends_on)))
+    }
+
+    /// Whether `at` falls within the term
+    pub fn contains(&self, at: DateTime<Utc>) -> bool {
+        let (start, end) = self.bounds();
+        start <= at && at < end
+    }
+
+    /// Whether the term finished before `at`
+    pub fn ended_before(&self, at: DateTime<Utc>) -> bool {
+        self.bounds().1 <= at
+    }
+}
+
+fn club_timezone() -> chrono_tz::Tz {
+    parse_timezone(DEFAULT_TIMEZONE).unwrap_or(chrono_tz::America::Los_Angeles)
+}
+
+/// The calendar date at `at` in the club's time zone
+pub fn club_date(at: DateTime<Utc>) -> NaiveDate {
+    at.with_timezone(&club_timezone()).date_naive()
+}
+
+/// Midnight at the start of `date` in the club's time zone
+fn local_midnight(date: NaiveDate) -> DateTime<Utc> {
+    club_timezone()
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+        .earliest()
+        .map(|time| time.with_timezone(&Utc))
+        .unwrap_or_default()
+}
+
+impl Event {
+    /// The event's time zone, falling back to the club default for unknown names
+    pub fn tz(&self) -> chrono_tz::Tz {
+        parse_timezone(&self.timezone)
+            .or_else(|_| parse_timezone(DEFAULT_TIMEZONE))
+            .unwrap_or(chrono_tz::UTC)
+    }
+
+    pub fn recurrence_rule(&self) -> Option<RecurrenceRule> {
+        self.recurrence.as_deref().and_then(|rule| rule.parse().ok())
+    }
+
+    pub fn is_recurring(&self) -> bool {
+        self.recurrence_rule().is_some()
+    }
+
+    /// Expand into the occurrences overlapping `[from, to)`. One-off events
+    /// yield themselves when they overlap the window.
+    pub fn occurrences(&self, from: DateTime<Utc>, to: DateTime<Utc>) -> Vec<Event> {
+        let duration = self.ends_at - self.starts_at;
+        match self.recurrence_rule() {
+            Some(rule) => rule
+                .occurrences(self.starts_at.with_timezone(&self.tz()), duration, from, to)
+                .into_iter()
+                .map(|starts_at| Event {
+                    starts_at,
+                    ends_at: starts_at + duration,
+                    ..self.clone()
+                })
+                .collect(),
+            None if self.starts_at < to && self.ends_at > from => vec![self.clone()],
+            None => Vec::new(),
+        }
+    }
+
+    /// The occurrence running at `at`, allowing `slack` before its start and
+    /// after its end. When occurrences overlap, the one starting closest to `at`.
+    pub fn occurrence_near(&self, at: DateTime<Utc>, slack: Duration) -> Option<Event> {
+        self.occurrences(at - slack, at + slack)
+            .into_iter()
+            .min_by_key(|occurrence| (occurrence.starts_at - at).num_seconds().abs())
+    }
+
+    /// Whether `going` has reached `capacity`
+    pub fn is_full(&self) -> bool {
+        self.capacity.is_some_and(|capacity| self.going >= capacity)
+    }
+
+    /// Start date in the event's time zone, e.g. "Fri, Feb 14, 2025"
+    pub fn date_label(&self) -> String {
+        self.starts_at
+            .with_timezone(&self.tz())
+            .format("%a, %b %-d, %Y")
+            .to_string()
+    }
+
+    /// Recurrence summary such as "Every Friday", or None for one-off events
+    pub fn recurrence_label(&self) -> Option<String> {
+        self.recurrence_rule()
+            .map(|rule| rule.describe(self.starts_at.with_timezone(&self.tz())))
+    }
+
+    /// Time range in the event's time zone, e.g. "3:00 PM - 5:00 PM PST"
+    pub fn time_label(&self) -> String {
+        time_range_label(self.starts_at, self.ends_at, self.tz())
+    }
+}
+
+/// "1:00 PM - 5:00 PM PST", with the end date added when it differs
+fn time_range_label(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>, tz: chrono_tz::Tz) -> String {
+    let start = starts_at.with_timezone(&tz);
+    let end = ends_at.with_timezone(&tz);
+    if start.date_naive() == end.date_naive() {
+        format!("{} - {}", start.format("%-I:%M %p"), end.format("%-I:%M %p %Z"))
+    } else {
+        format!("{} - {}", start.format("%-I:%M %p"), end.format("%b %-d, %-I:%M %p %Z"))
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct Issue {
@@ -151,6 +406,13 @@ pub struct Issue {
     pub difficulty: DifficultyLevel,
     /// Associated tags
     pub tags: Vec<String>,
+    /// Whether a member is working on the issue: it has a live claim or an open PR
+    pub claimed: bool,
+    /// GitHub username of the member working on the issue, unless their
+    /// profile is not public
+    pub claimed_by: Option<String>,
+    /// When the claim lapses unless a PR is opened first
+    pub claim_expires_at: Option<DateTime<Utc>>,
     /// Creation timestamp
     pub created_at: DateTime<Utc>,
 }
@@ -182,6 +444,58 @@ pub struct Project {
     pub created_at: DateTime<Utc>,
 }
 
+/// Where a contribution stands. Claims move from claimed to PR opened to
+/// merged; a claim with no PR in time expires, and one given up is released.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum ContributionState {
+    Claimed,
+    PrOpened,
+    Merged,
+    Expired,
+    Released,
+}
+
+impl ContributionState {
+    /// Whether a contribution in this state may move to `next`
+    pub fn can_become(&self, next: ContributionState) -> bool {
+        use ContributionState::*;
+        matches!(
+            (self, next),
+            (Claimed, PrOpened) | (Claimed, Expired) | (Claimed, Released) | (PrOpened, Merged) | (PrOpened, Released)
+        )
+    }
+}
+
+/// A club member's work on an issue or pull request: a claim, an open PR or
+/// a merged PR
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Contribution {
+    /// Unique contribution identifier
+    pub id: String,
+    /// Contributing member
+    pub member: Member,
+    /// Claimed issue; absent for merged PRs recorded without a claim
+    pub issue_id: Option<String>,
+    /// Where the contribution stands
+    pub state: ContributionState,
+    /// Repository the PR targets, e.g. `rust-lang/rust`
+    pub repo: String,
+    /// Pull request URL, once a PR is opened
+    pub pr_url: Option<String>,
+    /// Pull request title, or the issue's title until a PR is opened
+    pub title: String,
+    /// When the issue was claimed
+    pub claimed_at: Option<DateTime<Utc>>,
+    /// When the claim lapses unless a PR is opened first
+    pub claim_expires_at: Option<DateTime<Utc>>,
+    /// When the PR was reported open
+    pub pr_opened_at: Option<DateTime<Utc>>,
+    /// When the PR was merged
+    pub merged_at: Option<DateTime<Utc>>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
 pub struct BlogPost {
@@ -203,83 +517,1591 @@ pub struct BlogPost {
     pub author_name: String,
     /// Author's GitHub username
     pub author_github: Option<String>,
+    /// Other members who wrote the post, in byline order
+    pub co_authors: Vec<BlogAuthor>,
     /// Difficulty level (optional)
     pub difficulty_level: Option<DifficultyLevel>,
     /// Estimated reading time in minutes
     pub estimated_read_time: u32,
     /// Publication date
     pub published_at: String,
-    /// Last update date
+    /// When the latest published revision went live
     pub updated_at: Option<String>,
-    /// View count
+    /// Deduplicated human view count, updated every few minutes
     pub views: u32,
     /// Like count
     pub likes: u32,
-    /// URL to markdown content on storage.chico.rs
+    /// URL of the markdown content, e.g. one returned by `POST /v1/content`
     pub markdown_url: String,
     /// Series information (optional)
     pub series: Option<BlogSeries>,
     /// External links
     pub external_links: Vec<ExternalLink>,
+    /// Where the post is in the editorial workflow
+    pub status: BlogPostStatus,
+    /// GitHub username of the member assigned to review the post
+    pub reviewer: Option<String>,
 }
 
+/// A hands-on workshop with a fixed number of seats. Registrations beyond
+/// `max_participants` join a waitlist.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[cfg_attr(feature = "openapi", derive(ToSchema))]
-pub struct HealthCheck {
-    /// Service status
-    pub status: String,
-    /// Current timestamp
-    pub timestamp: DateTime<Utc>,
-    /// API version
-    pub version: String,
+pub struct Workshop {
+    /// Unique workshop identifier
+    pub id: String,
+    /// Workshop title
+    pub title: String,
+    /// Workshop description
+    pub description: String,
+    /// Experience the workshop assumes
+    pub level: DifficultyLevel,
+    /// Start time (RFC 3339)
+    pub starts_at: DateTime<Utc>,
+    /// End time (RFC 3339)
+    pub ends_at: DateTime<Utc>,
+    /// Workshop location
+    pub location: String,
+    /// Who is running the workshop
+    pub instructor: String,
+    /// Topics covered
+    pub topics: Vec<String>,
+    /// What attendees should know or install beforehand
+    pub prerequisites: Vec<String>,
+    /// Slides, starter code or recording
+    pub materials_url: Option<String>,
+    /// Number of seats
+    pub max_participants: u32,
+    /// Confirmed registrations
+    pub current_participants: u32,
+    /// Registrations waiting for a seat
+    pub waitlisted: u32,
 }
 
-// Display implementations for enums
-impl fmt::Display for DifficultyLevel {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            DifficultyLevel::Easy => write!(f, "Easy"),
-            DifficultyLevel::Medium => write!(f, "Medium"),
-            DifficultyLevel::Hard => write!(f, "Hard"),
+impl BlogPost {
+    /// Whether `github_username` is the lead author or a co-author, ignoring case
+    pub fn is_author(&self, github_username: &str) -> bool {
+        self.author_github.as_deref().is_some_and(|author| author.eq_ignore_ascii_case(github_username))
+            || self.co_authors.iter().any(|author| author.github_username.eq_ignore_ascii_case(github_username))
+    }
+
+    /// Every author's name in byline order, e.g. "Ana, Ben and Cy"
+    pub fn byline(&self) -> String {
+        let names: Vec<&str> = std::iter::once(self.author_name.as_str())
+            .chain(self.co_authors.iter().map(|author| author.name.as_str()))
+            .collect();
+        match names.split_last() {
+            Some((last, rest)) if !rest.is_empty() => format!("{} and {}", rest.join(", "), last),
+            _ => self.author_name.clone(),
         }
     }
 }
 
-impl fmt::Display for EventType {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            EventType::Workshop => write!(f, "Workshop"),
-            EventType::StudyGroup => write!(f, "Study Group"),
-            EventType::Seminar => write!(f, "Seminar"),
-            EventType::Hackathon => write!(f, "Hackathon"),
-            EventType::Panel => write!(f, "Panel"),
-            EventType::Networking => write!(f, "Networking"),
-        }
+impl Workshop {
+    /// Seats still open to new registrations
+    pub fn seats_left(&self) -> u32 {
+        self.max_participants.saturating_sub(self.current_participants)
+    }
+
+    /// Start date in club local time, e.g. "Sat, Feb 15, 2025"
+    pub fn date_label(&self) -> String {
+        self.starts_at
+            .with_timezone(&club_timezone())
+            .format("%a, %b %-d, %Y")
+            .to_string()
+    }
+
+    /// Start and end time in club local time
+    pub fn time_label(&self) -> String {
+        time_range_label(self.starts_at, self.ends_at, club_timezone())
     }
 }
 
-impl fmt::Display for BlogPostType {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            BlogPostType::Tutorial => write!(f, "Tutorial"),
-            BlogPostType::Guide => write!(f, "Guide"),
-            BlogPostType::ShowAndTell => write!(f, "Show & Tell"),
-            BlogPostType::TechTalk => write!(f, "Tech Talk"),
-            BlogPostType::News => write!(f, "News"),
-            BlogPostType::Review => write!(f, "Review"),
-        }
+/// Whether a registration holds a seat
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum RegistrationStatus {
+    Registered,
+    Waitlisted,
+}
+
+/// A registration as seen by the person who made it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct WorkshopRegistration {
+    /// Confirmation token; needed to check on or cancel the registration
+    pub token: String,
+    /// Registrant's name
+    pub name: String,
+    /// Seat or waitlist
+    pub status: RegistrationStatus,
+    /// 1-based place in the waitlist, when waitlisted
+    pub waitlist_position: Option<u32>,
+    /// When the registration was made
+    pub registered_at: DateTime<Utc>,
+    /// The workshop, with current seat counts
+    pub workshop: Workshop,
+}
+
+/// A registration as listed to organisers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct WorkshopRegistrant {
+    /// Registrant's name
+    pub name: String,
+    /// Registrant's email
+    pub email: String,
+    /// Seat or waitlist
+    pub status: RegistrationStatus,
+    /// When the registration was made
+    pub registered_at: DateTime<Utc>,
+}
+
+/// A member's answer to an event invitation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum RsvpStatus {
+    Going,
+    Maybe,
+    NotGoing,
+}
+
+/// An RSVP as seen by the member who made it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct EventRsvp {
+    /// Confirmation token; needed to check on, change or withdraw the RSVP
+    pub token: String,
+    /// Who responded
+    pub member: Member,
+    /// Going, maybe or not going
+    pub status: RsvpStatus,
+    /// When the RSVP was made or last changed
+    pub responded_at: DateTime<Utc>,
+    /// The event, with current RSVP counts
+    pub event: Event,
+}
+
+/// An RSVP as listed to organisers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Rsvp {
+    /// Who responded
+    pub member: Member,
+    /// Going, maybe or not going
+    pub status: RsvpStatus,
+    /// When the RSVP was made or last changed
+    pub responded_at: DateTime<Utc>,
+}
+
+/// A short-lived code organisers show at the door. Members redeem it with
+/// `POST /v1/events/{id}/check-ins`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CheckInCode {
+    /// Code to type in, e.g. `K7PX2M`
+    pub code: String,
+    /// When the code stops working
+    pub expires_at: DateTime<Utc>,
+    /// Check-in page URL with the code filled in, for rendering as a QR code
+    pub qr_payload: String,
+}
+
+/// A member's recorded attendance at one occurrence of an event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CheckIn {
+    /// Event identifier
+    pub event_id: String,
+    /// Who attended
+    pub member: Member,
+    /// Start of the attended occurrence
+    pub occurrence_starts_at: DateTime<Utc>,
+    /// When the member checked in
+    pub checked_in_at: DateTime<Utc>,
+}
+
+/// A course the advocacy petition asks to accept Rust submissions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Course {
+    /// URL identifier, e.g. `cs-311`
+    pub slug: String,
+    /// Catalog code, e.g. "CS 311"
+    pub course_code: String,
+    /// Catalog title
+    pub course_name: String,
+    /// Department offering the course
+    pub department: String,
+    /// Instructor the petition is addressed to, if known
+    pub professor: Option<String>,
+    /// Why Rust fits this course
+    pub description: Option<String>,
+    /// Current status
+    pub status: CourseStatus,
+    /// People backing Rust in this course
+    pub supporters_count: u32,
+    /// Status changes, oldest first
+    pub history: Vec<CourseStatusChange>,
+}
+
+impl Course {
+    /// Approved and rejected courses no longer collect support
+    pub fn is_open(&self) -> bool {
+        matches!(self.status, CourseStatus::Pending | CourseStatus::UnderReview)
     }
 }
 
-impl fmt::Display for ProjectStatus {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        match self {
-            ProjectStatus::Planning => write!(f, "Planning"),
-            ProjectStatus::Active => write!(f, "Active"),
-            ProjectStatus::InDevelopment => write!(f, "In Development"),
-            ProjectStatus::Beta => write!(f, "Beta"),
-            ProjectStatus::Completed => write!(f, "Completed"),
-            ProjectStatus::Archived => write!(f, "Archived"),
+/// A milestone in a course's petition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CourseStatusChange {
+    /// Status the course moved to
+    pub status: CourseStatus,
+    /// What happened, e.g. "Presented to the curriculum committee"
+    pub note: Option<String>,
+    /// When the change happened
+    pub changed_at: DateTime<Utc>,
+    /// Term the change falls in, if any
+    pub term: Option<Term>,
+}
+
+/// Public totals for the advocacy petition
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PetitionSummary {
+    /// People who signed the petition or backed at least one course
+    pub total_supporters: u32,
+    /// Signatures on the petition itself
+    pub petition_signatures: u32,
+    /// Courses the petition covers
+    pub courses_targeted: u32,
+    /// Courses that now accept Rust
+    pub courses_approved: u32,
+}
+
+/// The member behind the session cookie, from `GET /v1/me`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CurrentUser {
+    /// The signed-in member
+    pub member: Member,
+    /// GitHub avatar, as of the last sign-in
+    pub avatar_url: Option<String>,
+    /// Roles granted to the member, beyond the implicit `Member`
+    pub roles: Vec<MemberRole>,
+}
+
+/// A member as listed in the directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct MemberSummary {
+    /// Member's name
+    pub name: String,
+    /// GitHub username; profiles are addressed by it
+    pub github_username: String,
+    /// GitHub avatar, as of the last sign-in
+    pub avatar_url: Option<String>,
+    /// Short bio written by the member
+    pub bio: Option<String>,
+    /// Skills the member lists, e.g. "Embedded"
+    pub skills: Vec<String>,
+    /// Roles granted to the member, beyond the implicit `Member`
+    pub roles: Vec<MemberRole>,
+    /// When the member joined the club
+    pub joined_at: DateTime<Utc>,
+}
+
+/// A member's profile page, from `GET /v1/members/{github}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct MemberProfile {
+    /// Who the member is
+    pub member: MemberSummary,
+    /// Blog posts the member wrote, newest first
+    pub blog_posts: Vec<BlogPost>,
+    /// Projects the member leads
+    pub projects_led: Vec<Project>,
+    /// Projects the member contributes to without leading them
+    pub projects_contributed: Vec<Project>,
+    /// Workshops that have ended where the member held a seat booked while
+    /// signed in, newest first. Empty when the member hides their attendance.
+    pub workshops_attended: Vec<Workshop>,
+    /// Event occurrences the member checked into; `None` when the member
+    /// hides their attendance
+    pub events_attended: Option<u32>,
+    /// Pull requests the member got merged, newest first
+    pub contributions: Vec<Contribution>,
+}
+
+/// A blog author's page, from `GET /v1/authors/{github}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct AuthorProfile {
+    /// Who the author is
+    pub author: BlogAuthor,
+    /// Short bio written by the member; `None` unless their profile is public
+    pub bio: Option<String>,
+    /// Published posts they wrote or co-wrote, newest first
+    pub posts: Page<BlogPost>,
+}
+
+/// A member's own profile settings, from `GET /v1/me/profile` and the body
+/// of `PUT /v1/me/profile`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ProfileSettings {
+    /// Short bio shown on the profile
+    #[serde(default)]
+    pub bio: Option<String>,
+    /// Skills to list, e.g. "Embedded"
+    #[serde(default)]
+    pub skills: Vec<String>,
+    /// Who may see the profile
+    pub visibility: ProfileVisibility,
+    /// Whether events and workshops attended appear on the profile
+    pub show_attendance: bool,
+}
+
+/// An entry in the role audit trail
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RoleChange {
+    /// Unique change identifier
+    pub id: String,
+    /// Member whose roles changed, as they were at the time
+    pub member: Member,
+    /// Role granted or revoked
+    pub role: MemberRole,
+    /// Whether the role was granted or revoked
+    pub action: RoleAction,
+    /// Who made the change: `@username`, or `admin token`
+    pub changed_by: String,
+    /// Why, if given
+    pub reason: Option<String>,
+    /// When the change was made
+    pub changed_at: DateTime<Utc>,
+}
+
+/// A saved version of a blog post, without its content
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogRevisionSummary {
+    /// Revision number, counting from 1 for each post
+    pub number: u32,
+    /// Post title in this revision
+    pub title: String,
+    /// Who saved it: `@username`, or `admin token`
+    pub created_by: String,
+    /// When it was saved
+    pub created_at: DateTime<Utc>,
+    /// When it went live; `None` if the post was never published with it
+    pub published_at: Option<DateTime<Utc>>,
+    /// Revision this one restored, if any
+    pub restored_from: Option<u32>,
+}
+
+/// A saved version of a blog post. Revisions are never changed once stored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogRevision {
+    /// Revision number, counting from 1 for each post
+    pub number: u32,
+    /// Who saved it: `@username`, or `admin token`
+    pub created_by: String,
+    /// When it was saved
+    pub created_at: DateTime<Utc>,
+    /// When it went live; `None` if the post was never published with it
+    pub published_at: Option<DateTime<Utc>>,
+    /// Revision this one restored, if any
+    pub restored_from: Option<u32>,
+    /// The post's metadata as saved
+    pub post: BlogPostInput,
+    /// The markdown at `post.markdown_url` when saved; `None` if it could not be fetched
+    pub markdown: Option<String>,
+}
+
+/// A metadata field that differs between two revisions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogFieldChange {
+    /// Field name, as in `BlogPostInput`
+    pub field: String,
+    /// Value in the older revision, as JSON
+    pub before: String,
+    /// Value in the newer revision, as JSON
+    pub after: String,
+}
+
+/// How a line of markdown changed between two revisions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum DiffOp {
+    Unchanged,
+    Added,
+    Removed,
+}
+
+/// One line of a markdown diff
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct DiffLine {
+    /// Whether the line was kept, added or removed
+    pub op: DiffOp,
+    /// The line, without its line ending
+    pub text: String,
+}
+
+/// Differences between two revisions of a post
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogRevisionDiff {
+    /// Older revision number
+    pub from: u32,
+    /// Newer revision number
+    pub to: u32,
+    /// Metadata fields that changed, by field name
+    pub fields: Vec<BlogFieldChange>,
+    /// Line diff of the markdown; empty if neither revision has markdown
+    pub markdown: Vec<DiffLine>,
+    /// Whether the markdown of either revision could not be fetched when it was saved
+    pub markdown_missing: bool,
+}
+
+/// An uploaded file in blog content storage. Files are named by the hash of
+/// their bytes, so uploading the same file twice returns the same URL.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ContentUpload {
+    /// Where the file is served, e.g. for a post's `markdown_url`
+    pub url: String,
+    /// Storage key: the hex SHA-256 of the bytes plus an extension
+    pub key: String,
+    /// MIME type the file is served with
+    pub content_type: String,
+    /// Size in bytes
+    pub size: u64,
+    /// Whether identical content was already stored, so nothing was written
+    pub deduplicated: bool,
+}
+
+/// A series of blog posts meant to be read in order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogSeriesSummary {
+    /// URL identifier, e.g. `rust-fundamentals`
+    pub slug: String,
+    /// Series title
+    pub title: String,
+    /// What the series covers
+    pub description: String,
+    /// How many parts the series is planned to have, if known
+    pub planned_parts: Option<u32>,
+    /// Parts published so far
+    pub published_parts: u32,
+}
+
+/// A published part of a series
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogSeriesEntry {
+    /// Part number within the series
+    pub part: u32,
+    /// Post title
+    pub title: String,
+    /// Post slug
+    pub slug: String,
+    /// Short excerpt/summary
+    pub excerpt: String,
+    /// Publication date
+    pub published_at: String,
+    /// Estimated reading time in minutes
+    pub estimated_read_time: u32,
+}
+
+/// A series with its published parts in reading order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogSeriesDetail {
+    /// URL identifier, e.g. `rust-fundamentals`
+    pub slug: String,
+    /// Series title
+    pub title: String,
+    /// What the series covers
+    pub description: String,
+    /// How many parts the series is planned to have, if known
+    pub planned_parts: Option<u32>,
+    /// Published parts, ordered by part number
+    pub parts: Vec<BlogSeriesEntry>,
+}
+
+/// A visitor's like state for a blog post
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct LikeStatus {
+    /// Total likes on the post
+    pub likes: u32,
+    /// Whether the requesting visitor has liked the post
+    pub liked: bool,
+}
+
+/// Kind of resource a search result points at
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub enum SearchResultKind {
+    BlogPost,
+    Issue,
+    Project,
+    Event,
+}
+
+/// A ranked full-text search hit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SearchResult {
+    /// Resource type
+    pub kind: SearchResultKind,
+    /// Identifier of the matched resource
+    pub id: String,
+    /// Plain-text title
+    pub title: String,
+    /// Title as HTML: matched terms are wrapped in `<mark>` and everything else is escaped
+    pub title_html: String,
+    /// Excerpt around the best match, as HTML in the same form as `title_html`
+    pub snippet_html: String,
+    /// Site path (e.g. `/blog/getting-started`) or external URL of the resource
+    pub url: String,
+    /// Relevance score; higher is a better match
+    pub score: f64,
+}
+
+/// Response of `/v1/search`, best match first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct SearchResults {
+    /// The query as received
+    pub query: String,
+    /// Matches across all requested resource types
+    pub results: Vec<SearchResult>,
+}
+
+/// One page of a list endpoint. Pass `next_cursor` back as `?cursor=` to
+/// fetch the following page; it is `null` on the last page.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct Page<T> {
+    /// Items on this page
+    pub items: Vec<T>,
+    /// Opaque cursor for the next page, if there is one
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct HealthCheck {
+    /// Service status
+    pub status: String,
+    /// Current timestamp
+    pub timestamp: DateTime<Utc>,
+    /// API version
+    pub version: String,
+}
+
+// Error model returned by every API route
+
+/// Machine-readable error code. Each code maps to exactly one HTTP status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+#[serde(rename_all = "snake_case")]
+pub enum ErrorCode {
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    Conflict,
+    PayloadTooLarge,
+    UnsupportedMediaType,
+    ValidationFailed,
+    RateLimited,
+    Internal,
+    ServiceUnavailable,
+}
+
+impl ErrorCode {
+    /// HTTP status code for this error
+    pub fn status(&self) -> u16 {
+        match self {
+            ErrorCode::BadRequest => 400,
+            ErrorCode::Unauthorized => 401,
+            ErrorCode::Forbidden => 403,
+            ErrorCode::NotFound => 404,
+            ErrorCode::Conflict => 409,
+            ErrorCode::PayloadTooLarge => 413,
+            ErrorCode::UnsupportedMediaType => 415,
+            ErrorCode::ValidationFailed => 422,
+            ErrorCode::RateLimited => 429,
+            ErrorCode::Internal => 500,
+            ErrorCode::ServiceUnavailable => 503,
+        }
+    }
+
+    /// Code for an HTTP status, used when a response has no structured body
+    pub fn from_status(status: u16) -> ErrorCode {
+        match status {
+            400 => ErrorCode::BadRequest,
+            401 => ErrorCode::Unauthorized,
+            403 => ErrorCode::Forbidden,
+            404 => ErrorCode::NotFound,
+            409 => ErrorCode::Conflict,
+            413 => ErrorCode::PayloadTooLarge,
+            415 => ErrorCode::UnsupportedMediaType,
+            422 => ErrorCode::ValidationFailed,
+            429 => ErrorCode::RateLimited,
+            503 => ErrorCode::ServiceUnavailable,
+            _ => ErrorCode::Internal,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ApiError {
+    /// Machine-readable error code
+    pub code: ErrorCode,
+    /// Human-readable description of what went wrong
+    pub message: String,
+    /// Per-field problems, e.g. validation failures
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<String>,
+    /// Identifier of the failed request, for bug reports and log lookup
+    #[serde(default)]
+    pub request_id: String,
+}
+
+impl ApiError {
+    pub fn new(code: ErrorCode, message: impl Into<String>) -> Self {
+        ApiError {
+            code,
+            message: message.into(),
+            details: Vec::new(),
+            request_id: String::new(),
+        }
+    }
+
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::BadRequest, message)
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Unauthorized, message)
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Forbidden, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::NotFound, message)
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Conflict, message)
+    }
+
+    pub fn rate_limited(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::RateLimited, message)
+    }
+
+    pub fn validation(details: Vec<String>) -> Self {
+        Self::new(ErrorCode::ValidationFailed, "Request body failed validation").with_details(details)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::new(ErrorCode::Internal, message)
+    }
+
+    pub fn with_details(mut self, details: Vec<String>) -> Self {
+        self.details = details;
+        self
+    }
+
+    /// HTTP status code for this error
+    pub fn status(&self) -> u16 {
+        self.code.status()
+    }
+}
+
+impl fmt::Display for ApiError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if !self.details.is_empty() {
+            write!(f, " ({})", self.details.join("; "))?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ApiError {}
+
+// Write models (request bodies for the authenticated admin API)
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct EventInput {
+    /// Event title
+    pub title: String,
+    /// Event description
+    pub description: String,
+    /// Start of the first occurrence (RFC 3339)
+    pub starts_at: DateTime<Utc>,
+    /// End of the first occurrence (RFC 3339)
+    pub ends_at: DateTime<Utc>,
+    /// IANA time zone, defaults to America/Los_Angeles
+    #[serde(default = "default_timezone")]
+    pub timezone: String,
+    /// RRULE-style recurrence, e.g. FREQ=WEEKLY;BYDAY=FR
+    #[serde(default)]
+    pub recurrence: Option<String>,
+    /// Event location
+    pub location: String,
+    /// Event type
+    pub event_type: EventType,
+    /// Most people who can RSVP as going; omit for no limit
+    #[serde(default)]
+    pub capacity: Option<u32>,
+}
+
+fn default_timezone() -> String {
+    DEFAULT_TIMEZONE.to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct IssueInput {
+    /// Issue title
+    pub title: String,
+    /// Issue description
+    pub description: String,
+    /// Repository name (owner/repo)
+    pub repo: String,
+    /// GitHub issue URL
+    pub github_url: String,
+    /// Issue difficulty level
+    pub difficulty: DifficultyLevel,
+    /// Associated tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ProjectInput {
+    /// Project name
+    pub name: String,
+    /// Project description
+    pub description: String,
+    /// GitHub repository URL
+    pub github_url: String,
+    /// GitHub username of the project leader (must be an existing member)
+    pub leader_github: String,
+    /// Project status
+    pub status: ProjectStatus,
+    /// Technology stack
+    #[serde(default)]
+    pub tech_stack: Vec<String>,
+    /// Whether contributors are needed
+    #[serde(default)]
+    pub contributors_needed: bool,
+    /// Skills needed for contribution
+    #[serde(default)]
+    pub skills_needed: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ContributionInput {
+    /// GitHub username of the contributor (must be an existing member)
+    pub github_username: String,
+    /// Repository the PR was merged into, e.g. `rust-lang/rust`
+    pub repo: String,
+    /// Pull request URL
+    pub pr_url: String,
+    /// Pull request title
+    pub title: String,
+    /// When the PR was merged
+    pub merged_at: DateTime<Utc>,
+}
+
+/// A pull request opened for a claimed issue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct PullRequestInput {
+    /// Pull request URL
+    pub pr_url: String,
+    /// Pull request title
+    pub title: String,
+}
+
+/// Confirmation that an opened pull request was merged
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct MergeInput {
+    /// When the PR was merged; defaults to now
+    #[serde(default)]
+    pub merged_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct WorkshopInput {
+    /// Workshop title
+    pub title: String,
+    /// Workshop description
+    pub description: String,
+    /// Experience the workshop assumes
+    pub level: DifficultyLevel,
+    /// Start time (RFC 3339)
+    pub starts_at: DateTime<Utc>,
+    /// End time (RFC 3339)
+    pub ends_at: DateTime<Utc>,
+    /// Workshop location
+    pub location: String,
+    /// Who is running the workshop
+    pub instructor: String,
+    /// Topics covered
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// What attendees should know or install beforehand
+    #[serde(default)]
+    pub prerequisites: Vec<String>,
+    /// Slides, starter code or recording
+    #[serde(default)]
+    pub materials_url: Option<String>,
+    /// Number of seats
+    pub max_participants: u32,
+}
+
+/// Body of `POST /v1/workshops/{id}/registrations`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct WorkshopRegistrationInput {
+    /// Registrant's name
+    pub name: String,
+    /// Registrant's email; one registration per address per workshop
+    pub email: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct TermInput {
+    /// URL identifier, e.g. `fall-2025`
+    pub slug: String,
+    /// Display name, e.g. "Fall 2025"
+    pub name: String,
+    /// First day of the term, in club local time
+    pub starts_on: NaiveDate,
+    /// Last day of the term (inclusive), in club local time
+    pub ends_on: NaiveDate,
+}
+
+/// Where a post sits in a series
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogSeriesPart {
+    /// Slug of an existing series
+    pub slug: String,
+    /// Part number within the series, from 1
+    pub part: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogSeriesInput {
+    /// URL identifier, e.g. `rust-fundamentals`
+    pub slug: String,
+    /// Series title
+    pub title: String,
+    /// What the series covers
+    #[serde(default)]
+    pub description: String,
+    /// How many parts the series is planned to have, if known
+    #[serde(default)]
+    pub planned_parts: Option<u32>,
+}
+
+/// Body of `POST /v1/events/{id}/rsvps`. The RSVP is the signed-in member's.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RsvpInput {
+    /// Going, maybe or not going
+    pub status: RsvpStatus,
+}
+
+/// Body of `PUT /v1/events/{id}/rsvps/{token}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RsvpChange {
+    /// Going, maybe or not going
+    pub status: RsvpStatus,
+}
+
+/// Body of `POST /v1/members/{github}/roles`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct RoleGrantInput {
+    /// Role to grant; `Member` is implicit and cannot be granted
+    pub role: MemberRole,
+    /// Why, for the audit trail
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Body of `POST /v1/events/{id}/check-ins`. The signed-in member is the
+/// one checking in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CheckInInput {
+    /// Code shown at the door
+    pub code: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CourseInput {
+    /// URL identifier, e.g. `cs-311`
+    pub slug: String,
+    /// Catalog code, e.g. "CS 311"
+    pub course_code: String,
+    /// Catalog title
+    pub course_name: String,
+    /// Department offering the course
+    pub department: String,
+    /// Instructor the petition is addressed to, if known
+    #[serde(default)]
+    pub professor: Option<String>,
+    /// Why Rust fits this course
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+/// Body of `POST /v1/courses/{slug}/status`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct CourseStatusInput {
+    /// Status the course moved to
+    pub status: CourseStatus,
+    /// What happened
+    #[serde(default)]
+    pub note: Option<String>,
+    /// When it happened; defaults to now
+    #[serde(default)]
+    pub changed_at: Option<DateTime<Utc>>,
+}
+
+/// Body of `POST /v1/projects/{id}/contributors`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ContributorInput {
+    /// GitHub username of the contributor (must be an existing member)
+    pub github_username: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogPostInput {
+    /// Post title
+    pub title: String,
+    /// URL-friendly slug
+    pub slug: String,
+    /// Short excerpt/summary
+    pub excerpt: String,
+    /// Post type
+    pub post_type: BlogPostType,
+    /// Post category
+    pub category: BlogCategory,
+    /// Associated tags
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// Author information
+    pub author_name: String,
+    /// Author's GitHub username
+    #[serde(default)]
+    pub author_github: Option<String>,
+    /// GitHub usernames of other members who wrote the post, in byline order
+    #[serde(default)]
+    pub co_authors: Vec<String>,
+    /// Difficulty level (optional)
+    #[serde(default)]
+    pub difficulty_level: Option<DifficultyLevel>,
+    /// Estimated reading time in minutes
+    pub estimated_read_time: u32,
+    /// Publication date (RFC 3339). Set to the actual time when the post is
+    /// published or scheduled.
+    pub published_at: String,
+    /// Last update date (RFC 3339), used until the post has a published
+    /// revision
+    #[serde(default)]
+    pub updated_at: Option<String>,
+    /// URL of the markdown content, e.g. one returned by `POST /v1/content`
+    pub markdown_url: String,
+    /// Series the post belongs to (optional)
+    #[serde(default)]
+    pub series: Option<BlogSeriesPart>,
+    /// External links
+    #[serde(default)]
+    pub external_links: Vec<ExternalLink>,
+}
+
+/// A move to another step of the editorial workflow
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogStatusInput {
+    /// Status to move the post to
+    pub status: BlogPostStatus,
+    /// When a scheduled post goes live; required when scheduling
+    #[serde(default)]
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+/// Who reviews a blog post
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct ReviewerInput {
+    /// GitHub username of the reviewer (must be an existing member), or null to unassign
+    pub github_username: Option<String>,
+}
+
+/// A private link to a post that is not published yet
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "openapi", derive(ToSchema))]
+pub struct BlogPreviewLink {
+    /// Website page showing the preview
+    pub url: String,
+    /// Preview token; shown once, and replaced by the next link made for the post
+    pub token: String,
+    /// When the link stops working
+    pub expires_at: DateTime<Utc>,
+}
+
+fn require_non_empty(errors: &mut Vec<String>, field: &str, value: &str) {
+    if value.trim().is_empty() {
+        errors.push(format!("{} must not be empty", field));
+    }
+}
+
+fn require_url(errors: &mut Vec<String>, field: &str, value: &str) {
+    if !(value.starts_with("https://") || value.starts_with("http://")) {
+        errors.push(format!("{} must be an http(s) URL", field));
+    }
+}
+
+fn require_slug(errors: &mut Vec<String>, field: &str, value: &str) {
+    if value.is_empty()
+        || !value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        errors.push(format!("{} must contain only lowercase letters, digits and dashes", field));
+    }
+}
+
+fn require_email(errors: &mut Vec<String>, field: &str, value: &str) {
+    let valid = value
+        .trim()
+        .split_once('@')
+        .is_some_and(|(local, domain)| !local.is_empty() && domain.contains('.') && !domain.ends_with('.'));
+    if !valid {
+        errors.push(format!("{} must be an email address", field));
+    }
+}
+
+fn finish(errors: Vec<String>) -> Result<(), Vec<String>> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+impl EventInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "title", &self.title);
+        require_non_empty(&mut errors, "description", &self.description);
+        require_non_empty(&mut errors, "location", &self.location);
+        if self.ends_at <= self.starts_at {
+            errors.push("ends_at must be after starts_at".to_string());
+        }
+        if let Err(e) = parse_timezone(&self.timezone) {
+            errors.push(format!("timezone: {}", e));
+        }
+        if let Some(recurrence) = &self.recurrence {
+            if let Err(e) = recurrence.parse::<RecurrenceRule>() {
+                errors.push(format!("recurrence: {}", e));
+            }
+        }
+        if self.capacity == Some(0) {
+            errors.push("capacity must be at least 1".to_string());
+        }
+        finish(errors)
+    }
+}
+
+impl IssueInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "title", &self.title);
+        require_non_empty(&mut errors, "description", &self.description);
+        if self.repo.split('/').filter(|part| !part.is_empty()).count() != 2 {
+            errors.push("repo must be in owner/name form".to_string());
+        }
+        require_url(&mut errors, "github_url", &self.github_url);
+        finish(errors)
+    }
+}
+
+impl ProjectInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "name", &self.name);
+        require_non_empty(&mut errors, "description", &self.description);
+        require_url(&mut errors, "github_url", &self.github_url);
+        require_non_empty(&mut errors, "leader_github", &self.leader_github);
+        finish(errors)
+    }
+}
+
+impl WorkshopInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "title", &self.title);
+        require_non_empty(&mut errors, "description", &self.description);
+        require_non_empty(&mut errors, "location", &self.location);
+        require_non_empty(&mut errors, "instructor", &self.instructor);
+        if self.ends_at <= self.starts_at {
+            errors.push("ends_at must be after starts_at".to_string());
+        }
+        if let Some(url) = &self.materials_url {
+            require_url(&mut errors, "materials_url", url);
+        }
+        if self.max_participants == 0 {
+            errors.push("max_participants must be at least 1".to_string());
+        }
+        finish(errors)
+    }
+}
+
+impl WorkshopRegistrationInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "name", &self.name);
+        require_email(&mut errors, "email", &self.email);
+        finish(errors)
+    }
+}
+
+impl RoleGrantInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if self.role == MemberRole::Member {
+            errors.push("role: every member already holds Member".to_string());
+        }
+        finish(errors)
+    }
+}
+
+/// Longest bio a member may write, in characters
+pub const MAX_BIO_CHARS: usize = 500;
+
+/// Most skills a member may list
+pub const MAX_SKILLS: usize = 20;
+
+impl ProfileSettings {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if let Some(bio) = &self.bio {
+            if bio.chars().count() > MAX_BIO_CHARS {
+                errors.push(format!("bio must be at most {} characters", MAX_BIO_CHARS));
+            }
+        }
+        if self.skills.len() > MAX_SKILLS {
+            errors.push(format!("skills may list at most {} entries", MAX_SKILLS));
+        }
+        for skill in &self.skills {
+            require_non_empty(&mut errors, "skills", skill);
+        }
+        finish(errors)
+    }
+}
+
+impl CheckInInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "code", &self.code);
+        finish(errors)
+    }
+}
+
+impl CourseInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_slug(&mut errors, "slug", &self.slug);
+        require_non_empty(&mut errors, "course_code", &self.course_code);
+        require_non_empty(&mut errors, "course_name", &self.course_name);
+        require_non_empty(&mut errors, "department", &self.department);
+        finish(errors)
+    }
+}
+
+impl CourseStatusInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if self.changed_at.is_some_and(|at| at > Utc::now()) {
+            errors.push("changed_at must not be in the future".to_string());
+        }
+        finish(errors)
+    }
+}
+
+impl TermInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_slug(&mut errors, "slug", &self.slug);
+        require_non_empty(&mut errors, "name", &self.name);
+        if self.ends_on < self.starts_on {
+            errors.push("ends_on must not be before starts_on".to_string());
+        }
+        finish(errors)
+    }
+}
+
+impl BlogSeriesInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_slug(&mut errors, "slug", &self.slug);
+        require_non_empty(&mut errors, "title", &self.title);
+        if self.planned_parts == Some(0) {
+            errors.push("planned_parts must be at least 1".to_string());
+        }
+        finish(errors)
+    }
+}
+
+impl ContributionInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "github_username", &self.github_username);
+        require_non_empty(&mut errors, "repo", &self.repo);
+        require_url(&mut errors, "pr_url", &self.pr_url);
+        require_non_empty(&mut errors, "title", &self.title);
+        if self.merged_at > Utc::now() {
+            errors.push("merged_at must not be in the future".to_string());
+        }
+        finish(errors)
+    }
+}
+
+impl PullRequestInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_url(&mut errors, "pr_url", &self.pr_url);
+        require_non_empty(&mut errors, "title", &self.title);
+        finish(errors)
+    }
+}
+
+impl MergeInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if self.merged_at.is_some_and(|merged_at| merged_at > Utc::now()) {
+            errors.push("merged_at must not be in the future".to_string());
+        }
+        finish(errors)
+    }
+}
+
+impl ContributorInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "github_username", &self.github_username);
+        finish(errors)
+    }
+}
+
+impl BlogStatusInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        match (self.status, self.publish_at) {
+            (BlogPostStatus::Scheduled, None) => errors.push("publish_at is required when scheduling".to_string()),
+            (BlogPostStatus::Scheduled, Some(publish_at)) if publish_at <= Utc::now() => {
+                errors.push("publish_at must be in the future".to_string())
+            }
+            (BlogPostStatus::Scheduled, Some(_)) | (_, None) => {}
+            (_, Some(_)) => errors.push("publish_at is only used when scheduling".to_string()),
+        }
+        finish(errors)
+    }
+}
+
+/// Path segments under `/v1/blog/` claimed by routes registered before
+/// `/v1/blog/:slug`, so a post using one could not be fetched or liked
+const RESERVED_BLOG_SLUGS: &[&str] = &["featured", "editorial", "series", "preview", "markdown", "categories", "tags"];
+
+impl BlogPostInput {
+    /// Check the input for missing or malformed fields
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        require_non_empty(&mut errors, "title", &self.title);
+        require_non_empty(&mut errors, "excerpt", &self.excerpt);
+        require_non_empty(&mut errors, "author_name", &self.author_name);
+        for (i, co_author) in self.co_authors.iter().enumerate() {
+            require_non_empty(&mut errors, "co_authors", co_author);
+            let is = |other: &str| other.eq_ignore_ascii_case(co_author);
+            if self.author_github.as_deref().is_some_and(is) || self.co_authors[..i].iter().any(|other| is(other)) {
+                errors.push(format!("co_authors lists {} more than once", co_author));
+            }
+        }
+        require_slug(&mut errors, "slug", &self.slug);
+        if RESERVED_BLOG_SLUGS.contains(&self.slug.as_str()) {
+            errors.push(format!("slug '{}' is reserved", self.slug));
+        }
+        if self.estimated_read_time == 0 {
+            errors.push("estimated_read_time must be at least 1 minute".to_string());
+        }
+        if DateTime::parse_from_rfc3339(&self.published_at).is_err() {
+            errors.push("published_at must be an RFC 3339 timestamp".to_string());
+        }
+        if let Some(updated_at) = &self.updated_at {
+            if DateTime::parse_from_rfc3339(updated_at).is_err() {
+                errors.push("updated_at must be an RFC 3339 timestamp".to_string());
+            }
+        }
+        require_url(&mut errors, "markdown_url", &self.markdown_url);
+        if let Some(series) = &self.series {
+            require_slug(&mut errors, "series.slug", &series.slug);
+            if series.part == 0 {
+                errors.push("series.part must be at least 1".to_string());
+            }
+        }
+        for link in &self.external_links {
+            require_url(&mut errors, "external_links.url", &link.url);
+        }
+        finish(errors)
+    }
+}
+
+impl From<Event> for EventInput {
+    fn from(event: Event) -> Self {
+        EventInput {
+            title: event.title,
+            description: event.description,
+            starts_at: event.starts_at,
+            ends_at: event.ends_at,
+            timezone: event.timezone,
+            recurrence: event.recurrence,
+            location: event.location,
+            event_type: event.event_type,
+            capacity: event.capacity,
+        }
+    }
+}
+
+impl From<Workshop> for WorkshopInput {
+    fn from(workshop: Workshop) -> Self {
+        WorkshopInput {
+            title: workshop.title,
+            description: workshop.description,
+            level: workshop.level,
+            starts_at: workshop.starts_at,
+            ends_at: workshop.ends_at,
+            location: workshop.location,
+            instructor: workshop.instructor,
+            topics: workshop.topics,
+            prerequisites: workshop.prerequisites,
+            materials_url: workshop.materials_url,
+            max_participants: workshop.max_participants,
+        }
+    }
+}
+
+impl From<Issue> for IssueInput {
+    fn from(issue: Issue) -> Self {
+        IssueInput {
+            title: issue.title,
+            description: issue.description,
+            repo: issue.repo,
+            github_url: issue.github_url,
+            difficulty: issue.difficulty,
+            tags: issue.tags,
+        }
+    }
+}
+
+impl From<Project> for ProjectInput {
+    fn from(project: Project) -> Self {
+        ProjectInput {
+            name: project.name,
+            description: project.description,
+            github_url: project.github_url,
+            leader_github: project.leader.github_username.unwrap_or_default(),
+            status: project.status,
+            tech_stack: project.tech_stack,
+            contributors_needed: project.contributors_needed,
+            skills_needed: project.skills_needed,
+        }
+    }
+}
+
+impl From<BlogPost> for BlogPostInput {
+    fn from(post: BlogPost) -> Self {
+        BlogPostInput {
+            title: post.title,
+            slug: post.slug,
+            excerpt: post.excerpt,
+            post_type: post.post_type,
+            category: post.category,
+            tags: post.tags,
+            author_name: post.author_name,
+            author_github: post.author_github,
+            co_authors: post.co_authors.into_iter().map(|author| author.github_username).collect(),
+            difficulty_level: post.difficulty_level,
+            estimated_read_time: post.estimated_read_time,
+            published_at: post.published_at,
+            updated_at: post.updated_at,
+            markdown_url: post.markdown_url,
+            series: post.series.map(|series| BlogSeriesPart {
+                slug: series.slug,
+                part: series.part,
+            }),
+            external_links: post.external_links,
+        }
+    }
+}
+
+// Display implementations for enums
+impl fmt::Display for DifficultyLevel {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DifficultyLevel::Easy => write!(f, "Easy"),
+            DifficultyLevel::Medium => write!(f, "Medium"),
+            DifficultyLevel::Hard => write!(f, "Hard"),
+        }
+    }
+}
+
+impl fmt::Display for ContributionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ContributionState::Claimed => write!(f, "Claimed"),
+            ContributionState::PrOpened => write!(f, "PR Opened"),
+            ContributionState::Merged => write!(f, "Merged"),
+            ContributionState::Expired => write!(f, "Expired"),
+            ContributionState::Released => write!(f, "Released"),
+        }
+    }
+}
+
+impl fmt::Display for SearchResultKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SearchResultKind::BlogPost => write!(f, "Blog Post"),
+            SearchResultKind::Issue => write!(f, "Issue"),
+            SearchResultKind::Project => write!(f, "Project"),
+            SearchResultKind::Event => write!(f, "Event"),
+        }
+    }
+}
+
+impl fmt::Display for EventType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EventType::Workshop => write!(f, "Workshop"),
+            EventType::StudyGroup => write!(f, "Study Group"),
+            EventType::Seminar => write!(f, "Seminar"),
+            EventType::Hackathon => write!(f, "Hackathon"),
+            EventType::Panel => write!(f, "Panel"),
+            EventType::Networking => write!(f, "Networking"),
+        }
+    }
+}
+
+impl fmt::Display for BlogPostStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlogPostStatus::Draft => write!(f, "Draft"),
+            BlogPostStatus::InReview => write!(f, "In Review"),
+            BlogPostStatus::Scheduled => write!(f, "Scheduled"),
+            BlogPostStatus::Published => write!(f, "Published"),
+            BlogPostStatus::Archived => write!(f, "Archived"),
+        }
+    }
+}
+
+impl fmt::Display for BlogPostType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            BlogPostType::Tutorial => write!(f, "Tutorial"),
+            BlogPostType::Guide => write!(f, "Guide"),
+            BlogPostType::ShowAndTell => write!(f, "Show & Tell"),
+            BlogPostType::TechTalk => write!(f, "Tech Talk"),
+            BlogPostType::News => write!(f, "News"),
+            BlogPostType::Review => write!(f, "Review"),
+        }
+    }
+}
+
+impl fmt::Display for ProjectStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ProjectStatus::Planning => write!(f, "Planning"),
+            ProjectStatus::Active => write!(f, "Active"),
+            ProjectStatus::InDevelopment => write!(f, "In Development"),
+            ProjectStatus::Beta => write!(f, "Beta"),
+            ProjectStatus::Completed => write!(f, "Completed"),
+            ProjectStatus::Archived => write!(f, "Archived"),
+        }
+    }
+}
+
+impl fmt::Display for MemberRole {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MemberRole::President => write!(f, "President"),
+            MemberRole::VicePresident => write!(f, "Vice President"),
+            MemberRole::Secretary => write!(f, "Secretary"),
+            MemberRole::Treasurer => write!(f, "Treasurer"),
+            MemberRole::ProjectLead => write!(f, "Project Lead"),
+            MemberRole::Mentor => write!(f, "Mentor"),
+            MemberRole::Member => write!(f, "Member"),
+            MemberRole::Alumni => write!(f, "Alumni"),
+        }
+    }
+}
+
+impl fmt::Display for CourseStatus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CourseStatus::Approved => write!(f, "Approved"),
+            CourseStatus::UnderReview => write!(f, "Under Review"),
+            CourseStatus::Pending => write!(f, "Pending"),
+            CourseStatus::Rejected => write!(f, "Rejected"),
         }
     }
 }
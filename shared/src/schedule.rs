@@ -0,0 +1,401 @@
+use chrono::{DateTime, Datelike, Days, Duration, Months, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+use chrono_tz::Tz;
+use std::fmt;
+use std::str::FromStr;
+
+/// Time zone used when an event does not specify one (Chico, CA)
+pub const DEFAULT_TIMEZONE: &str = "America/Los_Angeles";
+
+/// Upper bound on generated periods so a malformed rule cannot loop forever
+const MAX_PERIODS: u32 = 100_000;
+
+/// Largest accepted `INTERVAL`; longer gaps are better entered as separate events
+const MAX_INTERVAL: u32 = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// The subset of RFC 5545 RRULE the club uses: `FREQ`, `INTERVAL`, `BYDAY`
+/// (weekly rules only), `COUNT` and `UNTIL`.
+/// Example: `FREQ=WEEKLY;BYDAY=FR` for "every Friday".
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecurrenceRule {
+    pub frequency: Frequency,
+    pub interval: u32,
+    pub by_day: Vec<Weekday>,
+    pub count: Option<u32>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl FromStr for RecurrenceRule {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let value = value.trim();
+        let value = value.strip_prefix("RRULE:").unwrap_or(value);
+
+        let mut frequency = None;
+        let mut rule = RecurrenceRule {
+            frequency: Frequency::Weekly,
+            interval: 1,
+            by_day: Vec::new(),
+            count: None,
+            until: None,
+        };
+
+        for part in value.split(';').filter(|part| !part.is_empty()) {
+            let (key, val) = part
+                .split_once('=')
+                .ok_or_else(|| format!("'{}' is not a KEY=VALUE pair", part))?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match val.to_ascii_uppercase().as_str() {
+                        "DAILY" => Frequency::Daily,
+                        "WEEKLY" => Frequency::Weekly,
+                        "MONTHLY" => Frequency::Monthly,
+                        other => return Err(format!("FREQ={} is not supported", other)),
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = val
+                        .parse()
+                        .ok()
+                        .filter(|interval| (1..=MAX_INTERVAL).contains(interval))
+                        .ok_or_else(|| format!("INTERVAL must be an integer from 1 to {}", MAX_INTERVAL))?
+                }
+                "BYDAY" => {
+                    rule.by_day = val
+                        .split(',')
+                        .map(parse_weekday)
+                        .collect::<Result<Vec<_>, _>>()?
+                }
+                "COUNT" => {
+                    rule.count = Some(
+                        val.parse()
+                            .ok()
+                            .filter(|count| *count > 0)
+                            .ok_or_else(|| "COUNT must be a positive integer".to_string())?,
+                    )
+                }
+                "UNTIL" => rule.until = Some(parse_until(val)?),
+                other => return Err(format!("{} is not supported", other)),
+            }
+        }
+
+        rule.frequency = frequency.ok_or_else(|| "FREQ is required".to_string())?;
+        if !rule.by_day.is_empty() && rule.frequency != Frequency::Weekly {
+            return Err("BYDAY is only supported with FREQ=WEEKLY".to_string());
+        }
+        if rule.count.is_some() && rule.until.is_some() {
+            return Err("COUNT and UNTIL cannot both be set".to_string());
+        }
+        Ok(rule)
+    }
+}
+
+impl fmt::Display for RecurrenceRule {
+    /// Canonical RRULE value, without the `RRULE:` prefix
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let frequency = match self.frequency {
+            Frequency::Daily => "DAILY",
+            Frequency::Weekly => "WEEKLY",
+            Frequency::Monthly => "MONTHLY",
+        };
+        write!(f, "FREQ={}", frequency)?;
+        if self.interval != 1 {
+            write!(f, ";INTERVAL={}", self.interval)?;
+        }
+        if !self.by_day.is_empty() {
+            let days: Vec<&str> = self.by_day.iter().map(|day| weekday_code(*day)).collect();
+            write!(f, ";BYDAY={}", days.join(","))?;
+        }
+        if let Some(count) = self.count {
+            write!(f, ";COUNT={}", count)?;
+        }
+        if let Some(until) = self.until {
+            write!(f, ";UNTIL={}", until.format("%Y%m%dT%H%M%SZ"))?;
+        }
+        Ok(())
+    }
+}
+
+impl RecurrenceRule {
+    /// Human readable summary, e.g. "Every Friday" or "Every 2 weeks on Monday, Wednesday"
+    pub fn describe(&self, first_start: DateTime<Tz>) -> String {
+        let days = if self.by_day.is_empty() {
+            vec![first_start.weekday()]
+        } else {
+            self.by_day.clone()
+        };
+        let day_names: Vec<&str> = days.iter().map(|day| weekday_name(*day)).collect();
+
+        match (self.frequency, self.interval) {
+            (Frequency::Daily, 1) => "Every day".to_string(),
+            (Frequency::Daily, n) => format!("Every {} days", n),
+            (Frequency::Weekly, 1) => format!("Every {}", day_names.join(", ")),
+            (Frequency::Weekly, n) => format!("Every {} weeks on {}", n, day_names.join(", ")),
+            (Frequency::Monthly, 1) => format!("Monthly on the {}", ordinal(first_start.day())),
+            (Frequency::Monthly, n) => format!("Every {} months on the {}", n, ordinal(first_start.day())),
+        }
+    }
+
+    /// Start times of every occurrence, beginning with `first_start`, that
+    /// overlaps `[from, to)` given each occurrence lasts `duration`.
+    /// Occurrences keep their local wall-clock time across DST changes.
+    /// Expansion stops at the end of the dates chrono can represent.
+    pub fn occurrences(
+        &self,
+        first_start: DateTime<Tz>,
+        duration: Duration,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Vec<DateTime<Utc>> {
+        let tz = first_start.timezone();
+        let local_time = first_start.naive_local().time();
+        let first_date = first_start.date_naive();
+        let mut produced = 0u32;
+        let mut result = Vec::new();
+
+        for period in 0..MAX_PERIODS {
+            let Some(mut dates) = self.period_dates(first_date, period) else {
+                break;
+            };
+            dates.retain(|date| *date >= first_date);
+
+            for date in dates {
+                let start = match resolve_local(&tz, date.and_time(local_time)) {
+                    Some(start) => start,
+                    None => continue,
+                };
+                if self.until.is_some_and(|until| start > until) || start >= to {
+                    return result;
+                }
+                produced += 1;
+                if start.checked_add_signed(duration).is_none_or(|end| end > from) {
+                    result.push(start);
+                }
+                if self.count.is_some_and(|count| produced >= count) {
+                    return result;
+                }
+            }
+        }
+        result
+    }
+
+    /// Candidate dates in the `period`-th interval after the first occurrence,
+    /// or `None` once the period lies beyond the representable dates
+    fn period_dates(&self, first_date: NaiveDate, period: u32) -> Option<Vec<NaiveDate>> {
+        let step = u64::from(period) * u64::from(self.interval);
+        match self.frequency {
+            Frequency::Daily => first_date.checked_add_days(Days::new(step)).map(|date| vec![date]),
+            Frequency::Weekly => {
+                let weeks = Days::new(step.checked_mul(7)?);
+                if self.by_day.is_empty() {
+                    return first_date.checked_add_days(weeks).map(|date| vec![date]);
+                }
+                let week_start = first_date
+                    .checked_sub_days(Days::new(first_date.weekday().num_days_from_monday().into()))?
+                    .checked_add_days(weeks)?;
+                let mut dates = self
+                    .by_day
+                    .iter()
+                    .map(|day| week_start.checked_add_days(Days::new(day.num_days_from_monday().into())))
+                    .collect::<Option<Vec<_>>>()?;
+                dates.sort();
+                Some(dates)
+            }
+            Frequency::Monthly => {
+                let month = first_date
+                    .with_day(1)?
+                    .checked_add_months(Months::new(u32::try_from(step).ok()?))?;
+                // Months without the start day (e.g. the 31st) are skipped, as RFC 5545 does
+                Some(month.with_day(first_date.day()).into_iter().collect())
+            }
+        }
+    }
+}
+
+/// Parse an IANA time zone name such as `America/Los_Angeles`
+pub fn parse_timezone(name: &str) -> Result<Tz, String> {
+    name.parse::<Tz>()
+        .map_err(|_| format!("'{}' is not a known IANA time zone", name))
+}
+
+/// Map a local wall-clock time to UTC. Ambiguous times (DST fall back) use
+/// the earlier instant; times skipped by DST spring forward move ahead an hour.
+fn resolve_local(tz: &Tz, local: NaiveDateTime) -> Option<DateTime<Utc>> {
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            let later = local.checked_add_signed(Duration::hours(1))?;
+            tz.from_local_datetime(&later).earliest()
+        })
+        .map(|time| time.with_timezone(&Utc))
+}
+
+fn parse_weekday(code: &str) -> Result<Weekday, String> {
+    match code.trim().to_ascii_uppercase().as_str() {
+        "MO" => Ok(Weekday::Mon),
+        "TU" => Ok(Weekday::Tue),
+        "WE" => Ok(Weekday::Wed),
+        "TH" => Ok(Weekday::Thu),
+        "FR" => Ok(Weekday::Fri),
+        "SA" => Ok(Weekday::Sat),
+        "SU" => Ok(Weekday::Sun),
+        other => Err(format!("BYDAY value '{}' is not supported", other)),
+    }
+}
+
+/// RFC 5545 two-letter weekday code, e.g. `FR`
+pub fn weekday_code(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "MO",
+        Weekday::Tue => "TU",
+        Weekday::Wed => "WE",
+        Weekday::Thu => "TH",
+        Weekday::Fri => "FR",
+        Weekday::Sat => "SA",
+        Weekday::Sun => "SU",
+    }
+}
+
+fn weekday_name(day: Weekday) -> &'static str {
+    match day {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+fn ordinal(day: u32) -> String {
+    let suffix = match (day % 10, day % 100) {
+        (1, n) if n != 11 => "st",
+        (2, n) if n != 12 => "nd",
+        (3, n) if n != 13 => "rd",
+        _ => "th",
+    };
+    format!("{}{}", day, suffix)
+}
+
+/// `UNTIL` accepts the RFC 5545 forms `YYYYMMDD` and `YYYYMMDDTHHMMSSZ`
+fn parse_until(value: &str) -> Result<DateTime<Utc>, String> {
+    let error = || format!("UNTIL value '{}' must be YYYYMMDD or YYYYMMDDTHHMMSSZ", value);
+    if let Ok(time) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Ok(time.and_utc());
+    }
+    NaiveDate::parse_from_str(value, "%Y%m%d")
+        .map_err(|_| error())?
+        .and_hms_opt(23, 59, 59)
+        .map(|time| time.and_utc())
+        .ok_or_else(error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn la(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Tz> {
+        chrono_tz::America::Los_Angeles
+            .with_ymd_and_hms(year, month, day, hour, 0, 0)
+            .unwrap()
+    }
+
+    fn utc(year: i32, month: u32, day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(year, month, day, hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn parses_supported_parts() {
+        let rule: RecurrenceRule = "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;COUNT=6".parse().unwrap();
+        assert_eq!(rule.frequency, Frequency::Weekly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(rule.by_day, vec![Weekday::Mon, Weekday::Wed]);
+        assert_eq!(rule.count, Some(6));
+        assert_eq!(rule.to_string(), "FREQ=WEEKLY;INTERVAL=2;BYDAY=MO,WE;COUNT=6");
+
+        let rule: RecurrenceRule = "freq=daily;until=20261231".parse().unwrap();
+        assert_eq!(rule.until, Some(Utc.with_ymd_and_hms(2026, 12, 31, 23, 59, 59).unwrap()));
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        for value in [
+            "",
+            "INTERVAL=2",
+            "FREQ=YEARLY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;INTERVAL=1001",
+            "FREQ=DAILY;INTERVAL=100000000",
+            "FREQ=DAILY;COUNT=0",
+            "FREQ=DAILY;BYDAY=MO",
+            "FREQ=WEEKLY;BYDAY=XX",
+            "FREQ=WEEKLY;COUNT=3;UNTIL=20261231",
+            "FREQ=WEEKLY;UNTIL=tomorrow",
+            "FREQ=WEEKLY;BYMONTH=1",
+            "FREQ",
+        ] {
+            assert!(value.parse::<RecurrenceRule>().is_err(), "{} should be rejected", value);
+        }
+        assert!(format!("FREQ=DAILY;INTERVAL={}", MAX_INTERVAL).parse::<RecurrenceRule>().is_ok());
+    }
+
+    #[test]
+    fn weekly_by_day_within_window() {
+        let rule: RecurrenceRule = "FREQ=WEEKLY;BYDAY=TU,TH".parse().unwrap();
+        // Thursday 2026-09-03, 18:00 PDT
+        let starts = rule.occurrences(la(2026, 9, 3, 18), Duration::hours(2), utc(2026, 9, 7, 0), utc(2026, 9, 12, 0));
+        assert_eq!(starts, vec![utc(2026, 9, 9, 1), utc(2026, 9, 11, 1)]);
+    }
+
+    #[test]
+    fn keeps_local_time_across_dst() {
+        let rule: RecurrenceRule = "FREQ=WEEKLY;COUNT=3".parse().unwrap();
+        // Fridays around the end of daylight saving time on 2026-11-01
+        let starts = rule.occurrences(la(2026, 10, 23, 18), Duration::hours(2), utc(2026, 1, 1, 0), utc(2027, 1, 1, 0));
+        assert_eq!(starts, vec![utc(2026, 10, 24, 1), utc(2026, 10, 31, 1), utc(2026, 11, 7, 2)]);
+
+        // 02:30 does not exist on 2027-03-14 and moves ahead an hour
+        let rule: RecurrenceRule = "FREQ=DAILY;COUNT=2".parse().unwrap();
+        let first = chrono_tz::America::Los_Angeles.with_ymd_and_hms(2027, 3, 13, 2, 30, 0).unwrap();
+        let starts = rule.occurrences(first, Duration::hours(1), utc(2027, 1, 1, 0), utc(2028, 1, 1, 0));
+        assert_eq!(starts[1], Utc.with_ymd_and_hms(2027, 3, 14, 10, 30, 0).unwrap());
+    }
+
+    #[test]
+    fn monthly_skips_months_without_the_day() {
+        let rule: RecurrenceRule = "FREQ=MONTHLY;COUNT=4".parse().unwrap();
+        let starts = rule.occurrences(la(2027, 1, 31, 12), Duration::hours(1), utc(2027, 1, 1, 0), utc(2028, 1, 1, 0));
+        let days: Vec<(u32, u32)> = starts.iter().map(|start| (start.month(), start.day())).collect();
+        assert_eq!(days, vec![(1, 31), (3, 31), (5, 31), (7, 31)]);
+    }
+
+    #[test]
+    fn until_is_inclusive() {
+        let rule: RecurrenceRule = "FREQ=DAILY;UNTIL=20260905".parse().unwrap();
+        let starts = rule.occurrences(la(2026, 9, 3, 9), Duration::hours(1), utc(2026, 1, 1, 0), utc(2027, 1, 1, 0));
+        assert_eq!(starts.len(), 3);
+    }
+
+    #[test]
+    fn stops_at_the_end_of_representable_dates() {
+        for frequency in [Frequency::Daily, Frequency::Weekly, Frequency::Monthly] {
+            let rule = RecurrenceRule {
+                frequency,
+                interval: u32::MAX,
+                by_day: Vec::new(),
+                count: None,
+                until: None,
+            };
+            let starts = rule.occurrences(la(2026, 9, 3, 18), Duration::hours(2), utc(2026, 1, 1, 0), DateTime::<Utc>::MAX_UTC);
+            assert_eq!(starts.first(), Some(&utc(2026, 9, 4, 1)));
+        }
+    }
+}
@@ -1,153 +1,482 @@
+use serde::Serialize;
 use shared::*;
 
 const API_BASE_URL: &str = "https://api.chico.rs";
 
-// Stats API
-pub async fn fetch_stats() -> Result<Stats, String> {
-    let url = format!("{}/v1/stats", API_BASE_URL);
-
-    match reqwest::get(&url).await {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.json::<Stats>().await {
-                    Ok(stats) => Ok(stats),
-                    Err(e) => Err(format!("Failed to parse stats: {}", e)),
-                }
-            } else {
-                Err(format!("HTTP error: {}", response.status()))
-            }
+/// Query-string value of a shared enum, matching its serde variant name
+fn enum_value<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Build a list endpoint URL from `(name, value)` pairs, skipping unset ones
+fn list_url(path: &str, params: Vec<(&str, Option<String>)>) -> String {
+    let base = format!("{}{}", API_BASE_URL, path);
+    let pairs = params
+        .into_iter()
+        .filter_map(|(key, value)| value.map(|v| (key, v)));
+
+    match reqwest::Url::parse_with_params(&base, pairs) {
+        Ok(url) => url.to_string(),
+        Err(_) => base,
+    }
+}
+
+/// Filters and paging for `GET /v1/events`
+#[derive(Debug, Clone, Default)]
+pub struct EventQuery {
+    pub event_type: Option<EventType>,
+    /// Term slug; the API then returns the whole term instead of upcoming events
+    pub term: Option<String>,
+    pub cursor: Option<String>,
+}
+
+/// Filters and paging for `GET /v1/workshops`
+#[derive(Debug, Clone, Default)]
+pub struct WorkshopQuery {
+    pub upcoming: Option<bool>,
+    pub term: Option<String>,
+    pub cursor: Option<String>,
+}
+
+/// Filters and paging for `GET /v1/issues`
+#[derive(Debug, Clone, Default)]
+pub struct IssueQuery {
+    pub difficulty: Option<DifficultyLevel>,
+    pub repo: Option<String>,
+    pub cursor: Option<String>,
+}
+
+/// Filters and paging for `GET /v1/projects`
+#[derive(Debug, Clone, Default)]
+pub struct ProjectQuery {
+    pub status: Option<ProjectStatus>,
+    pub contributors_needed: Option<bool>,
+    pub cursor: Option<String>,
+}
+
+/// Filters and paging for `GET /v1/blog`
+#[derive(Debug, Clone, Default)]
+pub struct BlogQuery {
+    pub post_type: Option<BlogPostType>,
+    pub category: Option<BlogCategory>,
+    pub difficulty: Option<DifficultyLevel>,
+    pub author: Option<String>,
+    /// Term slug, for posts published during that term
+    pub term: Option<String>,
+    pub cursor: Option<String>,
+}
+
+/// Parameters for `GET /v1/search`
+#[derive(Debug, Clone, Default)]
+pub struct SearchQuery {
+    pub q: String,
+    pub kind: Option<SearchResultKind>,
+    pub limit: Option<u32>,
+}
+
+/// Failure modes of an API call, so pages can tell a missing record from an outage
+#[derive(Debug, Clone)]
+pub enum ClientError {
+    /// The request never reached the API
+    Network(String),
+    /// The API answered with a structured `ApiError` body
+    Api(ApiError),
+    /// Non-success status without a parseable error body
+    Status(u16),
+    /// The response body did not match the expected type
+    Decode(String),
+}
+
+impl std::fmt::Display for ClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClientError::Network(e) => write!(f, "Network error: {}", e),
+            ClientError::Api(e) => write!(f, "{}", e),
+            ClientError::Status(status) => write!(f, "HTTP error: {}", status),
+            ClientError::Decode(e) => write!(f, "Failed to parse response: {}", e),
         }
-        Err(e) => Err(format!("Network error: {}", e)),
     }
 }
 
-// Events API
-pub async fn fetch_events() -> Result<Vec<Event>, String> {
-    let url = format!("{}/v1/events", API_BASE_URL);
-
-    match reqwest::get(&url).await {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.json::<Vec<Event>>().await {
-                    Ok(events) => Ok(events),
-                    Err(e) => Err(format!("Failed to parse events: {}", e)),
-                }
-            } else {
-                Err(format!("HTTP error: {}", response.status()))
-            }
+impl ClientError {
+    pub fn is_not_found(&self) -> bool {
+        match self {
+            ClientError::Api(e) => e.code == ErrorCode::NotFound,
+            ClientError::Status(status) => *status == 404,
+            _ => false,
+        }
+    }
+
+    pub fn is_unauthorized(&self) -> bool {
+        match self {
+            ClientError::Api(e) => e.code == ErrorCode::Unauthorized,
+            ClientError::Status(status) => *status == 401,
+            _ => false,
+        }
+    }
+
+    pub fn is_conflict(&self) -> bool {
+        match self {
+            ClientError::Api(e) => e.code == ErrorCode::Conflict,
+            ClientError::Status(status) => *status == 409,
+            _ => false,
         }
-        Err(e) => Err(format!("Network error: {}", e)),
     }
 }
 
-// Issues API
-pub async fn fetch_issues() -> Result<Vec<Issue>, String> {
-    let url = format!("{}/v1/issues", API_BASE_URL);
-
-    match reqwest::get(&url).await {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.json::<Vec<Issue>>().await {
-                    Ok(issues) => Ok(issues),
-                    Err(e) => Err(format!("Failed to parse issues: {}", e)),
-                }
-            } else {
-                Err(format!("HTTP error: {}", response.status()))
-            }
+/// GET a URL and decode its JSON body, turning error responses into `ClientError::Api`
+async fn get_json<T: serde::de::DeserializeOwned>(url: &str) -> Result<T, ClientError> {
+    send_json(reqwest::Client::new().get(url)).await
+}
+
+/// Send a request and decode its JSON body, turning error responses into `ClientError::Api`
+async fn send_json<T: serde::de::DeserializeOwned>(request: reqwest::RequestBuilder) -> Result<T, ClientError> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| ClientError::Network(e.to_string()))?;
+
+    if response.status().is_success() {
+        response
+            .json::<T>()
+            .await
+            .map_err(|e| ClientError::Decode(e.to_string()))
+    } else {
+        let status = response.status().as_u16();
+        match response.json::<ApiError>().await {
+            Ok(error) => Err(ClientError::Api(error)),
+            Err(_) => Err(ClientError::Status(status)),
         }
-        Err(e) => Err(format!("Network error: {}", e)),
     }
 }
 
-// Projects API
-pub async fn fetch_projects() -> Result<Vec<Project>, String> {
-    let url = format!("{}/v1/projects", API_BASE_URL);
-
-    match reqwest::get(&url).await {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.json::<Vec<Project>>().await {
-                    Ok(projects) => Ok(projects),
-                    Err(e) => Err(format!("Failed to parse projects: {}", e)),
-                }
-            } else {
-                Err(format!("HTTP error: {}", response.status()))
-            }
+/// Send a request whose success response has no body, such as a DELETE
+async fn send_empty(request: reqwest::RequestBuilder) -> Result<(), ClientError> {
+    let response = request
+        .send()
+        .await
+        .map_err(|e| ClientError::Network(e.to_string()))?;
+
+    if response.status().is_success() {
+        Ok(())
+    } else {
+        let status = response.status().as_u16();
+        match response.json::<ApiError>().await {
+            Ok(error) => Err(ClientError::Api(error)),
+            Err(_) => Err(ClientError::Status(status)),
         }
-        Err(e) => Err(format!("Network error: {}", e)),
     }
 }
 
+// Stats API
+/// Club statistics for a term slug, or for the current term
+pub async fn fetch_stats(term: Option<&str>) -> Result<Stats, ClientError> {
+    get_json(&list_url("/v1/stats", vec![("term", term.map(str::to_string))])).await
+}
+
+// Terms API
+pub async fn fetch_terms() -> Result<Vec<Term>, ClientError> {
+    get_json(&format!("{}/v1/terms", API_BASE_URL)).await
+}
+
+pub async fn fetch_term(slug: &str) -> Result<Option<Term>, ClientError> {
+    match get_json(&format!("{}/v1/terms/{}", API_BASE_URL, slug)).await {
+        Ok(term) => Ok(Some(term)),
+        Err(e) if e.is_not_found() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// Session API
+
+/// Send the session cookie with a request. Only the API's allowed site
+/// origin may do this, so keep it to requests that act for the signed-in
+/// member.
+fn with_session(request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+    // Credentials mode only exists on the browser's fetch
+    #[cfg(target_arch = "wasm32")]
+    let request = request.fetch_credentials_include();
+    request
+}
+
+/// The signed-in member, or `None` when signed out
+pub async fn fetch_me() -> Result<Option<CurrentUser>, ClientError> {
+    let request = with_session(reqwest::Client::new().get(format!("{}/v1/me", API_BASE_URL)));
+    match send_json(request).await {
+        Ok(user) => Ok(Some(user)),
+        Err(e) if e.is_unauthorized() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn sign_out() -> Result<(), ClientError> {
+    let request = with_session(reqwest::Client::new().post(format!("{}/v1/auth/sign-out", API_BASE_URL)));
+    send_empty(request).await
+}
+
+/// Start GitHub sign-in; the API sends the member back to `return_to` afterwards
+pub fn sign_in_url(return_to: &str) -> String {
+    list_url("/v1/auth/github", vec![("return_to", Some(return_to.to_string()))])
+}
+
+// Members API
+
+/// A member's profile, or `None` if there is no such member or their
+/// profile is not visible. Sends the session so members-only profiles load.
+pub async fn fetch_member(github: &str) -> Result<Option<MemberProfile>, ClientError> {
+    let request = with_session(reqwest::Client::new().get(format!("{}/v1/members/{}", API_BASE_URL, github)));
+    match send_json(request).await {
+        Ok(profile) => Ok(Some(profile)),
+        Err(e) if e.is_not_found() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+// Events API
+pub async fn fetch_events(query: &EventQuery) -> Result<Page<Event>, ClientError> {
+    let url = list_url("/v1/events", vec![
+        ("event_type", query.event_type.as_ref().map(enum_value)),
+        ("term", query.term.clone()),
+        ("cursor", query.cursor.clone()),
+    ]);
+    get_json(&url).await
+}
+
+pub async fn fetch_event(id: &str) -> Result<Option<Event>, ClientError> {
+    match get_json(&format!("{}/v1/events/{}", API_BASE_URL, id)).await {
+        Ok(event) => Ok(Some(event)),
+        Err(e) if e.is_not_found() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+pub async fn rsvp_to_event(event_id: &str, input: &RsvpInput) -> Result<EventRsvp, ClientError> {
+    let url = format!("{}/v1/events/{}/rsvps", API_BASE_URL, event_id);
+    send_json(with_session(reqwest::Client::new().post(url).json(input))).await
+}
+
+pub async fn change_event_rsvp(event_id: &str, token: &str, status: RsvpStatus) -> Result<EventRsvp, ClientError> {
+    let url = format!("{}/v1/events/{}/rsvps/{}", API_BASE_URL, event_id, token);
+    send_json(reqwest::Client::new().put(url).json(&RsvpChange { status })).await
+}
+
+pub async fn withdraw_event_rsvp(event_id: &str, token: &str) -> Result<(), ClientError> {
+    let url = format!("{}/v1/events/{}/rsvps/{}", API_BASE_URL, event_id, token);
+    send_empty(reqwest::Client::new().delete(url)).await
+}
+
+pub async fn check_in_to_event(event_id: &str, input: &CheckInInput) -> Result<CheckIn, ClientError> {
+    let url = format!("{}/v1/events/{}/check-ins", API_BASE_URL, event_id);
+    send_json(with_session(reqwest::Client::new().post(url).json(input))).await
+}
+
+// Workshops API
+pub async fn fetch_workshops(query: &WorkshopQuery) -> Result<Page<Workshop>, ClientError> {
+    let url = list_url("/v1/workshops", vec![
+        ("upcoming", query.upcoming.map(|upcoming| upcoming.to_string())),
+        ("term", query.term.clone()),
+        ("cursor", query.cursor.clone()),
+    ]);
+    get_json(&url).await
+}
+
+pub async fn fetch_workshop(id: &str) -> Result<Workshop, ClientError> {
+    get_json(&format!("{}/v1/workshops/{}", API_BASE_URL, id)).await
+}
+
+pub async fn register_for_workshop(
+    workshop_id: &str,
+    input: &WorkshopRegistrationInput,
+) -> Result<WorkshopRegistration, ClientError> {
+    let url = format!("{}/v1/workshops/{}/registrations", API_BASE_URL, workshop_id);
+    // Signed-in members get the workshop on their profile
+    send_json(with_session(reqwest::Client::new().post(url)).json(input)).await
+}
+
+pub async fn cancel_workshop_registration(workshop_id: &str, token: &str) -> Result<(), ClientError> {
+    let url = format!("{}/v1/workshops/{}/registrations/{}", API_BASE_URL, workshop_id, token);
+    send_empty(reqwest::Client::new().delete(url)).await
+}
+
+// Advocacy API
+pub async fn fetch_courses() -> Result<Vec<Course>, ClientError> {
+    get_json(&format!("{}/v1/courses", API_BASE_URL)).await
+}
+
+pub async fn fetch_petition_summary() -> Result<PetitionSummary, ClientError> {
+    get_json(&format!("{}/v1/petition", API_BASE_URL)).await
+}
+
+/// Sign the petition as the signed-in member
+pub async fn sign_petition() -> Result<PetitionSummary, ClientError> {
+    let url = format!("{}/v1/petition/signatures", API_BASE_URL);
+    send_json(with_session(reqwest::Client::new().post(url))).await
+}
+
+/// Back a course as the signed-in member
+pub async fn support_course(slug: &str) -> Result<Course, ClientError> {
+    let url = format!("{}/v1/courses/{}/signatures", API_BASE_URL, slug);
+    send_json(with_session(reqwest::Client::new().post(url))).await
+}
+
+// Issues API
+pub async fn fetch_issues(query: &IssueQuery) -> Result<Page<Issue>, ClientError> {
+    let url = list_url("/v1/issues", vec![
+        ("difficulty", query.difficulty.as_ref().map(enum_value)),
+        ("repo", query.repo.clone()),
+        ("cursor", query.cursor.clone()),
+    ]);
+    get_json(&url).await
+}
+
+/// Claim an issue for the signed-in member
+pub async fn claim_issue(id: &str) -> Result<Contribution, ClientError> {
+    let url = format!("{}/v1/issues/{}/claim", API_BASE_URL, id);
+    send_json(with_session(reqwest::Client::new().post(url))).await
+}
+
+// Projects API
+pub async fn fetch_projects(query: &ProjectQuery) -> Result<Page<Project>, ClientError> {
+    let url = list_url("/v1/projects", vec![
+        ("status", query.status.as_ref().map(enum_value)),
+        ("contributors_needed", query.contributors_needed.map(|needed| needed.to_string())),
+        ("cursor", query.cursor.clone()),
+    ]);
+    get_json(&url).await
+}
+
 // Blog API
-pub async fn fetch_blog_posts() -> Result<Vec<BlogPost>, String> {
-    let url = format!("{}/v1/blog", API_BASE_URL);
-
-    match reqwest::get(&url).await {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.json::<Vec<BlogPost>>().await {
-                    Ok(posts) => Ok(posts),
-                    Err(e) => Err(format!("Failed to parse blog posts: {}", e)),
-                }
-            } else {
-                Err(format!("HTTP error: {}", response.status()))
-            }
-        }
-        Err(e) => Err(format!("Network error: {}", e)),
+pub async fn fetch_blog_posts(query: &BlogQuery) -> Result<Page<BlogPost>, ClientError> {
+    let url = list_url("/v1/blog", vec![
+        ("post_type", query.post_type.as_ref().map(enum_value)),
+        ("category", query.category.as_ref().map(enum_value)),
+        ("difficulty", query.difficulty.as_ref().map(enum_value)),
+        ("author", query.author.clone()),
+        ("term", query.term.clone()),
+        ("cursor", query.cursor.clone()),
+    ]);
+    get_json(&url).await
+}
+
+pub async fn fetch_featured_blog_posts() -> Result<Vec<BlogPost>, ClientError> {
+    get_json(&format!("{}/v1/blog/featured", API_BASE_URL)).await
+}
+
+pub async fn fetch_blog_post_by_slug(slug: &str) -> Result<Option<BlogPost>, ClientError> {
+    match get_json(&format!("{}/v1/blog/{}", API_BASE_URL, slug)).await {
+        Ok(post) => Ok(Some(post)),
+        Err(e) if e.is_not_found() => Ok(None),
+        Err(e) => Err(e),
     }
 }
 
-pub async fn fetch_featured_blog_posts() -> Result<Vec<BlogPost>, String> {
-    let url = format!("{}/v1/blog/featured", API_BASE_URL);
+/// A post shared through a preview link, whatever its status. `None` if the
+/// link is unknown, replaced or expired.
+pub async fn fetch_blog_post_preview(token: &str) -> Result<Option<BlogPost>, ClientError> {
+    match get_json(&format!("{}/v1/blog/preview/{}", API_BASE_URL, token)).await {
+        Ok(post) => Ok(Some(post)),
+        Err(e) if e.is_not_found() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
 
-    match reqwest::get(&url).await {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.json::<Vec<BlogPost>>().await {
-                    Ok(posts) => Ok(posts),
-                    Err(e) => Err(format!("Failed to parse featured blog posts: {}", e)),
-                }
-            } else {
-                Err(format!("HTTP error: {}", response.status()))
-            }
-        }
-        Err(e) => Err(format!("Network error: {}", e)),
+/// A series with its published parts in order. `None` if no series has this slug.
+pub async fn fetch_blog_series(slug: &str) -> Result<Option<BlogSeriesDetail>, ClientError> {
+    match get_json(&format!("{}/v1/blog/series/{}", API_BASE_URL, slug)).await {
+        Ok(series) => Ok(Some(series)),
+        Err(e) if e.is_not_found() => Ok(None),
+        Err(e) => Err(e),
     }
 }
 
-pub async fn fetch_blog_post_by_slug(slug: &str) -> Result<Option<BlogPost>, String> {
-    let url = format!("{}/v1/blog/{}", API_BASE_URL, slug);
+/// An author with a page of the posts they wrote or co-wrote, newest first.
+/// `None` if no member by this username has published a post.
+pub async fn fetch_author(github: &str, cursor: Option<String>) -> Result<Option<AuthorProfile>, ClientError> {
+    match get_json(&list_url(&format!("/v1/authors/{}", github), vec![("cursor", cursor)])).await {
+        Ok(profile) => Ok(Some(profile)),
+        Err(e) if e.is_not_found() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
 
-    match reqwest::get(&url).await {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.json::<BlogPost>().await {
-                    Ok(post) => Ok(Some(post)),
-                    Err(e) => Err(format!("Failed to parse blog post: {}", e)),
-                }
-            } else if response.status().as_u16() == 404 {
-                Ok(None)
-            } else {
-                Err(format!("HTTP error: {}", response.status()))
-            }
-        }
-        Err(e) => Err(format!("Network error: {}", e)),
+// Search API
+pub async fn search(query: &SearchQuery) -> Result<SearchResults, ClientError> {
+    let url = list_url("/v1/search", vec![
+        ("q", Some(query.q.clone())),
+        ("type", query.kind.as_ref().map(enum_value)),
+        ("limit", query.limit.map(|limit| limit.to_string())),
+    ]);
+    get_json(&url).await
+}
+
+/// Site path of the search results page, e.g. `/search?q=async&type=BlogPost`
+pub fn search_page_path(q: &str, kind: Option<SearchResultKind>) -> String {
+    let mut params = vec![("q", q.to_string())];
+    if let Some(kind) = kind {
+        params.push(("type", enum_value(&kind)));
+    }
+    match reqwest::Url::parse_with_params("https://chico.rs/search", params) {
+        Ok(url) => format!("{}?{}", url.path(), url.query().unwrap_or_default()),
+        Err(_) => "/search".to_string(),
     }
 }
 
-pub async fn fetch_markdown_content(url: &str) -> Result<String, String> {
-    match reqwest::get(url).await {
-        Ok(response) => {
-            if response.status().is_success() {
-                match response.text().await {
-                    Ok(content) => Ok(content),
-                    Err(e) => Err(format!("Failed to read markdown content: {}", e)),
-                }
-            } else {
-                Err(format!("HTTP error: {}", response.status()))
-            }
-        }
-        Err(e) => Err(format!("Network error: {}", e)),
+// Likes API
+pub async fn fetch_like_status(slug: &str) -> Result<LikeStatus, ClientError> {
+    get_json(&format!("{}/v1/blog/{}/like", API_BASE_URL, slug)).await
+}
+
+pub async fn like_blog_post(slug: &str) -> Result<LikeStatus, ClientError> {
+    let url = format!("{}/v1/blog/{}/like", API_BASE_URL, slug);
+    send_json(reqwest::Client::new().post(url)).await
+}
+
+pub async fn unlike_blog_post(slug: &str) -> Result<LikeStatus, ClientError> {
+    let url = format!("{}/v1/blog/{}/like", API_BASE_URL, slug);
+    send_json(reqwest::Client::new().delete(url)).await
+}
+
+// Views API
+/// Report a view of a blog post. The API answers 204 whether or not the view is
+/// counted, and a failed beacon is not worth surfacing to the reader.
+pub async fn send_view_beacon(slug: &str) {
+    let url = format!("{}/v1/blog/{}/view", API_BASE_URL, slug);
+    if let Err(e) = reqwest::Client::new().post(url).send().await {
+        log::debug!("View beacon failed: {}", e);
+    }
+}
+
+/// Download link for a single event's `.ics` file
+pub fn event_calendar_url(event_id: &str) -> String {
+    format!("{}/v1/events/{}/ics", API_BASE_URL, event_id)
+}
+
+/// `webcal://` link that asks the visitor's calendar app to subscribe to the events feed
+pub fn events_subscription_url() -> String {
+    format!("{}/v1/events.ics", API_BASE_URL.replacen("https://", "webcal://", 1))
+}
+
+/// Blog syndication feed, `file` being `rss.xml` or `atom.xml`
+pub fn blog_feed_url(file: &str) -> String {
+    format!("{}/v1/blog/{}", API_BASE_URL, file)
+}
+
+/// Markdown lives outside the API, so errors here are plain statuses
+pub async fn fetch_markdown_content(url: &str) -> Result<String, ClientError> {
+    let response = reqwest::get(url)
+        .await
+        .map_err(|e| ClientError::Network(e.to_string()))?;
+
+    if response.status().is_success() {
+        response
+            .text()
+            .await
+            .map_err(|e| ClientError::Decode(e.to_string()))
+    } else {
+        Err(ClientError::Status(response.status().as_u16()))
     }
 }
 
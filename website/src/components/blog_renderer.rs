@@ -21,8 +21,8 @@ pub fn BlogRenderer(
 
 #[component]
 pub fn BlogPostViewer(
-    /// Blog post slug for fetching content
-    slug: String,
+    /// Where the post's markdown is hosted
+    markdown_url: String,
 ) -> impl IntoView {
     let markdown_content = RwSignal::new(String::new());
     let loading = RwSignal::new(true);
@@ -30,32 +30,18 @@ pub fn BlogPostViewer(
 
     // Fetch markdown content when component loads
     Effect::new(move |_| {
-        let slug = slug.clone();
+        let markdown_url = markdown_url.clone();
         spawn_local(async move {
             loading.set(true);
             error.set(None);
 
-            // First fetch the blog post metadata to get the markdown URL
-            match crate::api::fetch_blog_post_by_slug(&slug).await {
-                Ok(Some(blog_post)) => {
-                    // Then fetch the markdown content from the URL
-                    match crate::api::fetch_markdown_content(&blog_post.markdown_url).await {
-                        Ok(content) => {
-                            markdown_content.set(content);
-                            loading.set(false);
-                        }
-                        Err(e) => {
-                            error.set(Some(format!("Failed to load markdown content: {}", e)));
-                            loading.set(false);
-                        }
-                    }
-                }
-                Ok(None) => {
-                    error.set(Some("Blog post not found".to_string()));
+            match crate::api::fetch_markdown_content(&markdown_url).await {
+                Ok(content) => {
+                    markdown_content.set(content);
                     loading.set(false);
                 }
                 Err(e) => {
-                    error.set(Some(format!("Failed to load blog post: {}", e)));
+                    error.set(Some(format!("Failed to load markdown content: {}", e)));
                     loading.set(false);
                 }
             }
@@ -1,8 +1,231 @@
+use std::time::Duration;
+
 use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_router::hooks::{use_location, use_navigate};
+use leptos_router::NavigateOptions;
 use lucide_leptos::*;
+use shared::SearchResult;
+
+use crate::api::client::{search, search_page_path, sign_in_url, SearchQuery};
+use crate::session::use_session;
 
 stylance::import_style!(style, "nav.module.scss");
 
+/// Suggestions shown under the search box
+const SUGGESTION_LIMIT: u32 = 6;
+/// Wait this long after the last keystroke before querying the API
+const SUGGEST_DELAY: Duration = Duration::from_millis(200);
+
+/// Follow a search result: site paths go through the router, external links
+/// (GitHub issues) load in place
+fn open_url(navigate: &impl Fn(&str, NavigateOptions), url: &str) {
+    if url.starts_with('/') {
+        navigate(url, NavigateOptions::default());
+    } else if let Err(e) = window().location().set_href(url) {
+        log::warn!("Failed to open {}: {:?}", url, e);
+    }
+}
+
+/// Site-wide search with as-you-type suggestions. Arrow keys move through
+/// the suggestions, Enter opens the selected one (or the full results page)
+/// and Escape closes the list.
+#[component]
+fn SearchBox() -> impl IntoView {
+    let navigate = use_navigate();
+    let query = RwSignal::new(String::new());
+    let results = RwSignal::new(Vec::<SearchResult>::new());
+    let selected = RwSignal::new(None::<usize>);
+    let open = RwSignal::new(false);
+    // Bumped on every keystroke so responses for older input are dropped
+    let generation = StoredValue::new(0u32);
+
+    let reset = move || {
+        generation.update_value(|current| *current += 1);
+        query.set(String::new());
+        results.set(Vec::new());
+        selected.set(None);
+        open.set(false);
+    };
+
+    let on_input = move |ev| {
+        let value = event_target_value(&ev);
+        query.set(value.clone());
+        selected.set(None);
+        generation.update_value(|current| *current += 1);
+        let current = generation.get_value();
+
+        if value.trim().is_empty() {
+            results.set(Vec::new());
+            open.set(false);
+            return;
+        }
+        set_timeout(
+            move || {
+                if generation.get_value() != current {
+                    return;
+                }
+                spawn_local(async move {
+                    let request = SearchQuery {
+                        q: value,
+                        limit: Some(SUGGESTION_LIMIT),
+                        ..Default::default()
+                    };
+                    match search(&request).await {
+                        Ok(found) if generation.get_value() == current => {
+                            results.set(found.results);
+                            open.set(true);
+                        }
+                        Ok(_) => {}
+                        Err(e) => log::warn!("Search failed: {}", e),
+                    }
+                });
+            },
+            SUGGEST_DELAY,
+        );
+    };
+
+    let on_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        let count = results.with_untracked(Vec::len);
+        match ev.key().as_str() {
+            "ArrowDown" if count > 0 => {
+                ev.prevent_default();
+                open.set(true);
+                selected.update(|index| *index = Some(index.map_or(0, |i| (i + 1) % count)));
+            }
+            "ArrowUp" if count > 0 => {
+                ev.prevent_default();
+                open.set(true);
+                selected.update(|index| *index = Some(index.map_or(count - 1, |i| (i + count - 1) % count)));
+            }
+            "Enter" => {
+                ev.prevent_default();
+                let text = query.get_untracked().trim().to_string();
+                let target = selected
+                    .get_untracked()
+                    .and_then(|index| results.with_untracked(|results| results.get(index).map(|r| r.url.clone())))
+                    .or_else(|| (!text.is_empty()).then(|| search_page_path(&text, None)));
+                if let Some(target) = target {
+                    reset();
+                    open_url(&navigate, &target);
+                }
+            }
+            "Escape" => {
+                open.set(false);
+                selected.set(None);
+            }
+            _ => {}
+        }
+    };
+
+    view! {
+        <div class={style::search}>
+            <Search size=16 />
+            <input
+                type="search"
+                class={style::search_input}
+                placeholder="Search"
+                aria-label="Search the site"
+                role="combobox"
+                aria-autocomplete="list"
+                aria-controls="nav-search-results"
+                aria-expanded=move || open.get().to_string()
+                aria-activedescendant=move || selected.get().map(|index| format!("nav-search-result-{}", index))
+                prop:value=move || query.get()
+                on:input=on_input
+                on:keydown=on_keydown
+                on:focus=move |_| open.set(!results.with(Vec::is_empty))
+                on:blur=move |_| open.set(false)
+            />
+            <Show when=move || open.get()>
+                // Keep focus in the input while a suggestion is clicked
+                <ul
+                    id="nav-search-results"
+                    role="listbox"
+                    class={style::search_results}
+                    on:mousedown=|ev| ev.prevent_default()
+                >
+                    {move || {
+                        let found = results.get();
+                        if found.is_empty() {
+                            return view! { <li class={style::search_empty}>"No matches"</li> }.into_any();
+                        }
+                        found.into_iter().enumerate().map(|(index, result)| {
+                            view! {
+                                <li
+                                    id={format!("nav-search-result-{}", index)}
+                                    role="option"
+                                    aria-selected=move || (selected.get() == Some(index)).to_string()
+                                    class=move || if selected.get() == Some(index) {
+                                        format!("{} {}", style::search_result, style::search_result_selected)
+                                    } else {
+                                        style::search_result.to_string()
+                                    }
+                                >
+                                    <a href={result.url.clone()} on:click=move |_| reset()>
+                                        <span class={style::search_result_kind}>{result.kind.to_string()}</span>
+                                        <span class={style::search_result_title} inner_html={result.title_html.clone()}></span>
+                                    </a>
+                                </li>
+                            }
+                        }).collect_view().into_any()
+                    }}
+                    <li class={style::search_all}>
+                        <a
+                            href=move || search_page_path(query.get().trim(), None)
+                            on:click=move |_| reset()
+                        >
+                            "See all results"
+                        </a>
+                    </li>
+                </ul>
+            </Show>
+        </div>
+    }
+}
+
+/// Sign-in link, or the signed-in member's avatar and a sign-out button.
+/// Renders nothing until the session check finishes.
+#[component]
+fn SessionButton() -> impl IntoView {
+    let session = use_session();
+    let location = use_location();
+
+    move || {
+        if !session.loaded.get() {
+            return ().into_any();
+        }
+        match session.user.get() {
+            Some(user) => view! {
+                <div class={style::session}>
+                    {user.avatar_url.map(|url| view! {
+                        <img class={style::session_avatar} src={url} alt="" width="28" height="28" />
+                    })}
+                    <a
+                        class={style::session_name}
+                        href={user.member.github_username.map(|github| format!("/members/{}", github)).unwrap_or_default()}
+                    >
+                        {user.member.name}
+                    </a>
+                    <button class={style::session_button} on:click=move |_| session.sign_out()>
+                        "Sign out"
+                    </button>
+                </div>
+            }.into_any(),
+            None => view! {
+                <a
+                    class={style::session_button}
+                    href=move || sign_in_url(&location.pathname.get())
+                    rel="external"
+                >
+                    <Github size=16 />
+                    "Sign in"
+                </a>
+            }.into_any(),
+        }
+    }
+}
+
 #[component]
 pub fn Nav() -> impl IntoView {
     let (is_mobile_menu_open, set_mobile_menu_open) = signal(false);
@@ -22,7 +245,9 @@ pub fn Nav() -> impl IntoView {
                     <Code />
                     <span class={style::nav_title}>"The Rust Club"</span>
                 </a>
-                
+
+                <SearchBox />
+
                 // Desktop menu
                 <ul class={style::nav_menu}>
                     <li class={style::nav_item}>
@@ -50,6 +275,8 @@ pub fn Nav() -> impl IntoView {
                         <a href="/about" class={style::nav_link}>"About"</a>
                     </li>
                 </ul>
+
+                <SessionButton />
                 
                 // Mobile menu button
                 <button 
@@ -99,6 +326,12 @@ pub fn Nav() -> impl IntoView {
                     <li class={style::mobile_nav_item}>
                         <a href="/about" class={style::mobile_nav_link} on:click=close_mobile_menu>"About"</a>
                     </li>
+                    <li class={style::mobile_nav_item}>
+                        <a href="/search" class={style::mobile_nav_link} on:click=close_mobile_menu>"Search"</a>
+                    </li>
+                    <li class={style::mobile_nav_item}>
+                        <SessionButton />
+                    </li>
                 </ul>
             </div>
         </nav>
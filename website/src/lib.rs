@@ -6,6 +6,7 @@ use leptos_router::{components::*, path};
 mod components;
 mod pages;
 mod api;
+mod session;
 
 // Components
 use crate::components::nav::Nav;
@@ -20,6 +21,11 @@ use crate::pages::workshops::Workshops;
 use crate::pages::events::Events;
 use crate::pages::projects::Projects;
 use crate::pages::advocacy::Advocacy;
+use crate::pages::search::SearchPage;
+use crate::pages::archive::{Archive, ArchiveTerm};
+use crate::pages::check_in::CheckInPage;
+use crate::pages::member::MemberPage;
+use crate::pages::author::AuthorPage;
 use crate::pages::not_found::NotFound;
 
 /// An app router which renders the homepage and handles 404's
@@ -27,6 +33,8 @@ use crate::pages::not_found::NotFound;
 pub fn App() -> impl IntoView {
     // Provides context that manages stylesheets, titles, meta tags, etc.
     provide_meta_context();
+    // Shares the signed-in member with every page
+    session::provide_session();
 
     view! {
         <Html attr:lang="en" attr:dir="ltr" attr:data-theme="light" />
@@ -46,11 +54,18 @@ pub fn App() -> impl IntoView {
                     <Route path=path!("/about") view=About />
                     <Route path=path!("/blog") view=Blog />
                     <Route path=path!("/blog/:slug") view=BlogPostPage />
+                    <Route path=path!("/blog/preview/:token") view=BlogPostPage />
                     <Route path=path!("/opensource") view=OpenSource />
                     <Route path=path!("/workshops") view=Workshops />
                     <Route path=path!("/events") view=Events />
                     <Route path=path!("/projects") view=Projects />
                     <Route path=path!("/advocacy") view=Advocacy />
+                    <Route path=path!("/search") view=SearchPage />
+                    <Route path=path!("/archive") view=Archive />
+                    <Route path=path!("/archive/:term") view=ArchiveTerm />
+                    <Route path=path!("/check-in") view=CheckInPage />
+                    <Route path=path!("/members/:github") view=MemberPage />
+                    <Route path=path!("/authors/:github") view=AuthorPage />
                 </Routes>
             </main>
         </Router>
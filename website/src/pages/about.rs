@@ -1,11 +1,30 @@
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 use leptos_router::components::A;
 use lucide_leptos::*;
+use crate::api::client::fetch_stats;
+use shared::Stats;
 
 stylance::import_style!(style, "about.module.scss");
 
 #[component]
 pub fn About() -> impl IntoView {
+    let stats = RwSignal::new(None::<Stats>);
+
+    Effect::new(move |_| {
+        spawn_local(async move {
+            match fetch_stats(None).await {
+                Ok(loaded) => stats.set(Some(loaded)),
+                Err(e) => log::warn!("Failed to load stats: {}", e),
+            }
+        });
+    });
+
+    // Placeholder until the numbers arrive
+    let stat = move |value: fn(&Stats) -> u32| {
+        move || stats.with(|stats| stats.as_ref().map_or("-".to_string(), |stats| value(stats).to_string()))
+    };
+
     view! {
         <div class={style::page_container}>
             <div class={style::page_header}>
@@ -66,25 +85,31 @@ pub fn About() -> impl IntoView {
             </section>
 
             <section class={style::stats_section}>
-                <h2>"Our Impact"</h2>
+                <h2>
+                    "Our Impact"
+                    {move || stats.with(|stats| stats.as_ref().map(|stats| format!(" in {}", stats.term.name)))}
+                </h2>
                 <div class={style::stats_grid}>
                     <div class={style::stat_card}>
-                        <div class={style::stat_number}>"47"</div>
-                        <div class={style::stat_label}>"PRs Merged This Semester"</div>
+                        <div class={style::stat_number}>{stat(|stats| stats.prs_merged_this_semester)}</div>
+                        <div class={style::stat_label}>"PRs Merged This Term"</div>
                     </div>
                     <div class={style::stat_card}>
-                        <div class={style::stat_number}>"23"</div>
+                        <div class={style::stat_number}>{stat(|stats| stats.active_members)}</div>
                         <div class={style::stat_label}>"Active Members"</div>
                     </div>
                     <div class={style::stat_card}>
-                        <div class={style::stat_number}>"12"</div>
-                        <div class={style::stat_label}>"First-Time Contributors"</div>
+                        <div class={style::stat_number}>{stat(|stats| stats.workshops_held)}</div>
+                        <div class={style::stat_label}>"Workshops Held"</div>
                     </div>
                     <div class={style::stat_card}>
-                        <div class={style::stat_number}>"8"</div>
+                        <div class={style::stat_number}>{stat(|stats| stats.projects_contributed_to)}</div>
                         <div class={style::stat_label}>"Projects Contributed To"</div>
                     </div>
                 </div>
+                <p class={style::archive_link}>
+                    <A href="/archive">"Browse past terms"</A>
+                </p>
             </section>
 
             <section class={style::join_section}>
@@ -1,58 +1,92 @@
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 use lucide_leptos::*;
+use crate::api::client::{fetch_courses, fetch_petition_summary, sign_in_url, sign_petition, support_course};
+use crate::session::use_session;
+use shared::{Course, CourseStatus, PetitionSummary};
 
 stylance::import_style!(style, "advocacy.module.scss");
 
-#[derive(Clone)]
-struct Course {
-    code: String,
-    name: String,
-    department: String,
-    status: String,
-    supporters: i32,
+/// What a submitted signature goes towards
+#[derive(Debug, Clone, PartialEq)]
+enum SignTarget {
+    Petition,
+    Course(String),
+}
+
+/// Badge class for a course's status
+fn status_class(status: CourseStatus) -> &'static str {
+    match status {
+        CourseStatus::Approved => style::status_approved,
+        CourseStatus::UnderReview => style::status_under_review,
+        CourseStatus::Pending => style::status_pending,
+        CourseStatus::Rejected => style::status_rejected,
+    }
 }
 
 #[component]
 pub fn Advocacy() -> impl IntoView {
-    let courses = vec![
-        Course {
-            code: "CS 311".to_string(),
-            name: "Computer Architecture".to_string(),
-            department: "Computer Science".to_string(),
-            status: "Under Review".to_string(),
-            supporters: 127,
-        },
-        Course {
-            code: "CS 211".to_string(),
-            name: "Programming & Algorithms II".to_string(),
-            department: "Computer Science".to_string(),
-            status: "Pending".to_string(),
-            supporters: 89,
-        },
-        Course {
-            code: "CS 370".to_string(),
-            name: "Parallel Programming".to_string(),
-            department: "Computer Science".to_string(),
-            status: "Approved".to_string(),
-            supporters: 156,
-        },
-        Course {
-            code: "CS 340".to_string(),
-            name: "Operating Systems".to_string(),
-            department: "Computer Science".to_string(),
-            status: "Under Review".to_string(),
-            supporters: 143,
-        },
-        Course {
-            code: "EECE 344".to_string(),
-            name: "Embedded Systems".to_string(),
-            department: "Electrical Engineering".to_string(),
-            status: "Pending".to_string(),
-            supporters: 67,
-        },
-    ];
+    let courses = RwSignal::new(Vec::<Course>::new());
+    let summary = RwSignal::new(None::<PetitionSummary>);
+    let error = RwSignal::new(None::<String>);
+
+    // Signatures are the signed-in member's, so signing is one click
+    let session = use_session();
+    let signed_out = move || session.loaded.get() && session.user.with(Option::is_none);
+    let backed = RwSignal::new(Vec::<SignTarget>::new());
+    let submitting = RwSignal::new(false);
+    let form_error = RwSignal::new(None::<String>);
+
+    Effect::new(move |_| {
+        spawn_local(async move {
+            match fetch_courses().await {
+                Ok(list) => courses.set(list),
+                Err(e) => error.set(Some(format!("Failed to load courses: {}", e))),
+            }
+            match fetch_petition_summary().await {
+                Ok(totals) => summary.set(Some(totals)),
+                Err(e) => error.set(Some(format!("Failed to load the petition: {}", e))),
+            }
+        });
+    });
 
-    let total_supporters = RwSignal::new(523);
+    let start_signing = move |target: SignTarget| {
+        spawn_local(async move {
+            submitting.set(true);
+            form_error.set(None);
+            let result = match &target {
+                SignTarget::Petition => sign_petition().await.map(|totals| summary.set(Some(totals))),
+                SignTarget::Course(slug) => support_course(slug).await.map(|course| {
+                    courses.update(|list| {
+                        if let Some(existing) = list.iter_mut().find(|c| c.slug == course.slug) {
+                            *existing = course;
+                        }
+                    });
+                }),
+            };
+            match result {
+                // Signing twice still means this signer backs the target
+                Ok(()) => {}
+                Err(e) if e.is_conflict() => {}
+                Err(e) => {
+                    form_error.set(Some(e.to_string()));
+                    submitting.set(false);
+                    return;
+                }
+            }
+            backed.update(|list| list.push(target.clone()));
+            if let SignTarget::Course(_) = target {
+                if let Ok(totals) = fetch_petition_summary().await {
+                    summary.set(Some(totals));
+                }
+            }
+            submitting.set(false);
+        });
+    };
+
+    let stat = move |field: fn(&PetitionSummary) -> u32| {
+        move || summary.with(|s| s.as_ref().map_or("-".to_string(), |s| field(s).to_string()))
+    };
 
     view! {
         <div class={style::page_container}>
@@ -84,49 +118,97 @@ pub fn Advocacy() -> impl IntoView {
                     </div>
                     <div class={style::petition_stats}>
                         <div class={format!("{} primary", style::stat_card)}>
-                            <div class={style::stat_number}>{move || total_supporters.get()}</div>
+                            <div class={style::stat_number}>{stat(|s| s.total_supporters)}</div>
                             <div class={style::stat_label}>"Total Supporters"</div>
                         </div>
                         <div class={style::stat_card}>
-                            <div class={style::stat_number}>"5"</div>
+                            <div class={style::stat_number}>{stat(|s| s.courses_targeted)}</div>
                             <div class={style::stat_label}>"Courses Targeted"</div>
                         </div>
                         <div class={format!("{} success", style::stat_card)}>
-                            <div class={style::stat_number}>"1"</div>
+                            <div class={style::stat_number}>{stat(|s| s.courses_approved)}</div>
                             <div class={style::stat_label}>"Approved"</div>
                         </div>
                     </div>
-                    <button class={format!("{} {} {}", style::btn, style::btn_primary, style::btn_large)} on:click=move |_| {
-                        total_supporters.update(|n| *n += 1);
-                    }>
-                        "Sign the Petition"
-                    </button>
+                    {move || error.get().map(|message| view! { <p class="error">{message}</p> })}
+                    {move || {
+                        if backed.with(|list| list.contains(&SignTarget::Petition)) {
+                            let name = session.user.with(|user| user.as_ref().map(|user| user.member.name.clone()));
+                            return view! {
+                                <p class={style::signed_message}>
+                                    <CircleCheck size=16 />
+                                    {name.map_or("Thanks for signing!".to_string(), |name| format!("Thanks for signing, {}!", name))}
+                                </p>
+                            }.into_any();
+                        }
+                        if signed_out() {
+                            return view! {
+                                <a href={sign_in_url("/advocacy")} class={format!("{} {} {}", style::btn, style::btn_primary, style::btn_large)}>
+                                    "Sign in to Sign the Petition"
+                                </a>
+                            }.into_any();
+                        }
+                        view! {
+                            {move || form_error.get().map(|message| view! { <p class={style::form_error}>{message}</p> })}
+                            <button
+                                class={format!("{} {} {}", style::btn, style::btn_primary, style::btn_large)}
+                                disabled=move || submitting.get()
+                                on:click=move |_| start_signing(SignTarget::Petition)
+                            >
+                                {move || if submitting.get() { "Signing..." } else { "Sign the Petition" }}
+                            </button>
+                        }.into_any()
+                    }}
                 </div>
             </div>
 
-            <section class="courses-section">
+            <section class={style::courses_section}>
                 <h2>"Target Courses"</h2>
-                <div class="courses-table">
-                    <div class="table-header">
+                <div class={style::courses_table}>
+                    <div class={style::table_header}>
                         <span>"Course"</span>
                         <span>"Department"</span>
                         <span>"Status"</span>
                         <span>"Supporters"</span>
                     </div>
-                    {courses.into_iter().map(|course| {
+                    {move || courses.get().into_iter().map(|course| {
+                        let target = SignTarget::Course(course.slug.clone());
+                        let open = course.is_open();
                         view! {
-                            <div class="table-row">
-                                <div class="course-info">
-                                    <span class="course-code">{course.code}</span>
-                                    <span class="course-name">{course.name}</span>
+                            <div class={style::table_row}>
+                                <div class={style::course_info}>
+                                    <span class={style::course_code}>{course.course_code}</span>
+                                    <span class={style::course_name}>{course.course_name}</span>
                                 </div>
-                                <span class="department">{course.department}</span>
-                                <span class={format!("status status-{}", course.status.to_lowercase().replace(" ", "-"))}>
-                                    {course.status.clone()}
+                                <span class={style::department}>{course.department}</span>
+                                <span class={format!("{} {}", style::status, status_class(course.status))}>
+                                    {course.status.to_string()}
                                 </span>
-                                <div class="supporters">
-                                    <span class="supporter-count">{course.supporters}</span>
-                                    <button class="support-btn">"+"</button>
+                                <div class={style::supporters}>
+                                    <span class={style::supporter_count}>{course.supporters_count}</span>
+                                    {open.then(|| {
+                                        if signed_out() {
+                                            return view! {
+                                                <a href={sign_in_url("/advocacy")} class={style::support_btn} title="Sign in to back Rust in this course">
+                                                    "+"
+                                                </a>
+                                            }.into_any();
+                                        }
+                                        let done = {
+                                            let target = target.clone();
+                                            Memo::new(move |_| backed.with(|list| list.contains(&target)))
+                                        };
+                                        view! {
+                                            <button
+                                                class={style::support_btn}
+                                                title="Back Rust in this course"
+                                                disabled=move || done.get() || submitting.get()
+                                                on:click=move |_| start_signing(target.clone())
+                                            >
+                                                {move || if done.get() { "✓" } else { "+" }}
+                                            </button>
+                                        }.into_any()
+                                    })}
                                 </div>
                             </div>
                         }
@@ -168,24 +250,26 @@ pub fn Advocacy() -> impl IntoView {
                 </div>
             </section>
 
-            <section class="success-stories">
+            <section class={style::success_stories}>
                 <h2>"Success Stories"</h2>
-                <div class="story-card success">
-                    <div class="story-header">
-                        <span class="success-badge"><Check size=16 />"SUCCESS"</span>
-                        <h3>"CS 370: Parallel Programming"</h3>
-                    </div>
-                    <p>
-                        "After presenting the benefits of Rust's fearless concurrency and demonstrating how it prevents "
-                        "data races at compile time, the CS department approved Rust for CS 370 submissions. Students using "
-                        "Rust reported fewer debugging hours and better understanding of parallel programming concepts."
-                    </p>
-                    <div class="story-stats">
-                        <span>"156 supporters"</span>
-                        <span>"3 months advocacy"</span>
-                        <span>"Approved Fall 2024"</span>
-                    </div>
-                </div>
+                {move || courses.get().into_iter().filter(|c| c.status == CourseStatus::Approved).map(|course| {
+                    let approved_in = course.history.iter().rev()
+                        .find(|change| change.status == CourseStatus::Approved)
+                        .and_then(|change| change.term.as_ref().map(|term| format!("Approved {}", term.name)));
+                    view! {
+                        <div class={format!("{} success", style::story_card)}>
+                            <div class={style::story_header}>
+                                <span class={style::success_badge}><Check size=16 />"SUCCESS"</span>
+                                <h3>{format!("{}: {}", course.course_code, course.course_name)}</h3>
+                            </div>
+                            {course.description.map(|text| view! { <p>{text}</p> })}
+                            <div class={style::story_stats}>
+                                <span>{format!("{} supporters", course.supporters_count)}</span>
+                                {approved_in.map(|label| view! { <span>{label}</span> })}
+                            </div>
+                        </div>
+                    }
+                }).collect_view()}
             </section>
 
             <section class="how-to-help">
@@ -218,7 +302,12 @@ pub fn Advocacy() -> impl IntoView {
                 <h2>"Ready to Make a Difference?"</h2>
                 <p>"Join us in bringing Rust to the academic curriculum. Your voice matters!"</p>
                 <div class="cta-actions">
-                    <button class={format!("{} {} {}", style::btn, style::btn_primary, style::btn_large)}>"Sign the Petition"</button>
+                    <button
+                        class={format!("{} {} {}", style::btn, style::btn_primary, style::btn_large)}
+                        on:click=move |_| start_signing(SignTarget::Petition)
+                    >
+                        "Sign the Petition"
+                    </button>
                     <button class="btn btn-secondary btn-large">"View Full Proposal"</button>
                 </div>
             </div>
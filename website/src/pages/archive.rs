@@ -0,0 +1,246 @@
+use chrono::Utc;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_router::hooks::use_params_map;
+use lucide_leptos::*;
+use crate::api::client::{fetch_blog_posts, fetch_events, fetch_stats, fetch_term, fetch_terms, BlogQuery, EventQuery};
+use shared::{BlogPost, Event, Stats, Term};
+
+stylance::import_style!(style, "archive.module.scss");
+
+/// e.g. "Aug 25 – Dec 19, 2025"
+fn date_range(term: &Term) -> String {
+    format!("{} – {}", term.starts_on.format("%b %-d"), term.ends_on.format("%b %-d, %Y"))
+}
+
+/// Index of finished terms
+#[component]
+pub fn Archive() -> impl IntoView {
+    let terms = RwSignal::new(Vec::<Term>::new());
+    let loading = RwSignal::new(true);
+    let error = RwSignal::new(None::<String>);
+
+    Effect::new(move |_| {
+        spawn_local(async move {
+            match fetch_terms().await {
+                Ok(all) => {
+                    let now = Utc::now();
+                    terms.set(all.into_iter().filter(|term| term.ended_before(now)).collect());
+                }
+                Err(e) => error.set(Some(format!("Failed to load terms: {}", e))),
+            }
+            loading.set(false);
+        });
+    });
+
+    view! {
+        <div class={style::page_container}>
+            <div class={style::page_header}>
+                <h1>"Archive"</h1>
+                <p class={style::page_subtitle}>"Events, posts and numbers from past terms."</p>
+            </div>
+
+            {move || {
+                if loading.get() {
+                    view! { <p class={style::status}>"Loading terms..."</p> }.into_any()
+                } else if let Some(err) = error.get() {
+                    view! { <p class="error">{err}</p> }.into_any()
+                } else if terms.with(Vec::is_empty) {
+                    view! { <p class={style::status}>"No terms have finished yet."</p> }.into_any()
+                } else {
+                    view! {
+                        <div class={style::term_grid}>
+                            {terms.get().into_iter().map(|term| {
+                                let dates = date_range(&term);
+                                view! {
+                                    <a href={format!("/archive/{}", term.slug)} class={style::term_card}>
+                                        <h2>{term.name}</h2>
+                                        <p>{dates}</p>
+                                    </a>
+                                }
+                            }).collect_view()}
+                        </div>
+                    }.into_any()
+                }
+            }}
+        </div>
+    }
+}
+
+/// Stats, events and blog posts for one term
+#[component]
+pub fn ArchiveTerm() -> impl IntoView {
+    let params = use_params_map();
+    let slug = Memo::new(move |_| params.get().get("term").unwrap_or_default());
+
+    let term = RwSignal::new(None::<Term>);
+    let stats = RwSignal::new(None::<Stats>);
+    let events = RwSignal::new(Vec::<Event>::new());
+    let posts = RwSignal::new(Vec::<BlogPost>::new());
+    let next_cursor = RwSignal::new(None::<String>);
+    let loading = RwSignal::new(true);
+    let error = RwSignal::new(None::<String>);
+
+    Effect::new(move |_| {
+        let slug = slug.get();
+        spawn_local(async move {
+            loading.set(true);
+            error.set(None);
+
+            match fetch_term(&slug).await {
+                Ok(Some(found)) => term.set(Some(found)),
+                Ok(None) => {
+                    error.set(Some("Term not found".to_string()));
+                    loading.set(false);
+                    return;
+                }
+                Err(e) => {
+                    error.set(Some(format!("Failed to load term: {}", e)));
+                    loading.set(false);
+                    return;
+                }
+            }
+
+            match fetch_stats(Some(&slug)).await {
+                Ok(loaded) => stats.set(Some(loaded)),
+                Err(e) => log::warn!("Failed to load stats for {}: {}", slug, e),
+            }
+            let event_query = EventQuery { term: Some(slug.clone()), ..Default::default() };
+            match fetch_events(&event_query).await {
+                Ok(page) => {
+                    events.set(page.items);
+                    next_cursor.set(page.next_cursor);
+                }
+                Err(e) => error.set(Some(format!("Failed to load events: {}", e))),
+            }
+            let blog_query = BlogQuery { term: Some(slug.clone()), ..Default::default() };
+            match fetch_blog_posts(&blog_query).await {
+                Ok(page) => posts.set(page.items),
+                Err(e) => error.set(Some(format!("Failed to load blog posts: {}", e))),
+            }
+
+            loading.set(false);
+        });
+    });
+
+    let load_more = move |_| {
+        let query = EventQuery {
+            term: Some(slug.get_untracked()),
+            cursor: next_cursor.get_untracked(),
+            ..Default::default()
+        };
+        spawn_local(async move {
+            match fetch_events(&query).await {
+                Ok(page) => {
+                    events.update(|list| list.extend(page.items));
+                    next_cursor.set(page.next_cursor);
+                }
+                Err(e) => error.set(Some(format!("Failed to load more events: {}", e))),
+            }
+        });
+    };
+
+    let stat = move |value: fn(&Stats) -> u32| {
+        move || stats.with(|stats| stats.as_ref().map_or("-".to_string(), |stats| value(stats).to_string()))
+    };
+
+    view! {
+        <div class={style::page_container}>
+            <a href="/archive" class={style::back_link}>
+                <ArrowLeft size=16 />
+                "All terms"
+            </a>
+
+            <div class={style::page_header}>
+                <h1>{move || term.with(|term| term.as_ref().map_or_else(|| slug.get(), |term| term.name.clone()))}</h1>
+                <p class={style::page_subtitle}>{move || term.with(|term| term.as_ref().map(date_range))}</p>
+            </div>
+
+            {move || {
+                if loading.get() {
+                    return view! { <p class={style::status}>"Loading..."</p> }.into_any();
+                }
+                if let Some(err) = error.get() {
+                    return view! { <p class="error">{err}</p> }.into_any();
+                }
+                view! {
+                    <section class={style::section}>
+                        <div class={style::stats_grid}>
+                            <div class={style::stat_card}>
+                                <div class={style::stat_number}>{stat(|stats| stats.prs_merged_this_semester)}</div>
+                                <div class={style::stat_label}>"PRs Merged"</div>
+                            </div>
+                            <div class={style::stat_card}>
+                                <div class={style::stat_number}>{stat(|stats| stats.active_members)}</div>
+                                <div class={style::stat_label}>"Members"</div>
+                            </div>
+                            <div class={style::stat_card}>
+                                <div class={style::stat_number}>{stat(|stats| stats.workshops_held)}</div>
+                                <div class={style::stat_label}>"Workshops Held"</div>
+                            </div>
+                            <div class={style::stat_card}>
+                                <div class={style::stat_number}>{stat(|stats| stats.projects_contributed_to)}</div>
+                                <div class={style::stat_label}>"Projects Contributed To"</div>
+                            </div>
+                            <div class={style::stat_card}>
+                                <div class={style::stat_number}>{stat(|stats| stats.event_check_ins)}</div>
+                                <div class={style::stat_label}>"Event Check-ins"</div>
+                            </div>
+                            <div class={style::stat_card}>
+                                <div class={style::stat_number}>{stat(|stats| stats.event_attendees)}</div>
+                                <div class={style::stat_label}>"Members Attending Events"</div>
+                            </div>
+                        </div>
+                    </section>
+
+                    <section class={style::section}>
+                        <h2>"Events"</h2>
+                        <Show
+                            when=move || !events.with(Vec::is_empty)
+                            fallback=|| view! { <p class={style::status}>"No events this term."</p> }
+                        >
+                            <ul class={style::item_list}>
+                                {move || events.get().into_iter().map(|event| {
+                                    let date = event.date_label();
+                                    view! {
+                                        <li class={style::item}>
+                                            <span class={style::item_meta}>{date}</span>
+                                            <span class={style::item_title}>{event.title}</span>
+                                            <span class={style::item_badge}>{event.event_type.to_string()}</span>
+                                        </li>
+                                    }
+                                }).collect_view()}
+                            </ul>
+                        </Show>
+                        <Show when=move || next_cursor.get().is_some()>
+                            <button class={format!("{} {}", style::btn, style::btn_secondary)} on:click=load_more>
+                                "Load More Events"
+                            </button>
+                        </Show>
+                    </section>
+
+                    <section class={style::section}>
+                        <h2>"Blog Posts"</h2>
+                        <Show
+                            when=move || !posts.with(Vec::is_empty)
+                            fallback=|| view! { <p class={style::status}>"No posts this term."</p> }
+                        >
+                            <ul class={style::item_list}>
+                                {move || posts.get().into_iter().map(|post| {
+                                    let byline = post.byline();
+                                    view! {
+                                        <li class={style::item}>
+                                            <span class={style::item_meta}>{post.published_at.clone()}</span>
+                                            <a href={format!("/blog/{}", post.slug)} class={style::item_title}>{post.title}</a>
+                                            <span class={style::item_badge}>{byline}</span>
+                                        </li>
+                                    }
+                                }).collect_view()}
+                            </ul>
+                        </Show>
+                    </section>
+                }.into_any()
+            }}
+        </div>
+    }
+}
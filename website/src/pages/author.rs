@@ -0,0 +1,114 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_router::hooks::use_params_map;
+use lucide_leptos::*;
+use crate::api::client::fetch_author;
+use shared::{BlogAuthor, BlogPost};
+
+stylance::import_style!(style, "author.module.scss");
+
+/// A blog author's page: who they are and every post they wrote or co-wrote
+#[component]
+pub fn AuthorPage() -> impl IntoView {
+    let params = use_params_map();
+    let github = Memo::new(move |_| params.get().get("github").unwrap_or_default());
+
+    let author = RwSignal::new(None::<BlogAuthor>);
+    let bio = RwSignal::new(None::<String>);
+    let posts = RwSignal::new(Vec::<BlogPost>::new());
+    let next_cursor = RwSignal::new(None::<String>);
+    let loading = RwSignal::new(true);
+    let error = RwSignal::new(None::<String>);
+
+    Effect::new(move |_| {
+        let github = github.get();
+        spawn_local(async move {
+            loading.set(true);
+            error.set(None);
+            match fetch_author(&github, None).await {
+                Ok(Some(profile)) => {
+                    author.set(Some(profile.author));
+                    bio.set(profile.bio);
+                    posts.set(profile.posts.items);
+                    next_cursor.set(profile.posts.next_cursor);
+                }
+                Ok(None) => error.set(Some("Author not found".to_string())),
+                Err(e) => error.set(Some(format!("Failed to load author: {}", e))),
+            }
+            loading.set(false);
+        });
+    });
+
+    let load_more = move |_| {
+        let github = github.get_untracked();
+        let cursor = next_cursor.get_untracked();
+        spawn_local(async move {
+            match fetch_author(&github, cursor).await {
+                Ok(Some(profile)) => {
+                    posts.update(|list| list.extend(profile.posts.items));
+                    next_cursor.set(profile.posts.next_cursor);
+                }
+                Ok(None) => next_cursor.set(None),
+                Err(e) => error.set(Some(format!("Failed to load more posts: {}", e))),
+            }
+        });
+    };
+
+    view! {
+        <div class={style::page_container}>
+            {move || {
+                if loading.get() {
+                    return view! { <p class={style::status}>"Loading..."</p> }.into_any();
+                }
+                if let Some(err) = error.get() {
+                    return view! { <p class="error">{err}</p> }.into_any();
+                }
+                let Some(author) = author.get() else {
+                    return ().into_any();
+                };
+                let github_url = format!("https://github.com/{}", author.github_username);
+
+                view! {
+                    <div class={style::profile_header}>
+                        {author.avatar_url.map(|url| view! {
+                            <img class={style::avatar} src={url} alt="" width="96" height="96" />
+                        })}
+                        <div>
+                            <h1>{author.name}</h1>
+                            <a href={github_url} class={style::github_link} target="_blank" rel="noopener noreferrer">
+                                <Github size=16 />
+                                {format!("@{}", author.github_username)}
+                            </a>
+                        </div>
+                    </div>
+
+                    {bio.get().map(|bio| view! { <p class={style::bio}>{bio}</p> })}
+
+                    <section class={style::section}>
+                        <h2>"Blog Posts"</h2>
+                        <ul class={style::item_list}>
+                            {move || posts.get().into_iter().map(|post| {
+                                let byline = post.byline();
+                                view! {
+                                    <li class={style::item}>
+                                        <span class={style::item_meta}>{post.published_at}</span>
+                                        <div class={style::item_title}>
+                                            <a href={format!("/blog/{}", post.slug)}>{post.title}</a>
+                                            <span class={style::item_byline}>{byline}</span>
+                                        </div>
+                                        <span class={style::item_badge}>{post.post_type.to_string()}</span>
+                                    </li>
+                                }
+                            }).collect_view()}
+                        </ul>
+                        <Show when=move || next_cursor.get().is_some()>
+                            <button class={format!("{} {}", style::btn, style::btn_secondary)} on:click=load_more>
+                                "Load More Posts"
+                            </button>
+                        </Show>
+                    </section>
+                }.into_any()
+            }}
+        </div>
+    }
+}
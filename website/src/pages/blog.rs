@@ -1,41 +1,98 @@
 use leptos::prelude::*;
 use leptos::task::spawn_local;
 use lucide_leptos::*;
-use crate::api::client::{fetch_blog_posts, fetch_featured_blog_posts};
-use shared::DifficultyLevel;
+use crate::api::client::{blog_feed_url, fetch_blog_posts, fetch_featured_blog_posts, BlogQuery};
+use shared::{BlogCategory, BlogPostType, DifficultyLevel};
 
 stylance::import_style!(style, "blog.module.scss");
 
+/// A filter toggle that sets `signal` to `value` and highlights itself while selected
+fn filter_button<T>(label: &'static str, signal: RwSignal<Option<T>>, value: Option<T>) -> impl IntoView
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let current = value.clone();
+    view! {
+        <button
+            class=move || if signal.get() == current {
+                format!("{} {}", style::filter_btn, style::active)
+            } else {
+                style::filter_btn.to_string()
+            }
+            on:click=move |_| signal.set(value.clone())
+        >
+            {label}
+        </button>
+    }
+}
+
 #[component]
 pub fn Blog() -> impl IntoView {
     let (blog_posts, set_blog_posts) = RwSignal::new(Vec::new()).split();
     let (featured_posts, set_featured_posts) = RwSignal::new(Vec::new()).split();
     let (loading, set_loading) = RwSignal::new(true).split();
     let (error, set_error) = RwSignal::new(None::<String>).split();
+    let (featured_loading, set_featured_loading) = RwSignal::new(true).split();
+    let (featured_error, set_featured_error) = RwSignal::new(None::<String>).split();
+    let next_cursor = RwSignal::new(None::<String>);
+
+    let post_type = RwSignal::new(None::<BlogPostType>);
+    let category = RwSignal::new(None::<BlogCategory>);
+    let difficulty = RwSignal::new(None::<DifficultyLevel>);
+
+    Effect::new(move |_| {
+        spawn_local(async move {
+            match fetch_featured_blog_posts().await {
+                Ok(posts) => set_featured_posts.set(posts),
+                Err(e) => set_featured_error.set(Some(format!("Failed to load featured posts: {}", e))),
+            }
+            set_featured_loading.set(false);
+        });
+    });
 
+    // Refetch from the first page whenever a filter changes
     Effect::new(move |_| {
+        let query = BlogQuery {
+            post_type: post_type.get(),
+            category: category.get(),
+            difficulty: difficulty.get(),
+            ..Default::default()
+        };
         spawn_local(async move {
             set_loading.set(true);
             set_error.set(None);
 
-            // Fetch both resources concurrently
-            let blog_result = fetch_blog_posts().await;
-            let featured_result = fetch_featured_blog_posts().await;
-
-            match blog_result {
-                Ok(posts) => set_blog_posts.set(posts),
+            match fetch_blog_posts(&query).await {
+                Ok(page) => {
+                    set_blog_posts.set(page.items);
+                    next_cursor.set(page.next_cursor);
+                }
                 Err(e) => set_error.set(Some(format!("Failed to load blog posts: {}", e))),
             }
 
-            match featured_result {
-                Ok(posts) => set_featured_posts.set(posts),
-                Err(e) => set_error.set(Some(format!("Failed to load featured posts: {}", e))),
-            }
-
             set_loading.set(false);
         });
     });
 
+    let load_more = move |_| {
+        let query = BlogQuery {
+            post_type: post_type.get_untracked(),
+            category: category.get_untracked(),
+            difficulty: difficulty.get_untracked(),
+            cursor: next_cursor.get_untracked(),
+            ..Default::default()
+        };
+        spawn_local(async move {
+            match fetch_blog_posts(&query).await {
+                Ok(page) => {
+                    set_blog_posts.update(|posts| posts.extend(page.items));
+                    next_cursor.set(page.next_cursor);
+                }
+                Err(e) => set_error.set(Some(format!("Failed to load more posts: {}", e))),
+            }
+        });
+    };
+
     view! {
         <div class={style::page_container}>
             <div class={style::page_header}>
@@ -44,6 +101,16 @@ pub fn Blog() -> impl IntoView {
                     "In-depth tutorials, guides, and member showcases. Learn from the community "
                     "and share your own Rust journey with fellow students."
                 </p>
+                <div class={style::feed_links}>
+                    <a class={format!("{} {} {}", style::btn, style::btn_secondary, style::btn_sm)} href={blog_feed_url("rss.xml")}>
+                        <Rss size=14 />
+                        "RSS"
+                    </a>
+                    <a class={format!("{} {} {}", style::btn, style::btn_secondary, style::btn_sm)} href={blog_feed_url("atom.xml")}>
+                        <Rss size=14 />
+                        "Atom"
+                    </a>
+                </div>
             </div>
 
             // Featured Posts Section
@@ -51,13 +118,14 @@ pub fn Blog() -> impl IntoView {
                 <h2>"Featured Posts"</h2>
                 <div class={style::featured_posts_grid}>
                     {move || {
-                        if loading.get() {
+                        if featured_loading.get() {
                             view! { <div>"Loading featured posts..."</div> }.into_any()
-                        } else if let Some(err) = error.get() {
+                        } else if let Some(err) = featured_error.get() {
                             view! { <div class="error">"Error loading featured posts: " {err}</div> }.into_any()
                         } else {
                             view! {
                                 {featured_posts.get().into_iter().map(|post| {
+                                                let byline = post.byline();
                                                 let difficulty_badge = if let Some(level) = &post.difficulty_level {
                                                     view! {
                                                         <span class={format!("{} {}", style::difficulty_badge, match level {
@@ -103,7 +171,7 @@ pub fn Blog() -> impl IntoView {
                                                         <p class={style::post_excerpt}>{post.excerpt}</p>
                                                         <div class={style::post_author}>
                                                             <div class={style::author_info}>
-                                                                <span class={style::author_name}>{post.author_name.clone()}</span>
+                                                                <span class={style::author_name}>{byline}</span>
                                                                 <span class={style::publish_date}>{post.published_at.clone()}</span>
                                                             </div>
                                                         </div>
@@ -131,31 +199,31 @@ pub fn Blog() -> impl IntoView {
                     <div class={style::filter_group}>
                         <label>"Filter by Type:"</label>
                         <div class={style::filter_buttons}>
-                            <button class={format!("{} {}", style::filter_btn, style::active)}>"All"</button>
-                            <button class={style::filter_btn}>"Tutorials"</button>
-                            <button class={style::filter_btn}>"Guides"</button>
-                            <button class={style::filter_btn}>"Show & Tell"</button>
-                            <button class={style::filter_btn}>"Tech Talks"</button>
-                            <button class={style::filter_btn}>"News"</button>
+                            {filter_button("All", post_type, None)}
+                            {filter_button("Tutorials", post_type, Some(BlogPostType::Tutorial))}
+                            {filter_button("Guides", post_type, Some(BlogPostType::Guide))}
+                            {filter_button("Show & Tell", post_type, Some(BlogPostType::ShowAndTell))}
+                            {filter_button("Tech Talks", post_type, Some(BlogPostType::TechTalk))}
+                            {filter_button("News", post_type, Some(BlogPostType::News))}
                         </div>
                     </div>
                     <div class={style::filter_group}>
                         <label>"Filter by Category:"</label>
                         <div class={style::filter_buttons}>
-                            <button class={format!("{} {}", style::filter_btn, style::active)}>"All"</button>
-                            <button class={style::filter_btn}>"Fundamentals"</button>
-                            <button class={style::filter_btn}>"Web Dev"</button>
-                            <button class={style::filter_btn}>"CLI"</button>
-                            <button class={style::filter_btn}>"Systems"</button>
+                            {filter_button("All", category, None)}
+                            {filter_button("Fundamentals", category, Some(BlogCategory::Fundamentals))}
+                            {filter_button("Web Dev", category, Some(BlogCategory::WebDevelopment))}
+                            {filter_button("CLI", category, Some(BlogCategory::CLI))}
+                            {filter_button("Systems", category, Some(BlogCategory::SystemsProgramming))}
                         </div>
                     </div>
                     <div class={style::filter_group}>
                         <label>"Filter by Level:"</label>
                         <div class={style::filter_buttons}>
-                            <button class={format!("{} {}", style::filter_btn, style::active)}>"All"</button>
-                            <button class={style::filter_btn}>"Beginner"</button>
-                            <button class={style::filter_btn}>"Intermediate"</button>
-                            <button class={style::filter_btn}>"Advanced"</button>
+                            {filter_button("All", difficulty, None)}
+                            {filter_button("Beginner", difficulty, Some(DifficultyLevel::Easy))}
+                            {filter_button("Intermediate", difficulty, Some(DifficultyLevel::Medium))}
+                            {filter_button("Advanced", difficulty, Some(DifficultyLevel::Hard))}
                         </div>
                     </div>
                 </div>
@@ -172,6 +240,7 @@ pub fn Blog() -> impl IntoView {
                         } else {
                             view! {
                                 {blog_posts.get().into_iter().map(|post| {
+                                                let byline = post.byline();
                                                 let difficulty_badge = if let Some(level) = &post.difficulty_level {
                                                     view! {
                                                         <span class={format!("{} {}", style::difficulty_badge, match level {
@@ -234,7 +303,7 @@ pub fn Blog() -> impl IntoView {
                                                         <div class={style::post_author}>
                                                             <div class={style::author_info}>
                                                                 <User size=14 />
-                                                                <span class={style::author_name}>{post.author_name.clone()}</span>
+                                                                <span class={style::author_name}>{byline}</span>
                                                                 <span class={style::publish_date}>{post.published_at.clone()}</span>
                                                             </div>
                                                         </div>
@@ -253,6 +322,11 @@ pub fn Blog() -> impl IntoView {
                                         }
                                     }}
                 </div>
+                <Show when=move || next_cursor.get().is_some() && !loading.get()>
+                    <button class={format!("{} {}", style::btn, style::btn_secondary)} on:click=load_more>
+                        "Load More Posts"
+                    </button>
+                </Show>
             </section>
 
             // Contributing Section
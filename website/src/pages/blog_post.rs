@@ -3,15 +3,22 @@ use leptos::task::spawn_local;
 use leptos_router::hooks::use_params_map;
 use lucide_leptos::*;
 use crate::components::blog_renderer::BlogPostViewer;
-use crate::api::fetch_blog_post_by_slug;
-use shared::BlogPost;
+use crate::api::{
+    fetch_blog_post_by_slug, fetch_blog_post_preview, fetch_blog_series, fetch_like_status, like_blog_post,
+    send_view_beacon, unlike_blog_post,
+};
+use shared::{BlogPost, BlogPostStatus, BlogSeries, BlogSeriesDetail};
 
 stylance::import_style!(style, "blog_post.module.scss");
 
+/// A published post at `/blog/:slug`, or any post at `/blog/preview/:token`.
+/// Previews are not counted as views and cannot be liked.
 #[component]
 pub fn BlogPostPage() -> impl IntoView {
     let params = use_params_map();
     let slug = RwSignal::new(params.get().get("slug").map(|s| s.clone()).unwrap_or_default());
+    let preview_token = params.get().get("token");
+    let is_preview = preview_token.is_some();
 
     let blog_post = RwSignal::new(Option::<BlogPost>::None);
     let loading = RwSignal::new(true);
@@ -20,14 +27,26 @@ pub fn BlogPostPage() -> impl IntoView {
     // Fetch blog post metadata when component loads
     Effect::new(move |_| {
         let slug_value = slug.get();
+        let preview_token = preview_token.clone();
         spawn_local(async move {
             loading.set(true);
             error.set(None);
 
-            match fetch_blog_post_by_slug(&slug_value).await {
+            let result = match &preview_token {
+                Some(token) => fetch_blog_post_preview(token).await,
+                None => fetch_blog_post_by_slug(&slug_value).await,
+            };
+            match result {
                 Ok(Some(post)) => {
                     blog_post.set(Some(post));
                     loading.set(false);
+                    if preview_token.is_none() {
+                        send_view_beacon(&slug_value).await;
+                    }
+                }
+                Ok(None) if preview_token.is_some() => {
+                    error.set(Some("This preview link has expired or been replaced".to_string()));
+                    loading.set(false);
                 }
                 Ok(None) => {
                     error.set(Some("Blog post not found".to_string()));
@@ -66,6 +85,19 @@ pub fn BlogPostPage() -> impl IntoView {
                 } else if let Some(post) = blog_post.get() {
                     view! {
                         <div class={style::blog_post_container}>
+                            {is_preview.then(|| view! {
+                                <div class={style::preview_banner}>
+                                    <Eye size=16 />
+                                    <span>
+                                        {match post.status {
+                                            BlogPostStatus::Published => "Preview of a published post.".to_string(),
+                                            status => format!("Preview: this post is {} and not public yet.", status.to_string().to_lowercase()),
+                                        }}
+                                        " Please don't share this link beyond reviewers."
+                                    </span>
+                                </div>
+                            })}
+
                             // Blog post header
                             <header class={style::post_header}>
                                 <div class={style::breadcrumb}>
@@ -113,7 +145,32 @@ pub fn BlogPostPage() -> impl IntoView {
                                 <div class={style::post_meta}>
                                     <div class={style::author_info}>
                                         <User size=16 />
-                                        <span class={style::author_name}>{post.author_name.clone()}</span>
+                                        {match post.author_github.clone() {
+                                            Some(github) => view! {
+                                                <a href={format!("/authors/{}", github)} class={style::author_name}>{post.author_name.clone()}</a>
+                                            }.into_any(),
+                                            None => view! {
+                                                <span class={style::author_name}>{post.author_name.clone()}</span>
+                                            }.into_any(),
+                                        }}
+                                        {(!post.co_authors.is_empty()).then(|| {
+                                            let count = post.co_authors.len();
+                                            view! {
+                                                <span class={style::co_authors}>
+                                                    "with "
+                                                    {post.co_authors.iter().enumerate().map(|(i, author)| view! {
+                                                        {match i {
+                                                            0 => "",
+                                                            _ if i + 1 == count => " and ",
+                                                            _ => ", ",
+                                                        }}
+                                                        <a href={format!("/authors/{}", author.github_username)} class={style::author_name}>
+                                                            {author.name.clone()}
+                                                        </a>
+                                                    }).collect_view()}
+                                                </span>
+                                            }
+                                        })}
                                         {post.author_github.as_ref().map(|github| {
                                             view! {
                                                 <a href={format!("https://github.com/{}", github)}
@@ -138,10 +195,9 @@ pub fn BlogPostPage() -> impl IntoView {
                                             <Eye size=14 />
                                             {format!("{} views", post.views)}
                                         </span>
-                                        <span class={style::like_count}>
-                                            <Heart size=14 />
-                                            {format!("{} likes", post.likes)}
-                                        </span>
+                                        {(!is_preview).then(|| view! {
+                                            <LikeButton slug={post.slug.clone()} initial_likes={post.likes} />
+                                        })}
                                     </div>
                                 </div>
 
@@ -173,9 +229,13 @@ pub fn BlogPostPage() -> impl IntoView {
 
                             // Blog post content
                             <main class={style::post_content}>
-                                <BlogPostViewer slug={slug.get()} />
+                                <BlogPostViewer markdown_url={post.markdown_url.clone()} />
                             </main>
 
+                            {post.series.clone().map(|series| view! {
+                                <SeriesNavigation series=series current_title={post.title.clone()} />
+                            })}
+
                             // External links
                             {if !post.external_links.is_empty() {
                                 view! {
@@ -224,4 +284,160 @@ pub fn BlogPostPage() -> impl IntoView {
             }}
         </div>
     }
-}
\ No newline at end of file
+}
+
+/// "Part N of M" table of contents for the post's series, with links to the
+/// previous and next published parts. Planned parts that are not out yet are
+/// listed without links. Renders nothing if the series cannot be loaded.
+#[component]
+fn SeriesNavigation(series: BlogSeries, current_title: String) -> impl IntoView {
+    let detail = RwSignal::new(Option::<BlogSeriesDetail>::None);
+
+    let series_slug = series.slug.clone();
+    spawn_local(async move {
+        match fetch_blog_series(&series_slug).await {
+            Ok(found) => detail.set(found),
+            Err(e) => log::warn!("Failed to load series {}: {}", series_slug, e),
+        }
+    });
+
+    move || detail.get().map(|detail| {
+        let current_part = series.part;
+        let total = detail.planned_parts
+            .unwrap_or(0)
+            .max(series.total_parts.unwrap_or(0))
+            .max(detail.parts.last().map_or(0, |entry| entry.part))
+            .max(current_part);
+        // A preview may be of a part that is not published yet, so neighbours
+        // are found by part number rather than by position in the list
+        let previous = detail.parts.iter().rev().find(|entry| entry.part < current_part).cloned();
+        let next = detail.parts.iter().find(|entry| entry.part > current_part).cloned();
+
+        view! {
+            <aside class={style::series_navigation}>
+                <h3 class={style::series_heading}>
+                    <BookOpen size=18 />
+                    <span>{format!("Part {} of {} in {}", current_part, total, detail.title)}</span>
+                </h3>
+                {(!detail.description.is_empty()).then(|| view! {
+                    <p class={style::series_description}>{detail.description.clone()}</p>
+                })}
+                <ol class={style::series_parts}>
+                    {(1..=total).map(|part| {
+                        let entry = detail.parts.iter().find(|entry| entry.part == part);
+                        if part == current_part {
+                            view! {
+                                <li class={format!("{} {}", style::series_part, style::series_part_current)} aria-current="page">
+                                    <span class={style::series_part_number}>{part.to_string()}</span>
+                                    <span>{current_title.clone()}</span>
+                                </li>
+                            }.into_any()
+                        } else if let Some(entry) = entry {
+                            view! {
+                                <li class={style::series_part}>
+                                    <span class={style::series_part_number}>{part.to_string()}</span>
+                                    <a href={format!("/blog/{}", entry.slug)}>{entry.title.clone()}</a>
+                                </li>
+                            }.into_any()
+                        } else {
+                            view! {
+                                <li class={format!("{} {}", style::series_part, style::series_part_planned)}>
+                                    <span class={style::series_part_number}>{part.to_string()}</span>
+                                    <span>"Coming soon"</span>
+                                </li>
+                            }.into_any()
+                        }
+                    }).collect_view()}
+                </ol>
+                {(previous.is_some() || next.is_some()).then(|| view! {
+                    <div class={style::series_pager}>
+                        {previous.map(|entry| view! {
+                            <a href={format!("/blog/{}", entry.slug)} class={style::series_previous}>
+                                <ArrowLeft size=16 />
+                                <span>
+                                    <small>{format!("Previous: part {}", entry.part)}</small>
+                                    {entry.title}
+                                </span>
+                            </a>
+                        })}
+                        {next.map(|entry| view! {
+                            <a href={format!("/blog/{}", entry.slug)} class={style::series_next}>
+                                <span>
+                                    <small>{format!("Next: part {}", entry.part)}</small>
+                                    {entry.title}
+                                </span>
+                                <ArrowRight size=16 />
+                            </a>
+                        })}
+                    </div>
+                })}
+            </aside>
+        }
+    })
+}
+
+/// Heart toggle for a post. The count updates immediately and is rolled back
+/// if the API call fails (including when the visitor is rate limited).
+#[component]
+fn LikeButton(slug: String, initial_likes: u32) -> impl IntoView {
+    let likes = RwSignal::new(initial_likes);
+    let liked = RwSignal::new(false);
+    let pending = RwSignal::new(false);
+
+    // Learn whether this visitor already liked the post
+    let status_slug = slug.clone();
+    spawn_local(async move {
+        if let Ok(status) = fetch_like_status(&status_slug).await {
+            likes.set(status.likes);
+            liked.set(status.liked);
+        }
+    });
+
+    let toggle = move |_| {
+        if pending.get_untracked() {
+            return;
+        }
+        let was_liked = liked.get_untracked();
+        let previous_likes = likes.get_untracked();
+        liked.set(!was_liked);
+        likes.set(if was_liked { previous_likes.saturating_sub(1) } else { previous_likes + 1 });
+        pending.set(true);
+
+        let slug = slug.clone();
+        spawn_local(async move {
+            let result = if was_liked {
+                unlike_blog_post(&slug).await
+            } else {
+                like_blog_post(&slug).await
+            };
+            match result {
+                Ok(status) => {
+                    likes.set(status.likes);
+                    liked.set(status.liked);
+                }
+                Err(e) => {
+                    log::warn!("Failed to update like: {}", e);
+                    liked.set(was_liked);
+                    likes.set(previous_likes);
+                }
+            }
+            pending.set(false);
+        });
+    };
+
+    view! {
+        <button
+            class=move || if liked.get() {
+                format!("{} {}", style::like_button, style::liked)
+            } else {
+                style::like_button.to_string()
+            }
+            aria-pressed=move || liked.get().to_string()
+            title=move || if liked.get() { "Unlike this post" } else { "Like this post" }
+            on:click=toggle
+        >
+            <Heart size=14 />
+            {move || format!("{} likes", likes.get())}
+        </button>
+    }
+}
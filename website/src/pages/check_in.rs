@@ -0,0 +1,111 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_router::hooks::use_query_map;
+use lucide_leptos::*;
+use crate::api::client::{check_in_to_event, fetch_event, sign_in_url};
+use crate::session::use_session;
+use shared::{CheckIn, CheckInInput, Event};
+
+stylance::import_style!(style, "check_in.module.scss");
+
+/// Door check-in for signed-in members. Organisers' QR codes open
+/// `/check-in?event=<id>&code=<code>` with both filled in; the code can also
+/// be typed by hand.
+#[component]
+pub fn CheckInPage() -> impl IntoView {
+    let query_map = use_query_map();
+    let event_id = Memo::new(move |_| query_map.get().get("event").unwrap_or_default());
+
+    let event = RwSignal::new(None::<Event>);
+    let error = RwSignal::new(None::<String>);
+    let session = use_session();
+    let code = RwSignal::new(query_map.get_untracked().get("code").unwrap_or_default());
+    let submitting = RwSignal::new(false);
+    let checked_in = RwSignal::new(None::<CheckIn>);
+
+    Effect::new(move |_| {
+        let id = event_id.get();
+        if id.is_empty() {
+            error.set(Some("Scan the code at the door to check in.".to_string()));
+            return;
+        }
+        spawn_local(async move {
+            match fetch_event(&id).await {
+                Ok(Some(found)) => event.set(Some(found)),
+                Ok(None) => error.set(Some("Event not found".to_string())),
+                Err(e) => error.set(Some(format!("Failed to load event: {}", e))),
+            }
+        });
+    });
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let input = CheckInInput {
+            code: code.get_untracked().trim().to_string(),
+        };
+        if let Err(details) = input.validate() {
+            error.set(Some(details.join(", ")));
+            return;
+        }
+        let id = event_id.get_untracked();
+        spawn_local(async move {
+            submitting.set(true);
+            error.set(None);
+            match check_in_to_event(&id, &input).await {
+                Ok(recorded) => checked_in.set(Some(recorded)),
+                Err(e) => error.set(Some(e.to_string())),
+            }
+            submitting.set(false);
+        });
+    };
+
+    view! {
+        <div class={style::page_container}>
+            <div class={style::page_header}>
+                <h1>"Check In"</h1>
+                <p class={style::page_subtitle}>
+                    {move || event.with(|event| event.as_ref().map(|event| event.title.clone()))}
+                </p>
+            </div>
+
+            {move || match checked_in.get() {
+                Some(recorded) => view! {
+                    <div class={style::success}>
+                        <CircleCheck size=32 />
+                        <p>{format!("You're checked in, {}. Enjoy the event!", recorded.member.name)}</p>
+                    </div>
+                }.into_any(),
+                // Come back to this page, code and all, after signing in
+                None if session.loaded.get() && session.user.with(Option::is_none) => {
+                    let return_to = format!("/check-in?event={}&code={}", event_id.get(), code.get_untracked());
+                    view! {
+                        <a href={sign_in_url(&return_to)} class={format!("{} {}", style::btn, style::btn_primary)}>
+                            "Sign in to Check In"
+                        </a>
+                    }.into_any()
+                }
+                None => view! {
+                    <form class={style::check_in_form} on:submit=on_submit>
+                        <input
+                            type="text"
+                            class={format!("{} {}", style::form_input, style::code_input)}
+                            placeholder="Code"
+                            aria-label="Check-in code"
+                            autocomplete="off"
+                            prop:value=move || code.get()
+                            on:input=move |ev| code.set(event_target_value(&ev).to_uppercase())
+                        />
+                        {move || error.get().map(|message| view! { <p class={style::form_error}>{message}</p> })}
+                        <button
+                            type="submit"
+                            class={format!("{} {}", style::btn, style::btn_primary)}
+                            disabled=move || submitting.get() || event.with(Option::is_none)
+                        >
+                            {move || if submitting.get() { "Checking in..." } else { "Check In" }}
+                        </button>
+                    </form>
+                }.into_any(),
+            }}
+        </div>
+    }
+}
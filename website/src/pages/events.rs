@@ -1,13 +1,236 @@
 use leptos::prelude::*;
-use lucide_leptos::{Clock, MapPin, Repeat};
-use crate::api::get_events;
+use leptos::task::spawn_local;
+use lucide_leptos::{CalendarPlus, CircleCheck, Clock, MapPin, Repeat, Rss, Users};
+use crate::api::client::{
+    change_event_rsvp, event_calendar_url, events_subscription_url, fetch_event, fetch_events, rsvp_to_event,
+    sign_in_url, withdraw_event_rsvp, EventQuery,
+};
+use crate::session::use_session;
+use shared::{Event, EventRsvp, EventType, RsvpInput, RsvpStatus};
 
 stylance::import_style!(style, "events.module.scss");
 
+/// A filter toggle that sets `signal` to `value` and highlights itself while selected
+fn filter_button<T>(label: &'static str, signal: RwSignal<Option<T>>, value: Option<T>) -> impl IntoView
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    let current = value.clone();
+    view! {
+        <button
+            class=move || if signal.get() == current {
+                format!("{} {}", style::filter_btn, style::active)
+            } else {
+                style::filter_btn.to_string()
+            }
+            on:click=move |_| signal.set(value.clone())
+        >
+            {label}
+        </button>
+    }
+}
+
+fn rsvp_label(status: RsvpStatus) -> &'static str {
+    match status {
+        RsvpStatus::Going => "Going",
+        RsvpStatus::Maybe => "Maybe",
+        RsvpStatus::NotGoing => "Can't go",
+    }
+}
+
+/// e.g. "12 going · 3 maybe · 8 spots left"
+fn attendance_label(event: &Event) -> String {
+    let mut label = format!("{} going · {} maybe", event.going, event.maybe);
+    match event.capacity {
+        Some(_) if event.is_full() => label.push_str(" · Full"),
+        Some(capacity) => label.push_str(&format!(" · {} spots left", capacity - event.going)),
+        None => {}
+    }
+    label
+}
+
+/// RSVP counts plus the controls to respond, change the answer or withdraw
+#[component]
+fn EventRsvpControls(event: Event) -> impl IntoView {
+    let id = event.id.clone();
+    let event = RwSignal::new(event);
+    let form_open = RwSignal::new(false);
+    let session = use_session();
+    let submitting = RwSignal::new(false);
+    let form_error = RwSignal::new(None::<String>);
+    let rsvp = RwSignal::new(None::<EventRsvp>);
+
+    let respond_id = id.clone();
+    let respond = move |status: RsvpStatus| {
+        let id = respond_id.clone();
+        let existing = rsvp.with_untracked(|r| r.as_ref().map(|r| r.token.clone()));
+        let input = RsvpInput { status };
+        spawn_local(async move {
+            submitting.set(true);
+            form_error.set(None);
+            let result = match existing {
+                Some(token) => change_event_rsvp(&id, &token, status).await,
+                None => rsvp_to_event(&id, &input).await,
+            };
+            match result {
+                Ok(updated) => {
+                    event.update(|event| {
+                        event.going = updated.event.going;
+                        event.maybe = updated.event.maybe;
+                        event.capacity = updated.event.capacity;
+                    });
+                    rsvp.set(Some(updated));
+                    form_open.set(false);
+                }
+                Err(e) => form_error.set(Some(e.to_string())),
+            }
+            submitting.set(false);
+        });
+    };
+
+    let withdraw = move |_| {
+        let Some(token) = rsvp.with_untracked(|r| r.as_ref().map(|r| r.token.clone())) else {
+            return;
+        };
+        let id = id.clone();
+        spawn_local(async move {
+            match withdraw_event_rsvp(&id, &token).await {
+                Ok(()) => {
+                    rsvp.set(None);
+                    if let Ok(Some(updated)) = fetch_event(&id).await {
+                        event.update(|event| {
+                            event.going = updated.going;
+                            event.maybe = updated.maybe;
+                        });
+                    }
+                }
+                Err(e) => form_error.set(Some(format!("Could not withdraw: {}", e))),
+            }
+        });
+    };
+
+    let status_button = move |status: RsvpStatus| {
+        let respond = respond.clone();
+        view! {
+            <button
+                class=move || {
+                    let chosen = rsvp.with(|r| r.as_ref().is_some_and(|r| r.status == status));
+                    if chosen {
+                        format!("{} {}", style::btn, style::btn_primary)
+                    } else {
+                        format!("{} {}", style::btn, style::btn_secondary)
+                    }
+                }
+                disabled=move || {
+                    let already_going = rsvp.with(|r| r.as_ref().is_some_and(|r| r.status == RsvpStatus::Going));
+                    submitting.get() || (status == RsvpStatus::Going && !already_going && event.with(Event::is_full))
+                }
+                on:click=move |_| respond(status)
+            >
+                {rsvp_label(status)}
+            </button>
+        }
+    };
+
+    view! {
+        <div class={style::rsvp}>
+            <div class={style::meta_item}>
+                <Users size=16 />
+                <span>{move || event.with(attendance_label)}</span>
+            </div>
+            {move || {
+                if let Some(current) = rsvp.get() {
+                    view! {
+                        <div class={style::rsvp_status}>
+                            <p>
+                                <CircleCheck size=16 />
+                                {format!("@{}: {}", current.member.github_username.unwrap_or_default(), rsvp_label(current.status))}
+                            </p>
+                            <div class={style::rsvp_buttons}>
+                                {status_button(RsvpStatus::Going)}
+                                {status_button(RsvpStatus::Maybe)}
+                                {status_button(RsvpStatus::NotGoing)}
+                                <button class={format!("{} {}", style::btn, style::btn_secondary)} on:click=withdraw.clone()>
+                                    "Withdraw"
+                                </button>
+                            </div>
+                        </div>
+                    }.into_any()
+                } else if session.loaded.get() && session.user.with(Option::is_none) {
+                    view! {
+                        <a href={sign_in_url("/events")} class={format!("{} {}", style::btn, style::btn_primary)}>
+                            "Sign in to RSVP"
+                        </a>
+                    }.into_any()
+                } else if form_open.get() {
+                    view! {
+                        <div class={style::rsvp_form}>
+                            <div class={style::rsvp_buttons}>
+                                {status_button(RsvpStatus::Going)}
+                                {status_button(RsvpStatus::Maybe)}
+                                {status_button(RsvpStatus::NotGoing)}
+                            </div>
+                        </div>
+                    }.into_any()
+                } else {
+                    view! {
+                        <button class={format!("{} {}", style::btn, style::btn_primary)} on:click=move |_| form_open.set(true)>
+                            "RSVP"
+                        </button>
+                    }.into_any()
+                }
+            }}
+            {move || form_error.get().map(|message| view! { <p class={style::form_error}>{message}</p> })}
+        </div>
+    }
+}
 
 #[component]
 pub fn Events() -> impl IntoView {
-    let events = get_events();
+    let event_type = RwSignal::new(None::<EventType>);
+    let events = RwSignal::new(Vec::<Event>::new());
+    let next_cursor = RwSignal::new(None::<String>);
+    let loading = RwSignal::new(true);
+    let error = RwSignal::new(None::<String>);
+
+    // Refetch from the first page whenever the type filter changes
+    Effect::new(move |_| {
+        let query = EventQuery {
+            event_type: event_type.get(),
+            ..Default::default()
+        };
+        spawn_local(async move {
+            loading.set(true);
+            error.set(None);
+
+            match fetch_events(&query).await {
+                Ok(page) => {
+                    events.set(page.items);
+                    next_cursor.set(page.next_cursor);
+                }
+                Err(e) => error.set(Some(format!("Failed to load events: {}", e))),
+            }
+
+            loading.set(false);
+        });
+    });
+
+    let load_more = move |_| {
+        let query = EventQuery {
+            event_type: event_type.get_untracked(),
+            cursor: next_cursor.get_untracked(),
+            ..Default::default()
+        };
+        spawn_local(async move {
+            match fetch_events(&query).await {
+                Ok(page) => {
+                    events.update(|list| list.extend(page.items));
+                    next_cursor.set(page.next_cursor);
+                }
+                Err(e) => error.set(Some(format!("Failed to load more events: {}", e))),
+            }
+        });
+    };
 
     view! {
         <div class={style::page_container}>
@@ -17,65 +240,91 @@ pub fn Events() -> impl IntoView {
                     "Regular meetups, study groups, and special events to keep you engaged with Rust. "
                     "Learn together, build together, grow together."
                 </p>
+                <a
+                    class={format!("{} {} {}", style::btn, style::btn_secondary, style::subscribe_link)}
+                    href={events_subscription_url()}
+                    title="Subscribe to club events in your calendar app"
+                >
+                    <Rss size=16 />
+                    "Subscribe"
+                </a>
             </div>
 
             <div class={style::events_calendar}>
                 <div class={style::calendar_header}>
                     <h2>"Upcoming Events"</h2>
                     <div class={style::event_filters}>
-                        <button class={format!("{} {}", style::filter_btn, "active")}>"All Events"</button>
-                        <button class={style::filter_btn}>"Study Groups"</button>
-                        <button class={style::filter_btn}>"Seminars"</button>
-                        <button class={style::filter_btn}>"Workshops"</button>
+                        {filter_button("All Events", event_type, None)}
+                        {filter_button("Study Groups", event_type, Some(EventType::StudyGroup))}
+                        {filter_button("Seminars", event_type, Some(EventType::Seminar))}
+                        {filter_button("Workshops", event_type, Some(EventType::Workshop))}
                     </div>
                 </div>
 
                 <div class={style::events_list}>
-                    {events.into_iter().map(|event| {
-                        let event_type_str = event.event_type.to_string();
-                        let date = event.date.clone();
-
-                        view! {
-                            <div class={style::event_card}>
-                                <div class={style::event_content}>
-                                    <div class={style::event_header}>
-                                        <div class={style::title_group}>
-                                            <span class={format!("{} type-{}", style::event_type_badge, event_type_str.to_lowercase().replace(" ", "-"))}>
-                                                {event_type_str.clone()}
-                                            </span>
-                                            <h3 class={style::event_title}>{event.title}</h3>
-                                        </div>
-                                        <div class={style::badge_group}>
-                                            <span class={style::event_date_badge}>{date.clone()}</span>
-                                            {if event.recurring {
-                                                Some(view! {
-                                                    <span class={style::recurring_badge}>
+                    {move || {
+                        if loading.get() {
+                            return view! { <div>"Loading events..."</div> }.into_any();
+                        }
+                        if let Some(err) = error.get() {
+                            return view! { <div class="error">{err}</div> }.into_any();
+                        }
+                        events.get().into_iter().map(|event| {
+                            let event_type_str = event.event_type.to_string();
+                            let date = event.date_label();
+                            let time = event.time_label();
+                            let recurrence = event.recurrence_label();
+                            let calendar_url = event_calendar_url(&event.id);
+                            let rsvp_event = event.clone();
+
+                            view! {
+                                <div class={style::event_card}>
+                                    <div class={style::event_content}>
+                                        <div class={style::event_header}>
+                                            <div class={style::title_group}>
+                                                <span class={format!("{} type-{}", style::event_type_badge, event_type_str.to_lowercase().replace(" ", "-"))}>
+                                                    {event_type_str.clone()}
+                                                </span>
+                                                <h3 class={style::event_title}>{event.title}</h3>
+                                            </div>
+                                            <div class={style::badge_group}>
+                                                <span class={style::event_date_badge}>{date.clone()}</span>
+                                                {recurrence.map(|label| view! {
+                                                    <span class={style::recurring_badge} title={label}>
                                                         <Repeat size=12 />
                                                         "Recurring"
                                                     </span>
-                                                })
-                                            } else {
-                                                None
-                                            }}
-                                        </div>
-                                    </div>
-                                    <p class={style::event_description}>{event.description}</p>
-                                    <div class={style::event_meta}>
-                                        <div class={style::meta_item}>
-                                            <Clock size=16 />
-                                            <span>{event.time}</span>
+                                                })}
+                                            </div>
                                         </div>
-                                        <div class={style::meta_item}>
-                                            <MapPin size=16 />
-                                            <span>{event.location}</span>
+                                        <p class={style::event_description}>{event.description}</p>
+                                        <div class={style::event_meta}>
+                                            <div class={style::meta_item}>
+                                                <Clock size=16 />
+                                                <span>{time}</span>
+                                            </div>
+                                            <div class={style::meta_item}>
+                                                <MapPin size=16 />
+                                                <span>{event.location}</span>
+                                            </div>
                                         </div>
+                                        <EventRsvpControls event=rsvp_event />
+                                        <a class={format!("{} {}", style::btn, style::btn_secondary)} href={calendar_url} download>
+                                            <CalendarPlus size=16 />
+                                            "Add to Calendar"
+                                        </a>
                                     </div>
-                                    <button class={format!("{} {}", style::btn, style::btn_secondary)}>"Add to Calendar"</button>
                                 </div>
-                            </div>
-                        }
-                    }).collect_view()}
+                            }
+                        }).collect_view().into_any()
+                    }}
                 </div>
+
+                <Show when=move || next_cursor.get().is_some() && !loading.get()>
+                    <button class={format!("{} {}", style::btn, style::btn_secondary)} on:click=load_more>
+                        "Load More Events"
+                    </button>
+                </Show>
             </div>
 
             <div class={style::join_discord}>
@@ -38,18 +38,20 @@ pub fn Home() -> impl IntoView {
                     </div>
                     <div class={style::events_list}>
                         {upcoming_events.into_iter().take(3).map(|event| {
+                            let date = event.date_label();
+                            let time = event.time_label();
                             view! {
                                 <div class={style::event_card}>
                                     <div class={style::event_content}>
                                         <div class={style::event_header}>
                                             <h3 class={style::event_title}>{event.title.clone()}</h3>
-                                            <span class={style::event_date_badge}>{event.date}</span>
+                                            <span class={style::event_date_badge}>{date}</span>
                                         </div>
                                         <p class={style::event_description}>{event.description}</p>
                                         <div class={style::event_meta}>
                                             <span class={style::event_time}>
                                                 <Clock />
-                                                {event.time}
+                                                {time}
                                             </span>
                                             <span class={style::event_location}>
                                                 <MapPin />
@@ -0,0 +1,183 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_router::hooks::use_params_map;
+use lucide_leptos::*;
+use crate::api::client::fetch_member;
+use shared::{MemberProfile, Project};
+
+stylance::import_style!(style, "member.module.scss");
+
+fn project_item(project: Project, role: &'static str) -> impl IntoView {
+    let link = project.github_url.clone();
+    view! {
+        <li class={style::item}>
+            <span class={style::item_meta}>{role}</span>
+            <a href={link} class={style::item_title} target="_blank" rel="noopener noreferrer">{project.name}</a>
+            <span class={style::item_badge}>{project.status.to_string()}</span>
+        </li>
+    }
+}
+
+/// A member's profile: who they are and what they have done in the club
+#[component]
+pub fn MemberPage() -> impl IntoView {
+    let params = use_params_map();
+    let github = Memo::new(move |_| params.get().get("github").unwrap_or_default());
+
+    let profile = RwSignal::new(None::<MemberProfile>);
+    let loading = RwSignal::new(true);
+    let error = RwSignal::new(None::<String>);
+
+    Effect::new(move |_| {
+        let github = github.get();
+        spawn_local(async move {
+            loading.set(true);
+            error.set(None);
+            match fetch_member(&github).await {
+                Ok(Some(found)) => profile.set(Some(found)),
+                Ok(None) => error.set(Some("Member not found".to_string())),
+                Err(e) => error.set(Some(format!("Failed to load member: {}", e))),
+            }
+            loading.set(false);
+        });
+    });
+
+    view! {
+        <div class={style::page_container}>
+            {move || {
+                if loading.get() {
+                    return view! { <p class={style::status}>"Loading..."</p> }.into_any();
+                }
+                if let Some(err) = error.get() {
+                    return view! { <p class="error">{err}</p> }.into_any();
+                }
+                let Some(profile) = profile.get() else {
+                    return ().into_any();
+                };
+                let member = profile.member;
+                let github_url = format!("https://github.com/{}", member.github_username);
+                let joined = member.joined_at.format("%B %Y").to_string();
+                let project_count = profile.projects_led.len() + profile.projects_contributed.len();
+                let has_projects = project_count > 0;
+                let has_skills = !member.skills.is_empty();
+                let has_posts = !profile.blog_posts.is_empty();
+                let has_workshops = !profile.workshops_attended.is_empty();
+                let has_contributions = !profile.contributions.is_empty();
+
+                view! {
+                    <div class={style::profile_header}>
+                        {member.avatar_url.map(|url| view! {
+                            <img class={style::avatar} src={url} alt="" width="96" height="96" />
+                        })}
+                        <div>
+                            <h1>{member.name}</h1>
+                            <a href={github_url} class={style::github_link} target="_blank" rel="noopener noreferrer">
+                                <Github size=16 />
+                                {format!("@{}", member.github_username)}
+                            </a>
+                            <div class={style::roles}>
+                                {member.roles.iter().map(|role| view! {
+                                    <span class={style::role}>{role.to_string()}</span>
+                                }).collect_view()}
+                            </div>
+                            <p class={style::joined}>{format!("Member since {}", joined)}</p>
+                        </div>
+                    </div>
+
+                    {member.bio.map(|bio| view! { <p class={style::bio}>{bio}</p> })}
+                    <Show when=move || has_skills>
+                        <div class={style::skills}>
+                            {member.skills.iter().map(|skill| view! {
+                                <span class={style::skill}>{skill.clone()}</span>
+                            }).collect_view()}
+                        </div>
+                    </Show>
+
+                    <section class={style::section}>
+                        <div class={style::stats_grid}>
+                            <div class={style::stat_card}>
+                                <div class={style::stat_number}>{profile.contributions.len()}</div>
+                                <div class={style::stat_label}>"PRs Merged"</div>
+                            </div>
+                            <div class={style::stat_card}>
+                                <div class={style::stat_number}>{profile.blog_posts.len()}</div>
+                                <div class={style::stat_label}>"Blog Posts"</div>
+                            </div>
+                            <div class={style::stat_card}>
+                                <div class={style::stat_number}>{project_count}</div>
+                                <div class={style::stat_label}>"Projects"</div>
+                            </div>
+                            {profile.events_attended.map(|count| view! {
+                                <div class={style::stat_card}>
+                                    <div class={style::stat_number}>{count}</div>
+                                    <div class={style::stat_label}>"Events Attended"</div>
+                                </div>
+                                <div class={style::stat_card}>
+                                    <div class={style::stat_number}>{profile.workshops_attended.len()}</div>
+                                    <div class={style::stat_label}>"Workshops Attended"</div>
+                                </div>
+                            })}
+                        </div>
+                    </section>
+
+                    <Show when=move || has_posts>
+                        <section class={style::section}>
+                            <h2>"Blog Posts"</h2>
+                            <ul class={style::item_list}>
+                                {profile.blog_posts.iter().map(|post| view! {
+                                    <li class={style::item}>
+                                        <span class={style::item_meta}>{post.published_at.clone()}</span>
+                                        <a href={format!("/blog/{}", post.slug)} class={style::item_title}>{post.title.clone()}</a>
+                                        <span class={style::item_badge}>{post.post_type.to_string()}</span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                        </section>
+                    </Show>
+
+                    <Show when=move || has_projects>
+                        <section class={style::section}>
+                            <h2>"Projects"</h2>
+                            <ul class={style::item_list}>
+                                {profile.projects_led.iter().cloned().map(|project| project_item(project, "Lead")).collect_view()}
+                                {profile.projects_contributed.iter().cloned().map(|project| project_item(project, "Contributor")).collect_view()}
+                            </ul>
+                        </section>
+                    </Show>
+
+                    <Show when=move || has_workshops>
+                        <section class={style::section}>
+                            <h2>"Workshops Attended"</h2>
+                            <ul class={style::item_list}>
+                                {profile.workshops_attended.iter().map(|workshop| view! {
+                                    <li class={style::item}>
+                                        <span class={style::item_meta}>{workshop.date_label()}</span>
+                                        <span class={style::item_title}>{workshop.title.clone()}</span>
+                                        <span class={style::item_badge}>{workshop.level.to_string()}</span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                        </section>
+                    </Show>
+
+                    <Show when=move || has_contributions>
+                        <section class={style::section}>
+                            <h2>"Merged Pull Requests"</h2>
+                            <ul class={style::item_list}>
+                                {profile.contributions.iter().map(|contribution| view! {
+                                    <li class={style::item}>
+                                        <span class={style::item_meta}>{contribution.merged_at.map(|at| at.format("%b %-d, %Y").to_string())}</span>
+                                        <a href={contribution.pr_url.clone()} class={style::item_title} target="_blank" rel="noopener noreferrer">
+                                            {contribution.title.clone()}
+                                        </a>
+                                        <span class={style::item_badge}>{contribution.repo.clone()}</span>
+                                    </li>
+                                }).collect_view()}
+                            </ul>
+                        </section>
+                    </Show>
+                }.into_any()
+            }}
+        </div>
+    }
+}
@@ -8,3 +8,8 @@ pub mod workshops;
 pub mod events;
 pub mod projects;
 pub mod advocacy;
+pub mod search;
+pub mod archive;
+pub mod check_in;
+pub mod member;
+pub mod author;
@@ -1,152 +1,184 @@
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 use lucide_leptos::*;
+use crate::api::client::{claim_issue, fetch_issues, sign_in_url, IssueQuery};
+use crate::session::use_session;
+use shared::{DifficultyLevel, Issue};
 
 stylance::import_style!(style, "opensource.module.scss");
 
-#[derive(Clone)]
-struct Issue {
-    title: String,
-    repo: String,
-    url: String,
-    difficulty: String,
-    tags: Vec<String>,
-    description: String,
+const DIFFICULTY_FILTERS: [(&str, Option<DifficultyLevel>); 4] = [
+    ("All", None),
+    ("Easy", Some(DifficultyLevel::Easy)),
+    ("Medium", Some(DifficultyLevel::Medium)),
+    ("Hard", Some(DifficultyLevel::Hard)),
+];
+
+fn claim_label(claimed_by: Option<String>) -> String {
+    match claimed_by {
+        Some(github) => format!("Claimed by @{}", github),
+        None => "Claimed".to_string(),
+    }
+}
+
+#[component]
+fn IssueCard(issue: Issue) -> impl IntoView {
+    let session = use_session();
+    let difficulty = issue.difficulty.to_string();
+    let id = issue.id.clone();
+    let claimed = RwSignal::new(issue.claimed);
+    let claimed_by = RwSignal::new(issue.claimed_by.clone());
+    let claiming = RwSignal::new(false);
+    let claim_error = RwSignal::new(None::<String>);
+
+    let claim = move |_| {
+        let id = id.clone();
+        spawn_local(async move {
+            claiming.set(true);
+            claim_error.set(None);
+            match claim_issue(&id).await {
+                Ok(contribution) => {
+                    claimed_by.set(contribution.member.github_username);
+                    claimed.set(true);
+                }
+                Err(e) if e.is_unauthorized() => claim_error.set(Some("Sign in to claim issues".to_string())),
+                Err(e) => claim_error.set(Some(e.to_string())),
+            }
+            claiming.set(false);
+        });
+    };
+
+    view! {
+        <div class={style::issue_card}>
+            <div class={style::issue_header}>
+                <span class={format!("{} difficulty-{}", style::difficulty_badge, difficulty.to_lowercase())}>
+                    {difficulty.clone()}
+                </span>
+                <span class={style::repo_name}>{issue.repo}</span>
+            </div>
+            <h3 class={style::issue_title}>{issue.title}</h3>
+            <p class={style::issue_description}>{issue.description}</p>
+            <div class={style::issue_tags}>
+                {issue.tags.into_iter().map(|tag| {
+                    view! { <span class={style::tag}>{"#"}{tag}</span> }
+                }).collect_view()}
+            </div>
+            <div class={style::issue_actions}>
+                <a href={issue.github_url} target="_blank" rel="noopener noreferrer" class={style::issue_link}>
+                    <ExternalLink size=16 />
+                    "View on GitHub"
+                </a>
+                {move || {
+                    if claimed.get() {
+                        view! { <span class={style::claimed}>{claim_label(claimed_by.get())}</span> }.into_any()
+                    } else if session.loaded.get() && session.user.with(Option::is_none) {
+                        view! { <a href={sign_in_url("/opensource")} class={style::claim_btn}>"Sign in to Claim"</a> }.into_any()
+                    } else {
+                        view! {
+                            <button class={style::claim_btn} on:click=claim.clone() disabled=move || claiming.get()>
+                                {move || if claiming.get() { "Claiming..." } else { "Claim Issue" }}
+                            </button>
+                        }.into_any()
+                    }
+                }}
+            </div>
+            {move || claim_error.get().map(|err| view! { <p class="error">{err}</p> })}
+        </div>
+    }
 }
 
 #[component]
 pub fn OpenSource() -> impl IntoView {
-    let issues = vec![
-        Issue {
-            title: "Fix broken link in README.md".to_string(),
-            repo: "rust-lang/mdBook".to_string(),
-            url: "https://github.com/rust-lang/mdBook/issues/2450".to_string(),
-            difficulty: "Easy".to_string(),
-            tags: vec!["documentation".to_string(), "good-first-issue".to_string()],
-            description: "A simple documentation fix - update broken links in the main README file. Great first contribution!".to_string(),
-        },
-        Issue {
-            title: "Add example for custom derive macro".to_string(),
-            repo: "tokio-rs/tokio".to_string(),
-            url: "https://github.com/tokio-rs/tokio/issues/6234".to_string(),
-            difficulty: "Easy".to_string(),
-            tags: vec!["documentation".to_string(), "examples".to_string()],
-            description: "Add a code example showing how to use derive macros with async traits. Help other developers learn!".to_string(),
-        },
-        Issue {
-            title: "Improve error message for type mismatch".to_string(),
-            repo: "serde-rs/serde".to_string(),
-            url: "https://github.com/serde-rs/serde/issues/2789".to_string(),
-            difficulty: "Easy".to_string(),
-            tags: vec!["error-handling".to_string(), "user-experience".to_string()],
-            description: "Make error messages more helpful by providing context about expected vs actual types.".to_string(),
-        },
-        Issue {
-            title: "Add unit tests for new feature flag".to_string(),
-            repo: "actix/actix-web".to_string(),
-            url: "https://github.com/actix/actix-web/issues/3156".to_string(),
-            difficulty: "Medium".to_string(),
-            tags: vec!["testing".to_string(), "feature-flags".to_string()],
-            description: "Write comprehensive tests for the new compression feature. Good practice with Rust testing patterns.".to_string(),
-        },
-        Issue {
-            title: "Implement trait for custom deserializer".to_string(),
-            repo: "clap-rs/clap".to_string(),
-            url: "https://github.com/clap-rs/clap/issues/4923".to_string(),
-            difficulty: "Medium".to_string(),
-            tags: vec!["traits".to_string(), "cli".to_string()],
-            description: "Implement a trait to support custom value parsing. Learn about trait design and CLI argument handling.".to_string(),
-        },
-        Issue {
-            title: "Add beginner-friendly CLI examples".to_string(),
-            repo: "rust-lang/cargo".to_string(),
-            url: "https://github.com/rust-lang/cargo/issues/12543".to_string(),
-            difficulty: "Easy".to_string(),
-            tags: vec!["documentation".to_string(), "cli".to_string(), "good-first-issue".to_string()],
-            description: "Create simple, well-commented examples for common cargo commands to help new Rust developers.".to_string(),
-        },
-        Issue {
-            title: "Fix typo in function documentation".to_string(),
-            repo: "rust-random/rand".to_string(),
-            url: "https://github.com/rust-random/rand/issues/1432".to_string(),
-            difficulty: "Easy".to_string(),
-            tags: vec!["documentation".to_string(), "typo".to_string()],
-            description: "Simple typo fix in documentation comments. Perfect for your first open source contribution!".to_string(),
-        },
-        Issue {
-            title: "Add validation for config file format".to_string(),
-            repo: "rust-analyzer/rust-analyzer".to_string(),
-            url: "https://github.com/rust-analyzer/rust-analyzer/issues/15678".to_string(),
-            difficulty: "Medium".to_string(),
-            tags: vec!["validation".to_string(), "config".to_string()],
-            description: "Implement input validation for configuration files. Learn about error handling and user input validation.".to_string(),
-        },
-        Issue {
-            title: "Update deprecated API usage in examples".to_string(),
-            repo: "hyperium/hyper".to_string(),
-            url: "https://github.com/hyperium/hyper/issues/3241".to_string(),
-            difficulty: "Easy".to_string(),
-            tags: vec!["examples".to_string(), "deprecation".to_string()],
-            description: "Update code examples to use the new API instead of deprecated functions. Learn about HTTP libraries in Rust.".to_string(),
-        },
-        Issue {
-            title: "Improve performance of string operations".to_string(),
-            repo: "BurntSushi/ripgrep".to_string(),
-            url: "https://github.com/BurntSushi/ripgrep/issues/2456".to_string(),
-            difficulty: "Hard".to_string(),
-            tags: vec!["performance".to_string(), "optimization".to_string()],
-            description: "Optimize string processing for better performance. Advanced contribution involving profiling and optimization.".to_string(),
-        },
-    ];
+    let issues = RwSignal::new(Vec::<Issue>::new());
+    let loading = RwSignal::new(true);
+    let error = RwSignal::new(None::<String>);
+    let next_cursor = RwSignal::new(None::<String>);
+    let difficulty = RwSignal::new(None::<DifficultyLevel>);
+
+    // Refetch from the first page whenever the filter changes
+    Effect::new(move |_| {
+        let query = IssueQuery { difficulty: difficulty.get(), ..Default::default() };
+        spawn_local(async move {
+            loading.set(true);
+            error.set(None);
+            match fetch_issues(&query).await {
+                Ok(page) => {
+                    issues.set(page.items);
+                    next_cursor.set(page.next_cursor);
+                }
+                Err(e) => error.set(Some(format!("Failed to load issues: {}", e))),
+            }
+            loading.set(false);
+        });
+    });
+
+    let load_more = move |_| {
+        let query = IssueQuery {
+            difficulty: difficulty.get_untracked(),
+            cursor: next_cursor.get_untracked(),
+            ..Default::default()
+        };
+        spawn_local(async move {
+            match fetch_issues(&query).await {
+                Ok(page) => {
+                    issues.update(|list| list.extend(page.items));
+                    next_cursor.set(page.next_cursor);
+                }
+                Err(e) => error.set(Some(format!("Failed to load more issues: {}", e))),
+            }
+        });
+    };
 
     view! {
         <div class={style::page_container}>
             <div class={style::page_header}>
                 <h1>"Open Source Opportunities"</h1>
                 <p class={style::page_subtitle}>
-                    "Start your open source journey with these curated, beginner-friendly Rust issues. "
-                    "Each issue has been selected to help you learn while contributing to real projects."
+                    "Start your open source journey with these beginner-friendly Rust issues, "
+                    "synced hourly from the projects we follow on GitHub."
                 </p>
             </div>
 
             <div class={style::filters_section}>
                 <div class={style::filter_chips}>
-                    <button class={format!("{} {}", style::chip, style::chip_active)}>"All"</button>
-                    <button class={style::chip}>"Easy"</button>
-                    <button class={style::chip}>"Medium"</button>
-                    <button class={style::chip}>"Hard"</button>
-                    <button class={style::chip}>"Documentation"</button>
-                    <button class={style::chip}>"Testing"</button>
+                    {DIFFICULTY_FILTERS.into_iter().map(|(label, level)| {
+                        let selected = level.clone();
+                        view! {
+                            <button
+                                class=move || if difficulty.get() == selected {
+                                    format!("{} {}", style::chip, style::chip_active)
+                                } else {
+                                    style::chip.to_string()
+                                }
+                                on:click=move |_| difficulty.set(level.clone())
+                            >
+                                {label}
+                            </button>
+                        }
+                    }).collect_view()}
                 </div>
             </div>
 
-            <div class={style::issues_grid}>
-                {issues.into_iter().map(|issue| {
+            {move || {
+                if loading.get() {
+                    view! { <p class={style::status}>"Loading issues..."</p> }.into_any()
+                } else if let Some(err) = error.get() {
+                    view! { <p class="error">{err}</p> }.into_any()
+                } else if issues.with(Vec::is_empty) {
+                    view! { <p class={style::status}>"No open issues match right now. Check back after the next sync."</p> }.into_any()
+                } else {
                     view! {
-                        <div class={style::issue_card}>
-                            <div class={style::issue_header}>
-                                <span class={format!("{} difficulty-{}", style::difficulty_badge, issue.difficulty.to_lowercase())}>
-                                    {issue.difficulty.clone()}
-                                </span>
-                                <span class={style::repo_name}>{issue.repo}</span>
-                            </div>
-                            <h3 class={style::issue_title}>{issue.title}</h3>
-                            <p class={style::issue_description}>{issue.description}</p>
-                            <div class={style::issue_tags}>
-                                {issue.tags.into_iter().map(|tag| {
-                                    view! { <span class={style::tag}>{"#"}{tag}</span> }
-                                }).collect_view()}
-                            </div>
-                            <div class={style::issue_actions}>
-                                <a href={issue.url} target="_blank" class={style::issue_link}>
-                                    <ExternalLink size=16 />
-                                    "View on GitHub"
-                                </a>
-                                <button class={style::claim_btn}>"Claim Issue"</button>
-                            </div>
+                        <div class={style::issues_grid}>
+                            {issues.get().into_iter().map(|issue| view! { <IssueCard issue=issue /> }).collect_view()}
                         </div>
-                    }
-                }).collect_view()}
-            </div>
+                    }.into_any()
+                }
+            }}
+            <Show when=move || next_cursor.get().is_some() && !loading.get()>
+                <div class={style::load_more}>
+                    <button class={style::chip} on:click=load_more>"Load More Issues"</button>
+                </div>
+            </Show>
 
             <div class={style::tips_section}>
                 <h2>"Your Open Source Journey"</h2>
@@ -161,7 +193,7 @@ pub fn OpenSource() -> impl IntoView {
                     </div>
                     <div class={style::tip_card}>
                         <h3>"3. Claim Your Issue"</h3>
-                        <p>"Use our 'Claim Issue' feature to let other club members know you're working on it. This prevents duplicate work and builds accountability."</p>
+                        <p>"Use our 'Claim Issue' feature to let other club members know you're working on it. A claim lasts two weeks, so open your PR before it expires!"</p>
                     </div>
                     <div class={style::tip_card}>
                         <h3>"4. Read Contributing Guidelines"</h3>
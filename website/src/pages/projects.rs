@@ -1,77 +1,131 @@
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 use lucide_leptos::*;
+use crate::api::client::{fetch_projects, ProjectQuery};
+use shared::{Project, ProjectStatus};
 
 stylance::import_style!(style, "projects.module.scss");
 
-#[derive(Clone)]
-struct Project {
-    name: String,
-    description: String,
-    status: String,
-    tech_stack: Vec<String>,
-    github_url: String,
-    contributors_needed: bool,
-    skills_needed: Vec<String>,
+const STATUS_FILTERS: [(&str, Option<ProjectStatus>); 5] = [
+    ("All", None),
+    ("Active", Some(ProjectStatus::Active)),
+    ("In Development", Some(ProjectStatus::InDevelopment)),
+    ("Beta", Some(ProjectStatus::Beta)),
+    ("Planning", Some(ProjectStatus::Planning)),
+];
+
+fn status_class(status: &ProjectStatus) -> &'static str {
+    match status {
+        ProjectStatus::Active => style::status_active,
+        ProjectStatus::InDevelopment => style::status_in_development,
+        ProjectStatus::Planning => style::status_planning,
+        ProjectStatus::Beta => style::status_beta,
+        ProjectStatus::Completed | ProjectStatus::Archived => style::status_completed,
+    }
+}
+
+fn chip_class(active: bool) -> String {
+    if active {
+        format!("{} {}", style::chip, style::chip_active)
+    } else {
+        style::chip.to_string()
+    }
+}
+
+#[component]
+fn ProjectCard(project: Project) -> impl IntoView {
+    view! {
+        <div class={style::project_card}>
+            <div class={style::project_header}>
+                <h3 class={style::project_name}>{project.name}</h3>
+                <span class={format!("{} {}", style::status_badge, status_class(&project.status))}>
+                    {project.status.to_string()}
+                </span>
+            </div>
+            <p class={style::project_description}>{project.description}</p>
+            <div class={style::tech_stack}>
+                <span class={style::stack_label}>"Tech Stack:"</span>
+                <div class={style::tech_tags}>
+                    {project.tech_stack.into_iter().map(|tech| {
+                        view! { <span class={style::tech_tag}>{tech}</span> }
+                    }).collect_view()}
+                </div>
+            </div>
+            <div class={style::contributors_section}>
+                {project.contributors_needed.then(|| view! {
+                    <div class={style::contributors_needed}>
+                        <span class={style::need_badge}>
+                            <Megaphone size=16 style:display="inline" style:margin-right="8px" />
+                            "Contributors Needed"
+                        </span>
+                        <div class={style::skills_needed}>
+                            {project.skills_needed.into_iter().map(|skill| {
+                                view! { <span class={style::skill_tag}>{skill}</span> }
+                            }).collect_view()}
+                        </div>
+                    </div>
+                })}
+            </div>
+            <div class={style::project_actions}>
+                <a href={project.github_url} target="_blank" rel="noopener noreferrer" class={format!("{} {}", style::btn, style::btn_secondary)}>
+                    "View on GitHub"
+                </a>
+                <div class={style::action_buttons}>
+                    {project.contributors_needed.then(|| view! {
+                        <button class={format!("{} {}", style::btn, style::btn_primary)}>"Join Project"</button>
+                    })}
+                </div>
+            </div>
+        </div>
+    }
 }
 
 #[component]
 pub fn Projects() -> impl IntoView {
-    let projects = vec![
-        Project {
-            name: "The Rust Club Website".to_string(),
-            description: "This very website you're looking at! Built with Leptos and Trunk. A practical project for learning modern Rust web development.".to_string(),
-            status: "Active".to_string(),
-            tech_stack: vec!["Leptos".to_string(), "Trunk".to_string(), "CSS".to_string()],
-            github_url: "https://github.com/rust-club/website".to_string(),
-            contributors_needed: true,
-            skills_needed: vec!["Frontend".to_string(), "CSS".to_string(), "Design".to_string()],
-        },
-        Project {
-            name: "Ferris Bot - Discord Helper".to_string(),
-            description: "Our club's Discord bot for event reminders, Rust code snippets, and helping newcomers. Features include daily Rust tips and workshop notifications.".to_string(),
-            status: "In Development".to_string(),
-            tech_stack: vec!["Serenity".to_string(), "Tokio".to_string(), "SQLite".to_string()],
-            github_url: "https://github.com/rust-club/ferris-bot".to_string(),
-            contributors_needed: true,
-            skills_needed: vec!["Discord API".to_string(), "Async Rust".to_string()],
-        },
-        Project {
-            name: "Campus CLI - University Tools".to_string(),
-            description: "Command-line tools for common campus tasks: checking grades, class schedules, dining hall menus, and library availability. Perfect for learning CLI development.".to_string(),
-            status: "Planning".to_string(),
-            tech_stack: vec!["Clap".to_string(), "Reqwest".to_string(), "Serde".to_string()],
-            github_url: "https://github.com/rust-club/campus-cli".to_string(),
-            contributors_needed: true,
-            skills_needed: vec!["CLI tools".to_string(), "HTTP APIs".to_string(), "JSON parsing".to_string()],
-        },
-        Project {
-            name: "Study Buddy - Peer Learning Platform".to_string(),
-            description: "Web app for organizing study groups, sharing notes, and tracking learning progress. Built to support our weekly seminars and study sessions.".to_string(),
-            status: "Beta".to_string(),
-            tech_stack: vec!["Axum".to_string(), "SQLx".to_string(), "htmx".to_string()],
-            github_url: "https://github.com/rust-club/study-buddy".to_string(),
-            contributors_needed: false,
-            skills_needed: vec![],
-        },
-        Project {
-            name: "RustLearning - Interactive Tutorials".to_string(),
-            description: "Interactive coding tutorials and exercises for learning Rust concepts. Complements our workshop materials with hands-on practice.".to_string(),
-            status: "Active".to_string(),
-            tech_stack: vec!["Yew".to_string(), "WASM".to_string(), "Monaco Editor".to_string()],
-            github_url: "https://github.com/rust-club/rust-learning".to_string(),
-            contributors_needed: true,
-            skills_needed: vec!["WASM".to_string(), "Frontend".to_string(), "Education".to_string()],
-        },
-        Project {
-            name: "Course Proposal Generator".to_string(),
-            description: "Tool to help generate and format course proposals for accepting Rust in academic assignments. Supports our advocacy efforts!".to_string(),
-            status: "Planning".to_string(),
-            tech_stack: vec!["Leptos".to_string(), "PDF generation".to_string()],
-            github_url: "https://github.com/rust-club/course-proposals".to_string(),
-            contributors_needed: true,
-            skills_needed: vec!["Document generation".to_string(), "Forms".to_string()],
-        },
-    ];
+    let projects = RwSignal::new(Vec::<Project>::new());
+    let loading = RwSignal::new(true);
+    let error = RwSignal::new(None::<String>);
+    let next_cursor = RwSignal::new(None::<String>);
+    let status = RwSignal::new(None::<ProjectStatus>);
+    let needs_help = RwSignal::new(false);
+
+    let query = move |cursor: Option<String>| ProjectQuery {
+        status: status.get_untracked(),
+        contributors_needed: needs_help.get_untracked().then_some(true),
+        cursor,
+    };
+
+    // Refetch from the first page whenever a filter changes
+    Effect::new(move |_| {
+        status.track();
+        needs_help.track();
+        let query = query(None);
+        spawn_local(async move {
+            loading.set(true);
+            error.set(None);
+            match fetch_projects(&query).await {
+                Ok(page) => {
+                    projects.set(page.items);
+                    next_cursor.set(page.next_cursor);
+                }
+                Err(e) => error.set(Some(format!("Failed to load projects: {}", e))),
+            }
+            loading.set(false);
+        });
+    });
+
+    let load_more = move |_| {
+        let query = query(next_cursor.get_untracked());
+        spawn_local(async move {
+            match fetch_projects(&query).await {
+                Ok(page) => {
+                    projects.update(|list| list.extend(page.items));
+                    next_cursor.set(page.next_cursor);
+                }
+                Err(e) => error.set(Some(format!("Failed to load more projects: {}", e))),
+            }
+        });
+    };
 
     view! {
         <div class={style::page_container}>
@@ -83,62 +137,48 @@ pub fn Projects() -> impl IntoView {
                 </p>
             </div>
 
-            <div class={style::projects_showcase}>
-                {projects.into_iter().map(|project| {
+            <div class={style::filters_section}>
+                <div class={style::filter_chips}>
+                    {STATUS_FILTERS.into_iter().map(|(label, value)| {
+                        let selected = value.clone();
+                        view! {
+                            <button
+                                class=move || chip_class(status.get() == selected)
+                                on:click=move |_| status.set(value.clone())
+                            >
+                                {label}
+                            </button>
+                        }
+                    }).collect_view()}
+                    <button
+                        class=move || chip_class(needs_help.get())
+                        on:click=move |_| needs_help.update(|on| *on = !*on)
+                    >
+                        "Contributors Needed"
+                    </button>
+                </div>
+            </div>
+
+            {move || {
+                if loading.get() {
+                    view! { <p class={style::status}>"Loading projects..."</p> }.into_any()
+                } else if let Some(err) = error.get() {
+                    view! { <p class="error">{err}</p> }.into_any()
+                } else if projects.with(Vec::is_empty) {
+                    view! { <p class={style::status}>"No projects match these filters."</p> }.into_any()
+                } else {
                     view! {
-                        <div class={style::project_card}>
-                            <div class={style::project_header}>
-                                <h3 class={style::project_name}>{project.name}</h3>
-                                <span class={format!("{} status-{}", style::status_badge, project.status.to_lowercase().replace(" ", "-"))}>
-                                    {project.status.clone()}
-                                </span>
-                            </div>
-                            <p class={style::project_description}>{project.description}</p>
-                            <div class={style::tech_stack}>
-                                <span class={style::stack_label}>"Tech Stack:"</span>
-                                <div class={style::tech_tags}>
-                                    {project.tech_stack.into_iter().map(|tech| {
-                                        view! { <span class={style::tech_tag}>{tech}</span> }
-                                    }).collect_view()}
-                                </div>
-                            </div>
-                            <div class={style::contributors_section}>
-                                {if project.contributors_needed {
-                                    Some(view! {
-                                        <div class={style::contributors_needed}>
-                                            <span class={style::need_badge}>
-                                                <Megaphone size=16 style:display="inline" style:margin-right="8px" />
-                                                "Contributors Needed"
-                                            </span>
-                                            <div class={style::skills_needed}>
-                                                {project.skills_needed.into_iter().map(|skill| {
-                                                    view! { <span class={style::skill_tag}>{skill}</span> }
-                                                }).collect_view()}
-                                            </div>
-                                        </div>
-                                    })
-                                } else {
-                                    None
-                                }}
-                            </div>
-                            <div class={style::project_actions}>
-                                <a href={project.github_url} target="_blank" class={format!("{} {}", style::btn, style::btn_secondary)}>
-                                    "View on GitHub"
-                                </a>
-                                <div class={style::action_buttons}>
-                                    {if project.contributors_needed {
-                                        Some(view! {
-                                            <button class={format!("{} {}", style::btn, style::btn_primary)}>"Join Project"</button>
-                                        })
-                                    } else {
-                                        None
-                                    }}
-                                </div>
-                            </div>
+                        <div class={style::projects_showcase}>
+                            {projects.get().into_iter().map(|project| view! { <ProjectCard project=project /> }).collect_view()}
                         </div>
-                    }
-                }).collect_view()}
-            </div>
+                    }.into_any()
+                }
+            }}
+            <Show when=move || next_cursor.get().is_some() && !loading.get()>
+                <div class={style::load_more}>
+                    <button class={style::chip} on:click=load_more>"Load More Projects"</button>
+                </div>
+            </Show>
 
             <section class={style::contribution_guide}>
                 <h2>"How to Contribute"</h2>
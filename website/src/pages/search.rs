@@ -0,0 +1,137 @@
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_router::hooks::{use_navigate, use_query_map};
+use leptos_router::NavigateOptions;
+use lucide_leptos::*;
+use crate::api::client::{search, search_page_path, SearchQuery};
+use shared::{SearchResult, SearchResultKind};
+
+stylance::import_style!(style, "search.module.scss");
+
+/// Results shown on the full search page
+const RESULT_LIMIT: u32 = 50;
+
+const KIND_FILTERS: [(&str, Option<SearchResultKind>); 5] = [
+    ("All", None),
+    ("Blog Posts", Some(SearchResultKind::BlogPost)),
+    ("Issues", Some(SearchResultKind::Issue)),
+    ("Projects", Some(SearchResultKind::Project)),
+    ("Events", Some(SearchResultKind::Event)),
+];
+
+fn parse_kind(value: &str) -> Option<SearchResultKind> {
+    serde_json::from_value(serde_json::Value::String(value.to_string())).ok()
+}
+
+#[component]
+pub fn SearchPage() -> impl IntoView {
+    let query_map = use_query_map();
+    let navigate = use_navigate();
+    let q = Memo::new(move |_| query_map.get().get("q").unwrap_or_default());
+    let kind = Memo::new(move |_| query_map.get().get("type").and_then(|value| parse_kind(&value)));
+
+    let draft = RwSignal::new(q.get_untracked());
+    let results = RwSignal::new(Vec::<SearchResult>::new());
+    let loading = RwSignal::new(false);
+    let error = RwSignal::new(None::<String>);
+
+    // Keep the input in step with back/forward navigation
+    Effect::new(move |_| draft.set(q.get()));
+
+    Effect::new(move |_| {
+        let request = SearchQuery {
+            q: q.get().trim().to_string(),
+            kind: kind.get(),
+            limit: Some(RESULT_LIMIT),
+        };
+        if request.q.is_empty() {
+            results.set(Vec::new());
+            error.set(None);
+            return;
+        }
+        spawn_local(async move {
+            loading.set(true);
+            error.set(None);
+
+            match search(&request).await {
+                // Ignore responses for a query the visitor has already moved on from
+                Ok(found) if found.query.trim() == q.get_untracked().trim() => results.set(found.results),
+                Ok(_) => {}
+                Err(e) => error.set(Some(format!("Search failed: {}", e))),
+            }
+
+            loading.set(false);
+        });
+    });
+
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        navigate(&search_page_path(draft.get_untracked().trim(), kind.get_untracked()), NavigateOptions::default());
+    };
+
+    view! {
+        <div class={style::page_container}>
+            <div class={style::page_header}>
+                <h1>"Search"</h1>
+                <form class={style::search_form} on:submit=on_submit>
+                    <Search size=18 />
+                    <input
+                        type="search"
+                        class={style::search_input}
+                        placeholder="Search posts, issues, projects and events"
+                        aria-label="Search the site"
+                        prop:value=move || draft.get()
+                        on:input=move |ev| draft.set(event_target_value(&ev))
+                    />
+                </form>
+            </div>
+
+            <div class={style::kind_filters}>
+                {KIND_FILTERS.into_iter().map(|(label, filter)| {
+                    view! {
+                        <a
+                            href=move || search_page_path(q.get().trim(), filter)
+                            class=move || if kind.get() == filter {
+                                format!("{} {}", style::kind_filter, style::active)
+                            } else {
+                                style::kind_filter.to_string()
+                            }
+                        >
+                            {label}
+                        </a>
+                    }
+                }).collect_view()}
+            </div>
+
+            {move || {
+                if q.get().trim().is_empty() {
+                    view! {
+                        <p class={style::status}>"Type something to search the blog, open source issues, projects and events."</p>
+                    }.into_any()
+                } else if loading.get() {
+                    view! { <p class={style::status}>"Searching..."</p> }.into_any()
+                } else if let Some(err) = error.get() {
+                    view! { <p class="error">{err}</p> }.into_any()
+                } else if results.with(Vec::is_empty) {
+                    view! {
+                        <p class={style::status}>{format!("No results for \"{}\"", q.get().trim())}</p>
+                    }.into_any()
+                } else {
+                    view! {
+                        <ul class={style::results}>
+                            {results.get().into_iter().map(|result| {
+                                view! {
+                                    <li class={style::result}>
+                                        <span class={style::result_kind}>{result.kind.to_string()}</span>
+                                        <a href={result.url.clone()} class={style::result_title} inner_html={result.title_html.clone()}></a>
+                                        <p class={style::result_snippet} inner_html={result.snippet_html.clone()}></p>
+                                    </li>
+                                }
+                            }).collect_view()}
+                        </ul>
+                    }.into_any()
+                }
+            }}
+        </div>
+    }
+}
@@ -1,159 +1,86 @@
 use leptos::prelude::*;
+use leptos::task::spawn_local;
 use lucide_leptos::*;
+use crate::api::client::{
+    cancel_workshop_registration, fetch_workshop, fetch_workshops, register_for_workshop, WorkshopQuery,
+};
+use shared::{DifficultyLevel, RegistrationStatus, Workshop, WorkshopRegistration, WorkshopRegistrationInput};
 
 stylance::import_style!(style, "workshops.module.scss");
 
-#[derive(Clone)]
-struct Workshop {
-    title: String,
-    date: String,
-    time: String,
-    location: String,
-    level: String,
-    description: String,
-    topics: Vec<String>,
+/// Badge text for a workshop's level; also the `level-*` class suffix
+fn level_label(level: &DifficultyLevel) -> &'static str {
+    match level {
+        DifficultyLevel::Easy => "Beginner",
+        DifficultyLevel::Medium => "Intermediate",
+        DifficultyLevel::Hard => "Advanced",
+    }
+}
+
+fn seats_label(workshop: &Workshop) -> String {
+    match workshop.seats_left() {
+        0 if workshop.waitlisted == 0 => "Full - join the waitlist".to_string(),
+        0 => format!("Full - {} on the waitlist", workshop.waitlisted),
+        1 => "1 seat left".to_string(),
+        left => format!("{} of {} seats left", left, workshop.max_participants),
+    }
 }
 
 #[component]
 pub fn Workshops() -> impl IntoView {
-    let upcoming_workshops = vec![
-        Workshop {
-            title: "Spring 2025 Workshop #1: Rust Fundamentals & CLI Tools".to_string(),
-            date: "February 15, 2025".to_string(),
-            time: "1:00 PM - 5:00 PM".to_string(),
-            location: "Engineering Building, Room 301".to_string(),
-            level: "Beginner".to_string(),
-            description: "Our first workshop this semester! Perfect for newcomers to Rust. Learn the fundamentals and build your first CLI tool to add to your portfolio.".to_string(),
-            topics: vec![
-                "Setting up Rust development environment".to_string(),
-                "Understanding ownership and borrowing".to_string(),
-                "Working with Cargo and crates.io".to_string(),
-                "Building a command-line todo application".to_string(),
-                "Error handling with Result<T, E>".to_string(),
-                "Testing your Rust code".to_string(),
-            ],
-        },
-        Workshop {
-            title: "Spring 2025 Workshop #2: Web Development & Deployment".to_string(),
-            date: "April 12, 2025".to_string(),
-            time: "1:00 PM - 5:00 PM".to_string(),
-            location: "Engineering Building, Room 301".to_string(),
-            level: "Intermediate".to_string(),
-            description: "Our second and final workshop this semester. Build a complete web application with Rust and deploy it to the cloud. Great for your resume!".to_string(),
-            topics: vec![
-                "Web frameworks: Actix-Web vs Axum vs Rocket".to_string(),
-                "RESTful API design and implementation".to_string(),
-                "Database integration with SQLx".to_string(),
-                "Frontend integration (connecting with React/Vue)".to_string(),
-                "Deployment to cloud platforms (AWS/Railway/Shuttle)".to_string(),
-                "Adding your project to your portfolio".to_string(),
-            ],
-        },
-    ];
-
-    let past_workshops = vec![
-        Workshop {
-            title: "Fall 2024 Workshop #2: Game Development with Bevy".to_string(),
-            date: "November 16, 2024".to_string(),
-            time: "1:00 PM - 5:00 PM".to_string(),
-            location: "Engineering Building, Room 301".to_string(),
-            level: "Intermediate".to_string(),
-            description: "Built a complete 2D game using the Bevy engine. Students created portfolio projects they could show to potential employers.".to_string(),
-            topics: vec![
-                "Bevy ECS (Entity Component System)".to_string(),
-                "Asset loading and sprite rendering".to_string(),
-                "Game logic and state management".to_string(),
-                "Input handling and physics".to_string(),
-                "Packaging and distribution".to_string(),
-            ],
-        },
-        Workshop {
-            title: "Fall 2024 Workshop #1: Rust Fundamentals & HTTP Servers".to_string(),
-            date: "September 21, 2024".to_string(),
-            time: "1:00 PM - 5:00 PM".to_string(),
-            location: "Engineering Building, Room 301".to_string(),
-            level: "Beginner".to_string(),
-            description: "Introduction to Rust for beginners, ending with building a simple HTTP server. Great first stepping stone into systems programming.".to_string(),
-            topics: vec![
-                "Rust installation and cargo basics".to_string(),
-                "Ownership, borrowing, and lifetimes".to_string(),
-                "Pattern matching and error handling".to_string(),
-                "Building a simple HTTP server".to_string(),
-                "Introduction to async programming".to_string(),
-            ],
-        },
-        Workshop {
-            title: "Summer 2024 Workshop #2: CLI Tools & Open Source".to_string(),
-            date: "July 20, 2024".to_string(),
-            time: "1:00 PM - 5:00 PM".to_string(),
-            location: "Online (Summer Session)".to_string(),
-            level: "Beginner".to_string(),
-            description: "Built command-line tools and made first open source contributions. Several students got their first PRs merged!".to_string(),
-            topics: vec![
-                "Command-line argument parsing with clap".to_string(),
-                "File I/O and text processing".to_string(),
-                "Finding good first issues".to_string(),
-                "Git workflow for contributions".to_string(),
-                "Code review best practices".to_string(),
-            ],
-        },
-    ];
+    let upcoming_workshops = RwSignal::new(Vec::<Workshop>::new());
+    let past_workshops = RwSignal::new(Vec::<Workshop>::new());
+    let loading = RwSignal::new(true);
+    let error = RwSignal::new(None::<String>);
+
+    Effect::new(move |_| {
+        spawn_local(async move {
+            let upcoming = WorkshopQuery { upcoming: Some(true), ..Default::default() };
+            match fetch_workshops(&upcoming).await {
+                // Newest first from the API; show the next workshop first
+                Ok(page) => upcoming_workshops.set(page.items.into_iter().rev().collect()),
+                Err(e) => error.set(Some(format!("Failed to load workshops: {}", e))),
+            }
+            let past = WorkshopQuery { upcoming: Some(false), ..Default::default() };
+            match fetch_workshops(&past).await {
+                Ok(page) => past_workshops.set(page.items),
+                Err(e) => error.set(Some(format!("Failed to load workshops: {}", e))),
+            }
+            loading.set(false);
+        });
+    });
 
     view! {
         <div class={style::page_container}>
             <div class={style::page_header}>
                 <h1>"Workshops"</h1>
                 <p class={style::page_subtitle}>
-                    "Two comprehensive workshops every semester to help students onboard and master Rust. "
-                    "From complete beginner to building portfolio-worthy projects in just one semester!"
+                    "Two comprehensive workshops every term to help students onboard and master Rust. "
+                    "From complete beginner to building portfolio-worthy projects in just one term!"
                 </p>
             </div>
 
             <section class={style::upcoming_workshops}>
                 <h2>"Upcoming Workshops"</h2>
-                <div class={style::workshops_grid}>
-                    {upcoming_workshops.into_iter().map(|workshop| {
+                {move || {
+                    if loading.get() {
+                        view! { <p class={style::status}>"Loading workshops..."</p> }.into_any()
+                    } else if let Some(err) = error.get() {
+                        view! { <p class="error">{err}</p> }.into_any()
+                    } else if upcoming_workshops.with(Vec::is_empty) {
                         view! {
-                            <div class={format!("{} featured", style::workshop_card)}>
-                                <div class={style::workshop_header}>
-                                    <span class={format!("{} level-{}", style::level_badge, workshop.level.to_lowercase())}>
-                                        {workshop.level.clone()}
-                                    </span>
-                                    <span class={style::workshop_date}>{workshop.date}</span>
-                                </div>
-                                <h3 class={style::workshop_title}>{workshop.title}</h3>
-                                <p class={style::workshop_description}>{workshop.description}</p>
-                                <div class={style::workshop_details}>
-                                    <div class={style::detail_item}>
-                                        <Clock size=16 />
-                                        <span>{workshop.time}</span>
-                                    </div>
-                                    <div class={style::detail_item}>
-                                        <MapPin size=16 />
-                                        <span>{workshop.location}</span>
-                                    </div>
-                                </div>
-                                <div class={style::workshop_topics}>
-                                    {if !workshop.topics.is_empty() {
-                                        Some(view! {
-                                            <>
-                                                <h4>"Topics Covered:"</h4>
-                                                <ul>
-                                                    {workshop.topics.into_iter().map(|topic| {
-                                                        view! { <li>{topic}</li> }
-                                                    }).collect_view()}
-                                                </ul>
-                                            </>
-                                        })
-                                    } else {
-                                        None
-                                    }}
-                                </div>
-                                <button class={format!("{} {} {}", style::btn, style::btn_primary, style::btn_block)}>"Register Now"</button>
+                            <p class={style::status}>"No workshops are scheduled yet. Join our Discord to hear when registration opens."</p>
+                        }.into_any()
+                    } else {
+                        view! {
+                            <div class={style::workshops_grid}>
+                                {upcoming_workshops.get().into_iter().map(|workshop| {
+                                    view! { <UpcomingWorkshop workshop=workshop /> }
+                                }).collect_view()}
                             </div>
-                        }
-                    }).collect_view()}
-                </div>
+                        }.into_any()
+                    }
+                }}
             </section>
 
             <section class={style::workshop_tracks}>
@@ -198,7 +125,8 @@ pub fn Workshops() -> impl IntoView {
             <section class={style::past_workshops}>
                 <h2>"Past Workshops"</h2>
                 <div class={style::past_workshops_list}>
-                    {past_workshops.into_iter().map(|workshop| {
+                    {move || past_workshops.get().into_iter().map(|workshop| {
+                        let date = workshop.date_label();
                         view! {
                             <div class={style::past_workshop_item}>
                                 <div class={style::past_workshop_info}>
@@ -206,8 +134,10 @@ pub fn Workshops() -> impl IntoView {
                                     <p>{workshop.description}</p>
                                 </div>
                                 <div class={style::past_workshop_meta}>
-                                    <span class={style::workshop_date}>{workshop.date}</span>
-                                    <a href="#" class={style::materials_link}>"View Materials →"</a>
+                                    <span class={style::workshop_date}>{date}</span>
+                                    {workshop.materials_url.map(|url| view! {
+                                        <a href={url} target="_blank" class={style::materials_link}>"View Materials →"</a>
+                                    })}
                                 </div>
                             </div>
                         }
@@ -222,4 +152,168 @@ pub fn Workshops() -> impl IntoView {
             </div>
         </div>
     }
-}
\ No newline at end of file
+}
+/// Card for a workshop that is open for registration, with live seat counts
+#[component]
+fn UpcomingWorkshop(workshop: Workshop) -> impl IntoView {
+    let id = workshop.id.clone();
+    let level = level_label(&workshop.level);
+    let date = workshop.date_label();
+    let time = workshop.time_label();
+    let workshop = RwSignal::new(workshop);
+
+    let form_open = RwSignal::new(false);
+    let name = RwSignal::new(String::new());
+    let email = RwSignal::new(String::new());
+    let submitting = RwSignal::new(false);
+    let form_error = RwSignal::new(None::<String>);
+    let registration = RwSignal::new(None::<WorkshopRegistration>);
+
+    let register_id = id.clone();
+    let on_submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        let input = WorkshopRegistrationInput {
+            name: name.get_untracked(),
+            email: email.get_untracked(),
+        };
+        if let Err(details) = input.validate() {
+            form_error.set(Some(details.join(", ")));
+            return;
+        }
+        let id = register_id.clone();
+        spawn_local(async move {
+            submitting.set(true);
+            form_error.set(None);
+            match register_for_workshop(&id, &input).await {
+                Ok(registered) => {
+                    workshop.set(registered.workshop.clone());
+                    registration.set(Some(registered));
+                    form_open.set(false);
+                }
+                Err(e) => form_error.set(Some(e.to_string())),
+            }
+            submitting.set(false);
+        });
+    };
+
+    let cancel = move |_| {
+        let Some(token) = registration.with_untracked(|r| r.as_ref().map(|r| r.token.clone())) else {
+            return;
+        };
+        let id = id.clone();
+        spawn_local(async move {
+            match cancel_workshop_registration(&id, &token).await {
+                Ok(()) => {
+                    registration.set(None);
+                    if let Ok(updated) = fetch_workshop(&id).await {
+                        workshop.set(updated);
+                    }
+                }
+                Err(e) => form_error.set(Some(format!("Could not cancel: {}", e))),
+            }
+        });
+    };
+
+    let topics = workshop.with_untracked(|w| w.topics.clone());
+    let prerequisites = workshop.with_untracked(|w| w.prerequisites.clone());
+
+    view! {
+        <div class={format!("{} featured", style::workshop_card)}>
+            <div class={style::workshop_header}>
+                <span class={format!("{} level-{}", style::level_badge, level.to_lowercase())}>
+                    {level}
+                </span>
+                <span class={style::workshop_date}>{date}</span>
+            </div>
+            <h3 class={style::workshop_title}>{move || workshop.with(|w| w.title.clone())}</h3>
+            <p class={style::workshop_description}>{move || workshop.with(|w| w.description.clone())}</p>
+            <div class={style::workshop_details}>
+                <div class={style::detail_item}>
+                    <Clock size=16 />
+                    <span>{time}</span>
+                </div>
+                <div class={style::detail_item}>
+                    <MapPin size=16 />
+                    <span>{move || workshop.with(|w| w.location.clone())}</span>
+                </div>
+                <div class={style::detail_item}>
+                    <Users size=16 />
+                    <span class={style::seat_count}>{move || workshop.with(seats_label)}</span>
+                </div>
+            </div>
+            <div class={style::workshop_topics}>
+                {(!topics.is_empty()).then(|| view! {
+                    <h4>"Topics Covered:"</h4>
+                    <ul>
+                        {topics.into_iter().map(|topic| view! { <li>{topic}</li> }).collect_view()}
+                    </ul>
+                })}
+                {(!prerequisites.is_empty()).then(|| view! {
+                    <h4>"Before You Come:"</h4>
+                    <ul>
+                        {prerequisites.into_iter().map(|item| view! { <li>{item}</li> }).collect_view()}
+                    </ul>
+                })}
+            </div>
+
+            {move || match registration.get() {
+                Some(registered) => {
+                    let message = match (registered.status, registered.waitlist_position) {
+                        (RegistrationStatus::Registered, _) => format!("You're registered, {}!", registered.name),
+                        (RegistrationStatus::Waitlisted, Some(position)) => {
+                            format!("You're #{} on the waitlist. We'll hold a seat for you if one opens up.", position)
+                        }
+                        (RegistrationStatus::Waitlisted, None) => "You're on the waitlist.".to_string(),
+                    };
+                    view! {
+                        <div class={style::registration_status}>
+                            <p><CircleCheck size=16 />{message}</p>
+                            <p class={style::confirmation_code}>
+                                "Confirmation code: " <code>{registered.token}</code>
+                            </p>
+                            <button class={format!("{} {}", style::btn, style::btn_secondary)} on:click=cancel.clone()>
+                                "Cancel Registration"
+                            </button>
+                        </div>
+                    }.into_any()
+                }
+                None if form_open.get() => view! {
+                    <form class={style::registration_form} on:submit=on_submit.clone()>
+                        <input
+                            type="text"
+                            class={style::form_input}
+                            placeholder="Your name"
+                            aria-label="Your name"
+                            prop:value=move || name.get()
+                            on:input=move |ev| name.set(event_target_value(&ev))
+                        />
+                        <input
+                            type="email"
+                            class={style::form_input}
+                            placeholder="you@example.edu"
+                            aria-label="Email address"
+                            prop:value=move || email.get()
+                            on:input=move |ev| email.set(event_target_value(&ev))
+                        />
+                        {move || form_error.get().map(|message| view! { <p class={style::form_error}>{message}</p> })}
+                        <button
+                            type="submit"
+                            class={format!("{} {} {}", style::btn, style::btn_primary, style::btn_block)}
+                            disabled=move || submitting.get()
+                        >
+                            {move || if submitting.get() { "Registering..." } else { "Confirm Registration" }}
+                        </button>
+                    </form>
+                }.into_any(),
+                None => view! {
+                    <button
+                        class={format!("{} {} {}", style::btn, style::btn_primary, style::btn_block)}
+                        on:click=move |_| form_open.set(true)
+                    >
+                        {move || if workshop.with(|w| w.seats_left() > 0) { "Register Now" } else { "Join Waitlist" }}
+                    </button>
+                }.into_any(),
+            }}
+        </div>
+    }
+}
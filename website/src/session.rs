@@ -0,0 +1,52 @@
+//! Who is signed in, shared with every page through context.
+//!
+//! `App` calls `provide_session` once; pages call `use_session` and read
+//! `user`, which is `None` both while loading and when signed out.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use shared::CurrentUser;
+
+use crate::api::client::{fetch_me, sign_out};
+
+#[derive(Debug, Clone, Copy)]
+pub struct Session {
+    /// The signed-in member
+    pub user: RwSignal<Option<CurrentUser>>,
+    /// Whether the first `/v1/me` check has finished, so pages can avoid
+    /// flashing signed-out UI
+    pub loaded: RwSignal<bool>,
+}
+
+impl Session {
+    /// End the session on the server and forget the member here
+    pub fn sign_out(&self) {
+        let user = self.user;
+        spawn_local(async move {
+            match sign_out().await {
+                Ok(()) => user.set(None),
+                Err(e) => log::warn!("Sign out failed: {}", e),
+            }
+        });
+    }
+}
+
+/// Load the current member and share the session with the rest of the app
+pub fn provide_session() {
+    let session = Session {
+        user: RwSignal::new(None),
+        loaded: RwSignal::new(false),
+    };
+    spawn_local(async move {
+        match fetch_me().await {
+            Ok(user) => session.user.set(user),
+            Err(e) => log::warn!("Failed to load session: {}", e),
+        }
+        session.loaded.set(true);
+    });
+    provide_context(session);
+}
+
+pub fn use_session() -> Session {
+    expect_context::<Session>()
+}